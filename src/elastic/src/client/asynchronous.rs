@@ -4,13 +4,26 @@ use reqwest::r#async::Client as AsyncHttpClient;
 use std::{
     error::Error as StdError,
     sync::Arc,
+    time::Duration,
 };
 use tokio_threadpool::ThreadPool;
 
 use crate::{
-    client::Client,
-    error::Error,
+    client::{
+        index_namer::SharedIndexNamer,
+        AsyncReadOnlyClient,
+        Client,
+        IndexNamer,
+    },
+    error::{
+        self,
+        Error,
+    },
     http::{
+        cache::{
+            ResponseCache,
+            SenderCache,
+        },
         sender::{
             sniffed_nodes::SniffedNodesBuilder,
             AsyncPreSend,
@@ -70,6 +83,9 @@ pub struct AsyncClientBuilder {
     nodes: NodeAddressesBuilder,
     params: SharedFluentBuilder<PreRequestParams>,
     pre_send: Option<Arc<AsyncPreSend>>,
+    index_namer: Option<Arc<dyn IndexNamer>>,
+    max_content_length: Option<usize>,
+    cache: Option<SenderCache>,
 }
 
 impl Default for AsyncClientBuilder {
@@ -96,6 +112,9 @@ impl AsyncClientBuilder {
             params: SharedFluentBuilder::new(),
             nodes: NodeAddressesBuilder::default(),
             pre_send: None,
+            index_namer: None,
+            max_content_length: None,
+            cache: None,
         }
     }
 
@@ -109,6 +128,9 @@ impl AsyncClientBuilder {
             params: SharedFluentBuilder::new().value(params),
             nodes: NodeAddressesBuilder::default(),
             pre_send: None,
+            index_namer: None,
+            max_content_length: None,
+            cache: None,
         }
     }
 
@@ -278,6 +300,84 @@ impl AsyncClientBuilder {
         self
     }
 
+    /**
+    Specify an [`IndexNamer`][IndexNamer] to apply to the indices of all typed requests.
+
+    # Examples
+
+    Prefix the indices for all typed requests with a tenant id:
+
+    ```
+    # use elastic::prelude::*;
+    # use elastic::client::AffixIndexNamer;
+    let builder = AsyncClientBuilder::new()
+        .index_namer(AffixIndexNamer::new().prefix("tenant-a-"));
+    ```
+
+    [IndexNamer]: trait.IndexNamer.html
+    */
+    pub fn index_namer(mut self, namer: impl IndexNamer + 'static) -> Self {
+        self.index_namer = Some(Arc::new(namer));
+
+        self
+    }
+
+    /**
+    Specify the maximum length of a request body in bytes.
+
+    Requests with a body that's larger than `max_content_length` will fail with a [`PayloadTooLarge`][PayloadTooLarge] error
+    before they're sent, instead of being rejected by Elasticsearch.
+    This should match the `http.max_content_length` setting on the cluster, which defaults to `100mb`.
+
+    # Examples
+
+    ```
+    # use elastic::prelude::*;
+    let builder = AsyncClientBuilder::new()
+        .max_content_length(100 * 1024 * 1024);
+    ```
+
+    [PayloadTooLarge]: ../error/struct.PayloadTooLarge.html
+    */
+    pub fn max_content_length(mut self, max_content_length: usize) -> Self {
+        self.max_content_length = Some(max_content_length);
+
+        self
+    }
+
+    /**
+    Cache the responses to idempotent requests for the given time-to-live.
+
+    Successful responses to `GET` requests and searches are cached on their method, path, query and body.
+    A cached response is returned instead of sending a request until it expires.
+    Individual requests can skip the cache with [`bypass_cache`][RequestParams.bypass_cache].
+
+    See the [`cache`][cache-mod] module for more details.
+
+    # Examples
+
+    Cache up to 1000 responses for 30 seconds:
+
+    ```
+    # use elastic::prelude::*;
+    # use elastic::http::cache::LruResponseCache;
+    # use std::time::Duration;
+    let builder = AsyncClientBuilder::new()
+        .response_cache(LruResponseCache::new(1000), Duration::from_secs(30));
+    ```
+
+    [RequestParams.bypass_cache]: ../http/sender/struct.RequestParams.html#method.bypass_cache
+    [cache-mod]: ../http/cache/index.html
+    */
+    pub fn response_cache(mut self, cache: impl ResponseCache + 'static, ttl: Duration) -> Self {
+        self.cache = Some(SenderCache {
+            store: Arc::new(cache),
+            ttl,
+        });
+
+        self
+    }
+
     /**
     Construct an [`AsyncClient`][AsyncClient] from this builder.
 
@@ -291,13 +391,29 @@ impl AsyncClientBuilder {
             http,
             serde_pool: self.serde_pool,
             pre_send: self.pre_send,
+            max_content_length: self.max_content_length,
+            cache: self.cache,
         };
 
         let addresses = self.nodes.build(params, sender.clone());
+        let index_namer = SharedIndexNamer::new(self.index_namer).map_err(error::build)?;
 
         Ok(AsyncClient {
             sender: sender,
             addresses: addresses,
+            index_namer: index_namer,
+            scrolls: Default::default(),
         })
     }
+
+    /**
+    Construct an [`AsyncReadOnlyClient`][AsyncReadOnlyClient] from this builder.
+
+    A read-only client can only send requests that don't write to Elasticsearch.
+
+    [AsyncReadOnlyClient]: type.AsyncReadOnlyClient.html
+    */
+    pub fn build_read_only(self) -> Result<AsyncReadOnlyClient, Error> {
+        self.build().map(Into::into)
+    }
 }
@@ -0,0 +1,395 @@
+/*!
+Transparent naming for the indices targeted by typed requests.
+
+An [`IndexNamer`][IndexNamer] is attached to a client when it's built and is applied to every index name
+that passes through a typed request builder, like `client.document().index(doc)` or `client.index("myindex").create()`.
+This makes it possible to keep tenant-specific naming in one place instead of threading a tenant id through every call.
+
+Raw requests sent with `client.request(...)` aren't affected.
+
+# Examples
+
+Prefix the indices for all typed requests with a tenant id:
+
+```no_run
+# use elastic::prelude::*;
+# use elastic::client::AffixIndexNamer;
+# fn main() { run().unwrap() }
+# fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+let client = SyncClientBuilder::new()
+    .index_namer(AffixIndexNamer::new().prefix("tenant-a-"))
+    .build()?;
+
+// Sends a request to `/tenant-a-myindex`
+let response = client.index("myindex").create().send()?;
+# Ok(())
+# }
+```
+
+[IndexNamer]: trait.IndexNamer.html
+*/
+
+use std::{
+    error::Error as StdError,
+    fmt,
+    sync::Arc,
+};
+
+use crate::params::Index;
+
+/**
+A hook that maps the index name given to a typed request to the index name sent to Elasticsearch.
+
+`IndexNamer` is implemented for closures, so simple naming schemes don't need a separate type.
+*/
+pub trait IndexNamer: Send + Sync {
+    /**
+    Get the name of the index to send a request to.
+
+    The `index` may be a comma-separated list of index names or patterns, where a leading `-` excludes an index.
+
+    # Panics
+
+    The returned name is checked before it's used by a typed request.
+    Typed requests panic if it isn't a valid index name, like a name with uppercase characters
+    or a wildcard that wasn't in the given `index`.
+    */
+    fn name(&self, index: &str) -> String;
+
+    /**
+    Check that this namer will only ever produce valid index names.
+
+    This method is called when a client is built.
+    */
+    fn validate(&self) -> Result<(), InvalidIndexName> {
+        Ok(())
+    }
+}
+
+impl<F> IndexNamer for F
+where
+    F: Fn(&str) -> String + Send + Sync,
+{
+    fn name(&self, index: &str) -> String {
+        self(index)
+    }
+}
+
+/**
+An [`IndexNamer`][IndexNamer] that adds a fixed prefix and suffix to every index name.
+
+Each name in a comma-separated list of indices is named individually,
+and the prefix of an excluded index is added after its leading `-`.
+The special `_all` index is replaced by a wildcard pattern that only matches the named indices,
+so a request can't accidentally target indices outside of its prefix and suffix.
+The prefix and suffix of a [`DateMathIndex`][DateMathIndex] are added inside its angle brackets.
+
+[IndexNamer]: trait.IndexNamer.html
+[DateMathIndex]: ../types/date/struct.DateMathIndex.html
+*/
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AffixIndexNamer {
+    prefix: String,
+    suffix: String,
+}
+
+impl AffixIndexNamer {
+    /** Create a new namer that doesn't change index names. */
+    pub fn new() -> Self {
+        AffixIndexNamer::default()
+    }
+
+    /** Set the prefix added to the start of index names. */
+    pub fn prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    /** Set the suffix added to the end of index names. */
+    pub fn suffix(mut self, suffix: impl Into<String>) -> Self {
+        self.suffix = suffix.into();
+        self
+    }
+
+    fn name_single(&self, index: &str) -> String {
+        // Excluded indices keep their `-` in front of the prefix
+        if let Some(index) = index.strip_prefix('-') {
+            return format!("-{}", self.name_single(index));
+        }
+
+        let index = match index {
+            "_all" => "*",
+            index => index,
+        };
+
+        // Date math index names are encoded as `%3C{name}%3E` in urls
+        let (open, index, close) = match date_math_name(index) {
+            Some(index) => ("%3C", index, "%3E"),
+            None => ("", index, ""),
+        };
+
+        let mut name = String::with_capacity(
+            open.len() + self.prefix.len() + index.len() + self.suffix.len() + close.len(),
+        );
+
+        name.push_str(open);
+        name.push_str(&self.prefix);
+        name.push_str(index);
+        name.push_str(&self.suffix);
+        name.push_str(close);
+
+        name
+    }
+}
+
+impl IndexNamer for AffixIndexNamer {
+    fn name(&self, index: &str) -> String {
+        index
+            .split(',')
+            .map(|index| self.name_single(index))
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    fn validate(&self) -> Result<(), InvalidIndexName> {
+        if self
+            .prefix
+            .starts_with(|c| c == '-' || c == '_' || c == '+')
+        {
+            return Err(InvalidIndexName::new(
+                &self.prefix,
+                "an index prefix can't start with `-`, `_` or `+`",
+            ));
+        }
+
+        validate_affix(&self.prefix)?;
+        validate_affix(&self.suffix)?;
+
+        Ok(())
+    }
+}
+
+fn date_math_name(index: &str) -> Option<&str> {
+    index
+        .strip_prefix("%3C")
+        .and_then(|index| index.strip_suffix("%3E"))
+        .filter(|index| !index.is_empty())
+}
+
+const INVALID_INDEX_CHARS: &'static [char] =
+    &['\\', '/', '*', '?', '"', '<', '>', '|', ' ', ',', '#', ':'];
+
+fn validate_affix(affix: &str) -> Result<(), InvalidIndexName> {
+    validate_chars(affix, affix, false)
+}
+
+fn validate_chars(name: &str, part: &str, allow_wildcards: bool) -> Result<(), InvalidIndexName> {
+    if part.chars().any(|c| c.is_uppercase()) {
+        return Err(InvalidIndexName::new(name, "index names must be lowercase"));
+    }
+
+    if let Some(c) = part
+        .chars()
+        .find(|&c| INVALID_INDEX_CHARS.contains(&c) && !(allow_wildcards && c == '*'))
+    {
+        return Err(InvalidIndexName::new(
+            name,
+            format!("index names can't contain `{}`", c),
+        ));
+    }
+
+    Ok(())
+}
+
+// Check the output of a namer for the given index
+// Wildcards are only allowed if the given index was already a pattern
+fn validate_name(index: &str, name: &str) -> Result<(), InvalidIndexName> {
+    let allow_wildcards = index
+        .split(',')
+        .any(|index| index == "_all" || index.contains('*'));
+
+    for part in name.split(',') {
+        let part = part.strip_prefix('-').unwrap_or(part);
+        let part = date_math_name(part).unwrap_or(part);
+
+        if part.is_empty() {
+            return Err(InvalidIndexName::new(name, "index names can't be empty"));
+        }
+
+        if part != "_all" && part.starts_with(|c| c == '-' || c == '_' || c == '+') {
+            return Err(InvalidIndexName::new(
+                name,
+                "index names can't start with `-`, `_` or `+`",
+            ));
+        }
+
+        validate_chars(name, part, allow_wildcards)?;
+    }
+
+    Ok(())
+}
+
+/** An index namer would produce invalid index names. */
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvalidIndexName {
+    name: String,
+    reason: String,
+}
+
+impl InvalidIndexName {
+    /** Create a new error for the given index name or part of a name. */
+    pub fn new(name: impl Into<String>, reason: impl Into<String>) -> Self {
+        InvalidIndexName {
+            name: name.into(),
+            reason: reason.into(),
+        }
+    }
+}
+
+impl fmt::Display for InvalidIndexName {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid index name `{}`: {}", self.name, self.reason)
+    }
+}
+
+impl StdError for InvalidIndexName {
+    fn description(&self) -> &str {
+        "invalid index name"
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct SharedIndexNamer(Option<Arc<dyn IndexNamer>>);
+
+impl SharedIndexNamer {
+    pub(crate) fn new(namer: Option<Arc<dyn IndexNamer>>) -> Result<Self, InvalidIndexName> {
+        if let Some(ref namer) = namer {
+            namer.validate()?;
+        }
+
+        Ok(SharedIndexNamer(namer))
+    }
+
+    pub(crate) fn name(&self, index: Index<'static>) -> Index<'static> {
+        match self.0 {
+            Some(ref namer) => {
+                let name = namer.name(&index);
+
+                // An invalid name is a bug in the namer, like a closure that doesn't lowercase a tenant id
+                if let Err(e) = validate_name(&index, &name) {
+                    panic!(
+                        "the index namer returned an invalid name for `{}`: {}",
+                        &*index, e
+                    );
+                }
+
+                Index::from(name)
+            }
+            None => index,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn affix_namer_names_index() {
+        let namer = AffixIndexNamer::new().prefix("tenant-").suffix("-v1");
+
+        assert_eq!("tenant-myindex-v1", namer.name("myindex"));
+    }
+
+    #[test]
+    fn affix_namer_names_index_list() {
+        let namer = AffixIndexNamer::new().prefix("tenant-");
+
+        assert_eq!("tenant-idx1,tenant-idx2", namer.name("idx1,idx2"));
+    }
+
+    #[test]
+    fn affix_namer_names_excluded_index() {
+        let namer = AffixIndexNamer::new().prefix("tenant-").suffix("-v1");
+
+        assert_eq!("-tenant-myindex-v1", namer.name("-myindex"));
+        assert_eq!(
+            "tenant-logs-*-v1,-tenant-logs-old-v1",
+            namer.name("logs-*,-logs-old")
+        );
+    }
+
+    #[test]
+    fn affix_namer_names_all() {
+        let namer = AffixIndexNamer::new().prefix("tenant-");
+
+        assert_eq!("tenant-*", namer.name("_all"));
+    }
+
+    #[test]
+    fn affix_namer_names_date_math_index() {
+        use crate::types::date::DateMathIndex;
+
+        let namer = AffixIndexNamer::new().prefix("tenant-").suffix("-v1");
+        let index = Index::from(DateMathIndex::now("logs-").round_day());
+
+        assert_eq!("%3Ctenant-logs-%7Bnow%2Fd%7D-v1%3E", namer.name(&index));
+    }
+
+    #[test]
+    fn affix_namer_rejects_invalid_prefix() {
+        assert!(AffixIndexNamer::new().prefix("Tenant").validate().is_err());
+        assert!(AffixIndexNamer::new().prefix("_tenant").validate().is_err());
+        assert!(AffixIndexNamer::new().prefix("ten*ant").validate().is_err());
+        assert!(AffixIndexNamer::new().suffix("a,b").validate().is_err());
+
+        assert!(AffixIndexNamer::new().prefix("tenant-").validate().is_ok());
+    }
+
+    #[test]
+    fn shared_namer_validates_affix_names() {
+        let namer = SharedIndexNamer::new(Some(Arc::new(AffixIndexNamer::new().prefix("tenant-"))))
+            .unwrap();
+
+        assert_eq!("tenant-*", namer.name(Index::from("_all")));
+        assert_eq!(
+            "tenant-idx1,-tenant-idx2",
+            namer.name(Index::from("idx1,-idx2"))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "index names must be lowercase")]
+    fn shared_namer_rejects_uppercase_name() {
+        let namer =
+            SharedIndexNamer::new(Some(Arc::new(|index: &str| format!("Tenant-{}", index))))
+                .unwrap();
+
+        namer.name(Index::from("myindex"));
+    }
+
+    #[test]
+    #[should_panic(expected = "index names can't contain `*`")]
+    fn shared_namer_rejects_added_wildcard() {
+        let namer =
+            SharedIndexNamer::new(Some(Arc::new(|index: &str| format!("{}-*", index)))).unwrap();
+
+        namer.name(Index::from("myindex"));
+    }
+
+    #[test]
+    fn shared_namer_allows_wildcard_patterns() {
+        let namer =
+            SharedIndexNamer::new(Some(Arc::new(|index: &str| format!("tenant-{}", index))))
+                .unwrap();
+
+        assert_eq!("tenant-logs-*", namer.name(Index::from("logs-*")));
+    }
+
+    #[test]
+    fn shared_namer_without_namer_is_noop() {
+        let namer = SharedIndexNamer::new(None).unwrap();
+
+        assert_eq!("myindex", namer.name(Index::from("myindex")));
+    }
+}
@@ -42,6 +42,36 @@ let client = AsyncClient::builder().build()?;
 Requests on the asynchronous client won't block the current thread.
 Instead a `Future` will be returned immediately that will resolve to a response at a later point.
 
+## Sharing a client between threads
+
+Clients, client builders and request builders are `Send + Sync`.
+A client is cheap to clone and its clones share the same connection pool and nodes,
+so a client can be cloned into each thread of a pool or shared behind an `Arc` without a mutex:
+
+```no_run
+# use elastic::prelude::*;
+# use std::thread;
+# fn main() { run().unwrap() }
+# fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+let client = SyncClient::builder().build()?;
+
+let handles: Vec<_> = (0..4)
+    .map(|_| {
+        let client = client.clone();
+
+        thread::spawn(move || client.ping().send())
+    })
+    .collect();
+
+for handle in handles {
+    handle.join().expect("thread panicked")?;
+}
+# Ok(())
+# }
+```
+
+Raw requests with a `SyncBody` that wraps a reader are only `Send`, because the reader doesn't need to be `Sync`.
+
 ## Sending requests
 
 Requests can be sent with an instance of a client using a builder API:
@@ -138,8 +168,12 @@ They're exposed as methods on the `Client`:
 Client method                                                 | Elasticsearch API                  | Raw request type                                        | Response type
 ------------------------------------------------------------- | ---------------------------------- | ------------------------------------------------------- | ------------------------------------
 [`search`][Client.search]                                     | [Search][docs-search]              | [`SearchRequest`][SearchRequest]                        | [`SearchResponse`][SearchResponse]
+[`aggregate`][Client.aggregate]                               | [Search][docs-search]              | [`SearchRequest`][SearchRequest]                        | [`AggregateResponse`][AggregateResponse]
+[`scroll`][Client.scroll]                                     | [Scroll][docs-scroll]              | [`SearchRequest`][SearchRequest]                        | [`SearchResponse`][SearchResponse]
 [`bulk`][Client.bulk]                                         | [Bulk][docs-bulk]                  | [`BulkRequest`][BulkRequest]                            | [`BulkResponse`][BulkResponse]
+[`copy_index`][Client.copy_index]                             | [Scroll][docs-scroll] and [Bulk][docs-bulk] | [`SearchRequest`][SearchRequest] and [`BulkRequest`][BulkRequest] | [`CopyIndexResponse`][CopyIndexResponse]
 [`ping`][Client.ping]                                         | -                                  | [`PingRequest`][PingRequest]                            | [`PingResponse`][PingResponse]
+[`simulate_pipeline`][Client.simulate_pipeline]               | [Simulate Pipeline][docs-simulate] | [`IngestSimulateRequest`][IngestSimulateRequest]        | [`PipelineSimulateResponse`][PipelineSimulateResponse]
 [`document.search`][Client.document.search]                   | [Search][docs-search]              | [`SearchRequest`][SearchRequest]                        | [`SearchResponse`][SearchResponse]
 [`document.get`][Client.document.get]                         | [Get Document][docs-get]           | [`GetRequest`][GetRequest]                              | [`GetResponse`][GetResponse]
 [`document.index`][Client.document.index]                     | [Index Document][docs-index]       | [`IndexRequest`][IndexRequest]                          | [`IndexResponse`][IndexResponse]
@@ -149,6 +183,7 @@ Client method                                                 | Elasticsearch AP
 [`index.create`][Client.index.create]                         | [Create Index][docs-create-index]  | [`IndicesCreateRequest`][IndicesCreateRequest]          | [`CommandResponse`][CommandResponse]
 [`index.open`][Client.index.open]                             | [Open Index][docs-open-index]      | [`IndicesOpenRequest`][IndicesOpenRequest]              | [`CommandResponse`][CommandResponse]
 [`index.close`][Client.index.close]                           | [Close Index][docs-close-index]    | [`IndicesCloseRequest`][IndicesCloseRequest]            | [`CommandResponse`][CommandResponse]
+[`index.update_analysis`][Client.index.update_analysis]       | [Update Settings][docs-settings]   | [`IndicesPutSettingsRequest`][IndicesPutSettingsRequest]| [`CommandResponse`][CommandResponse]
 [`index.delete`][Client.index.delete]                         | [Delete Index][docs-delete-index]  | [`IndicesDeleteRequest`][IndicesDeleteRequest]          | [`CommandResponse`][CommandResponse]
 [`index.exists`][Client.index.exists]                         | [Index Exists][docs-index-exists]  | [`IndicesExistsRequest`][IndicesExistsRequest]          | [`IndicesExistsResponse`][IndicesExistsResponse]
 
@@ -455,6 +490,7 @@ For more details see the [`responses`][responses-mod] module.
 
 [docs-bulk]: http://www.elastic.co/guide/en/elasticsearch/reference/master/docs-bulk.html
 [docs-search]: http://www.elastic.co/guide/en/elasticsearch/reference/master/search-search.html
+[docs-scroll]: http://www.elastic.co/guide/en/elasticsearch/reference/master/search-request-scroll.html
 [docs-get]: http://www.elastic.co/guide/en/elasticsearch/reference/master/docs-get.html
 [docs-update]: http://www.elastic.co/guide/en/elasticsearch/reference/master/docs-update.html
 [docs-delete]: http://www.elastic.co/guide/en/elasticsearch/reference/master/docs-delete.html
@@ -462,9 +498,11 @@ For more details see the [`responses`][responses-mod] module.
 [docs-mapping]: https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping.html
 [docs-create-index]: https://www.elastic.co/guide/en/elasticsearch/reference/master/indices-create-index.html
 [docs-close-index]: https://www.elastic.co/guide/en/elasticsearch/reference/master/indices-open-close.html
+[docs-settings]: https://www.elastic.co/guide/en/elasticsearch/reference/master/indices-update-settings.html
 [docs-open-index]: https://www.elastic.co/guide/en/elasticsearch/reference/master/indices-open-close.html
 [docs-index-exists]: https://www.elastic.co/guide/en/elasticsearch/reference/master/indices-exists.html
 [docs-delete-index]: https://www.elastic.co/guide/en/elasticsearch/reference/master/indices-delete-index.html
+[docs-simulate]: https://www.elastic.co/guide/en/elasticsearch/reference/master/simulate-pipeline-api.html
 
 [tokio]: https://tokio.rs
 
@@ -477,6 +515,9 @@ For more details see the [`responses`][responses-mod] module.
 [Client.request]: struct.Client.html#method.request
 [Client.bulk]: struct.Client.html#bulk-request
 [Client.search]: struct.Client.html#search-request
+[Client.aggregate]: struct.Client.html#aggregate-request
+[Client.scroll]: struct.Client.html#scroll-request
+[Client.copy_index]: struct.Client.html#copy-index-request
 [Client.document.search]: struct.DocumentClient.html#search-request
 [Client.document.get]: struct.DocumentClient.html#get-document-request
 [Client.document.update]: struct.DocumentClient.html#update-document-request
@@ -486,9 +527,11 @@ For more details see the [`responses`][responses-mod] module.
 [Client.index.create]: struct.IndexClient.html#create-index-request
 [Client.index.open]: struct.IndexClient.html#open-index-request
 [Client.index.close]: struct.IndexClient.html#close-index-request
+[Client.index.update_analysis]: struct.IndexClient.html#update-analysis-request
 [Client.index.delete]: struct.IndexClient.html#delete-index-request
 [Client.index.exists]: struct.IndexClient.html#index-exists-request
 [Client.ping]: struct.Client.html#ping-request
+[Client.simulate_pipeline]: struct.Client.html#simulate-pipeline-request
 
 [RequestBuilder]: requests/struct.RequestBuilder.html
 [RequestBuilder.params]: requests/struct.RequestBuilder.html#method.params
@@ -503,9 +546,11 @@ For more details see the [`responses`][responses-mod] module.
 [IndicesCreateRequest]: requests/endpoints/struct.IndicesCreateRequest.html
 [IndicesOpenRequest]: requests/endpoints/struct.IndicesOpenRequest.html
 [IndicesCloseRequest]: requests/endpoints/struct.IndicesCloseRequest.html
+[IndicesPutSettingsRequest]: requests/endpoints/struct.IndicesPutSettingsRequest.html
 [IndicesDeleteRequest]: requests/endpoints/struct.IndicesDeleteRequest.html
 [IndicesExistsRequest]: requests/endpoints/struct.IndicesExistsRequest.html
 [PingRequest]: requests/endpoints/struct.PingRequest.html
+[IngestSimulateRequest]: requests/endpoints/struct.IngestSimulateRequest.html
 
 [responses-mod]: responses/index.html
 [SyncResponseBuilder]: responses/struct.SyncResponseBuilder.html
@@ -515,13 +560,16 @@ For more details see the [`responses`][responses-mod] module.
 [AsyncResponseBuilder.into_response]: responses/struct.AsyncResponseBuilder.html#method.into_response
 [AsyncResponseBuilder.into_raw]: responses/struct.AsyncResponseBuilder.html#method.into_raw
 [SearchResponse]: responses/struct.SearchResponse.html
+[AggregateResponse]: responses/struct.AggregateResponse.html
 [BulkResponse]: responses/struct.BulkResponse.html
+[CopyIndexResponse]: requests/copy_index/struct.CopyIndexResponse.html
 [GetResponse]: responses/struct.GetResponse.html
 [UpdateResponse]: responses/struct.UpdateResponse.html
 [DeleteResponse]: responses/struct.DeleteResponse.html
 [IndexResponse]: responses/struct.IndexResponse.html
 [IndicesExistsResponse]: responses/struct.IndicesExistsResponse.html
 [PingResponse]: responses/struct.PingResponse.html
+[PipelineSimulateResponse]: responses/struct.PipelineSimulateResponse.html
 [CommandResponse]: responses/struct.CommandResponse.html
 [SyncHttpResponse]: responses/struct.SyncHttpResponse.html
 [AsyncHttpResponse]: responses/struct.AsyncHttpResponse.html
@@ -534,10 +582,19 @@ pub mod requests;
 pub mod responses;
 
 mod asynchronous;
+mod index_namer;
+mod percolator;
+mod read_only;
+mod registry;
+mod routing;
 mod synchronous;
 
 pub use self::{
     asynchronous::*,
+    index_namer::*,
+    percolator::PERCOLATOR_FIELD,
+    read_only::*,
+    routing::*,
     synchronous::*,
 };
 
@@ -547,6 +604,10 @@ pub use crate::http::sender::{
     RequestParams,
 };
 
+use self::{
+    index_namer::SharedIndexNamer,
+    requests::scroll::OpenScrolls,
+};
 use crate::{
     http::sender::{
         NodeAddresses,
@@ -608,6 +669,8 @@ tokio::runtime::current_thread::block_on_all(response_future)?;
 pub struct Client<TSender> {
     sender: TSender,
     addresses: NodeAddresses<TSender>,
+    index_namer: SharedIndexNamer,
+    scrolls: OpenScrolls,
 }
 
 impl<TSender> Client<TSender>
@@ -633,9 +696,29 @@ where
     pub fn index(&self, index: impl Into<Index<'static>>) -> IndexClient<TSender> {
         IndexClient {
             inner: (*self).clone(),
-            index: index.into(),
+            index: self.name_index(index.into()),
         }
     }
+
+    /**
+    Get the name of an index after applying the client's [`IndexNamer`][IndexNamer].
+
+    [IndexNamer]: trait.IndexNamer.html
+    */
+    pub(crate) fn name_index(&self, index: Index<'static>) -> Index<'static> {
+        self.index_namer.name(index)
+    }
+
+    /**
+    Get the index to send a request to when one isn't specified.
+
+    This is `_all` after applying the client's [`IndexNamer`][IndexNamer], so a namer can limit it to the indices it names.
+
+    [IndexNamer]: trait.IndexNamer.html
+    */
+    pub(crate) fn default_index(&self) -> Index<'static> {
+        self.name_index("_all".into())
+    }
 }
 
 /**
@@ -668,17 +751,40 @@ pub mod prelude {
         responses::prelude::*,
         AsyncClient,
         AsyncClientBuilder,
+        AsyncReadOnlyClient,
         PreRequestParams,
         RequestParams,
         SyncClient,
         SyncClientBuilder,
+        SyncReadOnlyClient,
     };
 }
 
 #[cfg(test)]
 mod tests {
+    use serde_json::Value;
+
     use super::*;
-    use crate::tests::*;
+    use crate::{
+        client::{
+            requests::{
+                bulk::Streamed,
+                BulkRequestBuilder,
+                IndexRequestBuilder,
+                ScrollRequestBuilder,
+                SearchRequestBuilder,
+            },
+            responses::BulkResponse,
+        },
+        http::{
+            sender::{
+                AsyncSender,
+                SyncSender,
+            },
+            DefaultBody,
+        },
+        tests::*,
+    };
 
     #[test]
     fn client_is_send_sync() {
@@ -687,5 +793,38 @@ mod tests {
 
         assert_send::<AsyncClient>();
         assert_sync::<AsyncClient>();
+
+        assert_send::<DocumentClient<SyncSender, Value>>();
+        assert_sync::<DocumentClient<SyncSender, Value>>();
+
+        assert_send::<IndexClient<AsyncSender>>();
+        assert_sync::<IndexClient<AsyncSender>>();
+    }
+
+    #[test]
+    fn client_builders_are_send_sync() {
+        assert_send::<SyncClientBuilder>();
+        assert_sync::<SyncClientBuilder>();
+
+        assert_send::<AsyncClientBuilder>();
+        assert_sync::<AsyncClientBuilder>();
+    }
+
+    #[test]
+    fn request_builders_are_send_sync() {
+        assert_send::<SearchRequestBuilder<SyncSender, Value, DefaultBody>>();
+        assert_sync::<SearchRequestBuilder<SyncSender, Value, DefaultBody>>();
+
+        assert_send::<ScrollRequestBuilder<Value>>();
+        assert_sync::<ScrollRequestBuilder<Value>>();
+
+        assert_send::<IndexRequestBuilder<AsyncSender, Value>>();
+        assert_sync::<IndexRequestBuilder<AsyncSender, Value>>();
+
+        assert_send::<BulkRequestBuilder<SyncSender, Vec<u8>, BulkResponse>>();
+        assert_sync::<BulkRequestBuilder<SyncSender, Vec<u8>, BulkResponse>>();
+
+        assert_send::<BulkRequestBuilder<AsyncSender, Streamed<Value>, BulkResponse>>();
+        assert_sync::<BulkRequestBuilder<AsyncSender, Streamed<Value>, BulkResponse>>();
     }
 }
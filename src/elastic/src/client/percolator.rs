@@ -0,0 +1,204 @@
+/*!
+Helpers for registering queries in a [percolator][docs-percolator] index and matching documents against them.
+
+[docs-percolator]: https://www.elastic.co/guide/en/elasticsearch/reference/master/percolator.html
+*/
+
+use serde::Serialize;
+use serde_json::{
+    self,
+    Value,
+};
+
+use crate::{
+    client::{
+        responses::IndexResponse,
+        Client,
+    },
+    error::{
+        self,
+        Error,
+    },
+    http::sender::SyncSender,
+    params::Id,
+    types::document::{
+        StaticIndex,
+        DEFAULT_DOC_TYPE,
+    },
+};
+
+/**
+The name of the field that percolator queries are stored in.
+
+The mapping for the document type must map this field as a `percolator`.
+*/
+pub const PERCOLATOR_FIELD: &str = "query";
+
+/**
+# Percolator
+*/
+impl Client<SyncSender> {
+    /**
+    Store a query in the percolator index for a document type.
+
+    The query is indexed with the given id into the static index of `TDocument`, under the [`query`][PERCOLATOR_FIELD] field.
+    The index must have the mapping for `TDocument` along with a `percolator` field for the query.
+
+    # Examples
+
+    Create a percolator index for a document type and register a query in it:
+
+    ```no_run
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # #[macro_use] extern crate serde_json;
+    # use elastic::prelude::*;
+    # use elastic::types::mappers::{self, FieldBuilder, IndexMapper, MappingBuilder};
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    #[derive(Serialize, Deserialize, ElasticType)]
+    #[elastic(index = "alerts")]
+    struct Article {
+        pub title: String,
+    }
+
+    let mapping = MappingBuilder::from(mappers::model::<Article>()?)
+        .field("query", FieldBuilder::percolator());
+
+    let body = IndexMapper::new().runtime_mapping("_doc", mapping);
+
+    client
+        .index("alerts")
+        .create()
+        .body(serde_json::to_string(&body)?)
+        .send()?;
+
+    client.register_percolator::<Article>(
+        "rust-articles",
+        json!({
+            "match": { "title": "rust" }
+        }),
+    )?;
+    # Ok(())
+    # }
+    ```
+
+    [PERCOLATOR_FIELD]: constant.PERCOLATOR_FIELD.html
+    */
+    pub fn register_percolator<TDocument>(
+        &self,
+        id: impl Into<Id<'static>>,
+        query: impl Serialize,
+    ) -> Result<IndexResponse, Error>
+    where
+        TDocument: StaticIndex,
+    {
+        let query = serde_json::to_value(query).map_err(error::request)?;
+
+        let mut body = serde_json::Map::new();
+        body.insert(PERCOLATOR_FIELD.to_owned(), query);
+
+        self.document::<Value>()
+            .index_raw(TDocument::static_index(), Value::Object(body))
+            .ty(static_ty::<TDocument>())
+            .id(id)
+            .send()
+    }
+
+    /**
+    Find the ids of the queries in the percolator index for a document type that match a document.
+
+    The document doesn't need to be indexed.
+
+    # Examples
+
+    ```no_run
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    # #[derive(Serialize, Deserialize, ElasticType)]
+    # #[elastic(index = "alerts")]
+    # struct Article { pub title: String }
+    let article = Article {
+        title: "Rust 2018 is here".to_owned(),
+    };
+
+    for id in client.match_document(&article)? {
+        println!("matched {}", id);
+    }
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn match_document<TDocument>(&self, doc: &TDocument) -> Result<Vec<Id<'static>>, Error>
+    where
+        TDocument: StaticIndex + Serialize,
+    {
+        let query = match_document_query(doc).map_err(error::request)?;
+
+        let response = self
+            .search::<Value>()
+            .index(TDocument::static_index())
+            .ty(static_ty::<TDocument>())
+            .body(query)
+            .send()?;
+
+        Ok(response.hits().map(|hit| hit.id().to_owned()).collect())
+    }
+}
+
+fn static_ty<TDocument>() -> String
+where
+    TDocument: StaticIndex,
+{
+    TDocument::partial_static_ty()
+        .map(|ty| ty.to_string())
+        .unwrap_or_else(|| DEFAULT_DOC_TYPE.to_owned())
+}
+
+fn match_document_query<TDocument>(doc: &TDocument) -> Result<Value, serde_json::Error>
+where
+    TDocument: Serialize,
+{
+    Ok(json!({
+        "query": {
+            "percolate": {
+                "field": PERCOLATOR_FIELD,
+                "document": serde_json::to_value(doc)?
+            }
+        },
+        "_source": false
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Article {
+        title: &'static str,
+    }
+
+    #[test]
+    fn percolate_query() {
+        let query = match_document_query(&Article { title: "rust" }).unwrap();
+
+        assert_eq!(
+            json!({
+                "query": {
+                    "percolate": {
+                        "field": "query",
+                        "document": { "title": "rust" }
+                    }
+                },
+                "_source": false
+            }),
+            query
+        );
+    }
+}
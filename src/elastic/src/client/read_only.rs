@@ -0,0 +1,415 @@
+/*!
+A client that can only send requests that read from Elasticsearch.
+
+A [`ReadOnlyClient`][ReadOnlyClient] wraps a regular [`Client`][Client] but only exposes request builders that don't write,
+so services that should only ever query a cluster can't issue writes by accident.
+Raw requests are limited to endpoints that implement [`ReadOnlyEndpoint`][ReadOnlyEndpoint].
+
+# Examples
+
+Build a read-only synchronous client and search for documents:
+
+```no_run
+# use serde_json::Value;
+# use elastic::prelude::*;
+# fn main() { run().unwrap() }
+# fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+let client: SyncReadOnlyClient = SyncClientBuilder::new().build_read_only()?;
+
+let response = client.search::<Value>()
+                     .index("myindex")
+                     .send()?;
+# Ok(())
+# }
+```
+
+Write requests can't be created through a read-only client:
+
+```compile_fail
+# use serde_json::Value;
+# use elastic::prelude::*;
+# fn main() { run().unwrap() }
+# fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+let client = SyncClientBuilder::new().build_read_only()?;
+
+client.index("myindex").delete().send()?;
+# Ok(())
+# }
+```
+
+[Client]: struct.Client.html
+[ReadOnlyClient]: struct.ReadOnlyClient.html
+[ReadOnlyEndpoint]: trait.ReadOnlyEndpoint.html
+*/
+
+use serde::de::DeserializeOwned;
+
+use crate::{
+    client::{
+        requests::{
+            GetRequestBuilder,
+            IndexExistsRequestBuilder,
+            PingRequestBuilder,
+            RawRequestBuilder,
+            SearchRequestBuilder,
+            SqlRequestBuilder,
+        },
+        Client,
+        DocumentClient,
+        IndexClient,
+    },
+    endpoints::*,
+    http::{
+        sender::{
+            AsyncSender,
+            Sender,
+            SyncSender,
+        },
+        DefaultBody,
+    },
+    params::{
+        Id,
+        Index,
+    },
+    types::document::{
+        DocumentType,
+        StaticIndex,
+        StaticType,
+    },
+};
+
+/** A synchronous client that can only send read requests. */
+pub type SyncReadOnlyClient = ReadOnlyClient<SyncSender>;
+
+/** An asynchronous client that can only send read requests. */
+pub type AsyncReadOnlyClient = ReadOnlyClient<AsyncSender>;
+
+/**
+A HTTP client for the Elasticsearch REST API that can only send read requests.
+
+Use [`SyncClientBuilder.build_read_only`][SyncClientBuilder.build_read_only] or [`AsyncClientBuilder.build_read_only`][AsyncClientBuilder.build_read_only]
+to build a `ReadOnlyClient`, or call [`Client.read_only`][Client.read_only] on an existing client.
+
+[SyncClientBuilder.build_read_only]: struct.SyncClientBuilder.html#method.build_read_only
+[AsyncClientBuilder.build_read_only]: struct.AsyncClientBuilder.html#method.build_read_only
+[Client.read_only]: struct.Client.html#method.read_only
+*/
+#[derive(Clone)]
+pub struct ReadOnlyClient<TSender> {
+    inner: Client<TSender>,
+}
+
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Get a client that shares this client's configuration but can only send read requests.
+    */
+    pub fn read_only(&self) -> ReadOnlyClient<TSender> {
+        ReadOnlyClient {
+            inner: self.clone(),
+        }
+    }
+}
+
+impl<TSender> From<Client<TSender>> for ReadOnlyClient<TSender> {
+    fn from(inner: Client<TSender>) -> Self {
+        ReadOnlyClient { inner }
+    }
+}
+
+impl<TSender> ReadOnlyClient<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Get a read-only client for working with specific document type.
+    */
+    pub fn document<TDocument>(&self) -> ReadOnlyDocumentClient<TSender, TDocument> {
+        ReadOnlyDocumentClient {
+            inner: self.inner.document(),
+        }
+    }
+
+    /**
+    Get a read-only client for working with a specific index.
+    */
+    pub fn index(&self, index: impl Into<Index<'static>>) -> ReadOnlyIndexClient<TSender> {
+        ReadOnlyIndexClient {
+            inner: self.inner.index(index),
+        }
+    }
+
+    /**
+    Create a [`SearchRequestBuilder`][SearchRequestBuilder] with this `ReadOnlyClient` that can be configured before sending.
+
+    See [`Client.search`][Client.search] for more details.
+
+    [SearchRequestBuilder]: requests/search/type.SearchRequestBuilder.html
+    [Client.search]: struct.Client.html#search-request
+    */
+    pub fn search<TDocument>(&self) -> SearchRequestBuilder<TSender, TDocument, DefaultBody>
+    where
+        TDocument: DeserializeOwned,
+    {
+        self.inner.search()
+    }
+
+    /**
+    Create a [`SqlRequestBuilder`][SqlRequestBuilder] with this `ReadOnlyClient` that can be configured before sending.
+
+    See [`Client.sql`][Client.sql] for more details.
+
+    [SqlRequestBuilder]: requests/sql/type.SqlRequestBuilder.html
+    [Client.sql]: struct.Client.html#sql-request
+    */
+    pub fn sql(&self) -> SqlRequestBuilder<TSender, DefaultBody> {
+        self.inner.sql()
+    }
+
+    /**
+    Create a [`PingRequestBuilder`][PingRequestBuilder] with this `ReadOnlyClient` that can be configured before sending.
+
+    [PingRequestBuilder]: requests/ping/type.PingRequestBuilder.html
+    */
+    pub fn ping(&self) -> PingRequestBuilder<TSender> {
+        self.inner.ping()
+    }
+
+    /**
+    Create a [`RawRequestBuilder`][RawRequestBuilder] for an endpoint that only reads from Elasticsearch.
+
+    See [`Client.request`][Client.request] for more details.
+
+    # Examples
+
+    Get the health of the cluster:
+
+    ```no_run
+    # use serde_json::Value;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build_read_only()?;
+    let health = client.request(CatHealthRequest::new())
+                       .send()?
+                       .into_raw();
+    # Ok(())
+    # }
+    ```
+
+    [RawRequestBuilder]: requests/raw/type.RawRequestBuilder.html
+    [Client.request]: struct.Client.html#raw-request
+    */
+    pub fn request<TEndpoint, TBody>(
+        &self,
+        endpoint: TEndpoint,
+    ) -> RawRequestBuilder<TSender, TEndpoint, TBody>
+    where
+        TEndpoint: ReadOnlyEndpoint + Into<Endpoint<'static, TBody>>,
+        TBody: Into<TSender::Body>,
+    {
+        self.inner.request(endpoint)
+    }
+}
+
+/**
+A [`ReadOnlyClient`] for a specific document type.
+
+[`ReadOnlyClient`]: struct.ReadOnlyClient.html
+*/
+#[derive(Clone)]
+pub struct ReadOnlyDocumentClient<TSender, TDocument> {
+    inner: DocumentClient<TSender, TDocument>,
+}
+
+impl<TSender, TDocument> ReadOnlyDocumentClient<TSender, TDocument>
+where
+    TSender: Sender,
+{
+    /**
+    Create a [`GetRequestBuilder`][GetRequestBuilder] for a document.
+
+    See [`DocumentClient.get`][DocumentClient.get] for more details.
+
+    [GetRequestBuilder]: requests/document_get/type.GetRequestBuilder.html
+    [DocumentClient.get]: struct.DocumentClient.html#get-document-request
+    */
+    pub fn get(self, id: impl Into<Id<'static>>) -> GetRequestBuilder<TSender, TDocument>
+    where
+        TDocument: DeserializeOwned + DocumentType + StaticIndex + StaticType,
+    {
+        self.inner.get(id)
+    }
+
+    /**
+    Create a [`GetRequestBuilder`][GetRequestBuilder] for a document in the given index.
+
+    See [`DocumentClient.get_raw`][DocumentClient.get_raw] for more details.
+
+    [GetRequestBuilder]: requests/document_get/type.GetRequestBuilder.html
+    [DocumentClient.get_raw]: struct.DocumentClient.html#get-document-request
+    */
+    pub fn get_raw(
+        self,
+        index: impl Into<Index<'static>>,
+        id: impl Into<Id<'static>>,
+    ) -> GetRequestBuilder<TSender, TDocument>
+    where
+        TDocument: DeserializeOwned,
+    {
+        self.inner.get_raw(index, id)
+    }
+
+    /**
+    Create a [`SearchRequestBuilder`][SearchRequestBuilder] for documents of this type.
+
+    See [`DocumentClient.search`][DocumentClient.search] for more details.
+
+    [SearchRequestBuilder]: requests/search/type.SearchRequestBuilder.html
+    [DocumentClient.search]: struct.DocumentClient.html#search-request
+    */
+    pub fn search(self) -> SearchRequestBuilder<TSender, TDocument, DefaultBody>
+    where
+        TDocument: DeserializeOwned + DocumentType,
+    {
+        self.inner.search()
+    }
+}
+
+/**
+A [`ReadOnlyClient`] for a specific index.
+
+[`ReadOnlyClient`]: struct.ReadOnlyClient.html
+*/
+#[derive(Clone)]
+pub struct ReadOnlyIndexClient<TSender> {
+    inner: IndexClient<TSender>,
+}
+
+impl<TSender> ReadOnlyIndexClient<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create an [`IndexExistsRequestBuilder`][IndexExistsRequestBuilder] for this index.
+
+    [IndexExistsRequestBuilder]: requests/index_exists/type.IndexExistsRequestBuilder.html
+    */
+    pub fn exists(self) -> IndexExistsRequestBuilder<TSender> {
+        self.inner.exists()
+    }
+}
+
+/**
+A marker for endpoints that only read from Elasticsearch.
+
+Endpoints that implement `ReadOnlyEndpoint` can be sent as raw requests by a [`ReadOnlyClient`][ReadOnlyClient].
+
+[ReadOnlyClient]: struct.ReadOnlyClient.html
+*/
+pub trait ReadOnlyEndpoint {}
+
+macro_rules! read_only_endpoints {
+    ($($endpoint:ident),*) => {
+        $(
+            impl<'a> ReadOnlyEndpoint for $endpoint<'a> {}
+        )*
+    };
+    ($($endpoint:ident<B>),*) => {
+        $(
+            impl<'a, B> ReadOnlyEndpoint for $endpoint<'a, B> {}
+        )*
+    };
+}
+
+read_only_endpoints!(
+    CatAliasesRequest,
+    CatAllocationRequest,
+    CatCountRequest,
+    CatFielddataRequest,
+    CatHealthRequest,
+    CatHelpRequest,
+    CatIndicesRequest,
+    CatMasterRequest,
+    CatNodeattrsRequest,
+    CatNodesRequest,
+    CatPendingTasksRequest,
+    CatPluginsRequest,
+    CatRecoveryRequest,
+    CatRepositoriesRequest,
+    CatSegmentsRequest,
+    CatShardsRequest,
+    CatSnapshotsRequest,
+    CatTasksRequest,
+    CatTemplatesRequest,
+    CatThreadPoolRequest,
+    ClusterHealthRequest,
+    ClusterStateRequest,
+    ClusterStatsRequest,
+    ExistsRequest,
+    GetRequest,
+    GetSourceRequest,
+    IndicesExistsRequest,
+    IndicesExistsTypeRequest,
+    IndicesGetAliasRequest,
+    IndicesGetMappingRequest,
+    IndicesGetRequest,
+    IndicesGetSettingsRequest,
+    IndicesStatsRequest,
+    InfoRequest,
+    NodesInfoRequest,
+    NodesStatsRequest,
+    PingRequest,
+    SimpleSearchRequest
+);
+
+read_only_endpoints!(
+    ClearScrollRequest<B>,
+    CountRequest<B>,
+    ExplainRequest<B>,
+    FieldCapsRequest<B>,
+    MgetRequest<B>,
+    MsearchRequest<B>,
+    ScrollRequest<B>,
+    SearchRequest<B>,
+    SearchShardsRequest<B>,
+    SqlQueryRequest<B>
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::SyncClientBuilder,
+        http::DefaultBody,
+        tests::*,
+    };
+
+    fn assert_read_only<T: ReadOnlyEndpoint>() {}
+
+    #[test]
+    fn client_is_send_sync() {
+        assert_send::<SyncReadOnlyClient>();
+        assert_sync::<SyncReadOnlyClient>();
+
+        assert_send::<AsyncReadOnlyClient>();
+        assert_sync::<AsyncReadOnlyClient>();
+    }
+
+    #[test]
+    fn read_endpoints_are_read_only() {
+        assert_read_only::<SearchRequest<DefaultBody>>();
+        assert_read_only::<CatIndicesRequest>();
+        assert_read_only::<IndicesStatsRequest>();
+    }
+
+    #[test]
+    fn client_from_client() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let _ = client.read_only().ping();
+        let _ = SyncReadOnlyClient::from(client).ping();
+    }
+}
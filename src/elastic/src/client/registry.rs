@@ -0,0 +1,198 @@
+/*!
+Startup routines for the document types in a [`TypeRegistry`][TypeRegistry].
+
+[TypeRegistry]: ../types/mappers/struct.TypeRegistry.html
+*/
+
+use serde_json::{
+    self,
+    Map,
+    Value,
+};
+
+use crate::{
+    client::Client,
+    endpoints::IndicesGetMappingRequest,
+    error::{
+        self,
+        Error,
+    },
+    http::sender::SyncSender,
+    types::mappers::{
+        DynamicMapping,
+        FieldDiff,
+        RegisteredType,
+        TypeRegistry,
+    },
+};
+
+/**
+# Type registry
+*/
+impl Client<SyncSender> {
+    /**
+    Create every index in a [`TypeRegistry`][TypeRegistry] that doesn't exist yet.
+
+    Each index is created with its settings and the mappings for every document type registered in it.
+    Indices that already exist aren't changed.
+    The names of the indices that were created are returned.
+
+    # Examples
+
+    Create the indices for an application when it starts:
+
+    ```no_run
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # use elastic::prelude::*;
+    # use elastic::types::mappers::TypeRegistry;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+    # #[derive(Serialize, Deserialize, ElasticType)]
+    # #[elastic(index = "posts")]
+    # struct Post { }
+    # #[derive(Serialize, Deserialize, ElasticType)]
+    # #[elastic(index = "users")]
+    # struct User { }
+    # let client = SyncClientBuilder::new().build()?;
+    let registry = TypeRegistry::new()
+        .register::<Post>()
+        .register::<User>();
+
+    let created = client.ensure_indices(&registry)?;
+
+    for index in created {
+        println!("created {}", index);
+    }
+    # Ok(())
+    # }
+    ```
+
+    [TypeRegistry]: ../types/mappers/struct.TypeRegistry.html
+    */
+    pub fn ensure_indices(&self, registry: &TypeRegistry) -> Result<Vec<String>, Error> {
+        let mut created = Vec::new();
+
+        for (index, body) in registry.indices() {
+            if self.index(index.clone()).exists().send()?.exists() {
+                continue;
+            }
+
+            let body = serde_json::to_string(&body).map_err(error::request)?;
+
+            self.index(index.clone()).create().body(body).send()?;
+
+            created.push(index);
+        }
+
+        Ok(created)
+    }
+
+    /**
+    Compare the mapping for every document type in a [`TypeRegistry`][TypeRegistry] with the mappings that are live on the cluster.
+
+    Document types whose mapping matches the live mapping aren't included in the result.
+    See [`TypeRegistry.diff`][TypeRegistry.diff] for more details.
+
+    # Examples
+
+    Check the live mappings for an application when it starts:
+
+    ```no_run
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # use elastic::prelude::*;
+    # use elastic::types::mappers::TypeRegistry;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+    # #[derive(Serialize, Deserialize, ElasticType)]
+    # #[elastic(index = "posts")]
+    # struct Post { }
+    # let client = SyncClientBuilder::new().build()?;
+    let registry = TypeRegistry::new().register::<Post>();
+
+    for (ty, fields) in client.diff_mappings(&registry)? {
+        for field in fields {
+            println!("{}: {}", ty.index(), field);
+        }
+    }
+    # Ok(())
+    # }
+    ```
+
+    [TypeRegistry]: ../types/mappers/struct.TypeRegistry.html
+    [TypeRegistry.diff]: ../types/mappers/struct.TypeRegistry.html#method.diff
+    */
+    pub fn diff_mappings<'a>(
+        &self,
+        registry: &'a TypeRegistry,
+    ) -> Result<Vec<(&'a RegisteredType, Vec<FieldDiff>)>, Error> {
+        let indices = registry.indices();
+        if indices.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let named: Vec<(String, String)> = indices
+            .keys()
+            .map(|index| {
+                let named = self.name_index(index.clone().into()).to_string();
+                (index.clone(), named)
+            })
+            .collect();
+
+        let req = IndicesGetMappingRequest::for_index(
+            named
+                .iter()
+                .map(|&(_, ref named)| named.as_str())
+                .collect::<Vec<_>>()
+                .join(","),
+        );
+
+        let live = self
+            .request(req)
+            .params_fluent(|params| params.url_param("ignore_unavailable", true))
+            .send()?
+            .into_response::<Value>()?;
+
+        let live = DynamicMapping::from_value(&unname_indices(live, &named));
+
+        registry.diff(&live).map_err(error::request)
+    }
+}
+
+// Key the live mappings by the index names used in the registry instead of the names sent to Elasticsearch
+fn unname_indices(live: Value, named: &[(String, String)]) -> Value {
+    let mut live = match live {
+        Value::Object(live) => live,
+        _ => return Value::Null,
+    };
+
+    let mut unnamed = Map::new();
+    for &(ref index, ref named) in named {
+        if let Some(mapping) = live.remove(named) {
+            unnamed.insert(index.clone(), mapping);
+        }
+    }
+
+    Value::Object(unnamed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unname_live_indices() {
+        let live = json!({
+            "tenant-posts": { "mappings": {} },
+            "other": { "mappings": {} }
+        });
+
+        let named = vec![("posts".to_owned(), "tenant-posts".to_owned())];
+
+        assert_eq!(
+            json!({ "posts": { "mappings": {} } }),
+            unname_indices(live, &named)
+        );
+    }
+}
@@ -0,0 +1,499 @@
+/*!
+Builders for aggregations-only [search requests][docs-search].
+
+[docs-search]: https://www.elastic.co/guide/en/elasticsearch/reference/master/search-search.html
+*/
+
+use futures::{
+    Future,
+    Poll,
+};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::marker::PhantomData;
+
+use crate::{
+    client::{
+        requests::{
+            raw::RawRequestInner,
+            RequestBuilder,
+        },
+        responses::AggregateResponse,
+        Client,
+        DocumentClient,
+    },
+    endpoints::SearchRequest,
+    error::Error,
+    http::sender::{
+        AsyncSender,
+        Sender,
+        SyncSender,
+    },
+    params::{
+        Index,
+        Type,
+    },
+    types::document::DocumentType,
+};
+
+/**
+An aggregations-only [search request][docs-search] builder that can be configured before sending.
+
+Call [`Client.aggregate`][Client.aggregate] to get an `AggregateRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+The request is always sent with a `size` of `0`, so no hits are returned.
+
+[docs-search]: https://www.elastic.co/guide/en/elasticsearch/reference/master/search-search.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.aggregate]: ../../struct.Client.html#aggregate-request
+*/
+pub type AggregateRequestBuilder<TSender, TAggs> =
+    RequestBuilder<TSender, AggregateRequestInner<TAggs>>;
+
+#[doc(hidden)]
+pub struct AggregateRequestInner<TAggs> {
+    index: Index<'static>,
+    ty: Option<Type<'static>>,
+    query: Value,
+    aggs: Value,
+    _marker: PhantomData<TAggs>,
+}
+
+/**
+# Aggregate request
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create an [`AggregateRequestBuilder`][AggregateRequestBuilder] with this `Client` that can be configured before sending.
+
+    The request only returns aggregation results, which are deserialised into `TAggs`.
+    The hits for the query aren't requested or deserialised.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Sum the `price` field for documents matching a [Query String][docs-querystring] query:
+
+    ```no_run
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate serde_json;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    #[derive(Deserialize)]
+    struct SalesAggs {
+        total_sales: SumAgg,
+    }
+
+    #[derive(Deserialize)]
+    struct SumAgg {
+        value: f64,
+    }
+
+    let response = client.aggregate::<SalesAggs>(
+                             json!({
+                                 "query_string": {
+                                     "query": "a query string"
+                                 }
+                             }),
+                             json!({
+                                 "total_sales": {
+                                     "sum": { "field": "price" }
+                                 }
+                             }),
+                         )
+                         .index("sales")
+                         .send()?;
+
+    println!("total sales: {}", response.aggs().total_sales.value);
+    # Ok(())
+    # }
+    ```
+
+    [AggregateRequestBuilder]: requests/aggregate/type.AggregateRequestBuilder.html
+    [builder-methods]: requests/aggregate/type.AggregateRequestBuilder.html#builder-methods
+    [send-sync]: requests/aggregate/type.AggregateRequestBuilder.html#send-synchronously
+    [send-async]: requests/aggregate/type.AggregateRequestBuilder.html#send-asynchronously
+    [docs-querystring]: https://www.elastic.co/guide/en/elasticsearch/reference/master/query-dsl-query-string-query.html
+    */
+    pub fn aggregate<TAggs>(
+        &self,
+        query: Value,
+        aggs: Value,
+    ) -> AggregateRequestBuilder<TSender, TAggs>
+    where
+        TAggs: DeserializeOwned,
+    {
+        RequestBuilder::initial(
+            self.clone(),
+            AggregateRequestInner::new(self.default_index(), query, aggs),
+        )
+    }
+}
+
+/**
+# Aggregate request
+*/
+impl<TSender, TDocument> DocumentClient<TSender, TDocument>
+where
+    TSender: Sender,
+{
+    /**
+    Create an [`AggregateRequestBuilder`][AggregateRequestBuilder] with this `Client` that can be configured before sending.
+
+    The index and type parameters will be inferred from the document type.
+
+    # Examples
+
+    Count the distinct values of the `tag` field for a [`DocumentType`][documents-mod] called `MyType`:
+
+    ```no_run
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # #[macro_use] extern crate serde_json;
+    # use serde_json::Value;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+    # #[derive(Debug, Serialize, Deserialize, ElasticType)]
+    # struct MyType { }
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.document::<MyType>()
+                         .aggregate::<Value>(
+                             json!({ "match_all": {} }),
+                             json!({
+                                 "tags": {
+                                     "cardinality": { "field": "tag" }
+                                 }
+                             }),
+                         )
+                         .send()?;
+
+    println!("distinct tags: {}", response.aggs()["tags"]["value"]);
+    # Ok(())
+    # }
+    ```
+
+    [AggregateRequestBuilder]: requests/aggregate/type.AggregateRequestBuilder.html
+    [documents-mod]: ../../types/document/index.html
+    */
+    pub fn aggregate<TAggs>(
+        self,
+        query: Value,
+        aggs: Value,
+    ) -> AggregateRequestBuilder<TSender, TAggs>
+    where
+        TDocument: DocumentType,
+        TAggs: DeserializeOwned,
+    {
+        let index = TDocument::partial_static_index()
+            .map(|idx| self.inner.name_index(idx.to_owned()))
+            .unwrap_or_else(|| self.inner.default_index());
+        let ty = TDocument::partial_static_ty().map(|ty| ty.to_owned());
+
+        RequestBuilder::initial(
+            self.inner,
+            AggregateRequestInner {
+                index: index,
+                ty: ty,
+                query: query,
+                aggs: aggs,
+                _marker: PhantomData,
+            },
+        )
+    }
+}
+
+impl<TAggs> AggregateRequestInner<TAggs>
+where
+    TAggs: DeserializeOwned,
+{
+    fn new(index: Index<'static>, query: Value, aggs: Value) -> Self {
+        AggregateRequestInner {
+            index: index,
+            ty: None,
+            query: query,
+            aggs: aggs,
+            _marker: PhantomData,
+        }
+    }
+
+    fn into_request(self) -> SearchRequest<'static, Vec<u8>> {
+        let body = json!({
+            "size": 0,
+            "query": self.query,
+            "aggs": self.aggs
+        })
+        .to_string()
+        .into_bytes();
+
+        match self.ty {
+            Some(ty) => SearchRequest::for_index_ty(self.index, ty, body),
+            None => SearchRequest::for_index(self.index, body),
+        }
+    }
+}
+
+/**
+# Builder methods
+
+Configure an `AggregateRequestBuilder` before sending it.
+*/
+impl<TSender, TAggs> AggregateRequestBuilder<TSender, TAggs>
+where
+    TSender: Sender,
+{
+    /**
+    Set the indices for the aggregate request.
+
+    If no index is specified then `_all` will be used.
+    If the client has an [`IndexNamer`][IndexNamer] then `_all` is named by it too.
+
+    [IndexNamer]: ../../trait.IndexNamer.html
+    */
+    pub fn index(mut self, index: impl Into<Index<'static>>) -> Self {
+        self.inner.index = self.client.name_index(index.into());
+        self
+    }
+
+    /** Set the types for the aggregate request. */
+    pub fn ty(mut self, ty: impl Into<Type<'static>>) -> Self {
+        self.inner.ty = Some(ty.into());
+        self
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl<TAggs> AggregateRequestBuilder<SyncSender, TAggs>
+where
+    TAggs: DeserializeOwned,
+{
+    /**
+    Send an `AggregateRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    ```no_run
+    # #[macro_use] extern crate serde_json;
+    # use serde_json::Value;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client.aggregate::<Value>(
+                             json!({ "match_all": {} }),
+                             json!({ "max_price": { "max": { "field": "price" } } }),
+                         )
+                         .index("myindex")
+                         .send()?;
+
+    println!("max price: {}", response.aggs()["max_price"]["value"]);
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<AggregateResponse<TAggs>, Error> {
+        let req = self.inner.into_request();
+
+        RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+            .send()?
+            .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl<TAggs> AggregateRequestBuilder<AsyncSender, TAggs>
+where
+    TAggs: DeserializeOwned + Send + 'static,
+{
+    /**
+    Send an `AggregateRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised aggregation results.
+
+    # Examples
+
+    ```no_run
+    # #[macro_use] extern crate serde_json;
+    # use futures::Future;
+    # use serde_json::Value;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = AsyncClientBuilder::new().build()?;
+    let future = client.aggregate::<Value>(
+                           json!({ "match_all": {} }),
+                           json!({ "max_price": { "max": { "field": "price" } } }),
+                       )
+                       .index("myindex")
+                       .send();
+
+    future.and_then(|response| {
+        println!("max price: {}", response.aggs()["max_price"]["value"]);
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending<TAggs> {
+        let req = self.inner.into_request();
+
+        let res_future =
+            RequestBuilder::new(self.client, self.params_builder, RawRequestInner::new(req))
+                .send()
+                .and_then(|res| res.into_response());
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub struct Pending<TAggs> {
+    inner: Box<dyn Future<Item = AggregateResponse<TAggs>, Error = Error> + Send>,
+}
+
+impl<TAggs> Pending<TAggs> {
+    fn new<F>(fut: F) -> Self
+    where
+        F: Future<Item = AggregateResponse<TAggs>, Error = Error> + Send + 'static,
+    {
+        Pending {
+            inner: Box::new(fut),
+        }
+    }
+}
+
+impl<TAggs> Future for Pending<TAggs>
+where
+    TAggs: DeserializeOwned + Send + 'static,
+{
+    type Item = AggregateResponse<TAggs>;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{
+        self,
+        Value,
+    };
+
+    use crate::{
+        client::AffixIndexNamer,
+        prelude::*,
+        tests::*,
+    };
+
+    #[test]
+    fn is_send() {
+        assert_send::<super::Pending<Value>>();
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types", index = "test-idx", ty = "test-ty")]
+    struct TestDoc {}
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .aggregate::<Value>(json!({ "match_all": {} }), json!({}))
+            .inner
+            .into_request();
+
+        assert_eq!("/_all/_search", req.url.as_ref());
+    }
+
+    #[test]
+    fn default_request_with_index_namer() {
+        let client = SyncClientBuilder::new()
+            .index_namer(AffixIndexNamer::new().prefix("tenant-"))
+            .build()
+            .unwrap();
+
+        let req = client
+            .aggregate::<Value>(json!({ "match_all": {} }), json!({}))
+            .inner
+            .into_request();
+
+        assert_eq!("/tenant-*/_search", req.url.as_ref());
+    }
+
+    #[test]
+    fn specify_index_and_ty() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .aggregate::<Value>(json!({ "match_all": {} }), json!({}))
+            .index("new-idx")
+            .ty("new-ty")
+            .inner
+            .into_request();
+
+        assert_eq!("/new-idx/new-ty/_search", req.url.as_ref());
+    }
+
+    #[test]
+    fn infer_index_and_ty_from_document() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .document::<TestDoc>()
+            .aggregate::<Value>(json!({ "match_all": {} }), json!({}))
+            .inner
+            .into_request();
+
+        assert_eq!("/test-idx/test-ty/_search", req.url.as_ref());
+    }
+
+    #[test]
+    fn body_has_zero_size() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .aggregate::<Value>(
+                json!({ "term": { "tag": "red" } }),
+                json!({ "prices": { "avg": { "field": "price" } } }),
+            )
+            .inner
+            .into_request();
+
+        let body: Value = serde_json::from_slice(&req.body).unwrap();
+
+        let expected = json!({
+            "size": 0,
+            "query": { "term": { "tag": "red" } },
+            "aggs": { "prices": { "avg": { "field": "price" } } }
+        });
+
+        assert_eq!(expected, body);
+    }
+}
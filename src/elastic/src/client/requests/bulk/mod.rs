@@ -8,6 +8,7 @@ use std::{
     error::Error as StdError,
     fmt,
     marker::PhantomData,
+    sync::Mutex,
     time::Duration,
 };
 
@@ -73,10 +74,12 @@ pub type BulkRequestBuilder<TSender, TBody, TResponse> =
     RequestBuilder<TSender, BulkRequestInner<TBody, TResponse>>;
 
 mod operation;
+mod shard_nodes;
 mod stream;
 
 pub use self::{
     operation::*,
+    shard_nodes::*,
     stream::*,
 };
 
@@ -284,7 +287,7 @@ where
     If an operation doesn't specify an index, then it will default to the supplied value here.
     */
     pub fn index(mut self, index: impl Into<Index<'static>>) -> Self {
-        self.inner.index = Some(index.into());
+        self.inner.index = Some(self.client.name_index(index.into()));
         self
     }
 
@@ -395,7 +398,10 @@ where
         TOperation: Into<BulkOperation<TDocument>>,
         TDocument: Serialize,
     {
-        self.inner.body.with_inner_mut(|b| b.push(op.into()));
+        let client = &self.client;
+        let op = op.into().map_index(|index| client.name_index(index));
+
+        self.inner.body.with_inner_mut(|b| b.push(op));
     }
 
     /**
@@ -487,6 +493,77 @@ impl<TDocument, TResponse> BulkRequestBuilder<AsyncSender, Streamed<TDocument>,
         self
     }
 
+    /**
+    Group operations by the shard they're routed to.
+
+    The `num_shards` is the number of primary shards in the target index.
+    Operations are routed by their `_id` using the same hash as Elasticsearch, so each bulk request emitted only targets a single shard.
+    This reduces the number of shards a coordinating node has to fan a single bulk request out to.
+    Operations without an `_id` are grouped together.
+
+    Each group has its own request buffer of up to `body_size` bytes.
+    All operations in the stream are expected to target indices with the same number of primary shards.
+    The indices are expected to have the default number of routing shards for Elasticsearch `7.0` or later.
+    Requests are still sent to the nodes configured on the client.
+    Use [`group_by_shard_nodes`][group_by_shard_nodes] to send them to the nodes that hold each shard instead.
+
+    # Panics
+
+    This method will panic if `num_shards` is `0`.
+
+    [group_by_shard_nodes]: #method.group_by_shard_nodes
+    */
+    pub fn group_by_shard(self, num_shards: u32) -> Self {
+        self.group_by_shard_nodes(ShardNodes::new(num_shards))
+    }
+
+    /**
+    Group operations by the shard they're routed to and send each group to the node that holds that shard.
+
+    This is like [`group_by_shard`][group_by_shard], but each bulk request emitted is sent directly to the node holding
+    the primary shard its operations are routed to, so a coordinating node doesn't need to forward it.
+    Operations without an `_id`, and shards that don't have a node, are sent to the nodes configured on the client.
+
+    Get the shard nodes for an index with [`Client.shard_nodes`][Client.shard_nodes].
+    They're a snapshot of the cluster, so a new stream should be built with fresh shard nodes if shards are relocated.
+
+    # Examples
+
+    Stream bulk operations to the nodes that hold the shards of an index:
+
+    ```no_run
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+    # #[derive(Serialize, Deserialize, ElasticType)]
+    # struct MyType { }
+    # let client = AsyncClientBuilder::new().sniff_nodes("http://localhost:9200").build()?;
+    let streams = client.shard_nodes("bulk_idx").map(move |shard_nodes| {
+        client
+            .bulk_stream::<MyType>()
+            .index("bulk_idx")
+            .group_by_shard_nodes(shard_nodes)
+            .build()
+    });
+    # Ok(())
+    # }
+    ```
+
+    [group_by_shard]: #method.group_by_shard
+    [Client.shard_nodes]: ../../struct.Client.html#method.shard_nodes
+    */
+    pub fn group_by_shard_nodes(mut self, shard_nodes: ShardNodes) -> Self {
+        self.inner.body.with_inner_mut(|s| {
+            s.shards = Some(shard_nodes);
+            Ok(())
+        });
+
+        self
+    }
+
     /**
     Create a channel for streaming bulk operations.
 
@@ -508,9 +585,10 @@ impl<TDocument, TResponse> BulkRequestBuilder<AsyncSender, Streamed<TDocument>,
 
         let body_size = body.body_size;
         let duration = body.timeout;
+        let shards = body.shards;
 
         let params = self.params_builder.into_value(RequestParams::default);
-        let body = SenderBody::new(body_size);
+        let body = SenderBodies::new(body_size, shards);
         let timeout = Timeout::new(duration);
         let req_template =
             SenderRequestTemplate::new(self.client, params, self.inner.index, self.inner.ty);
@@ -690,6 +768,7 @@ A streaming bulk request body.
 pub struct Streamed<TDocument> {
     body_size: usize,
     timeout: Duration,
+    shards: Option<ShardNodes>,
     _marker: PhantomData<TDocument>,
 }
 
@@ -698,6 +777,7 @@ impl<TDocument> Streamed<TDocument> {
         Streamed {
             body_size: DEFAULT_BODY_SIZE,
             timeout: Duration::from_secs(DEFAULT_TIMEOUT_SECS),
+            shards: None,
             _marker: PhantomData,
         }
     }
@@ -705,26 +785,32 @@ impl<TDocument> Streamed<TDocument> {
 
 struct WrappedBody<T> {
     inner: T,
-    errs: Vec<Error>,
+    // Errors are only `Send`, so they're kept behind a lock to keep the builder `Sync`
+    errs: Mutex<Vec<Error>>,
 }
 
 impl<T> WrappedBody<T> {
     fn new(inner: T) -> Self {
         WrappedBody {
             inner,
-            errs: Vec::new(),
+            errs: Mutex::new(Vec::new()),
         }
     }
 
     fn with_inner_mut(&mut self, f: impl FnOnce(&mut T) -> Result<(), Error>) {
         if let Err(e) = f(&mut self.inner) {
-            self.errs.push(e);
+            self.errs
+                .get_mut()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .push(e);
         }
     }
 
     fn try_into_inner(self) -> Result<T, Error> {
-        if self.errs.len() > 0 {
-            Err(error::request(BulkBodyError(self.errs)))
+        let errs = self.errs.into_inner().unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        if errs.len() > 0 {
+            Err(error::request(BulkBodyError(errs)))
         } else {
             Ok(self.inner)
         }
@@ -921,4 +1007,14 @@ mod tests {
 
         assert!(req.is_err());
     }
+
+    #[test]
+    fn operation_size_hint() {
+        let op = bulk_raw().index(json!({ "field": 1 })).id(1);
+
+        let mut buf = Vec::new();
+        op.write(&mut buf).unwrap();
+
+        assert_eq!(buf.len(), op.serialized_size_hint());
+    }
 }
@@ -12,7 +12,6 @@ use serde::ser::{
     SerializeMap,
     Serializer,
 };
-use serde_json;
 
 use crate::{
     client::requests::common::{
@@ -21,6 +20,7 @@ use crate::{
         Script,
         ScriptBuilder,
     },
+    http::ndjson::NdJsonWriter,
     params::{
         Id,
         Index,
@@ -120,6 +120,15 @@ impl<TValue> BulkOperation<TValue> {
         self.header.id = Some(id.into());
         self
     }
+
+    pub(crate) fn map_index(mut self, f: impl FnOnce(Index<'static>) -> Index<'static>) -> Self {
+        self.header.index = self.header.index.map(f);
+        self
+    }
+
+    pub(crate) fn routing_key(&self) -> Option<&str> {
+        self.header.id.as_ref().map(|id| &**id)
+    }
 }
 
 impl<TDocument> BulkOperation<Doc<TDocument>>
@@ -148,7 +157,7 @@ where
     Bulk operations have a particular line-delimited format.
     This method will write a json header, then a newline, then the document body.
     */
-    pub fn write<W>(&self, mut writer: W) -> io::Result<()>
+    pub fn write<W>(&self, writer: W) -> io::Result<()>
     where
         W: Write,
     {
@@ -177,22 +186,48 @@ where
             }
         }
 
-        serde_json::to_writer(
-            &mut writer,
-            &Header {
-                action: self.action,
-                inner: &self.header,
-            },
-        )?;
-        write!(&mut writer, "\n")?;
+        let mut writer = NdJsonWriter::new(writer);
+
+        writer.write(&Header {
+            action: self.action,
+            inner: &self.header,
+        })?;
 
         if let Some(ref inner) = self.inner {
-            serde_json::to_writer(&mut writer, inner)?;
-            write!(&mut writer, "\n")?;
+            writer.write(inner)?;
         }
 
         Ok(())
     }
+
+    /**
+    Get the number of bytes this operation will take up in a bulk request body.
+
+    This is the size of the header and document written by [`write`][write], so it can be used to
+    check an operation will fit in a request before adding it.
+    If the operation can't be serialised then the size of the data written before the error is returned.
+
+    [write]: #method.write
+    */
+    pub fn serialized_size_hint(&self) -> usize {
+        struct CountWriter(usize);
+
+        impl Write for CountWriter {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.0 += buf.len();
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let mut writer = CountWriter(0);
+        let _ = self.write(&mut writer);
+
+        writer.0
+    }
 }
 
 pub struct BulkDocumentOperation<TDocument> {
@@ -209,7 +244,9 @@ where
         }
     }
 
-    pub fn index(self, doc: TDocument) -> BulkOperation<TDocument> {
+    pub fn index(self, mut doc: TDocument) -> BulkOperation<TDocument> {
+        doc.before_index_hook();
+
         BulkOperation {
             action: Action::Index,
             header: BulkHeader {
@@ -221,7 +258,9 @@ where
         }
     }
 
-    pub fn update(self, doc: TDocument) -> BulkOperation<Doc<TDocument>> {
+    pub fn update(self, mut doc: TDocument) -> BulkOperation<Doc<TDocument>> {
+        doc.before_index_hook();
+
         BulkOperation {
             action: Action::Update,
             header: BulkHeader {
@@ -276,7 +315,9 @@ where
         .script_fluent(builder)
     }
 
-    pub fn create(self, doc: TDocument) -> BulkOperation<TDocument> {
+    pub fn create(self, mut doc: TDocument) -> BulkOperation<TDocument> {
+        doc.before_index_hook();
+
         BulkOperation {
             action: Action::Create,
             header: BulkHeader {
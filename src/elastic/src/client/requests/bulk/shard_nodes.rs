@@ -0,0 +1,402 @@
+use std::collections::BTreeMap;
+
+use futures::{
+    future,
+    Future,
+};
+use url::Url;
+
+use super::Pending;
+use crate::{
+    client::{
+        default_routing_num_shards,
+        routing_shard_with_routing_num_shards,
+        Client,
+    },
+    endpoints::{
+        ClusterStateRequest,
+        NodesInfoRequest,
+    },
+    error::{
+        self,
+        Error,
+    },
+    http::{
+        receiver::IsOkOnSuccess,
+        sender::{
+            AsyncSender,
+            NodeAddress,
+        },
+    },
+    params::Index,
+};
+
+/**
+The nodes that hold the primary shards of an index.
+
+Shard nodes can be given to a [bulk stream][group_by_shard_nodes] so each batch of operations is sent
+directly to the node that holds the primary shard the operations are routed to.
+Get the shard nodes for an index from the cluster with [`Client.shard_nodes`][Client.shard_nodes], or build them manually.
+
+[group_by_shard_nodes]: type.BulkRequestBuilder.html#method.group_by_shard_nodes
+[Client.shard_nodes]: ../../struct.Client.html#method.shard_nodes
+*/
+#[derive(Clone)]
+pub struct ShardNodes {
+    num_shards: u32,
+    routing_num_shards: u32,
+    nodes: BTreeMap<u32, NodeAddress>,
+}
+
+impl ShardNodes {
+    /**
+    Create shard nodes for an index with `num_shards` primary shards.
+
+    The index is assumed to have the [default number of routing shards][default_routing_num_shards].
+    No shards have a node until they're added with [`node`][node].
+
+    # Panics
+
+    This method will panic if `num_shards` is `0`.
+
+    [default_routing_num_shards]: ../../fn.default_routing_num_shards.html
+    [node]: #method.node
+    */
+    pub fn new(num_shards: u32) -> Self {
+        ShardNodes {
+            num_shards,
+            routing_num_shards: default_routing_num_shards(num_shards),
+            nodes: BTreeMap::new(),
+        }
+    }
+
+    /**
+    Set the number of routing shards for the index.
+
+    This is the value of the index's `index.number_of_routing_shards` setting,
+    or the number of primary shards for indices created before Elasticsearch `7.0`.
+
+    # Panics
+
+    This method will panic if `routing_num_shards` isn't a multiple of the number of primary shards.
+    */
+    pub fn routing_num_shards(mut self, routing_num_shards: u32) -> Self {
+        assert!(
+            routing_num_shards % self.num_shards == 0,
+            "the number of routing shards must be a multiple of the number of shards"
+        );
+
+        self.routing_num_shards = routing_num_shards;
+        self
+    }
+
+    /** Set the address of the node that holds a primary shard. */
+    pub fn node(mut self, shard: u32, address: impl Into<NodeAddress>) -> Self {
+        self.nodes.insert(shard, address.into());
+        self
+    }
+
+    /** The number of primary shards in the index. */
+    pub fn num_shards(&self) -> u32 {
+        self.num_shards
+    }
+
+    /** Get the address of the node that holds a primary shard. */
+    pub fn get(&self, shard: u32) -> Option<&NodeAddress> {
+        self.nodes.get(&shard)
+    }
+
+    /** Get the primary shard that a document with the given `routing` value is routed to. */
+    pub fn shard(&self, routing: &str) -> u32 {
+        routing_shard_with_routing_num_shards(routing, self.num_shards, self.routing_num_shards)
+    }
+
+    fn from_cluster(
+        cluster_state: ClusterStateResponse,
+        nodes_info: NodesInfoResponse,
+        scheme: &str,
+    ) -> Result<Self, Error> {
+        let mut indices = cluster_state.routing_table.indices.into_iter();
+
+        let (index, routing_table) = match (indices.next(), indices.next()) {
+            (Some(index), None) => index,
+            (None, _) => {
+                return Err(error::request(error::message(
+                    "the index to get shard nodes for doesn't exist",
+                )))
+            }
+            (Some(_), Some(_)) => {
+                return Err(error::request(error::message(
+                    "shard nodes can only be found for a single index",
+                )))
+            }
+        };
+
+        let num_shards = routing_table.shards.len() as u32;
+        if num_shards == 0 {
+            return Err(error::request(error::message(format!(
+                "the index `{}` doesn't have any shards",
+                index
+            ))));
+        }
+
+        // Indices created before `6.1` don't include their number of routing shards
+        let routing_num_shards = cluster_state
+            .metadata
+            .indices
+            .get(&index)
+            .and_then(|metadata| metadata.routing_num_shards)
+            .unwrap_or(num_shards);
+
+        let mut shard_nodes = ShardNodes::new(num_shards).routing_num_shards(routing_num_shards);
+
+        let primaries = routing_table
+            .shards
+            .into_iter()
+            .flat_map(|(_, copies)| copies)
+            .filter(|copy| copy.primary);
+
+        for primary in primaries {
+            let address = primary
+                .node
+                .as_ref()
+                .and_then(|node| nodes_info.nodes.get(node))
+                .and_then(|node| node.http.as_ref())
+                .and_then(|http| http.publish_address.as_ref());
+
+            if let Some(address) = address {
+                shard_nodes = shard_nodes.node(primary.shard, format!("{}://{}", scheme, address));
+            }
+        }
+
+        Ok(shard_nodes)
+    }
+}
+
+/**
+# Shard nodes request
+*/
+impl Client<AsyncSender> {
+    /**
+    Get the nodes that hold the primary shards of an index.
+
+    The addresses of the nodes are their `http.publish_address`, which is also used for sniffing.
+    Because those addresses need to be reachable by the client, shard nodes can only be requested
+    by a client that was built with [`sniff_nodes`][sniff_nodes].
+
+    The returned nodes are a snapshot of the cluster, so they should be requested again if shards are relocated.
+
+    # Examples
+
+    Get the node that holds the primary shard a document with an id of `1` is routed to:
+
+    ```no_run
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = AsyncClientBuilder::new().sniff_nodes("http://localhost:9200").build()?;
+    let future = client.shard_nodes("myindex").map(|shard_nodes| {
+        let shard = shard_nodes.shard("1");
+
+        if let Some(node) = shard_nodes.get(shard) {
+            println!("shard {} is on {}", shard, node.as_ref());
+        }
+    });
+    # Ok(())
+    # }
+    ```
+
+    [sniff_nodes]: ../struct.AsyncClientBuilder.html#method.sniff_nodes
+    */
+    pub fn shard_nodes(&self, index: impl Into<Index<'static>>) -> Pending<ShardNodes> {
+        let scheme = match self.addresses.sniffed_base_url().map(Url::parse) {
+            Some(Ok(url)) => url.scheme().to_owned(),
+            Some(Err(e)) => return Pending::new(future::err(error::request(e))),
+            None => {
+                return Pending::new(future::err(error::request(error::message(
+                    "shard nodes can only be requested by a client that sniffs nodes",
+                ))))
+            }
+        };
+
+        let index = self.name_index(index.into());
+
+        let cluster_state = self
+            .request(ClusterStateRequest::for_metric_index(
+                "metadata,routing_table",
+                index,
+            ))
+            .send()
+            .and_then(|res| res.into_response::<ClusterStateResponse>());
+
+        let nodes_info = self
+            .request(NodesInfoRequest::for_metric("http"))
+            .send()
+            .and_then(|res| res.into_response::<NodesInfoResponse>());
+
+        Pending::new(
+            cluster_state
+                .join(nodes_info)
+                .and_then(move |(cluster_state, nodes_info)| {
+                    ShardNodes::from_cluster(cluster_state, nodes_info, &scheme)
+                }),
+        )
+    }
+}
+
+#[derive(Deserialize)]
+struct ClusterStateResponse {
+    metadata: ClusterStateMetadata,
+    routing_table: ClusterStateRoutingTable,
+}
+
+#[derive(Deserialize)]
+struct ClusterStateMetadata {
+    indices: BTreeMap<String, IndexMetadata>,
+}
+
+#[derive(Deserialize)]
+struct IndexMetadata {
+    routing_num_shards: Option<u32>,
+}
+
+#[derive(Deserialize)]
+struct ClusterStateRoutingTable {
+    indices: BTreeMap<String, IndexRoutingTable>,
+}
+
+#[derive(Deserialize)]
+struct IndexRoutingTable {
+    shards: BTreeMap<String, Vec<ShardRouting>>,
+}
+
+#[derive(Deserialize)]
+struct ShardRouting {
+    shard: u32,
+    primary: bool,
+    node: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct NodesInfoResponse {
+    nodes: BTreeMap<String, NodeInfo>,
+}
+
+#[derive(Deserialize)]
+struct NodeInfo {
+    http: Option<NodeHttp>,
+}
+
+#[derive(Deserialize)]
+struct NodeHttp {
+    publish_address: Option<String>,
+}
+
+impl IsOkOnSuccess for ClusterStateResponse {}
+impl IsOkOnSuccess for NodesInfoResponse {}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+
+    use super::*;
+
+    fn nodes_info() -> NodesInfoResponse {
+        serde_json::from_value(json!({
+            "nodes": {
+                "node-a": { "http": { "publish_address": "10.0.0.1:9200" } },
+                "node-b": { "http": { "publish_address": "10.0.0.2:9200" } }
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn shard_nodes_from_cluster() {
+        let cluster_state: ClusterStateResponse = serde_json::from_value(json!({
+            "metadata": {
+                "indices": {
+                    "myindex": { "routing_num_shards": 640 }
+                }
+            },
+            "routing_table": {
+                "indices": {
+                    "myindex": {
+                        "shards": {
+                            "0": [
+                                { "shard": 0, "primary": true, "node": "node-a" },
+                                { "shard": 0, "primary": false, "node": "node-b" }
+                            ],
+                            "1": [
+                                { "shard": 1, "primary": false, "node": "node-a" },
+                                { "shard": 1, "primary": true, "node": "node-b" }
+                            ],
+                            "2": [
+                                { "shard": 2, "primary": true, "node": null }
+                            ],
+                            "3": [{ "shard": 3, "primary": true, "node": "node-a" }],
+                            "4": [{ "shard": 4, "primary": true, "node": "node-b" }]
+                        }
+                    }
+                }
+            }
+        }))
+        .unwrap();
+
+        let shard_nodes = ShardNodes::from_cluster(cluster_state, nodes_info(), "http").unwrap();
+
+        let address = |shard| shard_nodes.get(shard).map(|node| node.as_ref().to_owned());
+
+        assert_eq!(5, shard_nodes.num_shards());
+        assert_eq!(Some("http://10.0.0.1:9200".to_owned()), address(0));
+        assert_eq!(Some("http://10.0.0.2:9200".to_owned()), address(1));
+        assert_eq!(None, address(2));
+        assert_eq!(4, shard_nodes.shard("1"));
+    }
+
+    #[test]
+    fn shard_nodes_from_cluster_before_routing_shards() {
+        let cluster_state: ClusterStateResponse = serde_json::from_value(json!({
+            "metadata": {
+                "indices": {
+                    "myindex": { }
+                }
+            },
+            "routing_table": {
+                "indices": {
+                    "myindex": {
+                        "shards": {
+                            "0": [{ "shard": 0, "primary": true, "node": "node-a" }],
+                            "1": [{ "shard": 1, "primary": true, "node": "node-a" }],
+                            "2": [{ "shard": 2, "primary": true, "node": "node-a" }],
+                            "3": [{ "shard": 3, "primary": true, "node": "node-a" }],
+                            "4": [{ "shard": 4, "primary": true, "node": "node-a" }]
+                        }
+                    }
+                }
+            }
+        }))
+        .unwrap();
+
+        let shard_nodes = ShardNodes::from_cluster(cluster_state, nodes_info(), "http").unwrap();
+
+        assert_eq!(3, shard_nodes.shard("1"));
+    }
+
+    #[test]
+    fn shard_nodes_from_cluster_with_multiple_indices() {
+        let cluster_state: ClusterStateResponse = serde_json::from_value(json!({
+            "metadata": { "indices": { } },
+            "routing_table": {
+                "indices": {
+                    "myindex-1": { "shards": { } },
+                    "myindex-2": { "shards": { } }
+                }
+            }
+        }))
+        .unwrap();
+
+        assert!(ShardNodes::from_cluster(cluster_state, nodes_info(), "http").is_err());
+    }
+}
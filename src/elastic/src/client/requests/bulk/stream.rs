@@ -1,4 +1,5 @@
 use std::{
+    collections::BTreeMap,
     error::Error as StdError,
     fmt,
     io,
@@ -39,6 +40,7 @@ use super::{
     BulkRequestBuilder,
     BulkRequestInner,
     Pending,
+    ShardNodes,
     WrappedBody,
 };
 use crate::{
@@ -53,7 +55,10 @@ use crate::{
     },
     http::{
         receiver::IsOk,
-        sender::AsyncSender,
+        sender::{
+            AsyncSender,
+            NodeAddress,
+        },
     },
     params::{
         Index,
@@ -71,7 +76,7 @@ pub struct BulkSender<TDocument, TResponse> {
     req_template: SenderRequestTemplate<TResponse>,
     in_flight: BulkSenderInFlight<TResponse>,
     timeout: Timeout,
-    body: SenderBody,
+    body: SenderBodies,
     _marker: PhantomData<TDocument>,
 }
 
@@ -79,7 +84,7 @@ impl<TDocument, TResponse> BulkSender<TDocument, TResponse> {
     pub(super) fn new(
         req_template: SenderRequestTemplate<TResponse>,
         timeout: Timeout,
-        body: SenderBody,
+        body: SenderBodies,
     ) -> (Self, BulkReceiver<TResponse>) {
         let (tx, rx) = channel::bounded(1);
 
@@ -125,10 +130,19 @@ impl<TResponse> SenderRequestTemplate<TResponse> {
         }
     }
 
-    fn to_request(&self, body: Vec<u8>) -> BulkRequestBuilder<AsyncSender, Vec<u8>, TResponse> {
+    fn to_request(
+        &self,
+        node: Option<NodeAddress>,
+        body: Vec<u8>,
+    ) -> BulkRequestBuilder<AsyncSender, Vec<u8>, TResponse> {
+        let params = match node {
+            Some(node) => self.params.clone().base_url(node),
+            None => self.params.clone(),
+        };
+
         RequestBuilder::new(
             self.client.clone(),
-            FluentBuilder::new().value(self.params.clone()),
+            FluentBuilder::new().value(params),
             BulkRequestInner::<Vec<u8>, TResponse> {
                 index: self.index.clone(),
                 ty: self.ty.clone(),
@@ -258,6 +272,107 @@ impl SenderBody {
     }
 }
 
+/**
+A set of request bodies that bulk operations are grouped into.
+
+If shards are given then operations are grouped by the shard their `_id` is routed to,
+so each bulk request only targets a single shard.
+Operations without an `_id` are grouped together.
+*/
+pub(super) struct SenderBodies {
+    size: usize,
+    shards: Option<ShardNodes>,
+    bodies: BTreeMap<u32, SenderBody>,
+    flushing: bool,
+}
+
+impl SenderBodies {
+    pub(super) fn new(size: usize, shards: Option<ShardNodes>) -> Self {
+        SenderBodies {
+            size,
+            shards,
+            bodies: BTreeMap::new(),
+            flushing: false,
+        }
+    }
+
+    fn group<TDocument>(&self, op: &BulkOperation<TDocument>) -> u32 {
+        match (self.shards.as_ref(), op.routing_key()) {
+            (Some(shards), Some(routing)) => shards.shard(routing) + 1,
+            _ => 0,
+        }
+    }
+
+    /** The address of the node that holds the shard for a group of operations, if it's known. */
+    fn node(&self, group: u32) -> Option<NodeAddress> {
+        match (self.shards.as_ref(), group) {
+            (Some(shards), group) if group > 0 => shards.get(group - 1).cloned(),
+            _ => None,
+        }
+    }
+
+    fn has_capacity_for<TDocument>(&self, op: &BulkOperation<TDocument>) -> bool {
+        self.bodies
+            .get(&self.group(op))
+            .map(|body| body.has_capacity())
+            .unwrap_or(true)
+    }
+
+    fn push<TDocument>(&mut self, op: BulkOperation<TDocument>) -> Result<(), io::Error>
+    where
+        TDocument: Serialize,
+    {
+        let group = self.group(&op);
+        let size = self.size;
+
+        self.bodies
+            .entry(group)
+            .or_insert_with(|| SenderBody::new(size))
+            .push(op)
+    }
+
+    /** Send all non-empty bodies, even if they aren't full. */
+    fn flush(&mut self) {
+        self.flushing = !self.is_empty();
+    }
+
+    /** Whether a body is ready to send. */
+    fn is_ready(&self) -> bool {
+        self.flushing || self.is_full()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.bodies.values().all(|body| body.is_empty())
+    }
+
+    fn is_full(&self) -> bool {
+        self.bodies.values().any(|body| body.is_full())
+    }
+
+    fn take(&mut self) -> (Option<NodeAddress>, BytesMut) {
+        let group = self
+            .bodies
+            .iter()
+            .find(|(_, body)| body.is_full())
+            .or_else(|| self.bodies.iter().find(|(_, body)| !body.is_empty()))
+            .map(|(group, _)| *group)
+            .unwrap_or(0);
+
+        let size = self.size;
+        let body = self
+            .bodies
+            .entry(group)
+            .or_insert_with(|| SenderBody::new(size))
+            .take();
+
+        if self.is_empty() {
+            self.flushing = false;
+        }
+
+        (self.node(group), body)
+    }
+}
+
 impl<TDocument, TResponse> Sink for BulkSender<TDocument, TResponse>
 where
     TDocument: Serialize + Send + 'static,
@@ -283,7 +398,11 @@ where
             Err(e) => return Err(error::request(e)),
         }
 
-        if self.body.has_capacity() {
+        if self.body.has_capacity_for(&item) {
+            // Operations are only named once they're pushed because unsent ones are handed back to the caller
+            let client = &self.req_template.client;
+            let item = item.map_index(|index| client.name_index(index));
+
             self.body.push(item).map_err(error::request)?;
             Ok(AsyncSink::Ready)
         } else {
@@ -299,14 +418,17 @@ where
             // The `Sender` is ready to send another request
             BulkSenderInFlight::ReadyToSend => {
                 match self.timeout.poll() {
-                    // If the timeout hasn't expired and the body isn't full then we're not ready
-                    Ok(Async::NotReady) if !self.body.is_full() && !self.body.is_empty() => {
+                    // If the timeout hasn't expired and no body is ready then we're not ready
+                    Ok(Async::NotReady) if !self.body.is_ready() && !self.body.is_empty() => {
                         return Ok(Async::NotReady);
                     }
                     // Continue
                     Ok(Async::NotReady) => (),
-                    // Restart the expired timer
-                    Ok(Async::Ready(())) => self.timeout.restart(),
+                    // Restart the expired timer and send all pending bodies
+                    Ok(Async::Ready(())) => {
+                        self.timeout.restart();
+                        self.body.flush();
+                    }
                     Err(e) => return Err(error::request(e)),
                 }
 
@@ -316,9 +438,9 @@ where
 
                 debug!("Elasticsearch Bulk Stream: sending a bulk request");
 
-                let body = self.body.take();
+                let (node, body) = self.body.take();
 
-                let req = self.req_template.to_request(body.to_vec());
+                let req = self.req_template.to_request(node, body.to_vec());
                 let pending = req.send();
 
                 BulkSenderInFlight::Pending(pending)
@@ -439,3 +561,71 @@ impl StdError for Disconnected {
         "disconnected"
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::requests::bulk::bulk_raw;
+
+    #[test]
+    fn bodies_are_grouped_by_shard() {
+        let mut body = SenderBodies::new(1024, Some(ShardNodes::new(5)));
+
+        body.push(bulk_raw().delete().id("1")).unwrap();
+        body.push(bulk_raw().delete().id("2")).unwrap();
+        body.push(bulk_raw().delete().id("1")).unwrap();
+        body.push(bulk_raw().delete()).unwrap();
+
+        let groups: Vec<_> = body.bodies.keys().cloned().collect();
+
+        assert_eq!(vec![0, 4, 5], groups);
+    }
+
+    #[test]
+    fn bodies_are_taken_with_shard_nodes() {
+        let shards = ShardNodes::new(5).node(4, "http://node-a:9200");
+        let mut body = SenderBodies::new(1024, Some(shards));
+
+        body.push(bulk_raw().delete().id("1")).unwrap();
+        body.push(bulk_raw().delete().id("2")).unwrap();
+
+        body.flush();
+
+        let nodes: Vec<_> = vec![body.take().0, body.take().0]
+            .into_iter()
+            .map(|node| node.map(|node| node.as_ref().to_owned()))
+            .collect();
+
+        assert_eq!(vec![None, Some("http://node-a:9200".to_owned())], nodes);
+    }
+
+    #[test]
+    fn bodies_are_not_grouped_by_default() {
+        let mut body = SenderBodies::new(1024, None);
+
+        body.push(bulk_raw().delete().id("1")).unwrap();
+        body.push(bulk_raw().delete().id("2")).unwrap();
+
+        assert_eq!(1, body.bodies.len());
+    }
+
+    #[test]
+    fn flush_takes_all_bodies() {
+        let mut body = SenderBodies::new(1024, Some(ShardNodes::new(5)));
+
+        body.push(bulk_raw().delete().id("1")).unwrap();
+        body.push(bulk_raw().delete().id("2")).unwrap();
+
+        assert!(!body.is_ready());
+
+        body.flush();
+
+        assert!(body.is_ready());
+        body.take();
+        assert!(body.is_ready());
+        body.take();
+
+        assert!(body.is_empty());
+        assert!(!body.is_ready());
+    }
+}
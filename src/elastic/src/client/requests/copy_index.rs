@@ -0,0 +1,547 @@
+/*!
+Builders for copying the documents in an index from one cluster to another.
+
+Documents are [scrolled][docs-scroll] out of a source cluster and [bulk][docs-bulk] indexed into a destination cluster.
+This is useful when a [reindex from remote][docs-reindex-remote] isn't possible, like when the source cluster isn't whitelisted by the destination.
+
+[docs-scroll]: https://www.elastic.co/guide/en/elasticsearch/reference/master/search-request-scroll.html
+[docs-bulk]: https://www.elastic.co/guide/en/elasticsearch/reference/master/docs-bulk.html
+[docs-reindex-remote]: https://www.elastic.co/guide/en/elasticsearch/reference/master/docs-reindex.html#reindex-from-remote
+*/
+
+use serde_json::Value;
+use std::{
+    error::Error as StdError,
+    fmt,
+    mem,
+    sync::{
+        mpsc,
+        Arc,
+        Mutex,
+    },
+    thread,
+    time::Duration,
+};
+
+use crate::{
+    client::{
+        requests::{
+            bulk::bulk_raw,
+            RequestBuilder,
+        },
+        responses::{
+            search::Hit,
+            BulkErrorsResponse,
+        },
+        Client,
+        SyncClient,
+    },
+    error::{
+        self,
+        Error,
+    },
+    http::sender::SyncSender,
+    params::Index,
+};
+
+/**
+A copy index request builder that can be configured before sending.
+
+Call [`Client.copy_index`][Client.copy_index] to get a `CopyIndexRequestBuilder`.
+The `send` method will copy the documents [synchronously][send-sync].
+
+[send-sync]: #send-synchronously
+[Client.copy_index]: ../../struct.Client.html#copy-index-request
+*/
+pub type CopyIndexRequestBuilder = RequestBuilder<SyncSender, CopyIndexRequestInner>;
+
+type OnProgress = Arc<dyn Fn(&CopyProgress) + Send + Sync>;
+
+#[doc(hidden)]
+pub struct CopyIndexRequestInner {
+    dest: SyncClient,
+    index: Option<Index<'static>>,
+    dest_index: Option<Index<'static>>,
+    body: Value,
+    keep_alive: Duration,
+    concurrency: usize,
+    on_progress: Option<OnProgress>,
+}
+
+/**
+# Copy index request
+*/
+impl Client<SyncSender> {
+    /**
+    Create a [`CopyIndexRequestBuilder`][CopyIndexRequestBuilder] that copies documents from this `Client` to the `dest` client.
+
+    Batches of documents are scrolled from this client and bulk indexed into the `dest` client by a pool of worker threads.
+    A batch that fails doesn't stop the copy, instead the failure is returned in the [`CopyIndexResponse`][CopyIndexResponse].
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+
+    # Examples
+
+    Copy an index called `myindex` from one cluster to another, printing progress after each batch:
+
+    ```no_run
+    # #[macro_use] extern crate serde_json;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+    let source = SyncClientBuilder::new()
+        .static_node("http://old-cluster:9200")
+        .build()?;
+
+    let dest = SyncClientBuilder::new()
+        .static_node("http://new-cluster:9200")
+        .build()?;
+
+    let response = source
+        .copy_index(&dest)
+        .index("myindex")
+        .body(json!({ "size": 500, "sort": ["_doc"] }))
+        .concurrency(4)
+        .on_progress(|progress| println!("copied {} documents", progress.docs_copied()))
+        .send()?;
+
+    for error in response.errors() {
+        println!("batch {} failed: {}", error.batch(), error);
+    }
+    # Ok(())
+    # }
+    ```
+
+    [CopyIndexRequestBuilder]: requests/copy_index/type.CopyIndexRequestBuilder.html
+    [CopyIndexResponse]: requests/copy_index/struct.CopyIndexResponse.html
+    [builder-methods]: requests/copy_index/type.CopyIndexRequestBuilder.html#builder-methods
+    [send-sync]: requests/copy_index/type.CopyIndexRequestBuilder.html#send-synchronously
+    */
+    pub fn copy_index(&self, dest: &SyncClient) -> CopyIndexRequestBuilder {
+        RequestBuilder::initial(
+            self.clone(),
+            CopyIndexRequestInner {
+                dest: dest.clone(),
+                index: None,
+                dest_index: None,
+                body: json!({}),
+                keep_alive: Duration::from_secs(60),
+                concurrency: 1,
+                on_progress: None,
+            },
+        )
+    }
+}
+
+/**
+# Builder methods
+
+Configure a `CopyIndexRequestBuilder` before sending it.
+*/
+impl CopyIndexRequestBuilder {
+    /**
+    Set the indices to copy documents from.
+
+    If no index is specified then `_all` will be used.
+    */
+    pub fn index(mut self, index: impl Into<Index<'static>>) -> Self {
+        self.inner.index = Some(index.into());
+        self
+    }
+
+    /**
+    Set the index to copy documents into.
+
+    If no index is specified then each document is copied into an index with the same name it had in the source cluster.
+    */
+    pub fn dest_index(mut self, index: impl Into<Index<'static>>) -> Self {
+        self.inner.dest_index = Some(index.into());
+        self
+    }
+
+    /**
+    Set the search body used to scroll the source cluster.
+
+    The `size` in the body is the number of documents in each batch.
+    Documents that are returned without a `_source` aren't copied.
+    */
+    pub fn body(mut self, body: Value) -> Self {
+        self.inner.body = body;
+        self
+    }
+
+    /**
+    Set how long the scroll context is kept alive between batches.
+
+    The default is one minute.
+    */
+    pub fn keep_alive(mut self, keep_alive: Duration) -> Self {
+        self.inner.keep_alive = keep_alive;
+        self
+    }
+
+    /**
+    Set the number of bulk requests that can be sent to the destination cluster at the same time.
+
+    The default is `1`.
+    */
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.inner.concurrency = concurrency.max(1);
+        self
+    }
+
+    /**
+    Call a function after each batch has been bulk indexed.
+
+    The function is called from the worker thread that sent the batch.
+    */
+    pub fn on_progress(
+        mut self,
+        on_progress: impl Fn(&CopyProgress) + Send + Sync + 'static,
+    ) -> Self {
+        self.inner.on_progress = Some(Arc::new(on_progress));
+        self
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl CopyIndexRequestBuilder {
+    /**
+    Copy the documents using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until all documents have been scrolled and all batches have been bulk indexed.
+    An error is only returned if the source cluster couldn't be scrolled.
+    Batches that couldn't be indexed are returned in the response.
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<CopyIndexResponse, Error> {
+        let (client, params_builder, inner) = (self.client, self.params_builder, self.inner);
+
+        let mut scroll = client
+            .scroll::<Value>()
+            .body(inner.body.clone())
+            .keep_alive(inner.keep_alive);
+        scroll.params_builder = params_builder;
+
+        if let Some(index) = inner.index.clone() {
+            scroll = scroll.index(index);
+        }
+
+        let state = Arc::new(Mutex::new(CopyIndexResponse::default()));
+
+        let (batch_tx, batch_rx) = mpsc::sync_channel::<(u64, Vec<Hit<Value>>)>(inner.concurrency);
+        let batch_rx = Arc::new(Mutex::new(batch_rx));
+
+        let workers: Vec<_> = (0..inner.concurrency)
+            .map(|_| {
+                let batch_rx = batch_rx.clone();
+                let state = state.clone();
+                let dest = inner.dest.clone();
+                let dest_index = inner.dest_index.clone();
+                let on_progress = inner.on_progress.clone();
+
+                thread::spawn(move || loop {
+                    let (batch, hits) = match batch_rx.lock().expect("poisoned lock").recv() {
+                        Ok(next) => next,
+                        Err(_) => break,
+                    };
+
+                    let docs = hits.len() as u64;
+                    let result = copy_batch(&dest, dest_index.as_ref(), hits);
+
+                    let mut state = state.lock().expect("poisoned lock");
+                    state.record(batch, docs, result);
+
+                    if let Some(ref on_progress) = on_progress {
+                        on_progress(&state.progress);
+                    }
+                })
+            })
+            .collect();
+
+        let mut scrolled = Ok(());
+        for (batch, res) in scroll.send()?.enumerate() {
+            match res {
+                Ok(res) => {
+                    {
+                        let mut state = state.lock().expect("poisoned lock");
+                        if state.progress.total.is_none() {
+                            state.progress.total = Some(res.total());
+                        }
+                    }
+
+                    // The channel only closes early if all of the workers have panicked
+                    if batch_tx
+                        .send((batch as u64, res.into_hits().collect()))
+                        .is_err()
+                    {
+                        break;
+                    }
+                }
+                Err(e) => {
+                    scrolled = Err(e);
+                    break;
+                }
+            }
+        }
+
+        drop(batch_tx);
+
+        let mut panicked = false;
+        for worker in workers {
+            panicked |= worker.join().is_err();
+        }
+
+        scrolled?;
+
+        if panicked {
+            return Err(error::request(error::message(
+                "a worker thread panicked while copying documents",
+            )));
+        }
+
+        let mut state = state.lock().expect("poisoned lock");
+        let mut response = mem::replace(&mut *state, CopyIndexResponse::default());
+        response.errors.sort_by_key(|error| error.batch);
+
+        Ok(response)
+    }
+}
+
+fn copy_batch(
+    dest: &SyncClient,
+    dest_index: Option<&Index<'static>>,
+    hits: Vec<Hit<Value>>,
+) -> Result<(), CopyBatchErrorKind> {
+    let ops = hits.into_iter().filter_map(|hit| {
+        let (index, ty, id) = (
+            hit.index().to_owned(),
+            hit.ty().to_owned(),
+            hit.id().to_owned(),
+        );
+
+        hit.into_document().map(|doc| {
+            let op = bulk_raw().index(doc).ty(ty).id(id);
+
+            match dest_index {
+                Some(_) => op,
+                None => op.index(index),
+            }
+        })
+    });
+
+    let mut req = dest.bulk().errors_only();
+    if let Some(dest_index) = dest_index {
+        req = req.index(dest_index.clone());
+    }
+
+    let res = req
+        .extend(ops)
+        .send()
+        .map_err(CopyBatchErrorKind::Request)?;
+
+    if res.is_err() {
+        Err(CopyBatchErrorKind::Items(res))
+    } else {
+        Ok(())
+    }
+}
+
+/** The progress of a copy. */
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CopyProgress {
+    batches: u64,
+    docs_copied: u64,
+    docs_failed: u64,
+    total: Option<u64>,
+}
+
+impl CopyProgress {
+    /** The number of batches that have been bulk indexed, including ones that failed. */
+    pub fn batches(&self) -> u64 {
+        self.batches
+    }
+
+    /** The number of documents that have been copied. */
+    pub fn docs_copied(&self) -> u64 {
+        self.docs_copied
+    }
+
+    /** The number of documents that couldn't be copied. */
+    pub fn docs_failed(&self) -> u64 {
+        self.docs_failed
+    }
+
+    /** The total number of documents matched in the source cluster, once the first batch has been scrolled. */
+    pub fn total(&self) -> Option<u64> {
+        self.total
+    }
+}
+
+/**
+The result of copying documents between clusters.
+
+The copy may have partially succeeded, so check [`errors`][errors] for batches that failed.
+
+[errors]: #method.errors
+*/
+#[derive(Debug, Default)]
+pub struct CopyIndexResponse {
+    progress: CopyProgress,
+    errors: Vec<CopyBatchError>,
+}
+
+impl CopyIndexResponse {
+    /** The final progress of the copy. */
+    pub fn progress(&self) -> &CopyProgress {
+        &self.progress
+    }
+
+    /** The batches that couldn't be copied, in the order they were scrolled. */
+    pub fn errors(&self) -> &[CopyBatchError] {
+        &self.errors
+    }
+
+    /** Whether or not every document was copied. */
+    pub fn is_ok(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    fn record(&mut self, batch: u64, docs: u64, result: Result<(), CopyBatchErrorKind>) {
+        self.progress.batches += 1;
+
+        let failed = match result {
+            Ok(()) => 0,
+            Err(CopyBatchErrorKind::Request(e)) => {
+                self.errors.push(CopyBatchError {
+                    batch,
+                    docs,
+                    kind: CopyBatchErrorKind::Request(e),
+                });
+                docs
+            }
+            Err(CopyBatchErrorKind::Items(res)) => {
+                let failed = res.iter().count() as u64;
+                self.errors.push(CopyBatchError {
+                    batch,
+                    docs,
+                    kind: CopyBatchErrorKind::Items(res),
+                });
+                failed
+            }
+        };
+
+        self.progress.docs_copied += docs - failed;
+        self.progress.docs_failed += failed;
+    }
+}
+
+/** A batch of documents that couldn't be copied. */
+#[derive(Debug)]
+pub struct CopyBatchError {
+    batch: u64,
+    docs: u64,
+    kind: CopyBatchErrorKind,
+}
+
+impl CopyBatchError {
+    /** The position of the batch in the scroll, starting from `0`. */
+    pub fn batch(&self) -> u64 {
+        self.batch
+    }
+
+    /** The number of documents in the batch. */
+    pub fn docs(&self) -> u64 {
+        self.docs
+    }
+
+    /** Why the batch couldn't be copied. */
+    pub fn kind(&self) -> &CopyBatchErrorKind {
+        &self.kind
+    }
+}
+
+/** Why a batch of documents couldn't be copied. */
+#[derive(Debug)]
+pub enum CopyBatchErrorKind {
+    /** The bulk request failed, so none of the documents in the batch were copied. */
+    Request(Error),
+    /** The bulk request succeeded, but some of the documents in the batch were rejected. */
+    Items(BulkErrorsResponse),
+}
+
+impl fmt::Display for CopyBatchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.kind {
+            CopyBatchErrorKind::Request(ref e) => {
+                write!(f, "the bulk request for the batch failed: {}", e)
+            }
+            CopyBatchErrorKind::Items(ref res) => write!(
+                f,
+                "{} of {} documents in the batch were rejected",
+                res.iter().count(),
+                self.docs
+            ),
+        }
+    }
+}
+
+impl StdError for CopyBatchError {
+    fn description(&self) -> &str {
+        "a batch of documents couldn't be copied"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        prelude::*,
+        tests::*,
+    };
+
+    #[test]
+    fn is_send() {
+        assert_send::<CopyIndexResponse>();
+    }
+
+    #[test]
+    fn default_request() {
+        let source = SyncClientBuilder::new().build().unwrap();
+        let dest = SyncClientBuilder::new().build().unwrap();
+
+        let req = source
+            .copy_index(&dest)
+            .index("myindex")
+            .dest_index("newindex")
+            .concurrency(0);
+
+        assert_eq!(Some("myindex"), req.inner.index.as_ref().map(|i| &*i.0));
+        assert_eq!(
+            Some("newindex"),
+            req.inner.dest_index.as_ref().map(|i| &*i.0)
+        );
+        assert_eq!(1, req.inner.concurrency);
+    }
+
+    #[test]
+    fn record_batches() {
+        let mut response = CopyIndexResponse::default();
+
+        response.record(0, 10, Ok(()));
+        response.record(1, 10, Err(CopyBatchErrorKind::Request(error::test())));
+
+        assert_eq!(2, response.progress().batches());
+        assert_eq!(10, response.progress().docs_copied());
+        assert_eq!(10, response.progress().docs_failed());
+
+        assert!(!response.is_ok());
+        assert_eq!(1, response.errors()[0].batch());
+    }
+}
@@ -113,7 +113,7 @@ where
     where
         TDocument: DocumentType + StaticIndex + StaticType,
     {
-        let index = TDocument::static_index().into();
+        let index = self.inner.name_index(TDocument::static_index());
         let ty = TDocument::static_ty().into();
 
         RequestBuilder::initial(
@@ -172,10 +172,12 @@ where
         index: impl Into<Index<'static>>,
         id: impl Into<Id<'static>>,
     ) -> DeleteRequestBuilder<TSender, ()> {
+        let index = self.inner.name_index(index.into());
+
         RequestBuilder::initial(
             self.inner,
             DeleteRequestInner {
-                index: index.into(),
+                index: index,
                 ty: DEFAULT_DOC_TYPE.into(),
                 id: id.into(),
                 _marker: PhantomData,
@@ -201,7 +203,7 @@ where
 {
     /** Set the index for the delete request. */
     pub fn index(mut self, index: impl Into<Index<'static>>) -> Self {
-        self.inner.index = index.into();
+        self.inner.index = self.client.name_index(index.into());
         self
     }
 
@@ -113,7 +113,7 @@ where
     where
         TDocument: DeserializeOwned + DocumentType + StaticIndex + StaticType,
     {
-        let index = TDocument::static_index().into();
+        let index = self.inner.name_index(TDocument::static_index());
         let ty = TDocument::static_ty().into();
 
         RequestBuilder::initial(
@@ -171,10 +171,12 @@ where
     where
         TDocument: DeserializeOwned,
     {
+        let index = self.inner.name_index(index.into());
+
         RequestBuilder::initial(
             self.inner,
             GetRequestInner {
-                index: index.into(),
+                index: index,
                 ty: DEFAULT_DOC_TYPE.into(),
                 id: id.into(),
                 _marker: PhantomData,
@@ -200,7 +202,7 @@ where
 {
     /** Set the index for the get request. */
     pub fn index(mut self, index: impl Into<Index<'static>>) -> Self {
-        self.inner.index = index.into();
+        self.inner.index = self.client.name_index(index.into());
         self
     }
 
@@ -209,6 +211,29 @@ where
         self.inner.ty = ty.into();
         self
     }
+
+    /**
+    Set the [stored fields][stored-fields] to return with the document.
+
+    The values of the fields can be read from [`GetResponse.fields`][GetResponse.fields].
+    The `_source` isn't returned when stored fields are requested unless it's also requested with the `_source` parameter.
+
+    [stored-fields]: https://www.elastic.co/guide/en/elasticsearch/reference/master/docs-get.html#get-stored-fields
+    [GetResponse.fields]: ../../responses/struct.GetResponse.html#method.fields
+    */
+    pub fn stored_fields<I>(self, fields: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let fields = fields
+            .into_iter()
+            .map(|field| field.as_ref().to_owned())
+            .collect::<Vec<_>>()
+            .join(",");
+
+        self.params_fluent(move |params| params.url_param("stored_fields", fields.clone()))
+    }
 }
 
 /**
@@ -399,4 +424,21 @@ mod tests {
 
         assert_eq!("/testdoc/new-ty/1", req.url.as_ref());
     }
+
+    #[test]
+    fn specify_stored_fields() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let params = client
+            .document::<TestDoc>()
+            .get("1")
+            .stored_fields(vec!["title", "created"])
+            .params_builder
+            .into_value(RequestParams::default);
+
+        assert_eq!(
+            "?stored_fields=title%2Ccreated",
+            params.get_url_qry().1.unwrap()
+        );
+    }
 }
@@ -122,14 +122,18 @@ where
     [types-mod]: ../types/index.html
     [documents-mod]: ../types/document/index.html
     */
-    pub fn index(self, doc: TDocument) -> IndexRequestBuilder<TSender, TDocument>
+    pub fn index(self, mut doc: TDocument) -> IndexRequestBuilder<TSender, TDocument>
     where
         TDocument: Serialize + DocumentType,
     {
+        doc.before_index_hook();
+
+        let index = self.inner.name_index(doc.index().to_owned());
+
         RequestBuilder::initial(
             self.inner,
             IndexRequestInner {
-                index: doc.index().to_owned(),
+                index: index,
                 ty: doc.ty().to_owned(),
                 id: doc.partial_id().map(|id| id.to_owned()),
                 doc: doc,
@@ -191,10 +195,12 @@ where
     where
         TDocument: Serialize,
     {
+        let index = self.inner.name_index(index.into());
+
         RequestBuilder::initial(
             self.inner,
             IndexRequestInner {
-                index: index.into(),
+                index: index,
                 ty: DEFAULT_DOC_TYPE.into(),
                 id: None,
                 doc: doc,
@@ -230,7 +236,7 @@ where
 {
     /** Set the index for the index request. */
     pub fn index(mut self, index: impl Into<Index<'static>>) -> Self {
-        self.inner.index = index.into();
+        self.inner.index = self.client.name_index(index.into());
         self
     }
 
@@ -396,6 +402,7 @@ impl Future for Pending {
 #[cfg(test)]
 mod tests {
     use crate::{
+        client::AffixIndexNamer,
         prelude::*,
         tests::*,
     };
@@ -409,6 +416,20 @@ mod tests {
     #[elastic(crate_root = "crate::types")]
     struct TestDoc {}
 
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types", before_index)]
+    struct HookDoc {
+        #[elastic(id)]
+        id: String,
+        title: String,
+    }
+
+    impl BeforeIndex for HookDoc {
+        fn before_index(&mut self) {
+            self.id = self.title.to_lowercase();
+        }
+    }
+
     #[test]
     fn default_request() {
         let client = SyncClientBuilder::new().build().unwrap();
@@ -439,6 +460,24 @@ mod tests {
         assert_eq!("/new-idx/_doc", req.url.as_ref());
     }
 
+    #[test]
+    fn index_namer() {
+        let client = SyncClientBuilder::new()
+            .index_namer(AffixIndexNamer::new().prefix("tenant-"))
+            .build()
+            .unwrap();
+
+        let req = client
+            .document::<TestDoc>()
+            .index(TestDoc {})
+            .index("new-idx")
+            .inner
+            .into_request()
+            .unwrap();
+
+        assert_eq!("/tenant-new-idx/_doc", req.url.as_ref());
+    }
+
     #[test]
     fn specify_ty() {
         let client = SyncClientBuilder::new().build().unwrap();
@@ -454,6 +493,39 @@ mod tests {
         assert_eq!("/testdoc/new-ty", req.url.as_ref());
     }
 
+    #[test]
+    fn before_index_hook() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .document::<HookDoc>()
+            .index(HookDoc {
+                id: String::new(),
+                title: "Title".to_owned(),
+            })
+            .inner
+            .into_request()
+            .unwrap();
+
+        assert_eq!("/hookdoc/_doc/title", req.url.as_ref());
+        assert_eq!(
+            r#"{"id":"title","title":"Title"}"#.as_bytes().to_vec(),
+            req.body
+        );
+
+        let op = bulk::<HookDoc>().index(HookDoc {
+            id: String::new(),
+            title: "Bulk".to_owned(),
+        });
+
+        let mut buf = Vec::new();
+        op.write(&mut buf).unwrap();
+
+        assert!(String::from_utf8(buf)
+            .unwrap()
+            .contains(r#"{"id":"bulk","title":"Bulk"}"#));
+    }
+
     #[test]
     fn specify_id() {
         let client = SyncClientBuilder::new().build().unwrap();
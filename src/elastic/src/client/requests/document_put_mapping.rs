@@ -114,7 +114,7 @@ where
     where
         TDocument: DocumentType + StaticIndex + StaticType,
     {
-        let index = TDocument::static_index().into();
+        let index = self.inner.name_index(TDocument::static_index());
         let ty = TDocument::static_ty().into();
 
         RequestBuilder::initial(
@@ -156,7 +156,7 @@ where
 {
     /** Set the index for the put mapping request. */
     pub fn index(mut self, index: impl Into<Index<'static>>) -> Self {
-        self.inner.index = index.into();
+        self.inner.index = self.client.name_index(index.into());
         self
     }
 
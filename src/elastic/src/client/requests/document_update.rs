@@ -209,7 +209,7 @@ where
     where
         TDocument: DocumentType + StaticIndex + StaticType,
     {
-        let index = TDocument::static_index().into();
+        let index = self.inner.name_index(TDocument::static_index());
         let ty = TDocument::static_ty().into();
 
         RequestBuilder::initial(
@@ -267,10 +267,12 @@ where
         index: impl Into<Index<'static>>,
         id: impl Into<Id<'static>>,
     ) -> UpdateRequestBuilder<TSender, Doc<()>> {
+        let index = self.inner.name_index(index.into());
+
         RequestBuilder::initial(
             self.inner,
             UpdateRequestInner {
-                index: index.into(),
+                index: index,
                 ty: DEFAULT_DOC_TYPE.into(),
                 id: id.into(),
                 body: Doc::empty(),
@@ -304,7 +306,7 @@ where
 {
     /** Set the index for the update request. */
     pub fn index(mut self, index: impl Into<Index<'static>>) -> Self {
-        self.inner.index = index.into();
+        self.inner.index = self.client.name_index(index.into());
         self
     }
 
@@ -98,13 +98,12 @@ where
     # fn main() { run().unwrap() }
     # fn run() -> Result<(), Box<dyn ::std::error::Error>> {
     # let client = SyncClientBuilder::new().build()?;
+    let settings = IndexSettings::new()
+        .number_of_shards(3)
+        .number_of_replicas(2);
+
     let body = json!({
-        "settings": {
-            "index": {
-                "number_of_shards": 3,
-                "number_of_replicas": 2
-            }
-        },
+        "settings": settings,
         "mappings": {
             MyType::static_index(): MyType::index_mapping()
         }
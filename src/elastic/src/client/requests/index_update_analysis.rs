@@ -0,0 +1,395 @@
+/*!
+Builders for updating the [analysis settings][docs-analysis] of an existing index.
+
+Analysis settings can only be updated on a closed index, so the index is closed, updated and then reopened.
+
+[docs-analysis]: https://www.elastic.co/guide/en/elasticsearch/reference/master/indices-update-settings.html#update-settings-analysis
+*/
+
+use futures::{
+    Future,
+    Poll,
+};
+use serde_json;
+
+use crate::{
+    client::{
+        requests::{
+            raw::RawRequestInner,
+            RequestBuilder,
+        },
+        responses::CommandResponse,
+        IndexClient,
+    },
+    endpoints::{
+        IndicesCloseRequest,
+        IndicesOpenRequest,
+        IndicesPutSettingsRequest,
+    },
+    error::{
+        self,
+        Error,
+    },
+    http::{
+        empty_body,
+        sender::{
+            AsyncSender,
+            Sender,
+            SyncSender,
+        },
+        DefaultBody,
+    },
+    params::Index,
+    types::settings::analysis::Analysis,
+};
+
+/**
+An update analysis request builder that can be configured before sending.
+
+Call [`Client.index.update_analysis`][Client.index.update_analysis] to get an `IndexUpdateAnalysisRequestBuilder`.
+The `send` method will either send the requests [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+Sending the builder will:
+
+1. close the index
+1. put the analysis settings
+1. open the index again, even if putting the analysis settings failed.
+
+The index can't be searched or indexed into while it's closed.
+
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.index.update_analysis]: ../../struct.IndexClient.html#update-analysis-request
+*/
+pub type IndexUpdateAnalysisRequestBuilder<TSender> =
+    RequestBuilder<TSender, IndexUpdateAnalysisRequestInner>;
+
+#[doc(hidden)]
+pub struct IndexUpdateAnalysisRequestInner {
+    index: Index<'static>,
+    analysis: Analysis,
+}
+
+#[derive(Serialize)]
+struct AnalysisSettings<'a> {
+    analysis: &'a Analysis,
+}
+
+/**
+# Update analysis request
+*/
+impl<TSender> IndexClient<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create an [`IndexUpdateAnalysisRequestBuilder`][IndexUpdateAnalysisRequestBuilder] with this `Client` that can be configured before sending.
+
+    Only the analysis components in the given [`Analysis`][Analysis] are replaced.
+    This is useful for deploying changes to inline stopword and synonym lists.
+
+    For more details, see:
+
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Replace the synonyms used by an index called `myindex`:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # use elastic::types::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let synonyms = SynonymFilter::from_rules(vec![
+        SynonymRule::equivalent(vec!["couch", "sofa", "settee"]),
+        SynonymRule::explicit(vec!["i-pod", "i pod"], vec!["ipod"]),
+    ]);
+
+    let response = client
+        .index("myindex")
+        .update_analysis(Analysis::new().filter("product_synonyms", synonyms))
+        .send()?;
+
+    assert!(response.acknowledged());
+    # Ok(())
+    # }
+    ```
+
+    [IndexUpdateAnalysisRequestBuilder]: requests/index_update_analysis/type.IndexUpdateAnalysisRequestBuilder.html
+    [Analysis]: ../types/settings/analysis/struct.Analysis.html
+    [send-sync]: requests/index_update_analysis/type.IndexUpdateAnalysisRequestBuilder.html#send-synchronously
+    [send-async]: requests/index_update_analysis/type.IndexUpdateAnalysisRequestBuilder.html#send-asynchronously
+    */
+    pub fn update_analysis(self, analysis: Analysis) -> IndexUpdateAnalysisRequestBuilder<TSender> {
+        RequestBuilder::initial(
+            self.inner,
+            IndexUpdateAnalysisRequestInner {
+                index: self.index,
+                analysis,
+            },
+        )
+    }
+}
+
+impl IndexUpdateAnalysisRequestInner {
+    fn close_request(&self) -> IndicesCloseRequest<'static, DefaultBody> {
+        IndicesCloseRequest::for_index(self.index.clone(), empty_body())
+    }
+
+    fn put_settings_request(&self) -> Result<IndicesPutSettingsRequest<'static, Vec<u8>>, Error> {
+        let body = serde_json::to_vec(&AnalysisSettings {
+            analysis: &self.analysis,
+        })
+        .map_err(error::request)?;
+
+        Ok(IndicesPutSettingsRequest::for_index(
+            self.index.clone(),
+            body,
+        ))
+    }
+
+    fn open_request(&self) -> IndicesOpenRequest<'static, DefaultBody> {
+        IndicesOpenRequest::for_index(self.index.clone(), empty_body())
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl IndexUpdateAnalysisRequestBuilder<SyncSender> {
+    /**
+    Send an `IndexUpdateAnalysisRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until the index has been closed, updated and reopened.
+    The response is the response to putting the analysis settings.
+
+    # Examples
+
+    Replace the stopwords used by an index called `myindex`:
+
+    ```no_run
+    # use elastic::prelude::*;
+    # use elastic::types::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let stop = StopFilter {
+        stopwords: Some(Stopwords::list(vec!["a", "an", "the"])),
+        ..Default::default()
+    };
+
+    let response = client
+        .index("myindex")
+        .update_analysis(Analysis::new().filter("my_stop", stop))
+        .send()?;
+
+    assert!(response.acknowledged());
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<CommandResponse, Error> {
+        let (client, params_builder, inner) = (self.client, self.params_builder, self.inner);
+
+        let put_settings = inner.put_settings_request()?;
+
+        RequestBuilder::new(
+            client.clone(),
+            params_builder.clone(),
+            RawRequestInner::new(inner.close_request()),
+        )
+        .send()?
+        .into_response::<CommandResponse>()?;
+
+        let put_settings = RequestBuilder::new(
+            client.clone(),
+            params_builder.clone(),
+            RawRequestInner::new(put_settings),
+        )
+        .send()
+        .and_then(|res| res.into_response::<CommandResponse>());
+
+        // Always try to reopen the index, even if the settings couldn't be updated
+        RequestBuilder::new(
+            client,
+            params_builder,
+            RawRequestInner::new(inner.open_request()),
+        )
+        .send()?
+        .into_response::<CommandResponse>()?;
+
+        put_settings
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl IndexUpdateAnalysisRequestBuilder<AsyncSender> {
+    /**
+    Send an `IndexUpdateAnalysisRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve once the index has been closed, updated and reopened.
+    The response is the response to putting the analysis settings.
+
+    # Examples
+
+    Replace the stopwords used by an index called `myindex`:
+
+    ```no_run
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # use elastic::types::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = AsyncClientBuilder::new().build()?;
+    let stop = StopFilter {
+        stopwords: Some(Stopwords::list(vec!["a", "an", "the"])),
+        ..Default::default()
+    };
+
+    let future = client
+        .index("myindex")
+        .update_analysis(Analysis::new().filter("my_stop", stop))
+        .send();
+
+    future.and_then(|response| {
+        assert!(response.acknowledged());
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let (client, params_builder, inner) = (self.client, self.params_builder, self.inner);
+
+        let put_settings = match inner.put_settings_request() {
+            Ok(put_settings) => put_settings,
+            Err(e) => return Pending::new(futures::future::err(e)),
+        };
+
+        let open = {
+            let client = client.clone();
+            let params_builder = params_builder.clone();
+            let req = inner.open_request();
+
+            move || {
+                RequestBuilder::new(client, params_builder, RawRequestInner::new(req))
+                    .send()
+                    .and_then(|res| res.into_response::<CommandResponse>())
+            }
+        };
+
+        let put_settings = {
+            let client = client.clone();
+            let params_builder = params_builder.clone();
+
+            move || {
+                RequestBuilder::new(client, params_builder, RawRequestInner::new(put_settings))
+                    .send()
+                    .and_then(|res| res.into_response::<CommandResponse>())
+            }
+        };
+
+        let res_future = RequestBuilder::new(
+            client,
+            params_builder,
+            RawRequestInner::new(inner.close_request()),
+        )
+        .send()
+        .and_then(|res| res.into_response::<CommandResponse>())
+        .and_then(move |_| {
+            // Always try to reopen the index, even if the settings couldn't be updated
+            put_settings().then(move |res| open().and_then(move |_| res))
+        });
+
+        Pending::new(res_future)
+    }
+}
+
+/** A future returned by calling `send`. */
+pub struct Pending {
+    inner: Box<dyn Future<Item = CommandResponse, Error = Error> + Send>,
+}
+
+impl Pending {
+    fn new<F>(fut: F) -> Self
+    where
+        F: Future<Item = CommandResponse, Error = Error> + Send + 'static,
+    {
+        Pending {
+            inner: Box::new(fut),
+        }
+    }
+}
+
+impl Future for Pending {
+    type Item = CommandResponse;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{
+        self,
+        Value,
+    };
+
+    use crate::{
+        prelude::*,
+        tests::*,
+        types::prelude::*,
+    };
+
+    #[test]
+    fn is_send() {
+        assert_send::<super::Pending>();
+    }
+
+    #[test]
+    fn default_requests() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let analysis = Analysis::new().filter(
+            "my_synonyms",
+            SynonymFilter::from_rules(vec![SynonymRule::equivalent(vec!["couch", "sofa"])]),
+        );
+
+        let inner = client.index("testindex").update_analysis(analysis).inner;
+
+        let close = inner.close_request();
+        let put_settings = inner.put_settings_request().unwrap();
+        let open = inner.open_request();
+
+        let expected_body = json!({
+            "analysis": {
+                "filter": {
+                    "my_synonyms": {
+                        "type": "synonym",
+                        "synonyms": ["couch, sofa"]
+                    }
+                }
+            }
+        });
+
+        let actual_body: Value = serde_json::from_slice(&put_settings.body).unwrap();
+
+        assert_eq!("/testindex/_close", close.url.as_ref());
+        assert_eq!("/testindex/_settings", put_settings.url.as_ref());
+        assert_eq!(expected_body, actual_body);
+        assert_eq!("/testindex/_open", open.url.as_ref());
+    }
+}
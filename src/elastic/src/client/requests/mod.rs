@@ -28,6 +28,18 @@ pub mod search;
 #[doc(inline)]
 pub use self::search::SearchRequestBuilder;
 
+// Aggregate requests
+pub mod aggregate;
+
+#[doc(inline)]
+pub use self::aggregate::AggregateRequestBuilder;
+
+// Scroll requests
+pub mod scroll;
+
+#[doc(inline)]
+pub use self::scroll::ScrollRequestBuilder;
+
 // Sql requests
 pub mod sql;
 
@@ -56,6 +68,7 @@ pub mod index_create;
 pub mod index_delete;
 pub mod index_exists;
 pub mod index_open;
+pub mod index_update_analysis;
 
 #[doc(inline)]
 pub use self::{
@@ -64,16 +77,21 @@ pub use self::{
     index_delete::IndexDeleteRequestBuilder,
     index_exists::IndexExistsRequestBuilder,
     index_open::IndexOpenRequestBuilder,
+    index_update_analysis::IndexUpdateAnalysisRequestBuilder,
 };
 
 // Misc requests
 pub mod bulk;
+pub mod copy_index;
 pub mod ping;
+pub mod pipeline_simulate;
 
 #[doc(inline)]
 pub use self::{
     bulk::BulkRequestBuilder,
+    copy_index::CopyIndexRequestBuilder,
     ping::PingRequestBuilder,
+    pipeline_simulate::PipelineSimulateRequestBuilder,
 };
 
 pub mod common;
@@ -264,6 +282,8 @@ pub mod prelude {
     };
 
     pub use super::{
+        AggregateRequestBuilder,
+        CopyIndexRequestBuilder,
         DeleteRequestBuilder,
         GetRequestBuilder,
         IndexCloseRequestBuilder,
@@ -271,9 +291,12 @@ pub mod prelude {
         IndexDeleteRequestBuilder,
         IndexOpenRequestBuilder,
         IndexRequestBuilder,
+        IndexUpdateAnalysisRequestBuilder,
         PingRequestBuilder,
+        PipelineSimulateRequestBuilder,
         PutMappingRequestBuilder,
         RawRequestBuilder,
+        ScrollRequestBuilder,
         SearchRequestBuilder,
         SqlRequestBuilder,
         UpdateRequestBuilder,
@@ -0,0 +1,401 @@
+/*!
+Builders for [simulate pipeline requests][docs-simulate].
+
+Sample documents are run through an ingest pipeline with `verbose` results,
+so the output of each processor can be checked without indexing any documents.
+
+[docs-simulate]: https://www.elastic.co/guide/en/elasticsearch/reference/master/simulate-pipeline-api.html
+*/
+
+use futures::{
+    Future,
+    Poll,
+};
+use serde_json::{
+    self,
+    Value,
+};
+
+use crate::{
+    client::{
+        requests::{
+            raw::RawRequestInner,
+            RequestBuilder,
+        },
+        responses::PipelineSimulateResponse,
+        Client,
+    },
+    endpoints::IngestSimulateRequest,
+    error::{
+        self,
+        Error,
+    },
+    http::sender::{
+        AsyncSender,
+        RequestParams,
+        Sender,
+        SyncSender,
+    },
+    params::Id,
+};
+
+/**
+A [simulate pipeline request][docs-simulate] builder that can be configured before sending.
+
+Call [`Client.simulate_pipeline`][Client.simulate_pipeline] to get a `PipelineSimulateRequestBuilder`.
+The `send` method will either send the request [synchronously][send-sync] or [asynchronously][send-async], depending on the `Client` it was created from.
+
+[docs-simulate]: https://www.elastic.co/guide/en/elasticsearch/reference/master/simulate-pipeline-api.html
+[send-sync]: #send-synchronously
+[send-async]: #send-asynchronously
+[Client.simulate_pipeline]: ../../struct.Client.html#simulate-pipeline-request
+*/
+pub type PipelineSimulateRequestBuilder<TSender> =
+    RequestBuilder<TSender, PipelineSimulateRequestInner>;
+
+#[doc(hidden)]
+pub struct PipelineSimulateRequestInner {
+    id: Option<Id<'static>>,
+    pipeline: Option<Value>,
+    docs: Vec<Value>,
+}
+
+#[derive(Serialize)]
+struct PipelineSimulateBody<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pipeline: Option<&'a Value>,
+    docs: Vec<SimulateDoc<'a>>,
+}
+
+#[derive(Serialize)]
+struct SimulateDoc<'a> {
+    #[serde(rename = "_source")]
+    source: &'a Value,
+}
+
+/**
+# Simulate pipeline request
+*/
+impl<TSender> Client<TSender>
+where
+    TSender: Sender,
+{
+    /**
+    Create a [`PipelineSimulateRequestBuilder`][PipelineSimulateRequestBuilder] with this `Client` that can be configured before sending.
+
+    The pipeline to simulate is either given inline with [`pipeline`][builder-pipeline],
+    or is an existing pipeline on the cluster given with [`id`][builder-id].
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+    - [send asynchronously][send-async]
+
+    # Examples
+
+    Check that a grok processor parses a sample log line:
+
+    ```no_run
+    # #[macro_use] extern crate serde_json;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client
+        .simulate_pipeline()
+        .pipeline(json!({
+            "processors": [
+                {
+                    "grok": {
+                        "tag": "parse_message",
+                        "field": "message",
+                        "patterns": ["%{IP:client} %{WORD:method} %{URIPATHPARAM:request}"]
+                    }
+                }
+            ]
+        }))
+        .doc(json!({ "message": "55.3.244.1 GET /index.html" }))
+        .send()?;
+
+    let doc = &response.docs()[0];
+
+    assert!(doc.is_ok());
+    assert_eq!(Some("GET"), doc.source().and_then(|source| source["method"].as_str()));
+    # Ok(())
+    # }
+    ```
+
+    [PipelineSimulateRequestBuilder]: requests/pipeline_simulate/type.PipelineSimulateRequestBuilder.html
+    [builder-methods]: requests/pipeline_simulate/type.PipelineSimulateRequestBuilder.html#builder-methods
+    [builder-pipeline]: requests/pipeline_simulate/type.PipelineSimulateRequestBuilder.html#method.pipeline
+    [builder-id]: requests/pipeline_simulate/type.PipelineSimulateRequestBuilder.html#method.id
+    [send-sync]: requests/pipeline_simulate/type.PipelineSimulateRequestBuilder.html#send-synchronously
+    [send-async]: requests/pipeline_simulate/type.PipelineSimulateRequestBuilder.html#send-asynchronously
+    */
+    pub fn simulate_pipeline(&self) -> PipelineSimulateRequestBuilder<TSender> {
+        RequestBuilder::initial(
+            self.clone(),
+            PipelineSimulateRequestInner {
+                id: None,
+                pipeline: None,
+                docs: Vec::new(),
+            },
+        )
+    }
+}
+
+impl PipelineSimulateRequestInner {
+    fn into_request(self) -> Result<IngestSimulateRequest<'static, Vec<u8>>, Error> {
+        let body = serde_json::to_vec(&PipelineSimulateBody {
+            pipeline: self.pipeline.as_ref(),
+            docs: self
+                .docs
+                .iter()
+                .map(|source| SimulateDoc { source })
+                .collect(),
+        })
+        .map_err(error::request)?;
+
+        Ok(match self.id {
+            Some(id) => IngestSimulateRequest::for_id(id, body),
+            None => IngestSimulateRequest::new(body),
+        })
+    }
+}
+
+/**
+# Builder methods
+
+Configure a `PipelineSimulateRequestBuilder` before sending it.
+*/
+impl<TSender> PipelineSimulateRequestBuilder<TSender>
+where
+    TSender: Sender,
+{
+    /** Set the definition of the pipeline to simulate. */
+    pub fn pipeline(mut self, pipeline: Value) -> Self {
+        self.inner.pipeline = Some(pipeline);
+        self
+    }
+
+    /** Simulate an existing pipeline with the given id. */
+    pub fn id(mut self, id: impl Into<Id<'static>>) -> Self {
+        self.inner.id = Some(id.into());
+        self
+    }
+
+    /** Add a sample document to run through the pipeline. */
+    pub fn doc(mut self, source: Value) -> Self {
+        self.inner.docs.push(source);
+        self
+    }
+
+    /** Add a set of sample documents to run through the pipeline. */
+    pub fn docs(mut self, sources: impl IntoIterator<Item = Value>) -> Self {
+        self.inner.docs.extend(sources);
+        self
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl PipelineSimulateRequestBuilder<SyncSender> {
+    /**
+    Send a `PipelineSimulateRequestBuilder` synchronously using a [`SyncClient`][SyncClient].
+
+    This will block the current thread until a response arrives and is deserialised.
+
+    # Examples
+
+    Simulate an existing pipeline called `logs`:
+
+    ```no_run
+    # #[macro_use] extern crate serde_json;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let response = client
+        .simulate_pipeline()
+        .id("logs")
+        .doc(json!({ "message": "55.3.244.1 GET /index.html" }))
+        .send()?;
+
+    for result in response.docs()[0].processor_results() {
+        println!("{:?}: {:?}", result.tag(), result.status());
+    }
+    # Ok(())
+    # }
+    ```
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<PipelineSimulateResponse, Error> {
+        let req = self.inner.into_request()?;
+
+        RequestBuilder::new(
+            self.client,
+            self.params_builder.fluent(verbose).shared(),
+            RawRequestInner::new(req),
+        )
+        .send()?
+        .into_response()
+    }
+}
+
+/**
+# Send asynchronously
+*/
+impl PipelineSimulateRequestBuilder<AsyncSender> {
+    /**
+    Send a `PipelineSimulateRequestBuilder` asynchronously using an [`AsyncClient`][AsyncClient].
+
+    This will return a future that will resolve to the deserialised simulate pipeline response.
+
+    # Examples
+
+    Simulate an existing pipeline called `logs`:
+
+    ```no_run
+    # #[macro_use] extern crate serde_json;
+    # use futures::Future;
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = AsyncClientBuilder::new().build()?;
+    let future = client
+        .simulate_pipeline()
+        .id("logs")
+        .doc(json!({ "message": "55.3.244.1 GET /index.html" }))
+        .send();
+
+    future.and_then(|response| {
+        assert!(response.is_ok());
+
+        Ok(())
+    });
+    # Ok(())
+    # }
+    ```
+
+    [AsyncClient]: ../../type.AsyncClient.html
+    */
+    pub fn send(self) -> Pending {
+        let req = match self.inner.into_request() {
+            Ok(req) => req,
+            Err(e) => return Pending::new(futures::future::err(e)),
+        };
+
+        let res_future = RequestBuilder::new(
+            self.client,
+            self.params_builder.fluent(verbose).shared(),
+            RawRequestInner::new(req),
+        )
+        .send()
+        .and_then(|res| res.into_response());
+
+        Pending::new(res_future)
+    }
+}
+
+// Always ask for the results of each processor
+fn verbose(params: RequestParams) -> RequestParams {
+    params.url_param("verbose", true)
+}
+
+/** A future returned by calling `send`. */
+pub struct Pending {
+    inner: Box<dyn Future<Item = PipelineSimulateResponse, Error = Error> + Send>,
+}
+
+impl Pending {
+    fn new<F>(fut: F) -> Self
+    where
+        F: Future<Item = PipelineSimulateResponse, Error = Error> + Send + 'static,
+    {
+        Pending {
+            inner: Box::new(fut),
+        }
+    }
+}
+
+impl Future for Pending {
+    type Item = PipelineSimulateResponse;
+    type Error = Error;
+
+    fn poll(&mut self) -> Poll<Self::Item, Self::Error> {
+        self.inner.poll()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{
+        self,
+        Value,
+    };
+
+    use crate::{
+        prelude::*,
+        tests::*,
+    };
+
+    #[test]
+    fn is_send() {
+        assert_send::<super::Pending>();
+    }
+
+    #[test]
+    fn inline_pipeline_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let pipeline = json!({
+            "processors": [{ "lowercase": { "field": "method" } }]
+        });
+
+        let req = client
+            .simulate_pipeline()
+            .pipeline(pipeline.clone())
+            .doc(json!({ "method": "GET" }))
+            .docs(vec![json!({ "method": "POST" })])
+            .inner
+            .into_request()
+            .unwrap();
+
+        let expected_body = json!({
+            "pipeline": pipeline,
+            "docs": [
+                { "_source": { "method": "GET" } },
+                { "_source": { "method": "POST" } }
+            ]
+        });
+
+        let actual_body: Value = serde_json::from_slice(&req.body).unwrap();
+
+        assert_eq!("/_ingest/pipeline/_simulate", req.url.as_ref());
+        assert_eq!(expected_body, actual_body);
+    }
+
+    #[test]
+    fn existing_pipeline_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .simulate_pipeline()
+            .id("logs")
+            .doc(json!({ "message": "a" }))
+            .inner
+            .into_request()
+            .unwrap();
+
+        let actual_body: Value = serde_json::from_slice(&req.body).unwrap();
+
+        assert_eq!("/_ingest/pipeline/logs/_simulate", req.url.as_ref());
+        assert_eq!(
+            json!({ "docs": [{ "_source": { "message": "a" } }] }),
+            actual_body
+        );
+    }
+}
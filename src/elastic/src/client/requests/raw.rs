@@ -11,12 +11,19 @@ use crate::{
         Client,
     },
     endpoints::Endpoint,
-    http::sender::{
-        NextParams,
-        NodeAddresses,
-        SendableRequest,
-        SendableRequestParams,
-        Sender,
+    error::{
+        self,
+        Error,
+    },
+    http::{
+        record::RequestRecord,
+        sender::{
+            NextParams,
+            NodeAddresses,
+            SendableRequest,
+            SendableRequestParams,
+            Sender,
+        },
     },
 };
 
@@ -96,6 +103,46 @@ where
     {
         RequestBuilder::initial(self.clone(), RawRequestInner::new(endpoint))
     }
+
+    /**
+    Create a [`RawRequestBuilder`][RawRequestBuilder] that sends a [`RequestRecord`][RequestRecord] again.
+
+    The request is sent to the path in the record on the nodes of this `Client`.
+    An error is returned if the record has an invalid method.
+
+    # Examples
+
+    Replay a request from an audit log:
+
+    ```no_run
+    # extern crate serde_json;
+    # use elastic::prelude::*;
+    # use elastic::http::record::RequestRecord;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    # let line = "";
+    let record: RequestRecord = serde_json::from_str(line)?;
+
+    let response = client.replay(&record)?.send()?;
+    # Ok(())
+    # }
+    ```
+
+    [RawRequestBuilder]: requests/raw/type.RawRequestBuilder.html
+    [RequestRecord]: ../http/record/struct.RequestRecord.html
+    */
+    pub fn replay(
+        &self,
+        record: &RequestRecord,
+    ) -> Result<RawRequestBuilder<TSender, Endpoint<'static, String>, String>, Error>
+    where
+        String: Into<TSender::Body>,
+    {
+        let endpoint = record.to_endpoint().map_err(error::request)?;
+
+        Ok(self.request(endpoint))
+    }
 }
 
 impl<TSender, TEndpoint, TBody> RawRequestBuilder<TSender, TEndpoint, TBody>
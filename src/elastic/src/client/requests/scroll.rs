@@ -0,0 +1,1096 @@
+/*!
+Builders for [scrolling][docs-scroll] through all of the hits for a search request.
+
+Scrolling is useful for exporting large numbers of documents from an index.
+Long exports that page with `search_after` can persist their position with a [`Checkpoint`][Checkpoint] so they can be resumed after a crash instead of restarting from the beginning.
+
+Open scroll contexts hold resources on the cluster until they expire.
+A client keeps track of the scroll contexts opened by its scrolls so they can be cleared when they're no longer needed:
+
+- a scroll clears its context when it finishes
+- a scroll that's dropped before finishing leaves its context open and tracked by the client
+- [`Client.clear_all_scrolls`][Client.clear_all_scrolls] clears every context that's still open
+- [`Client.clear_scrolls_on_drop`][Client.clear_scrolls_on_drop] returns a guard that clears every context that's still open when it's dropped,
+like when a process is shutting down
+
+[docs-scroll]: https://www.elastic.co/guide/en/elasticsearch/reference/master/search-request-scroll.html
+[Checkpoint]: trait.Checkpoint.html
+[Client.clear_all_scrolls]: ../../struct.Client.html#method.clear_all_scrolls
+[Client.clear_scrolls_on_drop]: ../../struct.Client.html#method.clear_scrolls_on_drop
+*/
+
+use fluent_builder::SharedFluentBuilder;
+use serde::de::DeserializeOwned;
+use serde_json::{
+    self,
+    Value,
+};
+use std::{
+    collections::HashSet,
+    error::Error as StdError,
+    fs,
+    io,
+    marker::PhantomData,
+    path::PathBuf,
+    sync::{
+        Arc,
+        Mutex,
+        MutexGuard,
+    },
+    thread,
+    time::Duration,
+};
+
+use crate::{
+    client::{
+        requests::{
+            raw::RawRequestInner,
+            RequestBuilder,
+        },
+        responses::{
+            search::Hit,
+            SearchResponse,
+        },
+        Client,
+        SyncClient,
+    },
+    endpoints::{
+        ClearScrollRequest,
+        ScrollRequest,
+        SearchRequest,
+    },
+    error::{
+        self,
+        Error,
+    },
+    http::{
+        sender::{
+            RequestParams,
+            SyncSender,
+        },
+        StatusCode,
+    },
+    params::{
+        Index,
+        Type,
+    },
+    types::settings::time_value,
+};
+
+/**
+A [scroll request][docs-scroll] builder that can be configured before sending.
+
+Call [`Client.scroll`][Client.scroll] to get a `ScrollRequestBuilder`.
+The `send` method will return an iterator over the batches of hits.
+
+[docs-scroll]: https://www.elastic.co/guide/en/elasticsearch/reference/master/search-request-scroll.html
+[Client.scroll]: ../../struct.Client.html#scroll-request
+*/
+pub type ScrollRequestBuilder<TDocument> =
+    RequestBuilder<SyncSender, ScrollRequestInner<TDocument>>;
+
+#[doc(hidden)]
+pub struct ScrollRequestInner<TDocument> {
+    index: Index<'static>,
+    ty: Option<Type<'static>>,
+    body: Value,
+    keep_alive: Duration,
+    search_after: bool,
+    checkpoint: Option<SharedCheckpoint>,
+    checkpoint_every: u32,
+    _marker: PhantomData<TDocument>,
+}
+
+// Checkpoints only need to be `Send`, so they're kept behind a lock to keep the builder `Sync`
+struct SharedCheckpoint(Mutex<Box<dyn Checkpoint + Send>>);
+
+impl SharedCheckpoint {
+    fn get_mut(&mut self) -> &mut (dyn Checkpoint + Send + 'static) {
+        &mut **self
+            .0
+            .get_mut()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/** The ids of the scroll contexts opened by a client that haven't been cleared. */
+#[derive(Clone, Default)]
+pub(crate) struct OpenScrolls(Arc<Mutex<HashSet<String>>>);
+
+impl OpenScrolls {
+    fn ids(&self) -> MutexGuard<HashSet<String>> {
+        self.0
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn track(&self, scroll_id: &str) {
+        self.ids().insert(scroll_id.to_owned());
+    }
+
+    fn forget(&self, scroll_id: &str) {
+        self.ids().remove(scroll_id);
+    }
+
+    fn take(&self) -> Vec<String> {
+        self.ids().drain().collect()
+    }
+
+    fn len(&self) -> usize {
+        self.ids().len()
+    }
+}
+
+/**
+The position of a scroll.
+*/
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScrollCursor {
+    /**
+    The id of an open scroll context.
+
+    A scroll can't be resumed from a scroll id because the context moves forward on the cluster each time a batch is fetched.
+    */
+    ScrollId(String),
+    /** The sort values of the last hit that was processed. */
+    SearchAfter(Vec<Value>),
+}
+
+/**
+A persisted scroll position.
+*/
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScrollCheckpoint {
+    cursor: ScrollCursor,
+    hits: u64,
+}
+
+impl ScrollCheckpoint {
+    /** The position to resume the scroll from. */
+    pub fn cursor(&self) -> &ScrollCursor {
+        &self.cursor
+    }
+
+    /** The number of hits that were processed before the checkpoint was saved. */
+    pub fn hits(&self) -> u64 {
+        self.hits
+    }
+}
+
+/**
+A place to persist the position of a scroll.
+
+A checkpoint is saved when the next batch of hits is requested, so a batch is only checkpointed after it's been processed.
+When the scroll finishes the checkpoint is cleared.
+
+Only scrolls that page with `search_after` can be checkpointed,
+because a `search_after` cursor can be replayed to fetch the same batch again.
+*/
+pub trait Checkpoint {
+    /** Load a previously saved checkpoint, if there is one. */
+    fn load(&mut self) -> Result<Option<ScrollCheckpoint>, Box<dyn StdError + Send + Sync>>;
+
+    /** Save a checkpoint, replacing any previously saved one. */
+    fn save(
+        &mut self,
+        checkpoint: &ScrollCheckpoint,
+    ) -> Result<(), Box<dyn StdError + Send + Sync>>;
+
+    /** Remove any saved checkpoint. */
+    fn clear(&mut self) -> Result<(), Box<dyn StdError + Send + Sync>>;
+}
+
+/**
+A checkpoint that's persisted as json in a file.
+
+The file is replaced atomically when the checkpoint is saved and is removed when the scroll finishes.
+*/
+#[derive(Debug, Clone)]
+pub struct FileCheckpoint {
+    path: PathBuf,
+}
+
+impl FileCheckpoint {
+    /** Persist checkpoints to the file at the given path. */
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        FileCheckpoint { path: path.into() }
+    }
+}
+
+impl Checkpoint for FileCheckpoint {
+    fn load(&mut self) -> Result<Option<ScrollCheckpoint>, Box<dyn StdError + Send + Sync>> {
+        match fs::read(&self.path) {
+            Ok(bytes) => Ok(Some(serde_json::from_slice(&bytes)?)),
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn save(
+        &mut self,
+        checkpoint: &ScrollCheckpoint,
+    ) -> Result<(), Box<dyn StdError + Send + Sync>> {
+        let tmp = self.path.with_extension("tmp");
+
+        fs::write(&tmp, serde_json::to_vec(checkpoint)?)?;
+        fs::rename(&tmp, &self.path)?;
+
+        Ok(())
+    }
+
+    fn clear(&mut self) -> Result<(), Box<dyn StdError + Send + Sync>> {
+        match fs::remove_file(&self.path) {
+            Ok(()) => Ok(()),
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+/**
+# Scroll request
+*/
+impl Client<SyncSender> {
+    /**
+    Create a [`ScrollRequestBuilder`][ScrollRequestBuilder] with this `Client` that can be configured before sending.
+
+    For more details, see:
+
+    - [builder methods][builder-methods]
+    - [send synchronously][send-sync]
+
+    # Examples
+
+    Export all documents in an index sorted by a unique `id` field, saving a checkpoint to a file after every batch:
+
+    ```no_run
+    # #[macro_use] extern crate serde_json;
+    # use serde_json::Value;
+    # use elastic::prelude::*;
+    # use elastic::client::requests::scroll::FileCheckpoint;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    let batches = client.scroll::<Value>()
+                        .index("myindex")
+                        .body(json!({
+                            "size": 1000,
+                            "sort": ["id"]
+                        }))
+                        .search_after()
+                        .checkpoint(FileCheckpoint::new("export.checkpoint"))
+                        .send()?;
+
+    for batch in batches {
+        for doc in batch?.documents() {
+            println!("{:?}", doc);
+        }
+    }
+    # Ok(())
+    # }
+    ```
+
+    [ScrollRequestBuilder]: requests/scroll/type.ScrollRequestBuilder.html
+    [builder-methods]: requests/scroll/type.ScrollRequestBuilder.html#builder-methods
+    [send-sync]: requests/scroll/type.ScrollRequestBuilder.html#send-synchronously
+    */
+    pub fn scroll<TDocument>(&self) -> ScrollRequestBuilder<TDocument>
+    where
+        TDocument: DeserializeOwned,
+    {
+        RequestBuilder::initial(self.clone(), ScrollRequestInner::new(self.default_index()))
+    }
+
+    /** The number of scroll contexts opened by this client's scrolls that haven't been cleared. */
+    pub fn open_scrolls(&self) -> usize {
+        self.scrolls.len()
+    }
+
+    /**
+    Clear every scroll context opened by this client's scrolls that hasn't been cleared already.
+
+    Call this method when a process is shutting down so scroll contexts don't hold resources on the cluster until they expire.
+    Any scrolls that are still being iterated won't be able to continue.
+    Clones of a client share the same scroll contexts.
+
+    The number of scroll contexts that were cleared is returned.
+    If the request fails then the contexts are still tracked so clearing them can be retried.
+
+    # Examples
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+    # let client = SyncClientBuilder::new().build()?;
+    // Run the exporter, then clear any scrolls it left open before exiting
+    let cleared = client.clear_all_scrolls()?;
+
+    println!("cleared {} scroll contexts", cleared);
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn clear_all_scrolls(&self) -> Result<usize, Error> {
+        let scroll_ids = self.scrolls.take();
+        if scroll_ids.is_empty() {
+            return Ok(0);
+        }
+
+        let body = json!({ "scroll_id": scroll_ids });
+        let req = ClearScrollRequest::new(body.to_string().into_bytes());
+
+        let res = self.request(req).send().and_then(|res| match res.status() {
+            // None of the scroll contexts were found, so they've already expired
+            StatusCode::NOT_FOUND => Ok(()),
+            _ => res.into_response::<Value>().map(|_| ()),
+        });
+
+        match res {
+            Ok(()) => Ok(scroll_ids.len()),
+            Err(e) => {
+                for scroll_id in &scroll_ids {
+                    self.scrolls.track(scroll_id);
+                }
+
+                Err(e)
+            }
+        }
+    }
+
+    /**
+    Get a guard that clears every scroll context opened by this client's scrolls when it's dropped.
+
+    Keep the guard alive until a process is shutting down, like in a variable at the top of `main`,
+    so scroll contexts that are still open when it exits don't hold resources on the cluster until they expire.
+    See [`clear_all_scrolls`][clear_all_scrolls] for details.
+
+    Dropping the guard blocks until the contexts are cleared.
+    Errors are ignored, and the contexts aren't cleared if the guard is dropped while the thread is panicking.
+
+    # Examples
+
+    ```no_run
+    # use elastic::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+    let client = SyncClientBuilder::new().build()?;
+
+    let _guard = client.clear_scrolls_on_drop();
+
+    // Run the exporter. Any scrolls it leaves open are cleared when `_guard` goes out of scope
+    # Ok(())
+    # }
+    ```
+
+    [clear_all_scrolls]: #method.clear_all_scrolls
+    */
+    pub fn clear_scrolls_on_drop(&self) -> ClearScrollsGuard {
+        ClearScrollsGuard {
+            client: self.clone(),
+        }
+    }
+}
+
+/**
+A guard that clears every scroll context opened by a client when it's dropped.
+
+Call [`Client.clear_scrolls_on_drop`][Client.clear_scrolls_on_drop] to get a `ClearScrollsGuard`.
+
+[Client.clear_scrolls_on_drop]: ../../struct.Client.html#method.clear_scrolls_on_drop
+*/
+#[must_use = "scroll contexts are cleared when the guard is dropped"]
+pub struct ClearScrollsGuard {
+    client: SyncClient,
+}
+
+impl Drop for ClearScrollsGuard {
+    fn drop(&mut self) {
+        // Don't send requests while unwinding, the contexts will expire anyway
+        if thread::panicking() {
+            return;
+        }
+
+        let _ = self.client.clear_all_scrolls();
+    }
+}
+
+impl<TDocument> ScrollRequestInner<TDocument> {
+    fn new(index: Index<'static>) -> Self {
+        ScrollRequestInner {
+            index: index,
+            ty: None,
+            body: json!({}),
+            keep_alive: Duration::from_secs(60),
+            search_after: false,
+            checkpoint: None,
+            checkpoint_every: 1,
+            _marker: PhantomData,
+        }
+    }
+
+    fn search_request(&self, search_after: Option<&[Value]>) -> SearchRequest<'static, Vec<u8>> {
+        let mut body = self.body.clone();
+        if let (Some(search_after), Some(body)) = (search_after, body.as_object_mut()) {
+            body.insert("search_after".to_owned(), Value::from(search_after));
+        }
+
+        let body = body.to_string().into_bytes();
+
+        match self.ty {
+            Some(ref ty) => SearchRequest::for_index_ty(self.index.clone(), ty.clone(), body),
+            None => SearchRequest::for_index(self.index.clone(), body),
+        }
+    }
+
+    fn scroll_request(&self, scroll_id: &str) -> ScrollRequest<'static, Vec<u8>> {
+        let body = json!({
+            "scroll": time_value(self.keep_alive),
+            "scroll_id": scroll_id
+        });
+
+        ScrollRequest::new(body.to_string().into_bytes())
+    }
+}
+
+/**
+# Builder methods
+
+Configure a `ScrollRequestBuilder` before sending it.
+*/
+impl<TDocument> ScrollRequestBuilder<TDocument> {
+    /**
+    Set the indices for the scroll request.
+
+    If no index is specified then `_all` will be used.
+    If the client has an [`IndexNamer`][IndexNamer] then `_all` is named by it too.
+
+    [IndexNamer]: ../../trait.IndexNamer.html
+    */
+    pub fn index(mut self, index: impl Into<Index<'static>>) -> Self {
+        self.inner.index = self.client.name_index(index.into());
+        self
+    }
+
+    /** Set the types for the scroll request. */
+    pub fn ty(mut self, ty: impl Into<Type<'static>>) -> Self {
+        self.inner.ty = Some(ty.into());
+        self
+    }
+
+    /**
+    Set the search body for the scroll request.
+
+    The `size` in the body is the number of hits in each batch.
+    If no body is specified then all documents will be scrolled.
+    */
+    pub fn body(mut self, body: Value) -> Self {
+        self.inner.body = body;
+        self
+    }
+
+    /**
+    Set how long the scroll context is kept alive between batches.
+
+    The default is one minute.
+    */
+    pub fn keep_alive(mut self, keep_alive: Duration) -> Self {
+        self.inner.keep_alive = keep_alive;
+        self
+    }
+
+    /**
+    Page through hits using `search_after` instead of a scroll context.
+
+    The body must have a `sort` that uniquely identifies each document, like a unique id field.
+    Unlike a scroll id, a `search_after` cursor doesn't expire and can be replayed, so the scroll can be checkpointed and resumed any time after a crash.
+    */
+    pub fn search_after(mut self) -> Self {
+        self.inner.search_after = true;
+        self
+    }
+
+    /**
+    Persist the position of the scroll so it can be resumed.
+
+    The scroll must page with [`search_after`][search_after], otherwise sending it will fail.
+    If the checkpoint contains a previously saved position then the scroll will resume from there.
+
+    [search_after]: #method.search_after
+    */
+    pub fn checkpoint(mut self, checkpoint: impl Checkpoint + Send + 'static) -> Self {
+        self.inner.checkpoint = Some(SharedCheckpoint(Mutex::new(Box::new(checkpoint))));
+        self
+    }
+
+    /**
+    Only save a checkpoint after every `batches` batches of hits.
+
+    The default is to save a checkpoint after every batch.
+    Any batches processed since the last checkpoint are fetched again when the scroll is resumed.
+    */
+    pub fn checkpoint_every(mut self, batches: u32) -> Self {
+        self.inner.checkpoint_every = batches.max(1);
+        self
+    }
+}
+
+/**
+# Send synchronously
+*/
+impl<TDocument> ScrollRequestBuilder<TDocument>
+where
+    TDocument: DeserializeOwned,
+{
+    /**
+    Start scrolling using a [`SyncClient`][SyncClient].
+
+    Batches of hits are fetched as the returned iterator is consumed.
+    If a checkpoint was given then it's loaded before returning.
+
+    [SyncClient]: ../../type.SyncClient.html
+    */
+    pub fn send(self) -> Result<Scroll<TDocument>, Error> {
+        let (client, params_builder, mut inner) = (self.client, self.params_builder, self.inner);
+
+        if inner.checkpoint.is_some() && !inner.search_after {
+            return Err(error::request(error::message(
+                "only scrolls that use `search_after` can be checkpointed",
+            )));
+        }
+
+        let resume = match inner.checkpoint {
+            Some(ref mut checkpoint) => checkpoint
+                .get_mut()
+                .load()
+                .map_err(|e| error::request(error::wrapped(e)))?,
+            None => None,
+        };
+
+        let (cursor, hits) = match resume {
+            // The scroll context has moved past the saved scroll id, so resuming from it would skip hits
+            Some(ScrollCheckpoint {
+                cursor: ScrollCursor::ScrollId(_),
+                ..
+            }) => {
+                return Err(error::request(error::message(
+                    "a scroll can't be resumed from a scroll id",
+                )));
+            }
+            Some(ScrollCheckpoint { cursor, hits }) => (Some(cursor), hits),
+            None => (None, 0),
+        };
+
+        Ok(Scroll {
+            client,
+            params_builder,
+            inner,
+            cursor,
+            hits,
+            pending: None,
+            batches: 0,
+            done: false,
+        })
+    }
+}
+
+/**
+An iterator over the batches of hits for a scroll request.
+
+Each batch is fetched when it's requested from the iterator.
+The iterator finishes when a batch has no hits, or after the first error.
+Dropping the iterator before it finishes doesn't block to clear the scroll context.
+The context is left open until it expires or it's cleared by [`Client.clear_all_scrolls`][Client.clear_all_scrolls].
+
+[Client.clear_all_scrolls]: ../../struct.Client.html#method.clear_all_scrolls
+*/
+pub struct Scroll<TDocument> {
+    client: SyncClient,
+    params_builder: SharedFluentBuilder<RequestParams>,
+    inner: ScrollRequestInner<TDocument>,
+    cursor: Option<ScrollCursor>,
+    hits: u64,
+    pending: Option<ScrollCheckpoint>,
+    batches: u32,
+    done: bool,
+}
+
+impl<TDocument> Scroll<TDocument>
+where
+    TDocument: DeserializeOwned,
+{
+    /** The position of the last batch that was fetched. */
+    pub fn cursor(&self) -> Option<&ScrollCursor> {
+        self.cursor.as_ref()
+    }
+
+    /** The number of hits that have been fetched, including any before the scroll was resumed. */
+    pub fn hits(&self) -> u64 {
+        self.hits
+    }
+
+    fn save_pending(&mut self) -> Result<(), Error> {
+        if let Some(pending) = self.pending.take() {
+            self.batches += 1;
+
+            if self.batches % self.inner.checkpoint_every == 0 {
+                if let Some(ref mut checkpoint) = self.inner.checkpoint {
+                    checkpoint
+                        .get_mut()
+                        .save(&pending)
+                        .map_err(|e| error::request(error::wrapped(e)))?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn fetch(&mut self) -> Result<SearchResponse<TDocument>, Error> {
+        let client = self.client.clone();
+        let params_builder = self.params_builder.clone();
+
+        match (self.cursor.as_ref(), self.inner.search_after) {
+            (Some(&ScrollCursor::ScrollId(ref scroll_id)), _) => {
+                let req = self.inner.scroll_request(scroll_id);
+
+                RequestBuilder::new(client, params_builder, RawRequestInner::new(req))
+                    .send()?
+                    .into_response()
+            }
+            (cursor, true) => {
+                let search_after = match cursor {
+                    Some(&ScrollCursor::SearchAfter(ref sort)) => Some(sort.as_slice()),
+                    _ => None,
+                };
+
+                let req = self.inner.search_request(search_after);
+
+                RequestBuilder::new(client, params_builder, RawRequestInner::new(req))
+                    .send()?
+                    .into_response()
+            }
+            (_, false) => {
+                let req = self.inner.search_request(None);
+                let keep_alive = time_value(self.inner.keep_alive);
+
+                let params_builder = params_builder
+                    .fluent(move |params| params.url_param("scroll", keep_alive.clone()))
+                    .shared();
+
+                RequestBuilder::new(client, params_builder, RawRequestInner::new(req))
+                    .send()?
+                    .into_response()
+            }
+        }
+    }
+
+    fn finish(&mut self) -> Result<(), Error> {
+        self.done = true;
+
+        self.clear_context();
+
+        if let Some(ref mut checkpoint) = self.inner.checkpoint {
+            checkpoint
+                .get_mut()
+                .clear()
+                .map_err(|e| error::request(error::wrapped(e)))?;
+        }
+
+        Ok(())
+    }
+
+    fn next_batch(&mut self) -> Result<Option<SearchResponse<TDocument>>, Error> {
+        self.save_pending()?;
+
+        let batch = self.fetch()?;
+
+        let batch_hits = batch.hits().count() as u64;
+        if batch_hits == 0 {
+            self.finish()?;
+            return Ok(None);
+        }
+
+        self.hits += batch_hits;
+
+        let cursor = if self.inner.search_after {
+            batch
+                .hits()
+                .last()
+                .and_then(Hit::sort)
+                .map(|sort| ScrollCursor::SearchAfter(sort.to_vec()))
+        } else {
+            batch
+                .scroll_id()
+                .map(|scroll_id| ScrollCursor::ScrollId(scroll_id.to_owned()))
+        };
+
+        match cursor {
+            Some(cursor) => {
+                if let ScrollCursor::SearchAfter(_) = cursor {
+                    self.pending = Some(ScrollCheckpoint {
+                        cursor: cursor.clone(),
+                        hits: self.hits,
+                    });
+                }
+
+                if let ScrollCursor::ScrollId(ref scroll_id) = cursor {
+                    if let Some(ScrollCursor::ScrollId(ref previous)) = self.cursor {
+                        self.client.scrolls.forget(previous);
+                    }
+
+                    self.client.scrolls.track(scroll_id);
+                }
+
+                self.cursor = Some(cursor);
+            }
+            None => {
+                return Err(error::request(error::message(
+                    "the search response doesn't contain a cursor to fetch the next batch with",
+                )))
+            }
+        }
+
+        Ok(Some(batch))
+    }
+}
+
+impl<TDocument> Scroll<TDocument> {
+    fn clear_context(&mut self) {
+        if let Some(ScrollCursor::ScrollId(ref scroll_id)) = self.cursor {
+            // If the context can't be cleared then it's still tracked by the client,
+            // so it can be cleared later by `clear_all_scrolls`
+            if clear_scroll(&self.client, self.params_builder.clone(), scroll_id).is_ok() {
+                self.client.scrolls.forget(scroll_id);
+            }
+        }
+    }
+}
+
+fn clear_scroll(
+    client: &SyncClient,
+    params_builder: SharedFluentBuilder<RequestParams>,
+    scroll_id: &str,
+) -> Result<(), Error> {
+    let body = json!({ "scroll_id": [scroll_id] });
+    let req = ClearScrollRequest::new(body.to_string().into_bytes());
+
+    RequestBuilder::new(client.clone(), params_builder, RawRequestInner::new(req))
+        .send()
+        .and_then(|res| match res.status() {
+            // The scroll context wasn't found, so it's already expired
+            StatusCode::NOT_FOUND => Ok(()),
+            _ => res.into_response::<Value>().map(|_| ()),
+        })
+}
+
+impl<TDocument> Iterator for Scroll<TDocument>
+where
+    TDocument: DeserializeOwned,
+{
+    type Item = Result<SearchResponse<TDocument>, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        match self.next_batch() {
+            Ok(Some(batch)) => Some(Ok(batch)),
+            Ok(None) => None,
+            Err(e) => {
+                self.done = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{
+        self,
+        Value,
+    };
+    use std::{
+        sync::{
+            Arc,
+            Mutex,
+        },
+        time::Duration,
+    };
+
+    use super::*;
+    use crate::{
+        client::AffixIndexNamer,
+        prelude::*,
+    };
+
+    #[derive(Clone, Default)]
+    struct MemoryCheckpoint(Arc<Mutex<Option<ScrollCheckpoint>>>);
+
+    impl Checkpoint for MemoryCheckpoint {
+        fn load(&mut self) -> Result<Option<ScrollCheckpoint>, Box<dyn StdError + Send + Sync>> {
+            Ok(self.0.lock().unwrap().clone())
+        }
+
+        fn save(
+            &mut self,
+            checkpoint: &ScrollCheckpoint,
+        ) -> Result<(), Box<dyn StdError + Send + Sync>> {
+            *self.0.lock().unwrap() = Some(checkpoint.clone());
+            Ok(())
+        }
+
+        fn clear(&mut self) -> Result<(), Box<dyn StdError + Send + Sync>> {
+            *self.0.lock().unwrap() = None;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn default_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client.scroll::<Value>().inner.search_request(None);
+
+        assert_eq!("/_all/_search", req.url.as_ref());
+    }
+
+    #[test]
+    fn default_request_with_index_namer() {
+        let client = SyncClientBuilder::new()
+            .index_namer(AffixIndexNamer::new().prefix("tenant-"))
+            .build()
+            .unwrap();
+
+        let req = client.scroll::<Value>().inner.search_request(None);
+
+        assert_eq!("/tenant-*/_search", req.url.as_ref());
+    }
+
+    #[test]
+    fn search_after_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .scroll::<Value>()
+            .index("new-idx")
+            .body(json!({ "sort": ["id"] }))
+            .search_after()
+            .inner
+            .search_request(Some(&[json!(42)]));
+
+        let body: Value = serde_json::from_slice(&req.body).unwrap();
+
+        assert_eq!("/new-idx/_search", req.url.as_ref());
+        assert_eq!(json!({ "sort": ["id"], "search_after": [42] }), body);
+    }
+
+    #[test]
+    fn scroll_request() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let req = client
+            .scroll::<Value>()
+            .keep_alive(Duration::from_secs(30))
+            .inner
+            .scroll_request("abc");
+
+        let body: Value = serde_json::from_slice(&req.body).unwrap();
+
+        assert_eq!("/_search/scroll", req.url.as_ref());
+        assert_eq!(json!({ "scroll": "30s", "scroll_id": "abc" }), body);
+    }
+
+    #[test]
+    fn resume_from_checkpoint() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let checkpoint = MemoryCheckpoint::default();
+        *checkpoint.0.lock().unwrap() = Some(ScrollCheckpoint {
+            cursor: ScrollCursor::SearchAfter(vec![json!(42)]),
+            hits: 100,
+        });
+
+        let scroll = client
+            .scroll::<Value>()
+            .search_after()
+            .checkpoint(checkpoint)
+            .send()
+            .unwrap();
+
+        assert_eq!(
+            Some(&ScrollCursor::SearchAfter(vec![json!(42)])),
+            scroll.cursor()
+        );
+        assert_eq!(100, scroll.hits());
+    }
+
+    #[test]
+    fn resume_after_crash_mid_batch() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let checkpoint = MemoryCheckpoint::default();
+
+        let mut scroll = client
+            .scroll::<Value>()
+            .body(json!({ "sort": ["id"] }))
+            .search_after()
+            .checkpoint(checkpoint.clone())
+            .send()
+            .unwrap();
+
+        // The first batch is processed, then the second batch is requested
+        scroll.pending = Some(ScrollCheckpoint {
+            cursor: ScrollCursor::SearchAfter(vec![json!(10)]),
+            hits: 10,
+        });
+        scroll.save_pending().unwrap();
+
+        // The second batch is fetched, but the process crashes before it's processed
+        scroll.pending = Some(ScrollCheckpoint {
+            cursor: ScrollCursor::SearchAfter(vec![json!(20)]),
+            hits: 20,
+        });
+        drop(scroll);
+
+        let scroll = client
+            .scroll::<Value>()
+            .body(json!({ "sort": ["id"] }))
+            .search_after()
+            .checkpoint(checkpoint)
+            .send()
+            .unwrap();
+
+        assert_eq!(10, scroll.hits());
+
+        // The resumed scroll fetches the second batch again
+        let search_after = match scroll.cursor() {
+            Some(&ScrollCursor::SearchAfter(ref sort)) => sort.clone(),
+            cursor => panic!("unexpected cursor {:?}", cursor),
+        };
+        let req = scroll.inner.search_request(Some(&search_after));
+
+        let body: Value = serde_json::from_slice(&req.body).unwrap();
+
+        assert_eq!(json!({ "sort": ["id"], "search_after": [10] }), body);
+    }
+
+    #[test]
+    fn checkpoint_without_search_after_fails() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let scroll = client
+            .scroll::<Value>()
+            .checkpoint(MemoryCheckpoint::default())
+            .send();
+
+        assert!(scroll.is_err());
+    }
+
+    #[test]
+    fn dropped_scroll_is_tracked() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let builder = client.scroll::<Value>();
+        client.scrolls.track("abc");
+
+        let scroll = Scroll {
+            client: client.clone(),
+            params_builder: builder.params_builder,
+            inner: builder.inner,
+            cursor: Some(ScrollCursor::ScrollId("abc".to_owned())),
+            hits: 0,
+            pending: None,
+            batches: 0,
+            done: false,
+        };
+
+        drop(scroll);
+
+        // The context wasn't cleared, so it can still be cleared by the client
+        assert_eq!(1, client.open_scrolls());
+    }
+
+    #[test]
+    fn open_scrolls_are_shared() {
+        let scrolls = OpenScrolls::default();
+        let shared = scrolls.clone();
+
+        scrolls.track("abc");
+        scrolls.track("def");
+        shared.forget("abc");
+
+        assert_eq!(1, scrolls.len());
+        assert_eq!(vec!["def".to_owned()], shared.take());
+        assert_eq!(0, scrolls.len());
+    }
+
+    #[test]
+    fn resume_from_scroll_id_fails() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let checkpoint = MemoryCheckpoint::default();
+        *checkpoint.0.lock().unwrap() = Some(ScrollCheckpoint {
+            cursor: ScrollCursor::ScrollId("abc".to_owned()),
+            hits: 100,
+        });
+
+        let scroll = client
+            .scroll::<Value>()
+            .search_after()
+            .checkpoint(checkpoint)
+            .send();
+
+        assert!(scroll.is_err());
+    }
+
+    #[test]
+    fn save_checkpoint_for_processed_batch() {
+        let client = SyncClientBuilder::new().build().unwrap();
+
+        let checkpoint = MemoryCheckpoint::default();
+
+        let mut scroll = client
+            .scroll::<Value>()
+            .search_after()
+            .checkpoint(checkpoint.clone())
+            .checkpoint_every(2)
+            .send()
+            .unwrap();
+
+        let first = ScrollCheckpoint {
+            cursor: ScrollCursor::SearchAfter(vec![json!(10)]),
+            hits: 10,
+        };
+        let second = ScrollCheckpoint {
+            cursor: ScrollCursor::SearchAfter(vec![json!(20)]),
+            hits: 20,
+        };
+
+        scroll.pending = Some(first);
+        scroll.save_pending().unwrap();
+        assert_eq!(None, *checkpoint.0.lock().unwrap());
+
+        scroll.pending = Some(second.clone());
+        scroll.save_pending().unwrap();
+        assert_eq!(Some(second), *checkpoint.0.lock().unwrap());
+    }
+
+    #[test]
+    fn serialise_checkpoint() {
+        let checkpoint = ScrollCheckpoint {
+            cursor: ScrollCursor::SearchAfter(vec![json!("a"), json!(1)]),
+            hits: 5,
+        };
+
+        let ser = serde_json::to_value(&checkpoint).unwrap();
+
+        assert_eq!(
+            json!({ "cursor": { "search_after": ["a", 1] }, "hits": 5 }),
+            ser
+        );
+    }
+}
@@ -55,7 +55,7 @@ pub type SearchRequestBuilder<TSender, TDocument, TBody> =
 
 #[doc(hidden)]
 pub struct SearchRequestInner<TDocument, TBody> {
-    index: Option<Index<'static>>,
+    index: Index<'static>,
     ty: Option<Type<'static>>,
     body: TBody,
     _marker: PhantomData<TDocument>,
@@ -143,7 +143,10 @@ where
     where
         TDocument: DeserializeOwned,
     {
-        RequestBuilder::initial(self.clone(), SearchRequestInner::new(empty_body()))
+        RequestBuilder::initial(
+            self.clone(),
+            SearchRequestInner::new(self.default_index(), empty_body()),
+        )
     }
 }
 
@@ -214,7 +217,9 @@ where
     where
         TDocument: DeserializeOwned + DocumentType,
     {
-        let index = TDocument::partial_static_index().map(|idx| idx.to_owned());
+        let index = TDocument::partial_static_index()
+            .map(|idx| self.inner.name_index(idx.to_owned()))
+            .unwrap_or_else(|| self.inner.default_index());
         let ty = TDocument::partial_static_ty().map(|ty| ty.to_owned());
 
         RequestBuilder::initial(
@@ -233,9 +238,9 @@ impl<TDocument, TBody> SearchRequestInner<TDocument, TBody>
 where
     TDocument: DeserializeOwned,
 {
-    fn new(body: TBody) -> Self {
+    fn new(index: Index<'static>, body: TBody) -> Self {
         SearchRequestInner {
-            index: None,
+            index: index,
             ty: None,
             body: body,
             _marker: PhantomData,
@@ -243,11 +248,9 @@ where
     }
 
     fn into_request(self) -> SearchRequest<'static, TBody> {
-        let index = self.index.unwrap_or_else(|| "_all".into());
-
         match self.ty {
-            Some(ty) => SearchRequest::for_index_ty(index, ty, self.body),
-            None => SearchRequest::for_index(index, self.body),
+            Some(ty) => SearchRequest::for_index_ty(self.index, ty, self.body),
+            None => SearchRequest::for_index(self.index, self.body),
         }
     }
 }
@@ -265,9 +268,12 @@ where
     Set the indices for the search request.
 
     If no index is specified then `_all` will be used.
+    If the client has an [`IndexNamer`][IndexNamer] then `_all` is named by it too.
+
+    [IndexNamer]: ../../trait.IndexNamer.html
     */
     pub fn index(mut self, index: impl Into<Index<'static>>) -> Self {
-        self.inner.index = Some(index.into());
+        self.inner.index = self.client.name_index(index.into());
         self
     }
 
@@ -277,6 +283,29 @@ where
         self
     }
 
+    /**
+    Set the [stored fields][stored-fields] to return with each hit.
+
+    The values of the fields can be read from [`Hit.fields`][Hit.fields].
+    The `_source` isn't returned when stored fields are requested unless it's also requested with the `_source` parameter.
+
+    [stored-fields]: https://www.elastic.co/guide/en/elasticsearch/reference/master/search-request-stored-fields.html
+    [Hit.fields]: ../../responses/search/struct.Hit.html#method.fields
+    */
+    pub fn stored_fields<I>(self, fields: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let fields = fields
+            .into_iter()
+            .map(|field| field.as_ref().to_owned())
+            .collect::<Vec<_>>()
+            .join(",");
+
+        self.params_fluent(move |params| params.url_param("stored_fields", fields.clone()))
+    }
+
     /**
     Set the body for the search request.
 
@@ -445,6 +474,7 @@ mod tests {
     use serde_json::Value;
 
     use crate::{
+        client::AffixIndexNamer,
         prelude::*,
         tests::*,
     };
@@ -467,6 +497,18 @@ mod tests {
         assert_eq!("/_all/_search", req.url.as_ref());
     }
 
+    #[test]
+    fn default_request_with_index_namer() {
+        let client = SyncClientBuilder::new()
+            .index_namer(AffixIndexNamer::new().prefix("tenant-"))
+            .build()
+            .unwrap();
+
+        let req = client.search::<Value>().inner.into_request();
+
+        assert_eq!("/tenant-*/_search", req.url.as_ref());
+    }
+
     #[test]
     fn specify_index() {
         let client = SyncClientBuilder::new().build().unwrap();
@@ -1,12 +1,20 @@
+use serde::de::DeserializeOwned;
+use serde_json::{
+    Map,
+    Value,
+};
+
 /** A default type for allocated fields in responses. */
 pub(crate) type DefaultAllocatedField = String;
 
 /** Returned hits metadata. */
-#[derive(Deserialize, Debug, Clone, Copy)]
+#[derive(Deserialize, Debug, Clone)]
 pub struct Shards {
     total: u32,
     successful: u32,
     failed: u32,
+    #[serde(default)]
+    failures: Vec<ShardFailure>,
 }
 
 impl Shards {
@@ -24,6 +32,207 @@ impl Shards {
     pub fn failed(&self) -> u32 {
         self.failed
     }
+
+    /**
+    The failures for shards that couldn't process the request.
+
+    Elasticsearch only returns one failure for each distinct reason,
+    so there may be fewer failures than the [number of failed shards][Shards.failed].
+
+    [Shards.failed]: #method.failed
+    */
+    pub fn failures(&self) -> &[ShardFailure] {
+        &self.failures
+    }
+
+    /**
+    Whether every shard failure is worth retrying.
+
+    This is `false` if there weren't any failures.
+    See [`ShardFailureKind::is_retryable`][ShardFailureKind.is_retryable] for the failures that can be retried.
+
+    [ShardFailureKind.is_retryable]: enum.ShardFailureKind.html#method.is_retryable
+    */
+    pub fn is_retryable(&self) -> bool {
+        !self.failures.is_empty()
+            && self
+                .failures
+                .iter()
+                .all(|failure| failure.kind().is_retryable())
+    }
+}
+
+/**
+A failure to process a request on a single shard.
+
+# Examples
+
+Check whether a search that partially failed is worth retrying:
+
+```no_run
+# use elastic::prelude::*;
+# use serde_json::Value;
+# fn main() { run().unwrap() }
+# fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+# let client = SyncClientBuilder::new().build()?;
+let response = client
+    .search::<Value>()
+    .index("myindex")
+    .send()?;
+
+for failure in response.shards().failures() {
+    println!(
+        "shard {:?} of {:?} on {:?} failed: {:?}",
+        failure.shard(),
+        failure.index(),
+        failure.node(),
+        failure.kind()
+    );
+}
+
+if response.shards().is_retryable() {
+    // Back off and send the search again
+}
+# Ok(())
+# }
+```
+*/
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct ShardFailure {
+    #[serde(default)]
+    shard: Option<i32>,
+    #[serde(default)]
+    index: Option<String>,
+    #[serde(default)]
+    node: Option<String>,
+    reason: ShardFailureReason,
+}
+
+impl ShardFailure {
+    /** The number of the shard that failed, if it's known. */
+    pub fn shard(&self) -> Option<i32> {
+        self.shard.filter(|shard| *shard >= 0)
+    }
+
+    /** The index of the shard that failed. */
+    pub fn index(&self) -> Option<&str> {
+        self.index.as_ref().map(|index| &**index)
+    }
+
+    /** The id of the node the shard that failed is on. */
+    pub fn node(&self) -> Option<&str> {
+        self.node.as_ref().map(|node| &**node)
+    }
+
+    /** The reason the shard failed. */
+    pub fn reason(&self) -> &ShardFailureReason {
+        &self.reason
+    }
+
+    /**
+    Classify the failure by the types of its reason and the reasons that caused it.
+
+    The first reason in the chain that's recognised decides the kind of failure.
+    */
+    pub fn kind(&self) -> ShardFailureKind {
+        self.reason
+            .causes()
+            .map(|reason| ShardFailureKind::from_ty(reason.ty()))
+            .find(|kind| *kind != ShardFailureKind::Other)
+            .unwrap_or(ShardFailureKind::Other)
+    }
+
+    /** Whether the shard rejected the request because it was overloaded. */
+    pub fn is_rejection(&self) -> bool {
+        self.kind() == ShardFailureKind::Rejection
+    }
+
+    /** Whether the shard failed because its data is corrupt. */
+    pub fn is_corruption(&self) -> bool {
+        self.kind() == ShardFailureKind::Corruption
+    }
+
+    /** Whether the shard timed out processing the request. */
+    pub fn is_timeout(&self) -> bool {
+        self.kind() == ShardFailureKind::Timeout
+    }
+}
+
+/** The reason for a shard failure, and the reason that caused it. */
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct ShardFailureReason {
+    #[serde(rename = "type")]
+    ty: String,
+    #[serde(default)]
+    reason: Option<String>,
+    #[serde(default)]
+    caused_by: Option<Box<ShardFailureReason>>,
+}
+
+impl ShardFailureReason {
+    /** The type of exception, like `es_rejected_execution_exception`. */
+    pub fn ty(&self) -> &str {
+        &self.ty
+    }
+
+    /** The message of the exception. */
+    pub fn reason(&self) -> Option<&str> {
+        self.reason.as_ref().map(|reason| &**reason)
+    }
+
+    /** The reason that caused this one. */
+    pub fn caused_by(&self) -> Option<&ShardFailureReason> {
+        self.caused_by.as_ref().map(|caused_by| &**caused_by)
+    }
+
+    /** Iterate through this reason and the chain of reasons that caused it. */
+    pub fn causes(&self) -> impl Iterator<Item = &ShardFailureReason> {
+        ::std::iter::successors(Some(self), |reason| reason.caused_by())
+    }
+
+    /** The last reason in the chain, which is the original cause of the failure. */
+    pub fn root_cause(&self) -> &ShardFailureReason {
+        self.causes().last().unwrap_or(self)
+    }
+}
+
+/** A broad classification of a shard failure. */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShardFailureKind {
+    /** The shard was overloaded, like a full search thread pool queue or a tripped circuit breaker. */
+    Rejection,
+    /** The data on the shard is corrupt. */
+    Corruption,
+    /** The shard timed out processing the request. */
+    Timeout,
+    /** Any other failure, like a malformed query or a missing field. */
+    Other,
+}
+
+impl ShardFailureKind {
+    fn from_ty(ty: &str) -> Self {
+        match ty {
+            "es_rejected_execution_exception" | "circuit_breaking_exception" => {
+                ShardFailureKind::Rejection
+            }
+            ty if ty.contains("corrupt") => ShardFailureKind::Corruption,
+            ty if ty.ends_with("timeout_exception") => ShardFailureKind::Timeout,
+            _ => ShardFailureKind::Other,
+        }
+    }
+
+    /**
+    Whether sending the same request again could succeed.
+
+    Rejections and timeouts are usually temporary, so they can be retried after backing off.
+    Corruption and other failures will happen again.
+    */
+    pub fn is_retryable(&self) -> bool {
+        match *self {
+            ShardFailureKind::Rejection | ShardFailureKind::Timeout => true,
+            ShardFailureKind::Corruption | ShardFailureKind::Other => false,
+        }
+    }
 }
 
 #[derive(Clone, Copy, Deserialize, Debug, PartialEq, Eq)]
@@ -39,3 +248,99 @@ pub(crate) enum DocumentResult {
     #[serde(rename = "created")]
     Created,
 }
+
+/**
+The [stored fields][stored-fields] returned for a document.
+
+Stored fields are requested by name using the `stored_fields` method on get and search request builders.
+Elasticsearch always returns the values of a stored field as an array, even if the field only has a single value.
+
+Values are deserialised into the type of the field, so the same wrapper types used in the document can be used to read them,
+like a `Date<M>` with the mapping's format or a `GeoPoint<M>`.
+
+# Examples
+
+Read a stored date field:
+
+```no_run
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# use elastic::prelude::*;
+# fn main() { run().unwrap() }
+# #[derive(Deserialize, ElasticType)]
+# struct MyType { }
+# fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+# let client = SyncClientBuilder::new().build()?;
+let response = client
+    .document::<MyType>()
+    .get(1)
+    .stored_fields(vec!["title", "created"])
+    .send()?;
+
+let title: Option<String> = response.fields().get("title")?;
+let created: Option<Date<DefaultDateMapping<EpochMillis>>> = response.fields().get("created")?;
+# Ok(())
+# }
+```
+
+[stored-fields]: https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-store.html
+*/
+#[derive(Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct StoredFields(Map<String, Value>);
+
+impl StoredFields {
+    /**
+    Get the first value of a stored field.
+
+    Returns `Ok(None)` if the field wasn't returned.
+    */
+    pub fn get<TField>(&self, name: &str) -> Result<Option<TField>, serde_json::Error>
+    where
+        TField: DeserializeOwned,
+    {
+        match self.raw(name).and_then(|values| values.first()) {
+            Some(value) => serde_json::from_value(value.clone()).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /**
+    Get all values of a stored field.
+
+    Returns an empty `Vec` if the field wasn't returned.
+    */
+    pub fn get_all<TField>(&self, name: &str) -> Result<Vec<TField>, serde_json::Error>
+    where
+        TField: DeserializeOwned,
+    {
+        self.raw(name)
+            .unwrap_or(&[])
+            .iter()
+            .map(|value| serde_json::from_value(value.clone()))
+            .collect()
+    }
+
+    /** Get the raw json values of a stored field. */
+    pub fn raw(&self, name: &str) -> Option<&[Value]> {
+        match self.0.get(name) {
+            Some(Value::Array(values)) => Some(values.as_slice()),
+            Some(value) => Some(::std::slice::from_ref(value)),
+            None => None,
+        }
+    }
+
+    /** Whether a stored field was returned. */
+    pub fn contains(&self, name: &str) -> bool {
+        self.0.contains_key(name)
+    }
+
+    /** The names of the returned stored fields. */
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.0.keys().map(String::as_str)
+    }
+
+    /** Whether no stored fields were returned. */
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
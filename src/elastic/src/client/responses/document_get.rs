@@ -5,6 +5,8 @@ Response types for a [get document request](https://www.elastic.co/guide/en/elas
 use crate::http::StatusCode;
 use serde::de::DeserializeOwned;
 
+use super::common::StoredFields;
+
 use crate::{
     http::receiver::{
         HttpResponseHead,
@@ -37,6 +39,8 @@ pub struct GetResponse<T> {
     source: Option<T>,
     #[serde(rename = "_routing")]
     routing: Option<String>,
+    #[serde(default)]
+    fields: StoredFields,
 }
 
 impl<T> GetResponse<T> {
@@ -74,6 +78,17 @@ impl<T> GetResponse<T> {
     pub fn version(&self) -> Option<u32> {
         self.version.clone()
     }
+
+    /**
+    The stored fields of the document.
+
+    Stored fields are only returned when they're requested with [`stored_fields`][stored_fields].
+
+    [stored_fields]: ../requests/document_get/type.GetRequestBuilder.html#method.stored_fields
+    */
+    pub fn fields(&self) -> &StoredFields {
+        &self.fields
+    }
 }
 
 impl<T: DeserializeOwned> IsOk for GetResponse<T> {
@@ -0,0 +1,19 @@
+/*!
+Response types for a [get mapping request](https://www.elastic.co/guide/en/elasticsearch/reference/master/indices-get-mapping.html).
+*/
+
+use crate::{
+    http::receiver::IsOkOnSuccess,
+    types::mappers::DynamicMapping,
+};
+
+/**
+Response for a [get mapping request](https://www.elastic.co/guide/en/elasticsearch/reference/master/indices-get-mapping.html).
+
+See [`DynamicMapping`][DynamicMapping] for details.
+
+[DynamicMapping]: ../../types/mappers/struct.DynamicMapping.html
+*/
+pub type IndicesGetMappingResponse = DynamicMapping;
+
+impl IsOkOnSuccess for DynamicMapping {}
@@ -13,10 +13,12 @@ mod document_index;
 mod document_update;
 pub mod nodes_info;
 mod ping;
+mod pipeline_simulate;
 pub mod search;
 mod sql;
 
 mod index_exists;
+mod index_get_mapping;
 
 #[cfg(test)]
 mod tests;
@@ -34,17 +36,25 @@ pub use self::{
     document_update::*,
     nodes_info::NodesInfoResponse,
     ping::*,
-    search::SearchResponse,
+    pipeline_simulate::*,
+    search::{
+        AggregateResponse,
+        SearchResponse,
+    },
     sql::*,
 };
 
-pub use self::index_exists::*;
+pub use self::{
+    index_exists::*,
+    index_get_mapping::*,
+};
 
 pub mod prelude {
     /*! A glob import for convenience. */
 
     pub use super::{
         bulk::Action as BulkAction,
+        AggregateResponse,
         BulkErrorsResponse,
         BulkResponse,
         CommandResponse,
@@ -52,8 +62,10 @@ pub mod prelude {
         GetResponse,
         IndexResponse,
         IndicesExistsResponse,
+        IndicesGetMappingResponse,
         NodesInfoResponse,
         PingResponse,
+        PipelineSimulateResponse,
         SearchResponse,
         SqlResponse,
         UpdateResponse,
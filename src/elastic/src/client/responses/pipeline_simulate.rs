@@ -0,0 +1,170 @@
+/*!
+Response types for a [simulate pipeline request](https://www.elastic.co/guide/en/elasticsearch/reference/master/simulate-pipeline-api.html).
+*/
+
+use serde_json::Value;
+
+use crate::http::receiver::IsOkOnSuccess;
+
+/**
+Response for a [simulate pipeline request][docs-simulate] with `verbose` results.
+
+The response contains a [`SimulatedDocument`][SimulatedDocument] for each sample document,
+in the same order the documents were added to the request.
+
+[docs-simulate]: https://www.elastic.co/guide/en/elasticsearch/reference/master/simulate-pipeline-api.html
+[SimulatedDocument]: struct.SimulatedDocument.html
+*/
+#[derive(Deserialize, Debug, Clone)]
+pub struct PipelineSimulateResponse {
+    docs: Vec<SimulatedDocument>,
+}
+
+impl PipelineSimulateResponse {
+    /** Get the results for each sample document. */
+    pub fn docs(&self) -> &[SimulatedDocument] {
+        &self.docs
+    }
+
+    /** Whether or not every processor succeeded or was skipped for every sample document. */
+    pub fn is_ok(&self) -> bool {
+        self.docs.iter().all(SimulatedDocument::is_ok)
+    }
+}
+
+/** The results of running a single sample document through a pipeline. */
+#[derive(Deserialize, Debug, Clone)]
+pub struct SimulatedDocument {
+    #[serde(default)]
+    processor_results: Vec<ProcessorResult>,
+}
+
+impl SimulatedDocument {
+    /** Get the result of each processor in the pipeline, in the order they ran. */
+    pub fn processor_results(&self) -> &[ProcessorResult] {
+        &self.processor_results
+    }
+
+    /** Get the result of the first processor with the given tag. */
+    pub fn processor(&self, tag: &str) -> Option<&ProcessorResult> {
+        self.processor_results
+            .iter()
+            .find(|result| result.tag() == Some(tag))
+    }
+
+    /** Get the first processor that failed. */
+    pub fn error(&self) -> Option<&ProcessorResult> {
+        self.processor_results.iter().find(|result| !result.is_ok())
+    }
+
+    /** Whether or not every processor succeeded or was skipped. */
+    pub fn is_ok(&self) -> bool {
+        self.error().is_none()
+    }
+
+    /** Get the `_source` of the document after the last processor that produced a document. */
+    pub fn source(&self) -> Option<&Value> {
+        self.processor_results
+            .iter()
+            .rev()
+            .filter_map(ProcessorResult::source)
+            .next()
+    }
+}
+
+/** The result of running a single processor over a sample document. */
+#[derive(Deserialize, Debug, Clone)]
+pub struct ProcessorResult {
+    processor_type: Option<String>,
+    tag: Option<String>,
+    status: Option<ProcessorStatus>,
+    doc: Option<SimulatedSource>,
+    error: Option<ProcessorError>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+struct SimulatedSource {
+    #[serde(rename = "_source")]
+    source: Value,
+}
+
+impl ProcessorResult {
+    /** The type of the processor, like `grok`. Older versions of Elasticsearch don't return this. */
+    pub fn processor_type(&self) -> Option<&str> {
+        self.processor_type.as_ref().map(String::as_str)
+    }
+
+    /** The `tag` given to the processor in the pipeline. */
+    pub fn tag(&self) -> Option<&str> {
+        self.tag.as_ref().map(String::as_str)
+    }
+
+    /**
+    The status of the processor.
+
+    Older versions of Elasticsearch don't return a status, so it's inferred from whether or not there's an error.
+    */
+    pub fn status(&self) -> ProcessorStatus {
+        match (self.status, &self.error) {
+            (Some(status), _) => status,
+            (None, &Some(_)) => ProcessorStatus::Error,
+            (None, &None) => ProcessorStatus::Success,
+        }
+    }
+
+    /** Whether or not the processor succeeded or was skipped. */
+    pub fn is_ok(&self) -> bool {
+        match self.status() {
+            ProcessorStatus::Error => false,
+            _ => true,
+        }
+    }
+
+    /** The `_source` of the document after the processor ran. */
+    pub fn source(&self) -> Option<&Value> {
+        self.doc.as_ref().map(|doc| &doc.source)
+    }
+
+    /** The error returned by the processor, if it failed. */
+    pub fn error(&self) -> Option<&ProcessorError> {
+        self.error.as_ref()
+    }
+}
+
+/** The status of a processor that ran over a sample document. */
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ProcessorStatus {
+    /** The processor succeeded. */
+    Success,
+    /** The processor failed. */
+    Error,
+    /** The processor failed, but the failure was ignored by `ignore_failure`. */
+    ErrorIgnored,
+    /** The processor didn't run because its `if` condition wasn't met. */
+    Skipped,
+    /** The processor dropped the document. */
+    Dropped,
+}
+
+/** An error returned by a processor. */
+#[derive(Deserialize, Debug, Clone)]
+pub struct ProcessorError {
+    #[serde(rename = "type")]
+    ty: String,
+    reason: Option<String>,
+}
+
+impl ProcessorError {
+    /** The type of the error, like `illegal_argument_exception`. */
+    pub fn ty(&self) -> &str {
+        &self.ty
+    }
+
+    /** A description of the error, like `Provided Grok expressions do not match field value`. */
+    pub fn reason(&self) -> Option<&str> {
+        self.reason.as_ref().map(String::as_str)
+    }
+}
+
+impl IsOkOnSuccess for PipelineSimulateResponse {}
@@ -8,7 +8,10 @@ use serde_json::{
     Value,
 };
 
-use super::common::Shards;
+use super::common::{
+    Shards,
+    StoredFields,
+};
 
 use crate::{
     http::receiver::IsOkOnSuccess,
@@ -73,6 +76,8 @@ pub struct SearchResponse<T> {
     hits: HitsWrapper<T>,
     aggregations: Option<AggsWrapper>,
     status: Option<u16>,
+    #[serde(rename = "_scroll_id")]
+    scroll_id: Option<String>,
 }
 
 /** Struct to hold the search's Hits, serializable to type `T` or `serde_json::Value`. */
@@ -116,6 +121,11 @@ impl<T> SearchResponse<T> {
         self.hits.total.value
     }
 
+    /** The id to fetch the next batch of hits with if the request opened a scroll context. */
+    pub fn scroll_id(&self) -> Option<&str> {
+        self.scroll_id.as_ref().map(String::as_str)
+    }
+
     /** The max score for documents that matched the search query. */
     pub fn max_score(&self) -> Option<f32> {
         self.hits.max_score.clone()
@@ -164,6 +174,96 @@ impl<T> SearchResponse<T> {
 
 impl<T: DeserializeOwned> IsOkOnSuccess for SearchResponse<T> {}
 
+/**
+Response for an aggregations-only [search request][search-req].
+
+Unlike a [`SearchResponse`][SearchResponse], the hits in the response aren't deserialised.
+The aggregation results are deserialised into the type `TAggs`, which can be any type that matches the shape of the `aggregations` object.
+
+# Examples
+
+Deserialise the results of a `sum` aggregation called `total_sales`:
+
+```no_run
+# #[macro_use] extern crate serde_derive;
+# use elastic::prelude::*;
+# fn do_request() -> AggregateResponse<SalesAggs> { unimplemented!() }
+#[derive(Deserialize)]
+struct SalesAggs {
+    total_sales: SumAgg,
+}
+
+#[derive(Deserialize)]
+struct SumAgg {
+    value: f64,
+}
+
+# fn main() {
+let response: AggregateResponse<SalesAggs> = do_request();
+
+println!("total sales: {}", response.aggs().total_sales.value);
+# }
+```
+
+[search-req]: https://www.elastic.co/guide/en/elasticsearch/reference/master/search-search.html
+[SearchResponse]: struct.SearchResponse.html
+*/
+#[derive(Deserialize, Debug)]
+pub struct AggregateResponse<TAggs> {
+    took: u64,
+    timed_out: bool,
+    #[serde(rename = "_shards")]
+    shards: Shards,
+    hits: AggregateHitsWrapper,
+    aggregations: TAggs,
+    status: Option<u16>,
+}
+
+/** Struct to hold just the search's total hits, ignoring the hits themselves. */
+#[derive(Deserialize, Debug)]
+struct AggregateHitsWrapper {
+    total: HitsTotal,
+}
+
+impl<TAggs> AggregateResponse<TAggs> {
+    /** Time in milliseconds it took for Elasticsearch to process the request. */
+    pub fn took(&self) -> u64 {
+        self.took
+    }
+
+    /** Whether or not the request timed out before completing. */
+    pub fn timed_out(&self) -> bool {
+        self.timed_out
+    }
+
+    /** Shards metadata for the request. */
+    pub fn shards(&self) -> &Shards {
+        &self.shards
+    }
+
+    /** A http status associated with the response. */
+    pub fn status(&self) -> Option<u16> {
+        self.status.clone()
+    }
+
+    /** The total number of documents that matched the search query. */
+    pub fn total(&self) -> u64 {
+        self.hits.total.value
+    }
+
+    /** Get a reference to the aggregation results. */
+    pub fn aggs(&self) -> &TAggs {
+        &self.aggregations
+    }
+
+    /** Convert the response into the aggregation results. */
+    pub fn into_aggs(self) -> TAggs {
+        self.aggregations
+    }
+}
+
+impl<TAggs: DeserializeOwned> IsOkOnSuccess for AggregateResponse<TAggs> {}
+
 /** A borrowing iterator over search query hits. */
 pub struct Hits<'a, T: 'a> {
     inner: Iter<'a, Hit<T>>,
@@ -266,6 +366,9 @@ pub struct Hit<T> {
     #[serde(rename = "_routing")]
     routing: Option<String>,
     highlight: Option<Value>,
+    sort: Option<Vec<Value>>,
+    #[serde(default)]
+    fields: StoredFields,
 }
 
 impl<T> Hit<T> {
@@ -313,6 +416,26 @@ impl<T> Hit<T> {
     pub fn highlight(&self) -> Option<&Value> {
         self.highlight.as_ref()
     }
+
+    /**
+    The sort values of the hit.
+
+    These can be used as the `search_after` parameter to fetch the hits after this one.
+    */
+    pub fn sort(&self) -> Option<&[Value]> {
+        self.sort.as_ref().map(Vec::as_slice)
+    }
+
+    /**
+    The stored fields of the hit.
+
+    Stored fields are only returned when they're requested with [`stored_fields`][stored_fields].
+
+    [stored_fields]: ../../requests/search/type.SearchRequestBuilder.html#method.stored_fields
+    */
+    pub fn fields(&self) -> &StoredFields {
+        &self.fields
+    }
 }
 
 /** Type Struct to hold a generic `serde_json::Value` tree of the aggregation results. */
@@ -8,6 +8,11 @@ use crate::{
         },
         StatusCode,
     },
+    types::date::{
+        Date,
+        DefaultDateMapping,
+        EpochMillis,
+    },
 };
 use serde_json::Value;
 
@@ -72,3 +77,25 @@ fn error_parse_index_not_found() {
 
     assert!(valid);
 }
+
+#[test]
+fn success_parse_stored_fields() {
+    let f = include_bytes!("get_stored_fields.json");
+    let deserialized = parse::<GetResponse<Value>>()
+        .from_slice(StatusCode::OK, f as &[_])
+        .unwrap();
+
+    let fields = deserialized.fields();
+
+    assert_eq!(Some("A title".to_owned()), fields.get("title").unwrap());
+    assert_eq!(
+        vec!["a".to_owned(), "b".to_owned()],
+        fields.get_all::<String>("tags").unwrap()
+    );
+    assert_eq!(None, fields.get::<String>("missing").unwrap());
+
+    let created: Date<DefaultDateMapping<EpochMillis>> = fields.get("created").unwrap().unwrap();
+    assert_eq!(1435935302478, created.timestamp_millis());
+
+    assert!(deserialized.document().is_none());
+}
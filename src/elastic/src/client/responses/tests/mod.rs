@@ -7,4 +7,5 @@ mod document_update;
 mod index_exists;
 mod nodes_info;
 mod ping;
+mod pipeline_simulate;
 mod search;
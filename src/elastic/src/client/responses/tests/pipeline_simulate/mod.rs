@@ -0,0 +1,33 @@
+use crate::{
+    client::responses::*,
+    http::{
+        receiver::parse,
+        StatusCode,
+    },
+};
+
+#[test]
+fn success_parse_verbose_pipeline_simulate_response() {
+    let f = include_bytes!("verbose.json");
+    let deserialized = parse::<PipelineSimulateResponse>()
+        .from_slice(StatusCode::OK, f as &[_])
+        .unwrap();
+
+    assert!(!deserialized.is_ok());
+    assert_eq!(2, deserialized.docs().len());
+
+    let parsed = &deserialized.docs()[0];
+
+    assert!(parsed.is_ok());
+    assert_eq!(
+        Some("grok"),
+        parsed.processor("parse_message").unwrap().processor_type()
+    );
+    assert_eq!(Some("get"), parsed.source().unwrap()["method"].as_str());
+
+    let failed = deserialized.docs()[1].error().unwrap();
+
+    assert_eq!(Some("parse_message"), failed.tag());
+    assert_eq!(ProcessorStatus::Error, failed.status());
+    assert_eq!("illegal_argument_exception", failed.error().unwrap().ty());
+}
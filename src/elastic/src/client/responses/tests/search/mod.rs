@@ -1,5 +1,11 @@
 use crate::{
-    client::responses::*,
+    client::responses::{
+        common::{
+            ShardFailure,
+            ShardFailureKind,
+        },
+        *,
+    },
     error::*,
     http::{
         receiver::{
@@ -214,6 +220,76 @@ fn success_parse_hits_simple_as_value() {
     assert_eq!(deserialized["_shards"]["total"].as_u64().unwrap(), 5);
 }
 
+#[test]
+fn success_parse_shard_failures() {
+    let f = include_bytes!("search_shard_failures.json");
+    let deserialized = parse::<SearchResponse<Value>>()
+        .from_slice(StatusCode::OK, f as &[_])
+        .unwrap();
+
+    let shards = deserialized.shards();
+    let failures = shards.failures();
+
+    assert_eq!(2, shards.failed());
+    assert_eq!(2, failures.len());
+
+    assert_eq!(Some(0), failures[0].shard());
+    assert_eq!(Some("logs-2019.01.01"), failures[0].index());
+    assert_eq!(Some("kcRdSxhXQnGaW6kZ2e1UJQ"), failures[0].node());
+    assert_eq!(ShardFailureKind::Rejection, failures[0].kind());
+    assert!(failures[0].is_rejection());
+
+    let reason = failures[1].reason();
+    assert_eq!("query_shard_exception", reason.ty());
+    assert_eq!(Some("failed to create query"), reason.reason());
+    assert_eq!("number_format_exception", reason.root_cause().ty());
+    assert_eq!(
+        vec!["query_shard_exception", "number_format_exception"],
+        reason.causes().map(|cause| cause.ty()).collect::<Vec<_>>()
+    );
+    assert_eq!(ShardFailureKind::Other, failures[1].kind());
+
+    assert!(!shards.is_retryable());
+}
+
+#[test]
+fn shard_failure_kinds() {
+    let kind = |reason: Value| {
+        serde_json::from_value::<ShardFailure>(json!({ "reason": reason }))
+            .unwrap()
+            .kind()
+    };
+
+    assert_eq!(
+        ShardFailureKind::Timeout,
+        kind(json!({ "type": "receive_timeout_transport_exception" }))
+    );
+    assert_eq!(
+        ShardFailureKind::Corruption,
+        kind(json!({
+            "type": "search_phase_execution_exception",
+            "caused_by": { "type": "corrupt_index_exception" }
+        }))
+    );
+    assert_eq!(
+        ShardFailureKind::Rejection,
+        kind(json!({ "type": "circuit_breaking_exception" }))
+    );
+    assert!(ShardFailureKind::Timeout.is_retryable());
+    assert!(!ShardFailureKind::Corruption.is_retryable());
+}
+
+#[test]
+fn success_parse_no_shard_failures() {
+    let f = include_bytes!("search_empty.json");
+    let deserialized = parse::<SearchResponse<Value>>()
+        .from_slice(StatusCode::OK, f as &[_])
+        .unwrap();
+
+    assert!(deserialized.shards().failures().is_empty());
+    assert!(!deserialized.shards().is_retryable());
+}
+
 #[test]
 fn error_parse_index_not_found() {
     let f = include_bytes!("../error/error_index_not_found.json");
@@ -228,3 +304,24 @@ fn error_parse_index_not_found() {
 
     assert!(valid);
 }
+
+#[test]
+fn success_parse_aggregate_response() {
+    #[derive(Deserialize)]
+    struct Aggs {
+        myagg: ValueAgg,
+    }
+
+    #[derive(Deserialize)]
+    struct ValueAgg {
+        value: f64,
+    }
+
+    let f = include_bytes!("search_aggregation_simple.json");
+    let deserialized = parse::<AggregateResponse<Aggs>>()
+        .from_slice(StatusCode::OK, f as &[_])
+        .unwrap();
+
+    assert_eq!(15859, deserialized.total());
+    assert_eq!(10f64, deserialized.aggs().myagg.value);
+}
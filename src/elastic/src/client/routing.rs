@@ -0,0 +1,225 @@
+/*!
+Predict the shard a document will be routed to.
+
+Elasticsearch routes each document to a shard by hashing its routing value, which is the document `_id` unless a custom `routing` is given.
+The functions in this module implement the same murmur3-based hash as Elasticsearch so a client can work out which shard a document lands on
+without asking the cluster.
+
+# Examples
+
+Get the shard a document with an id of `1` is routed to in an index with `5` primary shards:
+
+```
+# use elastic::client::routing_shard;
+let shard = routing_shard("1", 5);
+
+assert_eq!(4, shard);
+```
+*/
+
+use std::cmp;
+
+/**
+Get the shard that a document with the given `routing` value is routed to.
+
+The `routing` value is the document `_id` unless a custom `routing` is given.
+The `num_shards` is the number of primary shards in the index.
+
+This assumes the index was created on Elasticsearch `7.0` or later and doesn't have a custom `index.number_of_routing_shards` setting,
+so its number of routing shards is the [default][default_routing_num_shards].
+For other indices use [`routing_shard_with_routing_num_shards`][routing_shard_with_routing_num_shards].
+
+# Panics
+
+This function will panic if `num_shards` is `0`.
+
+[default_routing_num_shards]: fn.default_routing_num_shards.html
+[routing_shard_with_routing_num_shards]: fn.routing_shard_with_routing_num_shards.html
+*/
+pub fn routing_shard(routing: &str, num_shards: u32) -> u32 {
+    routing_shard_with_routing_num_shards(
+        routing,
+        num_shards,
+        default_routing_num_shards(num_shards),
+    )
+}
+
+/**
+Get the default number of routing shards for an index with `num_shards` primary shards.
+
+Indices created on Elasticsearch `7.0` or later can be split, so by default they're routed as if they had
+the largest number of shards that's `num_shards` times a power of `2` and no more than `1024`.
+They can always be split at least once.
+Indices created before `7.0` have the same number of routing shards as primary shards.
+
+# Examples
+
+```
+# use elastic::client::default_routing_num_shards;
+assert_eq!(640, default_routing_num_shards(5));
+assert_eq!(1024, default_routing_num_shards(1));
+```
+
+# Panics
+
+This function will panic if `num_shards` is `0`.
+*/
+pub fn default_routing_num_shards(num_shards: u32) -> u32 {
+    assert!(
+        num_shards > 0,
+        "the number of shards must be greater than 0"
+    );
+
+    // The number of times the index can be split in half, up to a maximum of 1024 shards
+    let log2_num_shards = 32 - (num_shards - 1).leading_zeros();
+    let num_splits = cmp::max(1, 10u32.saturating_sub(log2_num_shards));
+
+    num_shards << num_splits
+}
+
+/**
+Get the shard that a document with the given `routing` value is routed to in an index that can be split.
+
+The `routing_num_shards` is the value of the index's `index.number_of_routing_shards` setting.
+It must be a multiple of `num_shards`.
+For indices created before Elasticsearch `7.0` it's the same as `num_shards`.
+
+# Panics
+
+This function will panic if `num_shards` is `0` or `routing_num_shards` isn't a multiple of `num_shards`.
+*/
+pub fn routing_shard_with_routing_num_shards(
+    routing: &str,
+    num_shards: u32,
+    routing_num_shards: u32,
+) -> u32 {
+    assert!(
+        num_shards > 0,
+        "the number of shards must be greater than 0"
+    );
+    assert!(
+        routing_num_shards % num_shards == 0,
+        "the number of routing shards must be a multiple of the number of shards"
+    );
+
+    let routing_factor = routing_num_shards / num_shards;
+    let hash = i64::from(routing_hash(routing));
+
+    (hash.rem_euclid(i64::from(routing_num_shards)) / i64::from(routing_factor)) as u32
+}
+
+/**
+Hash a `routing` value the same way Elasticsearch does.
+
+The value is hashed as UTF-16 code units using the 32bit murmur3 hash with a seed of `0`.
+*/
+pub fn routing_hash(routing: &str) -> i32 {
+    let bytes: Vec<u8> = routing
+        .encode_utf16()
+        .flat_map(|unit| unit.to_le_bytes().to_vec())
+        .collect();
+
+    murmur3_x86_32(&bytes, 0) as i32
+}
+
+fn murmur3_x86_32(data: &[u8], seed: u32) -> u32 {
+    const C1: u32 = 0xcc9e_2d51;
+    const C2: u32 = 0x1b87_3593;
+
+    fn mix_k(k: u32) -> u32 {
+        k.wrapping_mul(C1).rotate_left(15).wrapping_mul(C2)
+    }
+
+    let mut h = seed;
+
+    let mut blocks = data.chunks_exact(4);
+    for block in &mut blocks {
+        let k = u32::from_le_bytes([block[0], block[1], block[2], block[3]]);
+
+        h ^= mix_k(k);
+        h = h.rotate_left(13).wrapping_mul(5).wrapping_add(0xe654_6b64);
+    }
+
+    let tail = blocks.remainder();
+    if !tail.is_empty() {
+        let k = tail
+            .iter()
+            .rev()
+            .fold(0u32, |k, &byte| (k << 8) | u32::from(byte));
+
+        h ^= mix_k(k);
+    }
+
+    h ^= data.len() as u32;
+
+    h ^= h >> 16;
+    h = h.wrapping_mul(0x85eb_ca6b);
+    h ^= h >> 13;
+    h = h.wrapping_mul(0xc2b2_ae35);
+    h ^= h >> 16;
+
+    h
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn routing_hash_matches_elasticsearch() {
+        let cases: Vec<(u32, &str)> = vec![
+            (0x5a0c_b7c3, "hell"),
+            (0xd7c3_1989, "hello"),
+            (0x22ab_2984, "hello w"),
+            (0xdf0c_a123, "hello wo"),
+            (0xe774_4d61, "hello wor"),
+            (0xe07d_b09c, "The quick brown fox jumps over the lazy dog"),
+            (0x4e63_d2ad, "The quick brown fox jumps over the lazy cog"),
+        ];
+
+        for (expected, routing) in cases {
+            assert_eq!(expected as i32, routing_hash(routing), "{}", routing);
+        }
+    }
+
+    #[test]
+    fn routing_shard_for_ids() {
+        assert_eq!(0, routing_shard("1", 1));
+        assert_eq!(4, routing_shard("1", 5));
+        assert_eq!(0, routing_shard("1", 7));
+        assert_eq!(3, routing_shard("2", 7));
+        assert_eq!(4, routing_shard("abc", 5));
+    }
+
+    #[test]
+    fn routing_shard_for_ids_before_7_0() {
+        assert_eq!(3, routing_shard_with_routing_num_shards("1", 5, 5));
+        assert_eq!(2, routing_shard_with_routing_num_shards("1", 7, 7));
+        assert_eq!(5, routing_shard_with_routing_num_shards("2", 7, 7));
+    }
+
+    #[test]
+    fn default_routing_num_shards_for_shards() {
+        assert_eq!(1024, default_routing_num_shards(1));
+        assert_eq!(1024, default_routing_num_shards(2));
+        assert_eq!(768, default_routing_num_shards(3));
+        assert_eq!(640, default_routing_num_shards(5));
+        assert_eq!(896, default_routing_num_shards(7));
+        assert_eq!(2048, default_routing_num_shards(1024));
+    }
+
+    #[test]
+    fn routing_shard_with_routing_factor() {
+        for routing in &["1", "2", "abc", "user-42"] {
+            let shard = routing_shard_with_routing_num_shards(routing, 5, 10);
+
+            assert!(shard < 5);
+            assert_eq!(
+                routing_shard_with_routing_num_shards(routing, 10, 10) / 2,
+                shard,
+                "{}",
+                routing
+            );
+        }
+    }
+}
@@ -185,6 +185,53 @@ pub(crate) fn test() -> Error {
     })
 }
 
+/**
+A request body is larger than the maximum content length configured on the client.
+
+This error is returned before the request is sent, instead of an opaque `413` response from Elasticsearch.
+It can be found as the source of a client error.
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PayloadTooLarge {
+    body_len: usize,
+    max_content_length: usize,
+}
+
+impl PayloadTooLarge {
+    pub(crate) fn new(body_len: usize, max_content_length: usize) -> Self {
+        PayloadTooLarge {
+            body_len,
+            max_content_length,
+        }
+    }
+
+    /** The length of the request body in bytes. */
+    pub fn body_len(&self) -> usize {
+        self.body_len
+    }
+
+    /** The maximum content length in bytes. */
+    pub fn max_content_length(&self) -> usize {
+        self.max_content_length
+    }
+}
+
+impl fmt::Display for PayloadTooLarge {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "the request body is {} bytes, but the maximum content length is {} bytes",
+            self.body_len, self.max_content_length
+        )
+    }
+}
+
+impl StdError for PayloadTooLarge {
+    fn description(&self) -> &str {
+        "request body is too large"
+    }
+}
+
 pub(crate) enum MaybeApiError<E> {
     Api(ApiError),
     Other(E),
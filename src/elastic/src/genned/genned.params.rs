@@ -4,22 +4,24 @@ This file is automatically included into the generated `genned::params` module
 
 use crate::types::string::{Keyword, keyword::mapping::KeywordMapping};
 
-impl<'a, M> From<Keyword<M>> for Id<'a>
+impl<'a, M, TValue> From<Keyword<M, TValue>> for Id<'a>
 where
     M: KeywordMapping,
+    TValue: AsRef<str> + Into<::std::borrow::Cow<'a, str>>,
 {
-    fn from(value: Keyword<M>) -> Id<'a> {
-        let value: String = value.into();
+    fn from(value: Keyword<M, TValue>) -> Id<'a> {
+        let value: ::std::borrow::Cow<'a, str> = value.into();
 
-        Id::from(value)
+        Id(value)
     }
 }
 
-impl<'a, M> From<&'a Keyword<M>> for Id<'a>
+impl<'a, M, TValue> From<&'a Keyword<M, TValue>> for Id<'a>
 where
     M: KeywordMapping,
+    TValue: AsRef<str>,
 {
-    fn from(value: &'a Keyword<M>) -> Id<'a> {
+    fn from(value: &'a Keyword<M, TValue>) -> Id<'a> {
         let value: &str = value.into();
 
         Id::from(value)
@@ -1,4 +1,5 @@
 use bytes::Bytes;
+use serde::Serialize;
 use serde_json::Value;
 use std::{
     borrow::Cow,
@@ -10,6 +11,7 @@ use std::{
 };
 
 use futures::{
+    Async,
     Poll,
     Stream,
 };
@@ -70,6 +72,19 @@ impl<'a> Read for AsyncBodyReader<'a> {
 impl<'a> AsyncRead for AsyncBodyReader<'a> {}
 
 impl AsyncBody {
+    /**
+    Serialise a value as json into a body.
+
+    The value is serialised straight into the body, so a document or query doesn't need to be
+    converted into a `serde_json::Value` first.
+    */
+    pub fn json<T>(body: &T) -> Result<AsyncBody, serde_json::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        serde_json::to_vec(body).map(AsyncBody::from)
+    }
+
     /** Convert the body into its inner value. */
     pub(crate) fn into_inner(self) -> Body {
         match self.0 {
@@ -85,6 +100,16 @@ impl AsyncBody {
         }
     }
 
+    /** Get the length of the body in bytes. */
+    pub(crate) fn len(&self) -> usize {
+        self.0.as_ref().len()
+    }
+
+    /** Get the bytes of the body. */
+    pub(crate) fn as_bytes(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+
     /**
     Get a reader over the asynchronous body.
     */
@@ -132,10 +157,36 @@ impl From<&'static str> for AsyncBody {
 }
 
 /** A raw HTTP response that can be buffered using `Read`. */
-pub struct AsyncHttpResponse(StatusCode, RawResponse);
+pub struct AsyncHttpResponse(StatusCode, AsyncResponseBody);
+
+/** The body of a response, which is either being read from the network or was cached. */
+pub(crate) enum AsyncResponseBody {
+    Raw(RawResponse),
+    Cached(Option<Bytes>),
+}
+
+impl Stream for AsyncResponseBody {
+    type Item = AsyncChunk;
+    type Error = reqwest::Error;
+
+    fn poll(&mut self) -> Poll<Option<Self::Item>, Self::Error> {
+        match *self {
+            AsyncResponseBody::Raw(ref mut response) => response.body_mut().poll(),
+            AsyncResponseBody::Cached(ref mut body) => {
+                let chunk = body.take().map(|body| {
+                    let mut chunk = AsyncChunk::default();
+                    chunk.extend(body);
+                    chunk
+                });
+
+                Ok(Async::Ready(chunk))
+            }
+        }
+    }
+}
 
 impl AsyncHttpResponse {
-    pub(crate) fn from_raw(status: StatusCode, response: RawResponse) -> Self {
+    pub(crate) fn from_raw(status: StatusCode, response: AsyncResponseBody) -> Self {
         AsyncHttpResponse(status, response)
     }
 }
@@ -145,10 +196,9 @@ impl Stream for AsyncHttpResponse {
     type Error = Error;
 
     fn poll(&mut self) -> Poll<Option<Self::Item>, Self::Error> {
-        self.1
-            .body_mut()
-            .poll()
-            .map_err(|e| error::response(self.0, e))
+        let status = self.0;
+
+        self.1.poll().map_err(|e| error::response(status, e))
     }
 }
 
@@ -195,4 +245,27 @@ mod tests {
     fn json_value_into_body() {
         AsyncBody::from(json!({}));
     }
+
+    #[test]
+    fn cached_response_body() {
+        let mut body = AsyncResponseBody::Cached(Some(Bytes::from_static(b"{}")));
+
+        let chunk = match body.poll() {
+            Ok(Async::Ready(Some(chunk))) => chunk,
+            _ => panic!("expected a chunk"),
+        };
+
+        assert_eq!(b"{}", chunk.as_ref());
+        assert!(match body.poll() {
+            Ok(Async::Ready(None)) => true,
+            _ => false,
+        });
+    }
+
+    #[test]
+    fn serialize_into_body() {
+        let body = AsyncBody::json(&json!({ "query": { "match_all": {} } })).unwrap();
+
+        assert_eq!(br#"{"query":{"match_all":{}}}"#, body.as_bytes());
+    }
 }
@@ -0,0 +1,420 @@
+/*!
+Caching responses to idempotent requests.
+
+A [`ResponseCache`][ResponseCache] can be given to a [`SyncClientBuilder`][SyncClientBuilder] or [`AsyncClientBuilder`][AsyncClientBuilder]
+to cache the responses to repeated identical requests, like the queries behind a dashboard,
+so they don't need to be sent to the cluster again.
+Only successful responses to `GET` requests and searches are cached.
+
+Responses are keyed on the method, path, query and body of the request, but not the node it's sent to or its headers.
+That means requests that use different credentials can get the same cached response,
+so a cache shouldn't be shared by clients for users that can see different documents.
+
+# Examples
+
+Cache up to 1000 responses for 30 seconds:
+
+```
+# use elastic::prelude::*;
+# use elastic::http::cache::LruResponseCache;
+# use std::time::Duration;
+let builder = SyncClientBuilder::new()
+    .response_cache(LruResponseCache::new(1000), Duration::from_secs(30));
+```
+
+Send a request that skips the cache:
+
+```no_run
+# use elastic::prelude::*;
+# use elastic::http::cache::LruResponseCache;
+# use std::time::Duration;
+# fn main() { run().unwrap() }
+# fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+# let client = SyncClientBuilder::new()
+#     .response_cache(LruResponseCache::new(1000), Duration::from_secs(30))
+#     .build()?;
+let response = client
+    .request(SimpleSearchRequest::for_index("my-index"))
+    .params_fluent(|p| p.bypass_cache())
+    .send()?;
+# Ok(())
+# }
+```
+
+[ResponseCache]: trait.ResponseCache.html
+[SyncClientBuilder]: ../../client/struct.SyncClientBuilder.html
+[AsyncClientBuilder]: ../../client/struct.AsyncClientBuilder.html
+*/
+
+use bytes::Bytes;
+use std::{
+    collections::{
+        BTreeMap,
+        HashMap,
+    },
+    sync::{
+        Arc,
+        Mutex,
+        MutexGuard,
+    },
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+use crate::http::{
+    Method,
+    StatusCode,
+    Url,
+};
+
+/** The key for a cached response. */
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CacheKey {
+    method: Method,
+    url: String,
+    body: Bytes,
+}
+
+impl CacheKey {
+    pub(crate) fn new(method: Method, url: String, body: Bytes) -> Self {
+        CacheKey { method, url, body }
+    }
+
+    /** The method of the request. */
+    pub fn method(&self) -> &Method {
+        &self.method
+    }
+
+    /** The path and query of the request, without the address of the node it was sent to. */
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /** The body of the request. */
+    pub fn body(&self) -> &[u8] {
+        &self.body
+    }
+}
+
+/** A cached response. */
+#[derive(Debug, Clone)]
+pub struct CachedResponse {
+    status: StatusCode,
+    body: Bytes,
+    expires: Instant,
+}
+
+impl CachedResponse {
+    pub(crate) fn new(status: StatusCode, body: Bytes, ttl: Duration) -> Self {
+        CachedResponse {
+            status,
+            body,
+            expires: Instant::now() + ttl,
+        }
+    }
+
+    /** The HTTP status of the response. */
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /** The body of the response. */
+    pub fn body(&self) -> &Bytes {
+        &self.body
+    }
+
+    /** Whether the response has outlived the time-to-live of the cache. */
+    pub fn is_expired(&self) -> bool {
+        Instant::now() >= self.expires
+    }
+}
+
+/**
+A store for cached responses.
+
+Implement this trait to keep responses somewhere other than in memory.
+The client checks whether a response [is expired][CachedResponse.is_expired] before using it,
+but a store can also remove expired responses itself.
+
+[CachedResponse.is_expired]: struct.CachedResponse.html#method.is_expired
+*/
+pub trait ResponseCache: Send + Sync {
+    /** Get a cached response. */
+    fn get(&self, key: &CacheKey) -> Option<CachedResponse>;
+
+    /** Cache a response. */
+    fn put(&self, key: CacheKey, response: CachedResponse);
+}
+
+impl<T> ResponseCache for Arc<T>
+where
+    T: ResponseCache + ?Sized,
+{
+    fn get(&self, key: &CacheKey) -> Option<CachedResponse> {
+        (**self).get(key)
+    }
+
+    fn put(&self, key: CacheKey, response: CachedResponse) {
+        (**self).put(key, response)
+    }
+}
+
+/**
+An in-memory response cache that evicts the least recently used response when it's full.
+*/
+pub struct LruResponseCache {
+    capacity: usize,
+    entries: Mutex<LruEntries>,
+}
+
+#[derive(Default)]
+struct LruEntries {
+    tick: u64,
+    responses: HashMap<CacheKey, (u64, CachedResponse)>,
+    recency: BTreeMap<u64, CacheKey>,
+}
+
+impl LruResponseCache {
+    /** Create a cache that holds up to `capacity` responses. */
+    pub fn new(capacity: usize) -> Self {
+        LruResponseCache {
+            capacity,
+            entries: Mutex::new(LruEntries::default()),
+        }
+    }
+
+    /** The number of responses in the cache, including any that have expired. */
+    pub fn len(&self) -> usize {
+        self.entries().responses.len()
+    }
+
+    /** Whether the cache is empty. */
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /** Remove all responses from the cache. */
+    pub fn clear(&self) {
+        *self.entries() = LruEntries::default();
+    }
+
+    fn entries(&self) -> MutexGuard<LruEntries> {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl LruEntries {
+    fn next_tick(&mut self) -> u64 {
+        self.tick += 1;
+        self.tick
+    }
+
+    fn remove(&mut self, key: &CacheKey) {
+        if let Some((tick, _)) = self.responses.remove(key) {
+            self.recency.remove(&tick);
+        }
+    }
+}
+
+impl ResponseCache for LruResponseCache {
+    fn get(&self, key: &CacheKey) -> Option<CachedResponse> {
+        let mut entries = self.entries();
+
+        let expired = match entries.responses.get(key) {
+            Some(&(_, ref response)) => response.is_expired(),
+            None => return None,
+        };
+
+        if expired {
+            entries.remove(key);
+            return None;
+        }
+
+        let tick = entries.next_tick();
+        let (previous, response) = {
+            let entry = entries
+                .responses
+                .get_mut(key)
+                .expect("the response is cached");
+            let previous = ::std::mem::replace(&mut entry.0, tick);
+
+            (previous, entry.1.clone())
+        };
+
+        entries.recency.remove(&previous);
+        entries.recency.insert(tick, key.clone());
+
+        Some(response)
+    }
+
+    fn put(&self, key: CacheKey, response: CachedResponse) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let mut entries = self.entries();
+
+        entries.remove(&key);
+
+        while entries.responses.len() >= self.capacity {
+            let oldest = match entries.recency.keys().next() {
+                Some(&tick) => tick,
+                None => break,
+            };
+
+            if let Some(key) = entries.recency.remove(&oldest) {
+                entries.responses.remove(&key);
+            }
+        }
+
+        let tick = entries.next_tick();
+        entries.recency.insert(tick, key.clone());
+        entries.responses.insert(key, (tick, response));
+    }
+}
+
+/** A response cache and the time-to-live of the responses put in it. */
+#[derive(Clone)]
+pub(crate) struct SenderCache {
+    pub(crate) store: Arc<dyn ResponseCache>,
+    pub(crate) ttl: Duration,
+}
+
+/** The path and query of a request url, which is used in its cache key. */
+pub(crate) fn path_and_query(url: &Url) -> String {
+    match url.query() {
+        Some(query) => format!("{}?{}", url.path(), query),
+        None => url.path().to_owned(),
+    }
+}
+
+/**
+Whether the response to a request can be cached.
+
+Searches that open a scroll context aren't cached, because each one returns a new scroll id.
+*/
+pub(crate) fn is_cacheable(method: &Method, path_and_query: &str) -> bool {
+    let mut parts = path_and_query.splitn(2, '?');
+    let path = parts.next().unwrap_or("");
+    let query = parts.next().unwrap_or("");
+
+    let opens_scroll = query
+        .split('&')
+        .any(|param| param == "scroll" || param.starts_with("scroll="));
+    if opens_scroll {
+        return false;
+    }
+
+    if *method == Method::GET {
+        return true;
+    }
+
+    *method == Method::POST
+        && match path.trim_end_matches('/').rsplit('/').next() {
+            Some("_search") | Some("_msearch") | Some("_count") => true,
+            _ => false,
+        }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{
+        assert_send,
+        assert_sync,
+    };
+
+    fn key(url: &str) -> CacheKey {
+        CacheKey::new(Method::GET, url.to_owned(), Bytes::new())
+    }
+
+    fn response(body: &'static str, ttl: Duration) -> CachedResponse {
+        CachedResponse::new(StatusCode::OK, Bytes::from_static(body.as_bytes()), ttl)
+    }
+
+    #[test]
+    fn assert_send_sync() {
+        assert_send::<LruResponseCache>();
+        assert_sync::<LruResponseCache>();
+    }
+
+    #[test]
+    fn get_cached_response() {
+        let cache = LruResponseCache::new(10);
+
+        cache.put(key("/a"), response("a", Duration::from_secs(60)));
+
+        assert_eq!(
+            Some(Bytes::from_static(b"a")),
+            cache.get(&key("/a")).map(|res| res.body().clone())
+        );
+        assert!(cache.get(&key("/b")).is_none());
+    }
+
+    #[test]
+    fn key_includes_body() {
+        let cache = LruResponseCache::new(10);
+
+        let search = |body: &'static str| {
+            CacheKey::new(
+                Method::POST,
+                "/_search".to_owned(),
+                Bytes::from_static(body.as_bytes()),
+            )
+        };
+
+        cache.put(search("{}"), response("a", Duration::from_secs(60)));
+
+        assert!(cache.get(&search("{}")).is_some());
+        assert!(cache.get(&search(r#"{"size":0}"#)).is_none());
+    }
+
+    #[test]
+    fn expired_responses_are_removed() {
+        let cache = LruResponseCache::new(10);
+
+        cache.put(key("/a"), response("a", Duration::from_secs(0)));
+
+        assert!(cache.get(&key("/a")).is_none());
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn least_recently_used_is_evicted() {
+        let cache = LruResponseCache::new(2);
+
+        cache.put(key("/a"), response("a", Duration::from_secs(60)));
+        cache.put(key("/b"), response("b", Duration::from_secs(60)));
+
+        // Use `a` so `b` is the least recently used
+        cache.get(&key("/a"));
+
+        cache.put(key("/c"), response("c", Duration::from_secs(60)));
+
+        assert_eq!(2, cache.len());
+        assert!(cache.get(&key("/a")).is_some());
+        assert!(cache.get(&key("/b")).is_none());
+        assert!(cache.get(&key("/c")).is_some());
+    }
+
+    #[test]
+    fn cacheable_requests() {
+        assert!(is_cacheable(&Method::GET, "/my-index/_doc/1"));
+        assert!(is_cacheable(&Method::POST, "/my-index/_search"));
+        assert!(is_cacheable(&Method::POST, "/my-index/_search?size=0"));
+        assert!(is_cacheable(&Method::POST, "/_msearch"));
+        assert!(is_cacheable(&Method::POST, "/my-index/_count"));
+
+        assert!(!is_cacheable(&Method::POST, "/_search/scroll"));
+        assert!(!is_cacheable(&Method::POST, "/my-index/_search?scroll=1m"));
+        assert!(!is_cacheable(
+            &Method::GET,
+            "/my-index/_search?size=10&scroll=1m"
+        ));
+        assert!(!is_cacheable(&Method::POST, "/my-index/_doc"));
+        assert!(!is_cacheable(&Method::PUT, "/my-index/_doc/1"));
+        assert!(!is_cacheable(&Method::DELETE, "/my-index/_doc/1"));
+    }
+}
@@ -3,19 +3,34 @@ Raw HTTP modules.
 
 These types are lower-level details for sending requests and receiving
 responses.
+
+Without the `transport` feature only the `Method` and `StatusCode` types, the url path and body helpers,
+and the [`ndjson`](ndjson/index.html) and [`record`](record/index.html) modules are available.
 */
 
+#[cfg(feature = "transport")]
 mod asynchronous;
+#[cfg(feature = "transport")]
+mod curl;
+#[cfg(feature = "transport")]
 mod synchronous;
 
+#[cfg(feature = "transport")]
 pub use self::{
     asynchronous::*,
     synchronous::*,
 };
 
+#[cfg(feature = "transport")]
+pub mod cache;
+pub mod ndjson;
+pub mod record;
+#[cfg(feature = "transport")]
 pub mod receiver;
+#[cfg(feature = "transport")]
 pub mod sender;
 
+#[cfg(feature = "transport")]
 #[doc(inline)]
 pub use reqwest::{
     header,
@@ -35,16 +50,19 @@ pub use crate::genned::http::{
     UrlPath,
 };
 
+#[cfg(feature = "transport")]
 use std::{
     fmt,
     sync::Arc,
 };
 
+#[cfg(feature = "transport")]
 use self::header::HeaderMap;
 
 /**
 A request just before being sent.
 */
+#[cfg(feature = "transport")]
 #[derive(Clone)]
 pub struct HttpRequest<TBody> {
     pub(crate) url: Url,
@@ -53,6 +71,7 @@ pub struct HttpRequest<TBody> {
     pub(crate) body: Option<TBody>,
 }
 
+#[cfg(feature = "transport")]
 impl<TBody> HttpRequest<TBody> {
     pub fn url_mut(&mut self) -> &mut Url {
         &mut self.url
@@ -71,6 +90,7 @@ impl<TBody> HttpRequest<TBody> {
     }
 }
 
+#[cfg(feature = "transport")]
 impl<TBody> fmt::Debug for HttpRequest<TBody> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.debug_struct("HttpRequest")
@@ -0,0 +1,224 @@
+/*!
+Newline-delimited JSON readers and writers.
+
+Elasticsearch uses newline-delimited JSON (NDJSON) for request bodies like bulk and multi-search,
+and for files exported from an index.
+Each line in an NDJSON stream is a single JSON value.
+
+# Examples
+
+Write some values as NDJSON:
+
+```
+# #[macro_use] extern crate serde_json;
+# use elastic::http::ndjson::NdJsonWriter;
+# fn main() { run().unwrap() }
+# fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+let mut writer = NdJsonWriter::new(Vec::new());
+
+writer.write(&json!({ "index": { "_id": "1" } }))?;
+writer.write(&json!({ "title": "A document" }))?;
+
+let body = writer.into_inner();
+
+assert_eq!(
+    "{\"index\":{\"_id\":\"1\"}}\n{\"title\":\"A document\"}\n",
+    String::from_utf8(body)?
+);
+# Ok(())
+# }
+```
+
+Read values from NDJSON:
+
+```
+# #[macro_use] extern crate serde_derive;
+# use elastic::http::ndjson::NdJsonReader;
+# fn main() { run().unwrap() }
+# fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+#[derive(Deserialize)]
+struct MyType {
+    title: String,
+}
+
+let ndjson = "{\"title\":\"First\"}\n{\"title\":\"Second\"}\n";
+
+let titles = NdJsonReader::<_, MyType>::new(ndjson.as_bytes())
+    .map(|doc| doc.map(|doc| doc.title))
+    .collect::<Result<Vec<_>, _>>()?;
+
+assert_eq!(vec!["First", "Second"], titles);
+# Ok(())
+# }
+```
+*/
+
+use std::{
+    io::{
+        self,
+        BufRead,
+        Write,
+    },
+    marker::PhantomData,
+};
+
+use serde::{
+    de::DeserializeOwned,
+    Serialize,
+};
+use serde_json;
+
+/**
+A writer for newline-delimited JSON.
+
+Each value is serialised on its own line.
+*/
+pub struct NdJsonWriter<W> {
+    inner: W,
+}
+
+impl<W> NdJsonWriter<W>
+where
+    W: Write,
+{
+    /** Create a new writer that writes to the given `inner` writer. */
+    pub fn new(inner: W) -> Self {
+        NdJsonWriter { inner }
+    }
+
+    /**
+    Serialise a value as a single line of JSON.
+
+    The value must not serialise to multiple lines, which is always the case for values serialised by `serde_json`.
+    */
+    pub fn write<T>(&mut self, value: &T) -> io::Result<()>
+    where
+        T: Serialize + ?Sized,
+    {
+        serde_json::to_writer(&mut self.inner, value)?;
+        self.inner.write_all(b"\n")
+    }
+
+    /** Get a reference to the inner writer. */
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /** Get the inner writer. */
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+/**
+A streaming reader for newline-delimited JSON.
+
+The reader is an iterator that deserialises each line as a `T`.
+Blank lines are skipped.
+*/
+pub struct NdJsonReader<R, T> {
+    inner: R,
+    line: String,
+    _marker: PhantomData<T>,
+}
+
+impl<R, T> NdJsonReader<R, T>
+where
+    R: BufRead,
+    T: DeserializeOwned,
+{
+    /** Create a new reader that reads lines from the given `inner` reader. */
+    pub fn new(inner: R) -> Self {
+        NdJsonReader {
+            inner,
+            line: String::new(),
+            _marker: PhantomData,
+        }
+    }
+
+    /** Get the inner reader. */
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R, T> Iterator for NdJsonReader<R, T>
+where
+    R: BufRead,
+    T: DeserializeOwned,
+{
+    type Item = Result<T, serde_json::Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            self.line.clear();
+
+            match self.inner.read_line(&mut self.line) {
+                Ok(0) => return None,
+                Ok(_) if self.line.trim().is_empty() => continue,
+                Ok(_) => return Some(serde_json::from_str(&self.line)),
+                Err(e) => return Some(Err(serde_json::Error::io(e))),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+
+    #[test]
+    fn write_values_on_separate_lines() {
+        let mut writer = NdJsonWriter::new(Vec::new());
+
+        writer.write(&json!({ "a": 1 })).unwrap();
+        writer.write("b").unwrap();
+
+        assert_eq!(b"{\"a\":1}\n\"b\"\n".to_vec(), writer.into_inner());
+    }
+
+    #[test]
+    fn read_values_skipping_blank_lines() {
+        let ndjson = "{\"a\":1}\n\n  \r\n{\"a\":2}";
+
+        let values = NdJsonReader::<_, Value>::new(ndjson.as_bytes())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(vec![json!({ "a": 1 }), json!({ "a": 2 })], values);
+    }
+
+    #[test]
+    fn read_invalid_line() {
+        let ndjson = "{\"a\":1}\nnot json\n{\"a\":2}\n";
+
+        let values: Vec<_> = NdJsonReader::<_, Value>::new(ndjson.as_bytes()).collect();
+
+        assert_eq!(3, values.len());
+        assert!(values[0].is_ok());
+        assert!(values[1].is_err());
+        assert!(values[2].is_ok());
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut writer = NdJsonWriter::new(Vec::new());
+
+        writer.write(&json!({ "index": { "_id": "1" } })).unwrap();
+        writer.write(&json!({ "title": "A document" })).unwrap();
+
+        let body = writer.into_inner();
+        let values = NdJsonReader::<_, Value>::new(&body[..])
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        assert_eq!(
+            vec![
+                json!({ "index": { "_id": "1" } }),
+                json!({ "title": "A document" }),
+            ],
+            values
+        );
+    }
+}
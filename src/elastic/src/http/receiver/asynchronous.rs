@@ -1,3 +1,4 @@
+use bytes::Bytes;
 use std::sync::Arc;
 
 use futures::{
@@ -22,6 +23,7 @@ use crate::{
         },
         AsyncChunk,
         AsyncHttpResponse,
+        AsyncResponseBody,
         StatusCode,
     },
 };
@@ -33,7 +35,7 @@ This structure wraps the completed HTTP response but gives you options for conve
 You can also `Read` directly from the response body.
 */
 pub struct AsyncResponseBuilder {
-    inner: RawResponse,
+    inner: AsyncResponseBody,
     status: StatusCode,
     de_pool: Option<Arc<ThreadPool>>,
 }
@@ -44,12 +46,24 @@ pub(crate) fn async_response(
 ) -> Result<AsyncResponseBuilder, Error> {
     let status = StatusCode::from_u16(res.status().into()).map_err(error::request)?;
     Ok(AsyncResponseBuilder {
-        inner: res,
+        inner: AsyncResponseBody::Raw(res),
         status,
         de_pool: de_pool,
     })
 }
 
+pub(crate) fn cached_async_response(
+    status: StatusCode,
+    body: Bytes,
+    de_pool: Option<Arc<ThreadPool>>,
+) -> AsyncResponseBuilder {
+    AsyncResponseBuilder {
+        inner: AsyncResponseBody::Cached(Some(body)),
+        status,
+        de_pool,
+    }
+}
+
 impl AsyncResponseBuilder {
     /** Get the HTTP status for the response. */
     pub fn status(&self) -> StatusCode {
@@ -127,7 +141,7 @@ impl AsyncResponseBuilder {
         T: IsOk + DeserializeOwned + Send + 'static,
     {
         let status = self.status;
-        let body = self.inner.into_body();
+        let body = self.inner;
 
         let de_fn = move |body: AsyncChunk| {
             parse()
@@ -1,5 +1,7 @@
+use bytes::Bytes;
 use reqwest::Response as RawResponse;
 use serde::de::DeserializeOwned;
+use std::io::Cursor;
 
 use crate::{
     error::{
@@ -13,6 +15,7 @@ use crate::{
         },
         StatusCode,
         SyncHttpResponse,
+        SyncResponseBody,
     },
 };
 
@@ -22,11 +25,15 @@ A builder for a response.
 This structure wraps the completed HTTP response but gives you options for converting it into a concrete type.
 You can also `Read` directly from the response body.
 */
-pub struct SyncResponseBuilder(StatusCode, RawResponse);
+pub struct SyncResponseBuilder(StatusCode, SyncResponseBody);
 
 pub(crate) fn sync_response(res: RawResponse) -> Result<SyncResponseBuilder, Error> {
     let status = StatusCode::from_u16(res.status().into()).map_err(error::request)?;
-    Ok(SyncResponseBuilder(status, res))
+    Ok(SyncResponseBuilder(status, SyncResponseBody::Raw(res)))
+}
+
+pub(crate) fn cached_sync_response(status: StatusCode, body: Bytes) -> SyncResponseBuilder {
+    SyncResponseBuilder(status, SyncResponseBody::Cached(Cursor::new(body)))
 }
 
 impl SyncResponseBuilder {
@@ -0,0 +1,367 @@
+/*!
+Serialisable records of sent requests for audit logging and replay.
+*/
+
+use serde_json::{
+    self,
+    Value,
+};
+#[cfg(feature = "transport")]
+use std::io;
+
+#[cfg(feature = "transport")]
+use crate::http::{
+    AsyncHttpRequest,
+    SyncHttpRequest,
+    Url,
+};
+use crate::{
+    endpoints::Endpoint,
+    http::Method,
+    types::mappers::canonicalize,
+};
+
+/**
+A record of a request, taken just before it's sent.
+
+A `RequestRecord` can be taken from any request, regardless of the builder it was created with,
+by calling [`from_sync`][RequestRecord.from_sync] or [`from_async`][RequestRecord.from_async] in a `pre_send_raw` function.
+The record contains the method, path, query and body of the request, but not its headers,
+so credentials like an `Authorization` header aren't logged.
+
+Records serialise to stable JSON: object keys in the body are sorted, so the same request always renders the same way.
+A record can be deserialised from a log and sent again with [`Client.replay`][Client.replay].
+
+# Examples
+
+Audit log every request sent by a `SyncClient`:
+
+```no_run
+# use elastic::prelude::*;
+# use elastic::http::record::RequestRecord;
+# fn main() { run().unwrap() }
+# fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+let client = SyncClientBuilder::new()
+    .pre_send_raw(|req| {
+        let record = RequestRecord::from_sync(req)?;
+
+        println!("{}", record.to_json());
+
+        Ok(())
+    })
+    .build()?;
+# Ok(())
+# }
+```
+
+[RequestRecord.from_sync]: #method.from_sync
+[RequestRecord.from_async]: #method.from_async
+[Client.replay]: ../../client/struct.Client.html#method.replay
+*/
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RequestRecord {
+    method: String,
+    path: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    query: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    body: Option<RecordBody>,
+}
+
+/**
+The body of a [`RequestRecord`][RequestRecord].
+
+[RequestRecord]: struct.RequestRecord.html
+*/
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordBody {
+    /** A single JSON document, like the body of a search request. */
+    Json(Value),
+    /** Newline-delimited JSON documents, like the body of a bulk request. */
+    Ndjson(Vec<Value>),
+    /** A body that isn't JSON. */
+    Raw(String),
+}
+
+impl RequestRecord {
+    /**
+    Take a record of a synchronous request.
+
+    If the request body can only be read once then it's buffered so the request can still be sent.
+    */
+    #[cfg(feature = "transport")]
+    pub fn from_sync(req: &mut SyncHttpRequest) -> Result<Self, io::Error> {
+        let body = match req.body {
+            Some(ref mut body) => Some(RecordBody::from_bytes(body.buffer()?)),
+            None => None,
+        };
+
+        Ok(RequestRecord::from_url(&req.method, &req.url, body))
+    }
+
+    /** Take a record of an asynchronous request. */
+    #[cfg(feature = "transport")]
+    pub fn from_async(req: &AsyncHttpRequest) -> Self {
+        let body = req
+            .body
+            .as_ref()
+            .map(|body| RecordBody::from_bytes(body.as_bytes()));
+
+        RequestRecord::from_url(&req.method, &req.url, body)
+    }
+
+    /**
+    Take a record of an endpoint without sending it.
+
+    This doesn't need the `transport` feature, so requests can be built and queued by a process that can't send them.
+    The record can then be sent later with [`Client.replay`][Client.replay].
+
+    # Examples
+
+    ```
+    # use elastic::prelude::*;
+    # use elastic::http::record::RequestRecord;
+    let endpoint = SearchRequest::for_index("my-index", r#"{"query":{"match_all":{}}}"#);
+
+    let record = RequestRecord::from_endpoint(endpoint);
+
+    assert_eq!(
+        r#"{"method":"POST","path":"/my-index/_search","body":{"json":{"query":{"match_all":{}}}}}"#,
+        record.to_json()
+    );
+    ```
+
+    [Client.replay]: ../../client/struct.Client.html#method.replay
+    */
+    pub fn from_endpoint<'a, TEndpoint, TBody>(endpoint: TEndpoint) -> Self
+    where
+        TEndpoint: Into<Endpoint<'a, TBody>>,
+        TBody: AsRef<[u8]>,
+    {
+        let endpoint = endpoint.into();
+        let body = endpoint
+            .body
+            .as_ref()
+            .map(|body| RecordBody::from_bytes(body.as_ref()));
+
+        let (path, query) = match endpoint.url.find('?') {
+            Some(i) => (&endpoint.url[..i], Some(&endpoint.url[i + 1..])),
+            None => (&endpoint.url[..], None),
+        };
+
+        RequestRecord::new(&endpoint.method, path, query, body)
+    }
+
+    #[cfg(feature = "transport")]
+    fn from_url(method: &Method, url: &Url, body: Option<RecordBody>) -> Self {
+        RequestRecord::new(method, url.path(), url.query(), body)
+    }
+
+    fn new(method: &Method, path: &str, query: Option<&str>, body: Option<RecordBody>) -> Self {
+        RequestRecord {
+            method: method.as_str().to_owned(),
+            path: path.to_owned(),
+            query: query.map(|query| query.to_owned()),
+            body: body.filter(|body| *body != RecordBody::Raw(String::new())),
+        }
+    }
+
+    /** The HTTP method of the request, like `POST`. */
+    pub fn method(&self) -> &str {
+        &self.method
+    }
+
+    /** The path of the request, without the base url of the node it was sent to. */
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /** The url query of the request, without the leading `?`. */
+    pub fn query(&self) -> Option<&str> {
+        self.query.as_ref().map(|query| query.as_str())
+    }
+
+    /** The body of the request. */
+    pub fn body(&self) -> Option<&RecordBody> {
+        self.body.as_ref()
+    }
+
+    /** Render the record as a single line of JSON. */
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("a request record is always serialisable")
+    }
+
+    /**
+    Convert the record into an endpoint that can be sent again.
+
+    The query is sent as part of the path, so it's combined with any url params on the client.
+    */
+    pub fn to_endpoint(&self) -> Result<Endpoint<'static, String>, RecordError> {
+        let method = Method::from_bytes(self.method.as_bytes())
+            .map_err(|_| RecordError::InvalidMethod(self.method.clone()))?;
+
+        let url = match self.query {
+            Some(ref query) => format!("{}?{}", self.path, query),
+            None => self.path.clone(),
+        };
+
+        Ok(Endpoint {
+            url: url.into(),
+            method,
+            body: self.body.as_ref().map(RecordBody::to_body),
+        })
+    }
+}
+
+impl RecordBody {
+    fn from_bytes(bytes: &[u8]) -> Self {
+        if let Ok(value) = serde_json::from_slice(bytes) {
+            return RecordBody::Json(canonicalize(value));
+        }
+
+        let lines = bytes
+            .split(|b| *b == b'\n')
+            .filter(|line| !line.is_empty())
+            .map(|line| serde_json::from_slice(line).map(canonicalize))
+            .collect::<Result<Vec<Value>, _>>();
+
+        match lines {
+            Ok(ref lines) if !lines.is_empty() => RecordBody::Ndjson(lines.clone()),
+            _ => RecordBody::Raw(String::from_utf8_lossy(bytes).into_owned()),
+        }
+    }
+
+    fn to_body(&self) -> String {
+        match *self {
+            RecordBody::Json(ref value) => value.to_string(),
+            RecordBody::Ndjson(ref lines) => {
+                lines.iter().map(|line| format!("{}\n", line)).collect()
+            }
+            RecordBody::Raw(ref body) => body.clone(),
+        }
+    }
+}
+
+quick_error! {
+    /** An error converting a request record into an endpoint. */
+    #[derive(Debug)]
+    pub enum RecordError {
+        /** The method of the record isn't a valid HTTP method. */
+        InvalidMethod(method: String) {
+            display("`{}` isn't a valid HTTP method", method)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "transport")]
+    use crate::http::{
+        HttpRequest,
+        SyncBody,
+    };
+    #[cfg(feature = "transport")]
+    use std::sync::Arc;
+
+    #[cfg(feature = "transport")]
+    fn sync_request(body: Option<SyncBody>) -> SyncHttpRequest {
+        HttpRequest {
+            url: Url::parse("http://localhost:9200/my-index/_search?routing=1").unwrap(),
+            method: Method::POST,
+            headers: Arc::new(Default::default()),
+            body,
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "transport")]
+    fn record_json_body() {
+        let mut req = sync_request(Some(r#"{"size":10,"query":{"match_all":{}}}"#.into()));
+
+        let record = RequestRecord::from_sync(&mut req).unwrap();
+
+        assert_eq!(
+            json!({
+                "method": "POST",
+                "path": "/my-index/_search",
+                "query": "routing=1",
+                "body": { "json": { "query": { "match_all": {} }, "size": 10 } }
+            }),
+            serde_json::to_value(&record).unwrap()
+        );
+        assert_eq!(
+            r#"{"method":"POST","path":"/my-index/_search","query":"routing=1","body":{"json":{"query":{"match_all":{}},"size":10}}}"#,
+            record.to_json()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "transport")]
+    fn record_ndjson_body() {
+        let body = "{\"index\":{\"_id\":\"1\"}}\n{\"title\":\"a\"}\n";
+        let mut req = sync_request(Some(body.into()));
+
+        let record = RequestRecord::from_sync(&mut req).unwrap();
+
+        assert_eq!(
+            Some(&RecordBody::Ndjson(vec![
+                json!({ "index": { "_id": "1" } }),
+                json!({ "title": "a" }),
+            ])),
+            record.body()
+        );
+        assert_eq!(body, record.to_endpoint().unwrap().body.unwrap());
+    }
+
+    #[test]
+    fn record_endpoint() {
+        let endpoint = Endpoint {
+            url: "/my-index/_search?routing=1".into(),
+            method: Method::POST,
+            body: Some(r#"{"size":10}"#),
+        };
+
+        let record = RequestRecord::from_endpoint(endpoint);
+
+        assert_eq!("POST", record.method());
+        assert_eq!("/my-index/_search", record.path());
+        assert_eq!(Some("routing=1"), record.query());
+        assert_eq!(
+            Some(&RecordBody::Json(json!({ "size": 10 }))),
+            record.body()
+        );
+    }
+
+    #[test]
+    fn record_endpoint_without_body() {
+        let record = RequestRecord::from_endpoint(Endpoint::<&[u8]> {
+            url: "/_cluster/health".into(),
+            method: Method::GET,
+            body: None,
+        });
+
+        assert_eq!(
+            r#"{"method":"GET","path":"/_cluster/health"}"#,
+            record.to_json()
+        );
+    }
+
+    #[test]
+    fn replay_record() {
+        let record: RequestRecord = serde_json::from_value(json!({
+            "method": "POST",
+            "path": "/my-index/_search",
+            "query": "routing=1",
+            "body": { "json": { "size": 10 } }
+        }))
+        .unwrap();
+
+        let endpoint = record.to_endpoint().unwrap();
+
+        assert_eq!("/my-index/_search?routing=1", endpoint.url.as_ref());
+        assert_eq!(Method::POST, endpoint.method);
+        assert_eq!(Some(r#"{"size":10}"#.to_owned()), endpoint.body);
+    }
+}
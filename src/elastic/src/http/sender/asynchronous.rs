@@ -1,3 +1,4 @@
+use bytes::Bytes;
 use futures::{
     future::{
         lazy,
@@ -7,9 +8,11 @@ use futures::{
     Future,
     IntoFuture,
     Poll,
+    Stream,
 };
 use reqwest::r#async::{
     Client as AsyncHttpClient,
+    Request as RawRequest,
     RequestBuilder as AsyncHttpRequestBuilder,
 };
 use std::{
@@ -28,13 +31,22 @@ use crate::{
         Error,
     },
     http::{
+        cache::{
+            is_cacheable,
+            path_and_query,
+            CacheKey,
+            CachedResponse,
+            SenderCache,
+        },
         receiver::{
             async_response,
+            cached_async_response,
             AsyncResponseBuilder,
         },
         sender::{
             build_reqwest_method,
             build_url,
+            check_content_length,
             NextParams,
             NodeAddresses,
             NodeAddressesInner,
@@ -63,6 +75,8 @@ pub struct AsyncSender {
     pub(crate) http: AsyncHttpClient,
     pub(crate) serde_pool: Option<Arc<ThreadPool>>,
     pub(crate) pre_send: Option<Arc<AsyncPreSend>>,
+    pub(crate) max_content_length: Option<usize>,
+    pub(crate) cache: Option<SenderCache>,
 }
 
 impl private::Sealed for AsyncSender {}
@@ -132,34 +146,61 @@ impl Sender for AsyncSender {
                     .map(|url| (params, url))
             })
             .and_then(move |(params, url)| {
-                Ok(AsyncHttpRequest {
+                let req = AsyncHttpRequest {
                     url,
                     method,
                     headers: params.get_headers(),
                     body: body.map(|body| body.into()),
-                })
+                };
+
+                Ok((req, params.get_bypass_cache()))
             });
 
         let pre_send = self.pre_send.clone();
-        let pre_send_future = build_req_future.and_then(move |mut req| {
+        let pre_send_future = build_req_future.and_then(move |(mut req, bypass_cache)| {
             if let Some(pre_send) = pre_send {
                 Either::A(
                     pre_send(&mut req)
                         .map_err(error::wrapped)
                         .map_err(error::request)
-                        .and_then(move |_| Ok(req).into_future()),
+                        .and_then(move |_| Ok((req, bypass_cache)).into_future()),
                 )
             } else {
-                Either::B(Ok(req).into_future())
+                Either::B(Ok((req, bypass_cache)).into_future())
             }
         });
 
+        let max_content_length = self.max_content_length;
+        let pre_send_future = pre_send_future.and_then(move |(req, bypass_cache)| {
+            check_content_length(req.body.as_ref().map(|body| body.len()), max_content_length)
+                .map(|_| (req, bypass_cache))
+        });
+
+        let cache = self.cache.clone();
         let pre_send_http = self.http.clone();
         let pre_send_future = pre_send_future
-            .and_then(move |req| {
+            .and_then(move |(req, bypass_cache)| {
+                let cache = match cache {
+                    Some(cache) if is_cacheable(&req.method, &path_and_query(&req.url)) => {
+                        let key = build_cache_key(&req);
+
+                        if !bypass_cache {
+                            if let Some(cached) =
+                                cache.store.get(&key).filter(|res| !res.is_expired())
+                            {
+                                return Ok(CacheLookup::Hit(cached));
+                            }
+                        }
+
+                        Some((cache, key))
+                    }
+                    _ => None,
+                };
+
                 build_reqwest(&pre_send_http, req)
                     .build()
                     .map_err(error::request)
+                    .map(|req| CacheLookup::Miss(req, cache))
             })
             .log_err(move |e| {
                 error!(
@@ -169,24 +210,54 @@ impl Sender for AsyncSender {
             });
 
         let req_http = self.http.clone();
-        let req_future = pre_send_future.and_then(move |req| {
-            req_http
-                .execute(req)
-                .map_err(error::request)
-                .and_then(move |res| {
-                    info!(
-                        "Elasticsearch Response: correlation_id: '{}', status: '{}'",
-                        correlation_id,
-                        res.status()
-                    );
-                    async_response(res, serde_pool).into_future()
-                })
-                .log_err(move |e| {
-                    error!(
-                        "Elasticsearch Response: correlation_id: '{}', error: '{:?}'",
-                        correlation_id, e
-                    )
-                })
+        let req_future = pre_send_future.and_then(move |lookup| match lookup {
+            CacheLookup::Hit(cached) => {
+                info!(
+                    "Elasticsearch Response: correlation_id: '{}', status: '{}', cached: 'true'",
+                    correlation_id,
+                    cached.status()
+                );
+
+                let res = cached_async_response(cached.status(), cached.body().clone(), serde_pool);
+
+                Either::A(Ok(res).into_future())
+            }
+            CacheLookup::Miss(req, cache) => Either::B(
+                req_http
+                    .execute(req)
+                    .map_err(error::request)
+                    .and_then(move |res| {
+                        info!(
+                            "Elasticsearch Response: correlation_id: '{}', status: '{}'",
+                            correlation_id,
+                            res.status()
+                        );
+                        async_response(res, serde_pool.clone())
+                            .map(|res| (res, serde_pool))
+                            .into_future()
+                    })
+                    .and_then(move |(res, serde_pool)| match cache {
+                        Some((cache, key)) if res.status().is_success() => {
+                            let status = res.status();
+
+                            Either::A(res.into_raw().concat2().map(move |body| {
+                                let body = Bytes::from(body.as_ref());
+                                cache
+                                    .store
+                                    .put(key, CachedResponse::new(status, body.clone(), cache.ttl));
+
+                                cached_async_response(status, body, serde_pool)
+                            }))
+                        }
+                        _ => Either::B(Ok(res).into_future()),
+                    })
+                    .log_err(move |e| {
+                        error!(
+                            "Elasticsearch Response: correlation_id: '{}', error: '{:?}'",
+                            correlation_id, e
+                        )
+                    }),
+            ),
         });
 
         PendingResponse::new(req_future)
@@ -235,6 +306,24 @@ impl From<RequestParams> for PendingParams {
     }
 }
 
+/** Whether the response to a request was found in the cache. */
+enum CacheLookup {
+    Hit(CachedResponse),
+    Miss(RawRequest, Option<(SenderCache, CacheKey)>),
+}
+
+/** Build the key for caching the response to a request. */
+fn build_cache_key(req: &AsyncHttpRequest) -> CacheKey {
+    let url = path_and_query(&req.url);
+
+    let body = match req.body {
+        Some(ref body) => Bytes::from(body.as_bytes()),
+        None => Bytes::new(),
+    };
+
+    CacheKey::new(req.method.clone(), url, body)
+}
+
 /** Build an asynchronous `reqwest::RequestBuilder` from an Elasticsearch request. */
 fn build_reqwest(client: &AsyncHttpClient, req: AsyncHttpRequest) -> AsyncHttpRequestBuilder {
     let AsyncHttpRequest {
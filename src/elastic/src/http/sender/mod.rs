@@ -175,6 +175,14 @@ impl<TSender> NodeAddresses<TSender> {
             inner: NodeAddressesInner::Sniffed(nodes),
         }
     }
+
+    /** The address of the node that the cluster is sniffed from, or `None` if the nodes are static. */
+    pub(crate) fn sniffed_base_url(&self) -> Option<&str> {
+        match self.inner {
+            NodeAddressesInner::Static(_) => None,
+            NodeAddressesInner::Sniffed(ref nodes) => Some(nodes.base_url()),
+        }
+    }
 }
 
 impl<TSender> private::Sealed for NodeAddresses<TSender> {}
@@ -248,3 +256,30 @@ impl NodeAddressesBuilder {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::*;
+
+    #[test]
+    fn senders_are_send_sync() {
+        assert_send::<SyncSender>();
+        assert_sync::<SyncSender>();
+
+        assert_send::<AsyncSender>();
+        assert_sync::<AsyncSender>();
+
+        assert_send::<NodeAddresses<SyncSender>>();
+        assert_sync::<NodeAddresses<SyncSender>>();
+
+        assert_send::<NodeAddresses<AsyncSender>>();
+        assert_sync::<NodeAddresses<AsyncSender>>();
+
+        assert_send::<NodeAddressesBuilder>();
+        assert_sync::<NodeAddressesBuilder>();
+
+        assert_send::<SniffedNodesBuilder>();
+        assert_sync::<SniffedNodesBuilder>();
+    }
+}
@@ -14,9 +14,16 @@ use reqwest::{
 };
 use url::form_urlencoded::Serializer;
 
-use crate::http::{
-    sender::NodeAddress,
-    Method,
+use crate::{
+    error::{
+        self,
+        Error,
+        PayloadTooLarge,
+    },
+    http::{
+        sender::NodeAddress,
+        Method,
+    },
 };
 
 pub const DEFAULT_NODE_ADDRESS: &'static str = "http://localhost:9200";
@@ -32,6 +39,7 @@ pub struct PreRequestParams {
     url_params: Arc<HashMap<&'static str, String>>,
     // We should be able to replace this with `Arc<HeaderMapMap>` from the `http` crate
     headers: Arc<HeaderMap>,
+    bypass_cache: bool,
 }
 
 /**
@@ -105,6 +113,7 @@ impl PreRequestParams {
                 headers
             }),
             url_params: Arc::new(HashMap::new()),
+            bypass_cache: false,
         }
     }
 
@@ -123,6 +132,18 @@ impl PreRequestParams {
         Arc::make_mut(&mut self.headers).insert(key, value);
         self
     }
+
+    /**
+    Always send requests to Elasticsearch, even if the client has a [response cache][cache-mod].
+
+    The response is still cached so it can be used by later requests.
+
+    [cache-mod]: ../cache/index.html
+    */
+    pub fn bypass_cache(mut self) -> Self {
+        self.bypass_cache = true;
+        self
+    }
 }
 
 impl Default for PreRequestParams {
@@ -172,6 +193,18 @@ impl RequestParams {
         self
     }
 
+    /**
+    Always send the request to Elasticsearch, even if the client has a [response cache][cache-mod].
+
+    The response is still cached so it can be used by later requests.
+
+    [cache-mod]: ../cache/index.html
+    */
+    pub fn bypass_cache(mut self) -> Self {
+        self.inner = self.inner.bypass_cache();
+        self
+    }
+
     /** Get the base url. */
     pub fn get_base_url(&self) -> &str {
         self.base_url.as_ref()
@@ -181,6 +214,10 @@ impl RequestParams {
         self.inner.headers.clone()
     }
 
+    pub(crate) fn get_bypass_cache(&self) -> bool {
+        self.inner.bypass_cache
+    }
+
     /**
     Get the url query params as a formatted string.
 
@@ -241,6 +278,14 @@ pub(crate) fn build_reqwest_method(method: Method) -> reqwest::Method {
     }
 }
 
+/** Check the length of a request body against an optional maximum content length. */
+pub(crate) fn check_content_length(len: Option<usize>, max: Option<usize>) -> Result<(), Error> {
+    match (len, max) {
+        (Some(len), Some(max)) if len > max => Err(error::request(PayloadTooLarge::new(len, max))),
+        _ => Ok(()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -335,4 +380,22 @@ mod tests {
 
         assert_eq!((0, None), req.get_url_qry());
     }
+
+    #[test]
+    fn request_params_can_bypass_cache() {
+        assert!(!RequestParams::default().get_bypass_cache());
+        assert!(RequestParams::default().bypass_cache().get_bypass_cache());
+    }
+
+    #[test]
+    fn check_content_length_under_max() {
+        assert!(check_content_length(Some(10), Some(10)).is_ok());
+        assert!(check_content_length(None, Some(10)).is_ok());
+        assert!(check_content_length(Some(11), None).is_ok());
+    }
+
+    #[test]
+    fn check_content_length_over_max() {
+        assert!(check_content_length(Some(11), Some(10)).is_err());
+    }
 }
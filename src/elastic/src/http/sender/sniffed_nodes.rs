@@ -98,6 +98,11 @@ struct SniffedNodesInner {
 }
 
 impl<TSender> SniffedNodes<TSender> {
+    /** The address of the node that the cluster is sniffed from. */
+    pub(crate) fn base_url(&self) -> &str {
+        self.refresh_params.get_base_url()
+    }
+
     /**
     Get the next async address or refresh.
 
@@ -281,7 +286,8 @@ impl<TSender> SniffedNodes<TSender> {
     ) -> SendableRequest<NodesInfoRequest<'static>, RequestParams, DefaultBody> {
         SendableRequest::new(
             NodesInfoRequest::new(),
-            SendableRequestParams::Value(self.refresh_params.clone()),
+            // Sniffing needs the current state of the cluster, not a cached response
+            SendableRequestParams::Value(self.refresh_params.clone().bypass_cache()),
         )
     }
 
@@ -1,9 +1,11 @@
+use bytes::Bytes;
 use reqwest::{
     Client as SyncHttpClient,
     RequestBuilder as SyncHttpRequestBuilder,
 };
 use std::{
     error::Error as StdError,
+    io::Read,
     sync::Arc,
 };
 
@@ -14,13 +16,22 @@ use crate::{
         Error,
     },
     http::{
+        cache::{
+            is_cacheable,
+            path_and_query,
+            CacheKey,
+            CachedResponse,
+            SenderCache,
+        },
         receiver::{
+            cached_sync_response,
             sync_response,
             SyncResponseBuilder,
         },
         sender::{
             build_reqwest_method,
             build_url,
+            check_content_length,
             NextParams,
             NodeAddresses,
             NodeAddressesInner,
@@ -44,6 +55,8 @@ pub(crate) type SyncPreSend =
 pub struct SyncSender {
     pub(crate) http: SyncHttpClient,
     pub(crate) pre_send: Option<Arc<SyncPreSend>>,
+    pub(crate) max_content_length: Option<usize>,
+    pub(crate) cache: Option<SenderCache>,
 }
 
 impl private::Sealed for SyncSender {}
@@ -86,6 +99,8 @@ impl Sender for SyncSender {
             }
         };
 
+        let bypass_cache = params.get_bypass_cache();
+
         let mut req = build_req(endpoint, params).log_err(|e| {
             error!(
                 "Elasticsearch Request: correlation_id: '{}', error: '{:?}'",
@@ -105,6 +120,38 @@ impl Sender for SyncSender {
                 })?;
         }
 
+        check_content_length(
+            req.body.as_ref().and_then(|body| body.len()),
+            self.max_content_length,
+        )
+        .log_err(|e| {
+            error!(
+                "Elasticsearch Request: correlation_id: '{}', error: '{:?}'",
+                correlation_id, e
+            )
+        })?;
+
+        let cache = match self.cache {
+            Some(ref cache) if is_cacheable(&req.method, &path_and_query(&req.url)) => {
+                let key = build_cache_key(&mut req)?;
+
+                if !bypass_cache {
+                    if let Some(cached) = cache.store.get(&key).filter(|res| !res.is_expired()) {
+                        info!(
+                            "Elasticsearch Response: correlation_id: '{}', status: '{}', cached: 'true'",
+                            correlation_id,
+                            cached.status()
+                        );
+
+                        return Ok(cached_sync_response(cached.status(), cached.body().clone()));
+                    }
+                }
+
+                Some((cache, key))
+            }
+            _ => None,
+        };
+
         let req = build_reqwest(&self.http, req)
             .build()
             .map_err(error::request)?;
@@ -127,10 +174,41 @@ impl Sender for SyncSender {
             }
         };
 
-        sync_response(res)
+        let res = sync_response(res)?;
+
+        match cache {
+            Some((cache, key)) if res.status().is_success() => {
+                let status = res.status();
+
+                let mut body = Vec::new();
+                res.into_raw()
+                    .read_to_end(&mut body)
+                    .map_err(|e| error::response(status, e))?;
+
+                let body = Bytes::from(body);
+                cache
+                    .store
+                    .put(key, CachedResponse::new(status, body.clone(), cache.ttl));
+
+                Ok(cached_sync_response(status, body))
+            }
+            _ => Ok(res),
+        }
     }
 }
 
+/** Build the key for caching the response to a request. */
+fn build_cache_key(req: &mut SyncHttpRequest) -> Result<CacheKey, Error> {
+    let url = path_and_query(&req.url);
+
+    let body = match req.body {
+        Some(ref mut body) => Bytes::from(body.buffer().map_err(error::request)?),
+        None => Bytes::new(),
+    };
+
+    Ok(CacheKey::new(req.method.clone(), url, body))
+}
+
 impl NextParams for NodeAddresses<SyncSender> {
     type Params = Params;
 
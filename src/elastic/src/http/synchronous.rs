@@ -1,4 +1,5 @@
 use bytes::Bytes;
+use serde::Serialize;
 use serde_json::Value;
 use std::{
     borrow::Cow,
@@ -65,6 +66,19 @@ impl<'a> BufferedSyncBodyInner<'a> {
 }
 
 impl SyncBody {
+    /**
+    Serialise a value as json into a body.
+
+    The value is serialised straight into the body, so a document or query doesn't need to be
+    converted into a `serde_json::Value` first.
+    */
+    pub fn json<T>(body: &T) -> Result<SyncBody, serde_json::Error>
+    where
+        T: Serialize + ?Sized,
+    {
+        serde_json::to_vec(body).map(SyncBody::from)
+    }
+
     /** Convert the body into its inner value. */
     pub(crate) fn into_inner(self) -> Body {
         match self.0 {
@@ -81,6 +95,37 @@ impl SyncBody {
         }
     }
 
+    /**
+    Get the length of the body in bytes.
+
+    The length of a body that can only be read once isn't known, so `None` is returned.
+    */
+    pub(crate) fn len(&self) -> Option<usize> {
+        match self.0 {
+            SyncBodyInner::UnBuffered(_) => None,
+            SyncBodyInner::Buffered(ref inner) => Some(AsRef::<[u8]>::as_ref(inner).len()),
+        }
+    }
+
+    /**
+    Buffer the body and get its bytes.
+
+    If the body can only be read once then it's read into a buffer that replaces it, so it can still be sent.
+    */
+    pub(crate) fn buffer(&mut self) -> Result<&[u8], io::Error> {
+        if let SyncBodyInner::UnBuffered(ref mut reader) = self.0 {
+            let mut buf = Vec::new();
+            reader.read_to_end(&mut buf)?;
+
+            self.0 = SyncBodyInner::Buffered(BufferedSyncBodyInner::Bytes(Cow::Owned(buf)));
+        }
+
+        match self.0 {
+            SyncBodyInner::Buffered(ref inner) => Ok(AsRef::<[u8]>::as_ref(inner)),
+            SyncBodyInner::UnBuffered(_) => unreachable!("the body was buffered"),
+        }
+    }
+
     /**
     Get a reader over the synchronous body.
 
@@ -191,10 +236,25 @@ impl From<File> for SyncBody {
 }
 
 /** A raw HTTP response that can be buffered using `Read`. */
-pub struct SyncHttpResponse(StatusCode, RawResponse);
+pub struct SyncHttpResponse(StatusCode, SyncResponseBody);
+
+/** The body of a response, which is either being read from the network or was cached. */
+pub(crate) enum SyncResponseBody {
+    Raw(RawResponse),
+    Cached(Cursor<Bytes>),
+}
+
+impl Read for SyncResponseBody {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match *self {
+            SyncResponseBody::Raw(ref mut response) => response.read(buf),
+            SyncResponseBody::Cached(ref mut body) => body.read(buf),
+        }
+    }
+}
 
 impl SyncHttpResponse {
-    pub(crate) fn from_raw(status: StatusCode, response: RawResponse) -> Self {
+    pub(crate) fn from_raw(status: StatusCode, response: SyncResponseBody) -> Self {
         SyncHttpResponse(status, response)
     }
 
@@ -246,4 +306,25 @@ mod tests {
     fn json_value_into_body() {
         SyncBody::from(json!({}));
     }
+
+    #[test]
+    fn serialize_into_body() {
+        let mut body = SyncBody::json(&json!({ "query": { "match_all": {} } })).unwrap();
+
+        assert_eq!(br#"{"query":{"match_all":{}}}"#, body.buffer().unwrap());
+    }
+
+    #[test]
+    fn buffer_unbuffered_body() {
+        let mut body = SyncBody(SyncBodyInner::UnBuffered(Box::new(Cursor::new(
+            b"abc".to_vec(),
+        ))));
+
+        assert_eq!(b"abc", body.buffer().unwrap());
+
+        let mut sent = Vec::new();
+        body.reader().read_to_end(&mut sent).unwrap();
+
+        assert_eq!(b"abc".to_vec(), sent);
+    }
 }
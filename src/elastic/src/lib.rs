@@ -241,6 +241,26 @@ This crate is mostly a meta-package composed of a number of smaller pieces inclu
 
 This crate glues these libraries together with some simple assumptions about how they're going to be used.
 
+## Building requests without a client
+
+The HTTP client is enabled by the default `transport` feature.
+Without it only the [`types`][types-mod], [`params`][params-mod] and [`endpoints`][endpoints-mod] modules and some of the [`http`][http-mod] module are available,
+and `reqwest`, `hyper` and `tokio` aren't linked at all.
+That's enough to build the same typed documents, mappings and requests as a client would,
+to render them for a script or queue them as a [`RequestRecord`][RequestRecord] to send later:
+
+```toml
+[dependencies]
+elastic = { version = "*", default-features = false, features = ["geo"] }
+```
+
+## WebAssembly
+
+The HTTP client doesn't support `wasm32` targets, so the `transport` feature needs to be disabled to build for them.
+Browser or edge code can then build the same typed documents and requests as a backend service.
+
+On `wasm32-unknown-unknown` the current date for `Date::now` is read from the JavaScript `Date` API.
+
 # Links
 
 - [Elasticsearch Docs][docs-root]
@@ -267,19 +287,26 @@ This crate glues these libraries together with some simple assumptions about how
 [client-mod]: client/index.html
 [requests-mod]: client/requests/index.html
 [types-mod]: types/index.html
+[params-mod]: params/index.html
+[endpoints-mod]: endpoints/index.html
+[http-mod]: http/index.html
+[RequestRecord]: http/record/struct.RequestRecord.html
 [request-builders]: client/index.html#request-builders
 */
 
 //#![deny(warnings, missing_docs)]
 
+#[cfg(feature = "transport")]
 #[macro_use]
 extern crate error_chain;
+#[cfg(feature = "transport")]
 #[macro_use]
 extern crate futures;
 #[macro_use]
 extern crate log;
 #[macro_use]
 extern crate quick_error;
+#[cfg(feature = "transport")]
 extern crate crossbeam_channel as channel;
 #[macro_use]
 extern crate serde_derive;
@@ -323,16 +350,19 @@ pub mod endpoints {
     };
 }
 
+#[cfg(feature = "transport")]
 pub mod error;
 
 mod private {
     pub trait Sealed {}
 }
 
+#[cfg(feature = "transport")]
 pub mod client;
 pub mod http;
 pub mod types;
 
+#[cfg(feature = "transport")]
 pub use self::{
     client::{
         AsyncClient,
@@ -344,8 +374,10 @@ pub use self::{
 pub mod prelude {
     /*! A glob import for convenience. */
 
+    #[cfg(feature = "transport")]
+    pub use super::client::prelude::*;
+
     pub use super::{
-        client::prelude::*,
         endpoints::*,
         http::empty_body,
         params::*,
@@ -12,17 +12,31 @@ use chrono::{
     },
     DateTime,
     Utc,
+    Weekday,
+};
+use serde::{
+    ser::Error as SerError,
+    Serialize,
+};
+use std::{
+    any::TypeId,
+    cell::Cell,
+    collections::BTreeMap,
+    sync::Mutex,
+    thread::LocalKey,
 };
-use serde::Serialize;
-use serde_json;
 
-use crate::types::private::field::{
-    FieldMapping,
-    FieldType,
-    SerializeFieldMapping,
+use crate::types::{
+    date::CustomDateFormat,
+    private::field::{
+        FieldMapping,
+        FieldType,
+        SerializeFieldMapping,
+    },
 };
 
 pub use crate::types::{
+    alias::mapping::AliasMapping,
     date::{
         DateFormat,
         DateValue,
@@ -30,19 +44,41 @@ pub use crate::types::{
         ParseError,
     },
     document::{
+        dynamic_templates::DynamicTemplate,
         mapping::{
             ObjectFieldType,
             ObjectMapping,
             PropertiesMapping,
+            SourceMapping,
+            NESTED_DATATYPE,
+            OBJECT_DATATYPE,
         },
+        BeforeIndex,
         DocumentType,
+        FieldNames,
         Id,
         Index,
+        OptionalField,
         StaticIndex,
         StaticType,
         Type,
         DEFAULT_DOC_TYPE,
     },
+    string::{
+        keyword::mapping::{
+            DefaultKeywordMapping,
+            KeywordFieldType,
+            KeywordMapping,
+        },
+        mapping::{
+            keyword_sub_fields_with_ignore_above,
+            StringField,
+        },
+        text::mapping::{
+            DefaultTextMapping,
+            TextMapping,
+        },
+    },
 };
 
 pub use chrono::format::{
@@ -52,6 +88,11 @@ pub use chrono::format::{
     Pad,
 };
 pub use serde::ser::SerializeStruct;
+pub use serde_json::{
+    Error as SerdeJsonError,
+    Map,
+    Value,
+};
 
 /** Serialise a field mapping as a field using the given serialiser. */
 #[inline]
@@ -64,10 +105,174 @@ where
     TMapping: FieldMapping<TPivot>,
     S: SerializeStruct,
     SerializeFieldMapping<TMapping, TPivot>: Serialize,
+{
+    mapping_ser::<TMapping, TPivot, S>(state, field)
+}
+
+/** Add the values a field can take to the given list, if it only takes a fixed set of values. */
+#[inline]
+pub fn field_values<TField, TMapping, TPivot>(
+    values: &mut Vec<(&'static str, &'static [&'static str])>,
+    field: &'static str,
+) where
+    TField: FieldType<TMapping, TPivot>,
+    TMapping: FieldMapping<TPivot>,
+{
+    if let Some(field_values) = TField::field_values() {
+        values.push((field, field_values));
+    }
+}
+
+/**
+Serialise a field mapping as a field with a `null_value` using the given serialiser.
+
+The `null_value` is serialised as the value of the field, so it's formatted the same way as the field when it's indexed.
+*/
+pub fn field_ser_with_null_value<TField, TMapping, TPivot, S, TValue>(
+    state: &mut S,
+    field: &'static str,
+    null_value: &TValue,
+) -> Result<(), S::Error>
+where
+    TField: FieldType<TMapping, TPivot>,
+    TMapping: FieldMapping<TPivot>,
+    S: SerializeStruct,
+    SerializeFieldMapping<TMapping, TPivot>: Serialize,
+    TValue: Serialize,
+{
+    mapping_ser_with_null_value::<TMapping, TPivot, S, TValue>(state, field, null_value)
+}
+
+/**
+Get the parsed format for a format declared by `date_fmt!`.
+
+Each format is parsed and leaked the first time it's used and reused after that,
+so dates formatted with it can borrow from the format.
+*/
+pub fn cached_custom_date_format<F>(format: &'static str) -> &'static CustomDateFormat
+where
+    F: 'static,
+{
+    static FORMATS: Mutex<BTreeMap<TypeId, &'static CustomDateFormat>> =
+        Mutex::new(BTreeMap::new());
+
+    let mut formats = FORMATS.lock().unwrap_or_else(|e| e.into_inner());
+
+    *formats.entry(TypeId::of::<F>()).or_insert_with(|| {
+        let custom = CustomDateFormat::new(format)
+            .unwrap_or_else(|e| panic!("invalid date format `{}`: {}", format, e));
+
+        &*Box::leak(Box::new(custom))
+    })
+}
+
+/**
+Get the path to a field from the root document.
+
+Fields on the root document have an empty `path`, so their path is just their name.
+*/
+pub fn field_path(path: &str, field: &'static str) -> String {
+    if path.is_empty() {
+        field.to_owned()
+    } else {
+        format!("{}.{}", path, field)
+    }
+}
+
+/**
+Serialise the properties of a type that can contain itself, up to a maximum depth.
+
+The `depth` tracks how many times the properties of the type are currently being serialised on this thread.
+Once the depth reaches the `limit` no more properties are serialised, so the mapping of a recursive type is finite.
+*/
+pub fn props_ser_with_recursion_limit<S, F>(
+    depth: &'static LocalKey<Cell<usize>>,
+    limit: usize,
+    state: &mut S,
+    serialize_props: F,
+) -> Result<(), S::Error>
+where
+    S: SerializeStruct,
+    F: FnOnce(&mut S) -> Result<(), S::Error>,
+{
+    let current = depth.with(Cell::get);
+
+    if current >= limit {
+        return Ok(());
+    }
+
+    depth.with(|depth| depth.set(current + 1));
+    let result = serialize_props(state);
+    depth.with(|depth| depth.set(current));
+
+    result
+}
+
+/**
+Serialise an explicit mapping as a field using the given serialiser.
+
+This is used for fields with an `#[elastic(mapping = "<ident>")]` attribute, where the mapping isn't
+determined by the type of the field.
+*/
+#[inline]
+pub fn mapping_ser<TMapping, TPivot, S>(state: &mut S, field: &'static str) -> Result<(), S::Error>
+where
+    TMapping: FieldMapping<TPivot>,
+    S: SerializeStruct,
+    SerializeFieldMapping<TMapping, TPivot>: Serialize,
 {
     state.serialize_field(field, &SerializeFieldMapping::<TMapping, TPivot>::default())
 }
 
+/** Serialise an explicit mapping as a field with a `null_value` using the given serialiser. */
+pub fn mapping_ser_with_null_value<TMapping, TPivot, S, TValue>(
+    state: &mut S,
+    field: &'static str,
+    null_value: &TValue,
+) -> Result<(), S::Error>
+where
+    TMapping: FieldMapping<TPivot>,
+    S: SerializeStruct,
+    SerializeFieldMapping<TMapping, TPivot>: Serialize,
+    TValue: Serialize,
+{
+    let mut mapping = serde_json::to_value(&SerializeFieldMapping::<TMapping, TPivot>::default())
+        .map_err(S::Error::custom)?;
+    let null_value = serde_json::to_value(null_value).map_err(S::Error::custom)?;
+
+    if let (&mut Value::Object(ref mut mapping), false) = (&mut mapping, null_value.is_null()) {
+        mapping.insert("null_value".to_owned(), null_value);
+    }
+
+    state.serialize_field(field, &mapping)
+}
+
+/**
+Fill a field in a document's `_source` with its `null_value` if it's missing or `null`.
+
+The `null_value` is serialised the same way as it is in the mapping, so a `null_value` of `null` is ignored.
+*/
+pub fn fill_null_value<TValue>(
+    source: &mut Map<String, Value>,
+    field: &'static str,
+    null_value: &TValue,
+) -> Result<(), serde_json::Error>
+where
+    TValue: Serialize,
+{
+    if source.get(field).map_or(false, |value| !value.is_null()) {
+        return Ok(());
+    }
+
+    let null_value = serde_json::to_value(null_value)?;
+
+    if !null_value.is_null() {
+        source.insert(field.to_owned(), null_value);
+    }
+
+    Ok(())
+}
+
 /**
 Serialize a field individually.
 
@@ -92,9 +297,33 @@ pub fn parse_from_tokens<'a>(date: &str, fmt: Vec<Item<'a>>) -> Result<DateValue
             // If the parsed result doesn't contain any time, set it to the default
             if parsed.hour_mod_12.is_none() {
                 let _ = parsed.set_hour(0);
+            }
+            if parsed.minute.is_none() {
                 let _ = parsed.set_minute(0);
             }
 
+            // If the parsed result doesn't contain a full date, set the missing parts to the start of the period
+            if parsed.isoyear.is_some() {
+                if parsed.isoweek.is_none() {
+                    let _ = parsed.set_isoweek(1);
+                }
+                if parsed.weekday.is_none() {
+                    let _ = parsed.set_weekday(Weekday::Mon);
+                }
+            } else if parsed.year.is_some() {
+                if parsed.ordinal.is_none() && parsed.month.is_none() {
+                    let _ = parsed.set_month(1);
+                }
+                if parsed.ordinal.is_none() && parsed.day.is_none() {
+                    let _ = parsed.set_day(1);
+                }
+            } else {
+                // Times without a date are on the epoch
+                let _ = parsed.set_year(1970);
+                let _ = parsed.set_month(1);
+                let _ = parsed.set_day(1);
+            }
+
             // Set the DateTime result
             let naive_date = parsed.to_naive_datetime_with_offset(0)?;
 
@@ -0,0 +1,131 @@
+/*! Mapping for the Elasticsearch `alias` type. */
+
+/**
+The base requirements for mapping an `alias` type.
+
+Custom mappings can be defined by implementing `AliasMapping`.
+Fields on derived documents can be mapped as an alias without a custom mapping using `#[elastic(alias_of = "path")]`.
+
+# Examples
+
+Define a custom `AliasMapping`:
+
+```
+# #[macro_use] use elastic::types::prelude::*;
+# fn main() {
+#[derive(Default)]
+struct HeadlineMapping;
+impl AliasMapping for HeadlineMapping {
+    fn path() -> &'static str {
+        "title"
+    }
+}
+# }
+```
+
+This will produce the following mapping:
+
+```
+# #[macro_use] extern crate serde_json;
+# use elastic::types::prelude::*;
+# #[derive(Default)]
+# struct HeadlineMapping;
+# impl AliasMapping for HeadlineMapping {
+#     fn path() -> &'static str {
+#         "title"
+#     }
+# }
+# fn main() {
+# let json = json!(
+{
+    "type": "alias",
+    "path": "title"
+}
+# );
+# let mapping = elastic::types::__derive::standalone_field_ser(HeadlineMapping).unwrap();
+# assert_eq!(json, mapping);
+# }
+```
+*/
+pub trait AliasMapping {
+    /**
+    The full path of the field the alias points to, like `meta.title`.
+
+    The target must be a concrete field in the same mapping, not an `object` or another alias.
+    */
+    fn path() -> &'static str;
+}
+
+mod private {
+    use super::AliasMapping;
+    use crate::types::private::field::{
+        FieldMapping,
+        SerializeFieldMapping,
+        StaticSerialize,
+    };
+    use serde::{
+        ser::SerializeStruct,
+        Serializer,
+    };
+
+    #[derive(Default)]
+    pub struct AliasPivot;
+
+    impl<TMapping> FieldMapping<AliasPivot> for TMapping
+    where
+        TMapping: AliasMapping,
+    {
+        type SerializeFieldMapping = SerializeFieldMapping<TMapping, AliasPivot>;
+
+        fn data_type() -> &'static str {
+            "alias"
+        }
+    }
+
+    impl<TMapping> StaticSerialize for SerializeFieldMapping<TMapping, AliasPivot>
+    where
+        TMapping: FieldMapping<AliasPivot> + AliasMapping,
+    {
+        fn static_serialize<S>(serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let mut state = serializer.serialize_struct("mapping", 2)?;
+
+            state.serialize_field("type", TMapping::data_type())?;
+            state.serialize_field("path", TMapping::path())?;
+
+            state.end()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+
+    use crate::types::{
+        prelude::*,
+        private::field,
+    };
+
+    #[derive(Default, Clone)]
+    pub struct MyAliasMapping;
+    impl AliasMapping for MyAliasMapping {
+        fn path() -> &'static str {
+            "meta.title"
+        }
+    }
+
+    #[test]
+    fn serialise_mapping() {
+        let ser = serde_json::to_value(&field::serialize(MyAliasMapping)).unwrap();
+
+        let expected = json!({
+            "type": "alias",
+            "path": "meta.title"
+        });
+
+        assert_eq!(expected, ser);
+    }
+}
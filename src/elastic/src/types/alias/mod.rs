@@ -0,0 +1,50 @@
+/*!
+Implementation of the Elasticsearch `alias` type.
+
+An `alias` field is an alternate name for another field in the same mapping.
+Queries and aggregations against the alias are run against the field it points to,
+so a field can be renamed without reindexing by keeping the old name as an alias.
+Values can't be indexed into an alias, so it's not mapped from a Rust type.
+
+# Examples
+
+Map a field as an alias in a derived document with `#[elastic(alias_of = "path")]`:
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# #[macro_use] extern crate serde_json;
+# use elastic::types::prelude::*;
+#[derive(Serialize, Deserialize, ElasticType)]
+pub struct MyType {
+    pub title: String,
+    #[serde(skip)]
+    #[elastic(alias_of = "title")]
+    pub headline: (),
+}
+# fn main() {
+# let mapping = serde_json::to_value(&MyType::index_mapping()).unwrap();
+# assert_eq!(json!({ "type": "alias", "path": "title" }), mapping["properties"]["headline"]);
+# }
+```
+
+The aliased field has a `#[serde(skip)]` attribute because it doesn't have a value in the document.
+
+For defining your own alias mapping, see [mapping details](mapping/trait.AliasMapping.html).
+
+# Links
+
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/alias.html)
+*/
+
+pub mod mapping;
+
+pub mod prelude {
+    /*!
+    Includes all types for the `alias` type.
+
+    This is a convenience module to make it easy to build mappings for multiple types without too many `use` statements.
+    */
+
+    pub use super::mapping::*;
+}
@@ -0,0 +1,154 @@
+use chrono::Utc;
+use std::{
+    cell::RefCell,
+    rc::Rc,
+};
+
+use super::format::DateValue;
+
+/**
+A source for the current date.
+
+[`Date::now`][Date.now] and [`DateValue::now`][DateValue.now] read the current date from the system clock,
+unless a different clock has been installed on the current thread with [`with_clock`][with_clock].
+Mappings that use the current date, like a `null_value` of `Date::now()`, can then be serialised deterministically in tests.
+
+[Date.now]: struct.Date.html#method.now
+[DateValue.now]: struct.DateValue.html#method.now
+[with_clock]: fn.with_clock.html
+*/
+pub trait Clock {
+    /** Get the current date. */
+    fn now(&self) -> DateValue;
+}
+
+/**
+A clock that reads the current date from the system.
+
+On `wasm32-unknown-unknown` the date is read from the JavaScript `Date` API.
+*/
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateValue {
+        DateValue::from(Utc::now())
+    }
+}
+
+/** A clock that's stopped at a fixed date. */
+#[derive(Debug, Clone, PartialEq)]
+pub struct FixedClock(DateValue);
+
+impl FixedClock {
+    /** Create a clock that always returns the given date. */
+    pub fn new(date: impl Into<DateValue>) -> Self {
+        FixedClock(date.into())
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateValue {
+        self.0.clone()
+    }
+}
+
+thread_local! {
+    static CLOCK: RefCell<Option<Rc<dyn Clock>>> = RefCell::new(None);
+}
+
+/**
+Run a function with a clock installed on the current thread.
+
+Calls to `Date::now` and `DateValue::now` on the current thread inside the function read from the given clock
+instead of the system clock.
+The previous clock is restored when the function returns, even if it panics.
+
+# Examples
+
+Assert the mapping for a field with a `null_value` of the current date:
+
+```
+# #[macro_use] extern crate serde_json;
+# use elastic::types::prelude::*;
+# use elastic::types::date::{with_clock, FixedClock};
+# fn main() {
+let clock = FixedClock::new(DateValue::build(2019, 3, 12, 0, 0, 0, 0));
+
+let date = with_clock(clock, || Date::<DefaultDateMapping<EpochMillis>>::now());
+
+assert_eq!(json!(1552348800000i64), serde_json::to_value(&date).unwrap());
+# }
+```
+*/
+pub fn with_clock<TClock, TFn, TResult>(clock: TClock, f: TFn) -> TResult
+where
+    TClock: Clock + 'static,
+    TFn: FnOnce() -> TResult,
+{
+    struct Restore(Option<Rc<dyn Clock>>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            let previous = self.0.take();
+            CLOCK.with(|clock| *clock.borrow_mut() = previous);
+        }
+    }
+
+    let previous = CLOCK.with(|current| current.replace(Some(Rc::new(clock))));
+    let _restore = Restore(previous);
+
+    f()
+}
+
+/** Get the current date from the clock installed on this thread, or the system clock. */
+pub(crate) fn now() -> DateValue {
+    CLOCK
+        .with(|clock| clock.borrow().as_ref().map(|clock| clock.now()))
+        .unwrap_or_else(|| SystemClock.now())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::prelude::*;
+
+    #[test]
+    fn fixed_clock() {
+        let fixed = DateValue::build(2019, 3, 12, 8, 30, 0, 0);
+
+        let (date, value) = with_clock(FixedClock::new(fixed.clone()), || {
+            (Date::<DefaultDateMapping>::now(), DateValue::now())
+        });
+
+        assert_eq!(fixed, DateValue::from(date));
+        assert_eq!(fixed, value);
+    }
+
+    #[test]
+    fn nested_clocks_are_restored() {
+        let outer = DateValue::build(2019, 1, 1, 0, 0, 0, 0);
+        let inner = DateValue::build(2020, 1, 1, 0, 0, 0, 0);
+
+        with_clock(FixedClock::new(outer.clone()), || {
+            let nested = with_clock(FixedClock::new(inner.clone()), DateValue::now);
+
+            assert_eq!(inner, nested);
+            assert_eq!(outer, DateValue::now());
+        });
+
+        assert_ne!(outer, DateValue::now());
+    }
+
+    #[test]
+    fn clock_is_restored_after_panic() {
+        let fixed = DateValue::build(2019, 1, 1, 0, 0, 0, 0);
+
+        let result = ::std::panic::catch_unwind(|| {
+            with_clock(FixedClock::new(fixed.clone()), || panic!("explicit panic"))
+        });
+
+        assert!(result.is_err());
+        assert_ne!(fixed, DateValue::now());
+    }
+}
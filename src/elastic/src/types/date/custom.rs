@@ -0,0 +1,334 @@
+use chrono::format::{
+    Fixed,
+    Item,
+    Numeric,
+    Pad,
+};
+use std::fmt::{
+    Display,
+    Formatter,
+    Result as FmtResult,
+};
+
+use super::format::{
+    DateValue,
+    FormattedDate,
+    ParseError,
+};
+use crate::types::__derive::{
+    format_with_tokens,
+    parse_from_tokens,
+};
+
+/**
+A date format that's parsed from an Elasticsearch format string at runtime.
+
+Formats that are known at compile time can be declared as a [`DateFormat`][DateFormat] with the [`date_fmt!`][date_fmt] macro,
+so they can be used as the format of a `Date`.
+A `CustomDateFormat` is useful when the format isn't known until runtime, like a format from configuration or
+from a live mapping.
+The format string is parsed once, when the `CustomDateFormat` is created, and supports the same subset of
+[Joda time](http://www.joda.org/joda-time/apidocs/org/joda/time/format/DateTimeFormat.html) as the derive:
+`yyyy`, `MM`, `dd`, `DDD`, `xxxx`, `ww`, `e`, `HH`, `mm`, `ss`, `.SSS`, `Z`, the delimiters `-:./ ` and literals quoted with `'`.
+
+# Examples
+
+```
+# use elastic::types::prelude::*;
+# fn main() { run().unwrap() }
+# fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+let format = CustomDateFormat::new("yyyy-MM-dd'T'HH:mm:ss")?;
+
+let date = format.parse("2019-03-12T08:30:00")?;
+
+assert_eq!("2019-03-12T08:30:00", format.format(&date).to_string());
+# Ok(())
+# }
+```
+
+The name of the format can be used when mapping a `date` field at runtime:
+
+```
+# use elastic::types::prelude::*;
+# use elastic::types::mappers::FieldBuilder;
+# fn main() { run().unwrap() }
+# fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+let format = CustomDateFormat::new("yyyy/MM/dd")?;
+
+let field = FieldBuilder::date().format(format.name());
+# Ok(())
+# }
+```
+
+[DateFormat]: trait.DateFormat.html
+[date_fmt]: ../../macro.date_fmt.html
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct CustomDateFormat {
+    name: String,
+    tokens: Vec<Token>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Numeric(Numeric),
+    Millisecond,
+    Literal(String),
+}
+
+impl CustomDateFormat {
+    /**
+    Parse an Elasticsearch format string.
+
+    An error is returned if the format contains a pattern that isn't supported.
+    */
+    pub fn new(format: impl Into<String>) -> Result<Self, ParseError> {
+        let name = format.into();
+        let tokens = to_tokens(&name)?;
+
+        Ok(CustomDateFormat { name, tokens })
+    }
+
+    /** The format string, which is also the name of the format in a mapping. */
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /** Parse a date string using this format. */
+    pub fn parse(&self, date: &str) -> Result<DateValue, ParseError> {
+        parse_from_tokens(date, self.items())
+    }
+
+    /** Format a date using this format. */
+    pub fn format<'a>(&'a self, date: &'a DateValue) -> FormattedDate<'a> {
+        format_with_tokens(date, self.items())
+    }
+
+    fn items(&self) -> Vec<Item> {
+        self.tokens
+            .iter()
+            .map(|token| match *token {
+                Token::Numeric(ref numeric) => Item::Numeric(numeric.clone(), Pad::Zero),
+                Token::Millisecond => Item::Fixed(Fixed::Nanosecond3),
+                Token::Literal(ref literal) => Item::Literal(literal),
+            })
+            .collect()
+    }
+}
+
+/**
+Declare a [`DateFormat`][DateFormat] from an Elasticsearch format string.
+
+The format is a unit struct that parses and formats dates with a [`CustomDateFormat`][CustomDateFormat],
+so it supports the same patterns.
+The format string is parsed the first time the format is used, which panics if the format string isn't supported.
+
+# Examples
+
+Declare a format and use it for a `Date`:
+
+```
+# #[macro_use] extern crate elastic;
+# use elastic::types::prelude::*;
+date_fmt!(pub MyFormat, "yyyy/MM/dd HH:mm");
+
+type MyDate = Date<DefaultDateMapping<MyFormat>>;
+
+# fn main() { run().unwrap() }
+# fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+let date = MyDate::build(2019, 3, 12, 8, 30, 0, 0);
+
+assert_eq!("yyyy/MM/dd HH:mm", MyFormat::name());
+assert_eq!(r#""2019/03/12 08:30""#, serde_json::to_string(&date)?);
+# Ok(())
+# }
+```
+
+[DateFormat]: types/date/trait.DateFormat.html
+[CustomDateFormat]: types/date/struct.CustomDateFormat.html
+*/
+#[macro_export]
+macro_rules! date_fmt {
+    ($(#[$attr:meta])* $vis:vis $name:ident, $format:literal) => {
+        $(#[$attr])*
+        #[derive(PartialEq, Debug, Default, Clone, Copy)]
+        $vis struct $name;
+
+        impl $crate::types::date::DateFormat for $name {
+            fn name() -> &'static str {
+                $format
+            }
+
+            fn parse(
+                date: &str,
+            ) -> Result<$crate::types::date::DateValue, $crate::types::date::ParseError> {
+                $crate::types::__derive::cached_custom_date_format::<Self>($format).parse(date)
+            }
+
+            fn format<'a>(
+                date: &'a $crate::types::date::DateValue,
+            ) -> $crate::types::date::FormattedDate<'a> {
+                $crate::types::__derive::cached_custom_date_format::<Self>($format).format(date)
+            }
+        }
+    };
+}
+
+impl Display for CustomDateFormat {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        self.name.fmt(f)
+    }
+}
+
+fn to_tokens(format: &str) -> Result<Vec<Token>, ParseError> {
+    let patterns = [
+        ("yyyy", Token::Numeric(Numeric::Year)),
+        ("MM", Token::Numeric(Numeric::Month)),
+        ("dd", Token::Numeric(Numeric::Day)),
+        ("DDD", Token::Numeric(Numeric::Ordinal)),
+        ("xxxx", Token::Numeric(Numeric::IsoYear)),
+        ("ww", Token::Numeric(Numeric::IsoWeek)),
+        ("e", Token::Numeric(Numeric::WeekdayFromMon)),
+        ("HH", Token::Numeric(Numeric::Hour)),
+        ("mm", Token::Numeric(Numeric::Minute)),
+        ("ss", Token::Numeric(Numeric::Second)),
+        (".SSS", Token::Millisecond),
+        ("Z", Token::Literal("Z".to_owned())),
+    ];
+
+    let mut tokens = Vec::new();
+    let mut rest = format;
+    'tokens: while let Some(c) = rest.chars().next() {
+        for &(pattern, ref token) in &patterns {
+            if rest.starts_with(pattern) {
+                tokens.push(token.clone());
+                rest = &rest[pattern.len()..];
+                continue 'tokens;
+            }
+        }
+
+        match c {
+            '\'' => {
+                let end = rest[1..]
+                    .find('\'')
+                    .filter(|&end| end > 0)
+                    .ok_or_else(|| format!("unclosed literal in date format `{}`", format))?;
+
+                tokens.push(Token::Literal(rest[1..1 + end].to_owned()));
+                rest = &rest[2 + end..];
+            }
+            '-' | ':' | '.' | '/' | ' ' => {
+                tokens.push(Token::Literal(c.to_string()));
+                rest = &rest[1..];
+            }
+            _ => {
+                return Err(
+                    format!("unsupported pattern `{}` in date format `{}`", rest, format).into(),
+                )
+            }
+        }
+    }
+
+    if tokens.is_empty() {
+        return Err(ParseError::from("empty date format".to_owned()));
+    }
+
+    Ok(tokens)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+
+    use super::*;
+    use crate::types::prelude::*;
+
+    date_fmt!(SlashFormat, "yyyy/MM/dd HH:mm:ss");
+
+    type SlashDate = Date<DefaultDateMapping<SlashFormat>>;
+
+    #[test]
+    fn parse_and_format_custom_format() {
+        let format = CustomDateFormat::new("yyyy-MM-dd'T'HH:mm:ss.SSSZ").unwrap();
+
+        let date = format.parse("2015-05-13T10:05:33.456Z").unwrap();
+
+        assert_eq!(
+            (2015, 5, 13, 10, 5, 33, 456),
+            (
+                date.year(),
+                date.month(),
+                date.day(),
+                date.hour(),
+                date.minute(),
+                date.second(),
+                date.nanosecond() / 1_000_000
+            )
+        );
+        assert_eq!("2015-05-13T10:05:33.456Z", format.format(&date).to_string());
+    }
+
+    #[test]
+    fn custom_format_matches_derived_format() {
+        let format = CustomDateFormat::new("yyyyMMdd'T'HHmmss.SSSZ").unwrap();
+
+        let date = DateValue::build(2019, 3, 12, 8, 30, 0, 15);
+
+        assert_eq!(BasicDateTime::name(), format.name());
+        assert_eq!(
+            BasicDateTime::format(&date).to_string(),
+            format.format(&date).to_string()
+        );
+        assert_eq!(
+            BasicDateTime::parse("20190312T083000.015Z").unwrap(),
+            format.parse("20190312T083000.015Z").unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_date_only() {
+        let format = CustomDateFormat::new("yyyy/MM/dd").unwrap();
+
+        let date = format.parse("2019/03/12").unwrap();
+
+        assert_eq!(
+            (2019, 3, 12, 0),
+            (date.year(), date.month(), date.day(), date.hour())
+        );
+    }
+
+    #[test]
+    fn date_fmt_round_trips_date() {
+        let date: SlashDate = serde_json::from_str(r#""2019/03/12 08:30:15""#).unwrap();
+
+        assert_eq!(
+            (2019, 3, 12, 8, 30, 15),
+            (
+                date.year(),
+                date.month(),
+                date.day(),
+                date.hour(),
+                date.minute(),
+                date.second()
+            )
+        );
+        assert_eq!(
+            r#""2019/03/12 08:30:15""#,
+            serde_json::to_string(&date).unwrap()
+        );
+    }
+
+    #[test]
+    fn date_fmt_name() {
+        assert_eq!("yyyy/MM/dd HH:mm:ss", SlashFormat::name());
+    }
+
+    #[test]
+    fn parse_invalid_formats() {
+        let cases = vec!["", "yyyy-MM-dd'T", "yyyy-MM-ddTHH", "yy-MM-dd", "yyyy''"];
+
+        for format in cases {
+            assert!(CustomDateFormat::new(format).is_err(), "{}", format);
+        }
+    }
+}
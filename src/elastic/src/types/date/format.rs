@@ -1,4 +1,14 @@
-use super::ChronoDateTime;
+use super::{
+    clock::{
+        self,
+        Clock,
+    },
+    vectors::{
+        built_in_test_vectors,
+        DateFormatTestVector,
+    },
+    ChronoDateTime,
+};
 use chrono::{
     self,
     format::{
@@ -34,9 +44,20 @@ You probably won't need to use it directly except to clobber the format on a `Da
 pub struct DateValue(ChronoDateTime);
 
 impl DateValue {
-    /** Equivalent to `DateTime<Utc>::now()` */
+    /**
+    Equivalent to `DateTime<Utc>::now()`.
+
+    The date is read from the [`Clock`][Clock] installed on the current thread, which is the system clock by default.
+
+    [Clock]: trait.Clock.html
+    */
     pub fn now() -> Self {
-        DateValue(Utc::now())
+        clock::now()
+    }
+
+    /** Get the current date from a specific clock. */
+    pub fn now_with(clock: &impl Clock) -> Self {
+        clock.now()
     }
 
     /** Construct a `DateValue` from individual parts. */
@@ -214,6 +235,18 @@ pub trait DateFormat {
     This is the string used when defining the format in the field mapping.
     */
     fn name() -> &'static str;
+
+    /**
+    Reference dates formatted by Elasticsearch for checking the format with [`check_test_vectors`][check_test_vectors].
+
+    Built-in Elasticsearch formats return vectors for their name.
+    Other formats don't have any unless they override this method.
+
+    [check_test_vectors]: fn.check_test_vectors.html
+    */
+    fn test_vectors() -> &'static [DateFormatTestVector] {
+        built_in_test_vectors(Self::name())
+    }
 }
 
 /**
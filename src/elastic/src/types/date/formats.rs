@@ -97,6 +97,1214 @@ impl DateFormat for EpochMillis {
     }
 }
 
+/**
+Format for `epoch_second`.
+
+Takes a string of seconds since the epoch and converts to a `DateTime`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(PartialEq, Debug, Default, Clone, Copy)]
+pub struct EpochSecond;
+
+impl DateFormat for EpochSecond {
+    fn name() -> &'static str {
+        "epoch_second"
+    }
+
+    fn parse(date: &str) -> Result<DateValue, ParseError> {
+        let secs = date
+            .parse::<i64>()
+            .map_err(|e| e.description().to_string())?;
+
+        let date = DateTime::from_utc(NaiveDateTime::from_timestamp(secs, 0), Utc);
+
+        Ok(date.into())
+    }
+
+    fn format<'a>(date: &'a DateValue) -> FormattedDate<'a> {
+        date.timestamp().into()
+    }
+}
+
+/**
+Format for `date_optional_time`.
+
+Parses a date with an optional time, like `2015-07-03`, `2015-07-03T14:55` or `2015-07-03T14:55:02.478Z`.
+Dates are always formatted with a time, the same as [`StrictDateTime`](struct.StrictDateTime.html).
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(PartialEq, Debug, Default, Clone, Copy)]
+pub struct DateOptionalTime;
+
+impl DateFormat for DateOptionalTime {
+    fn name() -> &'static str {
+        "date_optional_time"
+    }
+
+    fn parse(date: &str) -> Result<DateValue, ParseError> {
+        parse_optional_time(date)
+    }
+
+    fn format<'a>(date: &'a DateValue) -> FormattedDate<'a> {
+        StrictDateTime::format(date)
+    }
+}
+
+/**
+Format for `strict_date_optional_time`.
+
+Parses a date with an optional time, like `2015-07-03`, `2015-07-03T14:55` or `2015-07-03T14:55:02.478Z`.
+Dates are always formatted with a time, the same as [`StrictDateTime`](struct.StrictDateTime.html).
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(PartialEq, Debug, Default, Clone, Copy)]
+pub struct StrictDateOptionalTime;
+
+impl DateFormat for StrictDateOptionalTime {
+    fn name() -> &'static str {
+        "strict_date_optional_time"
+    }
+
+    fn parse(date: &str) -> Result<DateValue, ParseError> {
+        parse_optional_time(date)
+    }
+
+    fn format<'a>(date: &'a DateValue) -> FormattedDate<'a> {
+        StrictDateTime::format(date)
+    }
+}
+
+// Parse a date with an optional time by trying each precision from most to least specific
+fn parse_optional_time(date: &str) -> Result<DateValue, ParseError> {
+    let parsers: &[fn(&str) -> Result<DateValue, ParseError>] = &[
+        StrictDateTime::parse,
+        StrictDateTimeNoMillis::parse,
+        StrictDateHourMinuteSecondMillis::parse,
+        StrictDateHourMinuteSecond::parse,
+        DateHourMinute::parse,
+        StrictDate::parse,
+    ];
+
+    parsers
+        .iter()
+        .filter_map(|parse| parse(date).ok())
+        .next()
+        .ok_or_else(|| format!("`{}` isn't a date with an optional time", date).into())
+}
+
+/** Get the parser for a built-in Elasticsearch format by its name, like `epoch_millis`. */
+pub(crate) fn built_in_parser(name: &str) -> Option<fn(&str) -> Result<DateValue, ParseError>> {
+    let parsers: &[(&str, fn(&str) -> Result<DateValue, ParseError>)] = &[
+        (BasicDate::name(), BasicDate::parse),
+        (BasicDateTime::name(), BasicDateTime::parse),
+        (BasicDateTimeNoMillis::name(), BasicDateTimeNoMillis::parse),
+        (BasicOrdinalDate::name(), BasicOrdinalDate::parse),
+        (BasicOrdinalDateTime::name(), BasicOrdinalDateTime::parse),
+        (
+            BasicOrdinalDateTimeNoMillis::name(),
+            BasicOrdinalDateTimeNoMillis::parse,
+        ),
+        (BasicTTime::name(), BasicTTime::parse),
+        (BasicTTimeNoMillis::name(), BasicTTimeNoMillis::parse),
+        (BasicTime::name(), BasicTime::parse),
+        (BasicTimeNoMillis::name(), BasicTimeNoMillis::parse),
+        (BasicWeekDate::name(), BasicWeekDate::parse),
+        (BasicWeekDateTime::name(), BasicWeekDateTime::parse),
+        (
+            BasicWeekDateTimeNoMillis::name(),
+            BasicWeekDateTimeNoMillis::parse,
+        ),
+        (DateHour::name(), DateHour::parse),
+        (DateHourMinute::name(), DateHourMinute::parse),
+        (DateHourMinuteSecond::name(), DateHourMinuteSecond::parse),
+        (
+            DateHourMinuteSecondFraction::name(),
+            DateHourMinuteSecondFraction::parse,
+        ),
+        (
+            DateHourMinuteSecondMillis::name(),
+            DateHourMinuteSecondMillis::parse,
+        ),
+        (DateOptionalTime::name(), DateOptionalTime::parse),
+        (DateTimeNoMillis::name(), DateTimeNoMillis::parse),
+        (EpochMillis::name(), EpochMillis::parse),
+        (EpochSecond::name(), EpochSecond::parse),
+        (FullDate::name(), FullDate::parse),
+        (FullDateTime::name(), FullDateTime::parse),
+        (Hour::name(), Hour::parse),
+        (HourMinute::name(), HourMinute::parse),
+        (HourMinuteSecond::name(), HourMinuteSecond::parse),
+        (
+            HourMinuteSecondFraction::name(),
+            HourMinuteSecondFraction::parse,
+        ),
+        (
+            HourMinuteSecondMillis::name(),
+            HourMinuteSecondMillis::parse,
+        ),
+        (OrdinalDate::name(), OrdinalDate::parse),
+        (OrdinalDateTime::name(), OrdinalDateTime::parse),
+        (
+            OrdinalDateTimeNoMillis::name(),
+            OrdinalDateTimeNoMillis::parse,
+        ),
+        (StrictBasicWeekDate::name(), StrictBasicWeekDate::parse),
+        (
+            StrictBasicWeekDateTime::name(),
+            StrictBasicWeekDateTime::parse,
+        ),
+        (
+            StrictBasicWeekDateTimeNoMillis::name(),
+            StrictBasicWeekDateTimeNoMillis::parse,
+        ),
+        (StrictDate::name(), StrictDate::parse),
+        (StrictDateHour::name(), StrictDateHour::parse),
+        (StrictDateHourMinute::name(), StrictDateHourMinute::parse),
+        (
+            StrictDateHourMinuteSecond::name(),
+            StrictDateHourMinuteSecond::parse,
+        ),
+        (
+            StrictDateHourMinuteSecondFraction::name(),
+            StrictDateHourMinuteSecondFraction::parse,
+        ),
+        (
+            StrictDateHourMinuteSecondMillis::name(),
+            StrictDateHourMinuteSecondMillis::parse,
+        ),
+        (
+            StrictDateOptionalTime::name(),
+            StrictDateOptionalTime::parse,
+        ),
+        (StrictDateTime::name(), StrictDateTime::parse),
+        (
+            StrictDateTimeNoMillis::name(),
+            StrictDateTimeNoMillis::parse,
+        ),
+        (StrictHour::name(), StrictHour::parse),
+        (StrictHourMinute::name(), StrictHourMinute::parse),
+        (
+            StrictHourMinuteSecond::name(),
+            StrictHourMinuteSecond::parse,
+        ),
+        (
+            StrictHourMinuteSecondFraction::name(),
+            StrictHourMinuteSecondFraction::parse,
+        ),
+        (
+            StrictHourMinuteSecondMillis::name(),
+            StrictHourMinuteSecondMillis::parse,
+        ),
+        (StrictOrdinalDate::name(), StrictOrdinalDate::parse),
+        (StrictOrdinalDateTime::name(), StrictOrdinalDateTime::parse),
+        (
+            StrictOrdinalDateTimeNoMillis::name(),
+            StrictOrdinalDateTimeNoMillis::parse,
+        ),
+        (StrictTTime::name(), StrictTTime::parse),
+        (StrictTTimeNoMillis::name(), StrictTTimeNoMillis::parse),
+        (StrictTime::name(), StrictTime::parse),
+        (StrictTimeNoMillis::name(), StrictTimeNoMillis::parse),
+        (StrictWeekDate::name(), StrictWeekDate::parse),
+        (StrictWeekDateTime::name(), StrictWeekDateTime::parse),
+        (
+            StrictWeekDateTimeNoMillis::name(),
+            StrictWeekDateTimeNoMillis::parse,
+        ),
+        (StrictWeekyear::name(), StrictWeekyear::parse),
+        (StrictWeekyearWeek::name(), StrictWeekyearWeek::parse),
+        (StrictWeekyearWeekDay::name(), StrictWeekyearWeekDay::parse),
+        (StrictYear::name(), StrictYear::parse),
+        (StrictYearMonth::name(), StrictYearMonth::parse),
+        (StrictYearMonthDay::name(), StrictYearMonthDay::parse),
+        (TTime::name(), TTime::parse),
+        (TTimeNoMillis::name(), TTimeNoMillis::parse),
+        (Time::name(), Time::parse),
+        (TimeNoMillis::name(), TimeNoMillis::parse),
+        (WeekDate::name(), WeekDate::parse),
+        (WeekDateTime::name(), WeekDateTime::parse),
+        (WeekDateTimeNoMillis::name(), WeekDateTimeNoMillis::parse),
+        (Weekyear::name(), Weekyear::parse),
+        (WeekyearWeek::name(), WeekyearWeek::parse),
+        (WeekyearWeekDay::name(), WeekyearWeekDay::parse),
+        (Year::name(), Year::parse),
+        (YearMonth::name(), YearMonth::parse),
+        (YearMonthDay::name(), YearMonthDay::parse),
+    ];
+
+    parsers
+        .iter()
+        .find(|&&(parser_name, _)| parser_name == name)
+        .map(|&(_, parse)| parse)
+}
+
+/**
+Format for `basic_date`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(date_format = "yyyyMMdd", date_format_name = "basic_date")]
+pub struct BasicDate;
+
+/**
+Format for `basic_ordinal_date`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(date_format = "yyyyDDD", date_format_name = "basic_ordinal_date")]
+pub struct BasicOrdinalDate;
+
+/**
+Format for `basic_ordinal_date_time`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(
+    date_format = "yyyyDDD'T'HHmmss.SSSZ",
+    date_format_name = "basic_ordinal_date_time"
+)]
+pub struct BasicOrdinalDateTime;
+
+/**
+Format for `basic_ordinal_date_time_no_millis`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(
+    date_format = "yyyyDDD'T'HHmmssZ",
+    date_format_name = "basic_ordinal_date_time_no_millis"
+)]
+pub struct BasicOrdinalDateTimeNoMillis;
+
+/**
+Format for `basic_t_time`.
+
+Times are parsed on the epoch date, `1970-01-01`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(date_format = "'T'HHmmss.SSSZ", date_format_name = "basic_t_time")]
+pub struct BasicTTime;
+
+/**
+Format for `basic_t_time_no_millis`.
+
+Times are parsed on the epoch date, `1970-01-01`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(
+    date_format = "'T'HHmmssZ",
+    date_format_name = "basic_t_time_no_millis"
+)]
+pub struct BasicTTimeNoMillis;
+
+/**
+Format for `basic_time`.
+
+Times are parsed on the epoch date, `1970-01-01`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(date_format = "HHmmss.SSSZ", date_format_name = "basic_time")]
+pub struct BasicTime;
+
+/**
+Format for `basic_time_no_millis`.
+
+Times are parsed on the epoch date, `1970-01-01`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(date_format = "HHmmssZ", date_format_name = "basic_time_no_millis")]
+pub struct BasicTimeNoMillis;
+
+/**
+Format for `basic_week_date`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(date_format = "xxxx'W'wwe", date_format_name = "basic_week_date")]
+pub struct BasicWeekDate;
+
+/**
+Format for `basic_week_date_time`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(
+    date_format = "xxxx'W'wwe'T'HHmmss.SSSZ",
+    date_format_name = "basic_week_date_time"
+)]
+pub struct BasicWeekDateTime;
+
+/**
+Format for `basic_week_date_time_no_millis`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(
+    date_format = "xxxx'W'wwe'T'HHmmssZ",
+    date_format_name = "basic_week_date_time_no_millis"
+)]
+pub struct BasicWeekDateTimeNoMillis;
+
+/**
+Format for `date`.
+
+This is named `FullDate` so it doesn't clash with the [`Date`](struct.Date.html) type.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(date_format = "yyyy-MM-dd", date_format_name = "date")]
+pub struct FullDate;
+
+/**
+Format for `date_hour`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(date_format = "yyyy-MM-dd'T'HH", date_format_name = "date_hour")]
+pub struct DateHour;
+
+/**
+Format for `date_hour_minute`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(
+    date_format = "yyyy-MM-dd'T'HH:mm",
+    date_format_name = "date_hour_minute"
+)]
+pub struct DateHourMinute;
+
+/**
+Format for `date_hour_minute_second`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(
+    date_format = "yyyy-MM-dd'T'HH:mm:ss",
+    date_format_name = "date_hour_minute_second"
+)]
+pub struct DateHourMinuteSecond;
+
+/**
+Format for `date_hour_minute_second_fraction`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(
+    date_format = "yyyy-MM-dd'T'HH:mm:ss.SSS",
+    date_format_name = "date_hour_minute_second_fraction"
+)]
+pub struct DateHourMinuteSecondFraction;
+
+/**
+Format for `date_hour_minute_second_millis`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(
+    date_format = "yyyy-MM-dd'T'HH:mm:ss.SSS",
+    date_format_name = "date_hour_minute_second_millis"
+)]
+pub struct DateHourMinuteSecondMillis;
+
+/**
+Format for `date_time`.
+
+This is named `FullDateTime` so it doesn't clash with `chrono`s `DateTime` type.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(
+    date_format = "yyyy-MM-dd'T'HH:mm:ss.SSSZ",
+    date_format_name = "date_time"
+)]
+pub struct FullDateTime;
+
+/**
+Format for `date_time_no_millis`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(
+    date_format = "yyyy-MM-dd'T'HH:mm:ssZ",
+    date_format_name = "date_time_no_millis"
+)]
+pub struct DateTimeNoMillis;
+
+/**
+Format for `hour`.
+
+Times are parsed on the epoch date, `1970-01-01`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(date_format = "HH", date_format_name = "hour")]
+pub struct Hour;
+
+/**
+Format for `hour_minute`.
+
+Times are parsed on the epoch date, `1970-01-01`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(date_format = "HH:mm", date_format_name = "hour_minute")]
+pub struct HourMinute;
+
+/**
+Format for `hour_minute_second`.
+
+Times are parsed on the epoch date, `1970-01-01`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(date_format = "HH:mm:ss", date_format_name = "hour_minute_second")]
+pub struct HourMinuteSecond;
+
+/**
+Format for `hour_minute_second_fraction`.
+
+Times are parsed on the epoch date, `1970-01-01`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(
+    date_format = "HH:mm:ss.SSS",
+    date_format_name = "hour_minute_second_fraction"
+)]
+pub struct HourMinuteSecondFraction;
+
+/**
+Format for `hour_minute_second_millis`.
+
+Times are parsed on the epoch date, `1970-01-01`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(
+    date_format = "HH:mm:ss.SSS",
+    date_format_name = "hour_minute_second_millis"
+)]
+pub struct HourMinuteSecondMillis;
+
+/**
+Format for `ordinal_date`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(date_format = "yyyy-DDD", date_format_name = "ordinal_date")]
+pub struct OrdinalDate;
+
+/**
+Format for `ordinal_date_time`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(
+    date_format = "yyyy-DDD'T'HH:mm:ss.SSSZ",
+    date_format_name = "ordinal_date_time"
+)]
+pub struct OrdinalDateTime;
+
+/**
+Format for `ordinal_date_time_no_millis`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(
+    date_format = "yyyy-DDD'T'HH:mm:ssZ",
+    date_format_name = "ordinal_date_time_no_millis"
+)]
+pub struct OrdinalDateTimeNoMillis;
+
+/**
+Format for `strict_basic_week_date`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(
+    date_format = "xxxx'W'wwe",
+    date_format_name = "strict_basic_week_date"
+)]
+pub struct StrictBasicWeekDate;
+
+/**
+Format for `strict_basic_week_date_time`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(
+    date_format = "xxxx'W'wwe'T'HHmmss.SSSZ",
+    date_format_name = "strict_basic_week_date_time"
+)]
+pub struct StrictBasicWeekDateTime;
+
+/**
+Format for `strict_basic_week_date_time_no_millis`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(
+    date_format = "xxxx'W'wwe'T'HHmmssZ",
+    date_format_name = "strict_basic_week_date_time_no_millis"
+)]
+pub struct StrictBasicWeekDateTimeNoMillis;
+
+/**
+Format for `strict_date`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(date_format = "yyyy-MM-dd", date_format_name = "strict_date")]
+pub struct StrictDate;
+
+/**
+Format for `strict_date_hour`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(date_format = "yyyy-MM-dd'T'HH", date_format_name = "strict_date_hour")]
+pub struct StrictDateHour;
+
+/**
+Format for `strict_date_hour_minute`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(
+    date_format = "yyyy-MM-dd'T'HH:mm",
+    date_format_name = "strict_date_hour_minute"
+)]
+pub struct StrictDateHourMinute;
+
+/**
+Format for `strict_date_hour_minute_second`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(
+    date_format = "yyyy-MM-dd'T'HH:mm:ss",
+    date_format_name = "strict_date_hour_minute_second"
+)]
+pub struct StrictDateHourMinuteSecond;
+
+/**
+Format for `strict_date_hour_minute_second_fraction`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(
+    date_format = "yyyy-MM-dd'T'HH:mm:ss.SSS",
+    date_format_name = "strict_date_hour_minute_second_fraction"
+)]
+pub struct StrictDateHourMinuteSecondFraction;
+
+/**
+Format for `strict_date_hour_minute_second_millis`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(
+    date_format = "yyyy-MM-dd'T'HH:mm:ss.SSS",
+    date_format_name = "strict_date_hour_minute_second_millis"
+)]
+pub struct StrictDateHourMinuteSecondMillis;
+
+/**
+Format for `strict_date_time`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(
+    date_format = "yyyy-MM-dd'T'HH:mm:ss.SSSZ",
+    date_format_name = "strict_date_time"
+)]
+pub struct StrictDateTime;
+
+/**
+Format for `strict_date_time_no_millis`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(
+    date_format = "yyyy-MM-dd'T'HH:mm:ssZ",
+    date_format_name = "strict_date_time_no_millis"
+)]
+pub struct StrictDateTimeNoMillis;
+
+/**
+Format for `strict_hour`.
+
+Times are parsed on the epoch date, `1970-01-01`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(date_format = "HH", date_format_name = "strict_hour")]
+pub struct StrictHour;
+
+/**
+Format for `strict_hour_minute`.
+
+Times are parsed on the epoch date, `1970-01-01`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(date_format = "HH:mm", date_format_name = "strict_hour_minute")]
+pub struct StrictHourMinute;
+
+/**
+Format for `strict_hour_minute_second`.
+
+Times are parsed on the epoch date, `1970-01-01`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(
+    date_format = "HH:mm:ss",
+    date_format_name = "strict_hour_minute_second"
+)]
+pub struct StrictHourMinuteSecond;
+
+/**
+Format for `strict_hour_minute_second_fraction`.
+
+Times are parsed on the epoch date, `1970-01-01`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(
+    date_format = "HH:mm:ss.SSS",
+    date_format_name = "strict_hour_minute_second_fraction"
+)]
+pub struct StrictHourMinuteSecondFraction;
+
+/**
+Format for `strict_hour_minute_second_millis`.
+
+Times are parsed on the epoch date, `1970-01-01`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(
+    date_format = "HH:mm:ss.SSS",
+    date_format_name = "strict_hour_minute_second_millis"
+)]
+pub struct StrictHourMinuteSecondMillis;
+
+/**
+Format for `strict_ordinal_date`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(date_format = "yyyy-DDD", date_format_name = "strict_ordinal_date")]
+pub struct StrictOrdinalDate;
+
+/**
+Format for `strict_ordinal_date_time`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(
+    date_format = "yyyy-DDD'T'HH:mm:ss.SSSZ",
+    date_format_name = "strict_ordinal_date_time"
+)]
+pub struct StrictOrdinalDateTime;
+
+/**
+Format for `strict_ordinal_date_time_no_millis`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(
+    date_format = "yyyy-DDD'T'HH:mm:ssZ",
+    date_format_name = "strict_ordinal_date_time_no_millis"
+)]
+pub struct StrictOrdinalDateTimeNoMillis;
+
+/**
+Format for `strict_t_time`.
+
+Times are parsed on the epoch date, `1970-01-01`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(date_format = "'T'HH:mm:ss.SSSZ", date_format_name = "strict_t_time")]
+pub struct StrictTTime;
+
+/**
+Format for `strict_t_time_no_millis`.
+
+Times are parsed on the epoch date, `1970-01-01`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(
+    date_format = "'T'HH:mm:ssZ",
+    date_format_name = "strict_t_time_no_millis"
+)]
+pub struct StrictTTimeNoMillis;
+
+/**
+Format for `strict_time`.
+
+Times are parsed on the epoch date, `1970-01-01`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(date_format = "HH:mm:ss.SSSZ", date_format_name = "strict_time")]
+pub struct StrictTime;
+
+/**
+Format for `strict_time_no_millis`.
+
+Times are parsed on the epoch date, `1970-01-01`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(date_format = "HH:mm:ssZ", date_format_name = "strict_time_no_millis")]
+pub struct StrictTimeNoMillis;
+
+/**
+Format for `strict_week_date`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(date_format = "xxxx-'W'ww-e", date_format_name = "strict_week_date")]
+pub struct StrictWeekDate;
+
+/**
+Format for `strict_week_date_time`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(
+    date_format = "xxxx-'W'ww-e'T'HH:mm:ss.SSSZ",
+    date_format_name = "strict_week_date_time"
+)]
+pub struct StrictWeekDateTime;
+
+/**
+Format for `strict_week_date_time_no_millis`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(
+    date_format = "xxxx-'W'ww-e'T'HH:mm:ssZ",
+    date_format_name = "strict_week_date_time_no_millis"
+)]
+pub struct StrictWeekDateTimeNoMillis;
+
+/**
+Format for `strict_weekyear`.
+
+Dates are parsed as the Monday at the start of the first week of the week-based year.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(date_format = "xxxx", date_format_name = "strict_weekyear")]
+pub struct StrictWeekyear;
+
+/**
+Format for `strict_weekyear_week`.
+
+Dates are parsed as the Monday at the start of the week.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(date_format = "xxxx-'W'ww", date_format_name = "strict_weekyear_week")]
+pub struct StrictWeekyearWeek;
+
+/**
+Format for `strict_weekyear_week_day`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(
+    date_format = "xxxx-'W'ww-e",
+    date_format_name = "strict_weekyear_week_day"
+)]
+pub struct StrictWeekyearWeekDay;
+
+/**
+Format for `strict_year`.
+
+Dates are parsed as the first day of the year.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(date_format = "yyyy", date_format_name = "strict_year")]
+pub struct StrictYear;
+
+/**
+Format for `strict_year_month`.
+
+Dates are parsed as the first day of the month.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(date_format = "yyyy-MM", date_format_name = "strict_year_month")]
+pub struct StrictYearMonth;
+
+/**
+Format for `strict_year_month_day`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(date_format = "yyyy-MM-dd", date_format_name = "strict_year_month_day")]
+pub struct StrictYearMonthDay;
+
+/**
+Format for `t_time`.
+
+Times are parsed on the epoch date, `1970-01-01`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(date_format = "'T'HH:mm:ss.SSSZ", date_format_name = "t_time")]
+pub struct TTime;
+
+/**
+Format for `t_time_no_millis`.
+
+Times are parsed on the epoch date, `1970-01-01`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(date_format = "'T'HH:mm:ssZ", date_format_name = "t_time_no_millis")]
+pub struct TTimeNoMillis;
+
+/**
+Format for `time`.
+
+Times are parsed on the epoch date, `1970-01-01`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(date_format = "HH:mm:ss.SSSZ", date_format_name = "time")]
+pub struct Time;
+
+/**
+Format for `time_no_millis`.
+
+Times are parsed on the epoch date, `1970-01-01`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(date_format = "HH:mm:ssZ", date_format_name = "time_no_millis")]
+pub struct TimeNoMillis;
+
+/**
+Format for `week_date`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(date_format = "xxxx-'W'ww-e", date_format_name = "week_date")]
+pub struct WeekDate;
+
+/**
+Format for `week_date_time`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(
+    date_format = "xxxx-'W'ww-e'T'HH:mm:ss.SSSZ",
+    date_format_name = "week_date_time"
+)]
+pub struct WeekDateTime;
+
+/**
+Format for `week_date_time_no_millis`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(
+    date_format = "xxxx-'W'ww-e'T'HH:mm:ssZ",
+    date_format_name = "week_date_time_no_millis"
+)]
+pub struct WeekDateTimeNoMillis;
+
+/**
+Format for `weekyear`.
+
+Dates are parsed as the Monday at the start of the first week of the week-based year.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(date_format = "xxxx", date_format_name = "weekyear")]
+pub struct Weekyear;
+
+/**
+Format for `weekyear_week`.
+
+Dates are parsed as the Monday at the start of the week.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(date_format = "xxxx-'W'ww", date_format_name = "weekyear_week")]
+pub struct WeekyearWeek;
+
+/**
+Format for `weekyear_week_day`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(date_format = "xxxx-'W'ww-e", date_format_name = "weekyear_week_day")]
+pub struct WeekyearWeekDay;
+
+/**
+Format for `year`.
+
+Dates are parsed as the first day of the year.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(date_format = "yyyy", date_format_name = "year")]
+pub struct Year;
+
+/**
+Format for `year_month`.
+
+Dates are parsed as the first day of the month.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(date_format = "yyyy-MM", date_format_name = "year_month")]
+pub struct YearMonth;
+
+/**
+Format for `year_month_day`.
+
+# Links
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats)
+*/
+#[derive(ElasticDateFormat, PartialEq, Debug, Default, Clone, Copy)]
+#[elastic(crate_root = "crate::types")]
+#[elastic(date_format = "yyyy-MM-dd", date_format_name = "year_month_day")]
+pub struct YearMonthDay;
+
 #[cfg(test)]
 mod tests {
     use crate::{
@@ -382,6 +1590,203 @@ mod tests {
         assert_eq!("0", &fmtd);
     }
 
+    fn assert_date_format<F>(
+        input: &str,
+        expected: (i32, u32, u32, u32, u32, u32, u32),
+        formatted: &str,
+    ) where
+        F: DateFormat,
+    {
+        let date = F::parse(input).unwrap_or_else(|e| panic!("{}: {}", F::name(), e));
+
+        assert_eq!(
+            expected,
+            (
+                date.year(),
+                date.month(),
+                date.day(),
+                date.hour(),
+                date.minute(),
+                date.second(),
+                date.nanosecond() / 1000000
+            ),
+            "{}",
+            F::name()
+        );
+
+        assert_eq!(formatted, F::format(&date).to_string(), "{}", F::name());
+    }
+
+    #[test]
+    fn built_in_formats() {
+        let date = (2015, 7, 3, 14, 55, 2, 478);
+        let no_millis = (2015, 7, 3, 14, 55, 2, 0);
+        let no_time = (2015, 7, 3, 0, 0, 0, 0);
+
+        assert_date_format::<BasicDate>("20150703", no_time, "20150703");
+        assert_date_format::<BasicOrdinalDate>("2015184", no_time, "2015184");
+        assert_date_format::<BasicOrdinalDateTime>(
+            "2015184T145502.478Z",
+            date,
+            "2015184T145502.478Z",
+        );
+        assert_date_format::<BasicOrdinalDateTimeNoMillis>(
+            "2015184T145502Z",
+            no_millis,
+            "2015184T145502Z",
+        );
+        assert_date_format::<DateHourMinute>(
+            "2015-07-03T14:55",
+            (2015, 7, 3, 14, 55, 0, 0),
+            "2015-07-03T14:55",
+        );
+        assert_date_format::<DateHourMinuteSecond>(
+            "2015-07-03T14:55:02",
+            no_millis,
+            "2015-07-03T14:55:02",
+        );
+        assert_date_format::<DateHourMinuteSecondFraction>(
+            "2015-07-03T14:55:02.478",
+            date,
+            "2015-07-03T14:55:02.478",
+        );
+        assert_date_format::<DateHourMinuteSecondMillis>(
+            "2015-07-03T14:55:02.478",
+            date,
+            "2015-07-03T14:55:02.478",
+        );
+        assert_date_format::<DateTimeNoMillis>(
+            "2015-07-03T14:55:02Z",
+            no_millis,
+            "2015-07-03T14:55:02Z",
+        );
+        assert_date_format::<OrdinalDate>("2015-184", no_time, "2015-184");
+        assert_date_format::<OrdinalDateTime>(
+            "2015-184T14:55:02.478Z",
+            date,
+            "2015-184T14:55:02.478Z",
+        );
+        assert_date_format::<OrdinalDateTimeNoMillis>(
+            "2015-184T14:55:02Z",
+            no_millis,
+            "2015-184T14:55:02Z",
+        );
+        assert_date_format::<StrictDate>("2015-07-03", no_time, "2015-07-03");
+        assert_date_format::<StrictDateHourMinuteSecond>(
+            "2015-07-03T14:55:02",
+            no_millis,
+            "2015-07-03T14:55:02",
+        );
+        assert_date_format::<StrictDateHourMinuteSecondMillis>(
+            "2015-07-03T14:55:02.478",
+            date,
+            "2015-07-03T14:55:02.478",
+        );
+        assert_date_format::<StrictDateTime>(
+            "2015-07-03T14:55:02.478Z",
+            date,
+            "2015-07-03T14:55:02.478Z",
+        );
+        assert_date_format::<StrictDateTimeNoMillis>(
+            "2015-07-03T14:55:02Z",
+            no_millis,
+            "2015-07-03T14:55:02Z",
+        );
+        assert_date_format::<StrictYearMonthDay>("2015-07-03", no_time, "2015-07-03");
+        assert_date_format::<YearMonthDay>("2015-07-03", no_time, "2015-07-03");
+        assert_date_format::<EpochSecond>("1435935302", no_millis, "1435935302");
+        assert_date_format::<FullDate>("2015-07-03", no_time, "2015-07-03");
+        assert_date_format::<FullDateTime>(
+            "2015-07-03T14:55:02.478Z",
+            date,
+            "2015-07-03T14:55:02.478Z",
+        );
+        assert_date_format::<DateHour>("2015-07-03T14", (2015, 7, 3, 14, 0, 0, 0), "2015-07-03T14");
+        assert_date_format::<WeekDate>("2015-W27-5", no_time, "2015-W27-5");
+        assert_date_format::<BasicWeekDateTime>(
+            "2015W275T145502.478Z",
+            date,
+            "2015W275T145502.478Z",
+        );
+    }
+
+    #[test]
+    fn built_in_formats_without_a_full_date() {
+        assert_date_format::<HourMinute>("14:55", (1970, 1, 1, 14, 55, 0, 0), "14:55");
+        assert_date_format::<TTime>(
+            "T14:55:02.478Z",
+            (1970, 1, 1, 14, 55, 2, 478),
+            "T14:55:02.478Z",
+        );
+        assert_date_format::<Year>("2015", (2015, 1, 1, 0, 0, 0, 0), "2015");
+        assert_date_format::<YearMonth>("2015-07", (2015, 7, 1, 0, 0, 0, 0), "2015-07");
+        assert_date_format::<WeekyearWeek>("2015-W27", (2015, 6, 29, 0, 0, 0, 0), "2015-W27");
+    }
+
+    #[test]
+    fn built_in_format_names() {
+        let names = vec![
+            (BasicDate::name(), "basic_date"),
+            (BasicOrdinalDateTime::name(), "basic_ordinal_date_time"),
+            (
+                DateHourMinuteSecondMillis::name(),
+                "date_hour_minute_second_millis",
+            ),
+            (DateOptionalTime::name(), "date_optional_time"),
+            (EpochSecond::name(), "epoch_second"),
+            (FullDate::name(), "date"),
+            (FullDateTime::name(), "date_time"),
+            (HourMinute::name(), "hour_minute"),
+            (OrdinalDate::name(), "ordinal_date"),
+            (StrictDateOptionalTime::name(), "strict_date_optional_time"),
+            (StrictDateTime::name(), "strict_date_time"),
+            (StrictWeekyearWeekDay::name(), "strict_weekyear_week_day"),
+        ];
+
+        for (name, expected) in names {
+            assert_eq!(expected, name);
+        }
+    }
+
+    #[test]
+    fn date_optional_time() {
+        let cases = vec![
+            ("2015-07-03T14:55:02.478Z", (2015, 7, 3, 14, 55, 2, 478)),
+            ("2015-07-03T14:55:02Z", (2015, 7, 3, 14, 55, 2, 0)),
+            ("2015-07-03T14:55:02.478", (2015, 7, 3, 14, 55, 2, 478)),
+            ("2015-07-03T14:55:02", (2015, 7, 3, 14, 55, 2, 0)),
+            ("2015-07-03T14:55", (2015, 7, 3, 14, 55, 0, 0)),
+            ("2015-07-03", (2015, 7, 3, 0, 0, 0, 0)),
+        ];
+
+        for (input, expected) in cases {
+            let date = StrictDateOptionalTime::parse(input).unwrap();
+
+            assert_eq!(
+                expected,
+                (
+                    date.year(),
+                    date.month(),
+                    date.day(),
+                    date.hour(),
+                    date.minute(),
+                    date.second(),
+                    date.nanosecond() / 1000000
+                ),
+                "{}",
+                input
+            );
+        }
+
+        let date = DateOptionalTime::parse("2015-07-03").unwrap();
+        assert_eq!(
+            "2015-07-03T00:00:00.000Z",
+            DateOptionalTime::format(&date).to_string()
+        );
+
+        assert!(StrictDateOptionalTime::parse("03/07/2015").is_err());
+    }
+
     #[test]
     fn custom_format() {
         #[derive(Default)]
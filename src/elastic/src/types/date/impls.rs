@@ -1,4 +1,5 @@
 use super::{
+    clock::Clock,
     format::{
         DateFormat,
         DateValue,
@@ -6,7 +7,11 @@ use super::{
         FormattedDate,
         ParseError,
     },
-    formats::ChronoFormat,
+    formats::{
+        ChronoFormat,
+        StrictDate,
+        StrictDateOptionalTime,
+    },
     mapping::{
         DateFieldType,
         DateMapping,
@@ -16,6 +21,8 @@ use super::{
 use crate::types::private::field::StdField;
 use chrono::{
     DateTime,
+    NaiveDate,
+    NaiveDateTime,
     Utc,
 };
 use serde::{
@@ -55,6 +62,36 @@ impl From<ChronoDateTime> for FormattableDateValue<ChronoFormat> {
     }
 }
 
+/**
+A `chrono::NaiveDate` is mapped as a `date` with the `strict_date` format.
+
+Elasticsearch stores every date as an instant in UTC, so a naive date is indexed as midnight UTC on that day.
+*/
+impl DateFieldType<DefaultDateMapping<StrictDate>> for NaiveDate {}
+
+impl From<NaiveDate> for FormattableDateValue<StrictDate> {
+    fn from(date: NaiveDate) -> Self {
+        FormattableDateValue::from(DateValue::from(DateTime::from_utc(
+            date.and_hms(0, 0, 0),
+            Utc,
+        )))
+    }
+}
+
+/**
+A `chrono::NaiveDateTime` is mapped as a `date` with the `strict_date_optional_time` format.
+
+Elasticsearch stores every date as an instant in UTC, so a naive date time is indexed as if it were in UTC.
+Convert local date times to UTC before indexing them.
+*/
+impl DateFieldType<DefaultDateMapping<StrictDateOptionalTime>> for NaiveDateTime {}
+
+impl From<NaiveDateTime> for FormattableDateValue<StrictDateOptionalTime> {
+    fn from(date: NaiveDateTime) -> Self {
+        FormattableDateValue::from(DateValue::from(DateTime::from_utc(date, Utc)))
+    }
+}
+
 /**
 An Elasticsearch `date` type with a required `time` component.
 
@@ -192,6 +229,8 @@ where
     /**
     Gets the current system time.
 
+    The time is read from the [`Clock`](trait.Clock.html) installed on the current thread, which is the system clock by default.
+
     # Examples
 
     ```
@@ -203,6 +242,22 @@ where
         Date::new(DateValue::now())
     }
 
+    /**
+    Gets the current time from a specific clock.
+
+    # Examples
+
+    ```
+    # use elastic::types::prelude::*;
+    let clock = FixedClock::new(DateValue::build(2019, 3, 12, 0, 0, 0, 0));
+
+    let date: Date<DefaultDateMapping> = Date::now_with(&clock);
+    ```
+    */
+    pub fn now_with(clock: &impl Clock) -> Self {
+        Date::new(DateValue::now_with(clock))
+    }
+
     /**
     Change the format/mapping of this date.
 
@@ -500,7 +555,7 @@ where
 }
 
 #[derive(Debug, Clone, PartialEq)]
-enum DateExprOp {
+pub(super) enum DateExprOp {
     Add(usize, DateExprOpUnit),
     Sub(usize, DateExprOpUnit),
     Round(DateExprOpUnit),
@@ -517,7 +572,7 @@ impl Display for DateExprOp {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
-enum DateExprOpUnit {
+pub(super) enum DateExprOpUnit {
     Year,
     Month,
     Week,
@@ -773,6 +828,50 @@ mod tests {
         );
     }
 
+    #[test]
+    fn can_map_naive_dates() {
+        #[derive(Serialize, ElasticType)]
+        #[elastic(crate_root = "crate::types")]
+        pub struct NaiveType {
+            pub day: chrono::NaiveDate,
+            pub timestamp: chrono::NaiveDateTime,
+        }
+
+        let ser = serde_json::to_value(&NaiveType::index_mapping()).unwrap();
+
+        let expected = json!({
+            "properties": {
+                "day": {
+                    "type": "date",
+                    "format": "strict_date"
+                },
+                "timestamp": {
+                    "type": "date",
+                    "format": "strict_date_optional_time"
+                }
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn naive_dates_are_utc() {
+        let day = chrono::NaiveDate::from_ymd(2015, 5, 13);
+        let timestamp = day.and_hms_milli(10, 5, 33, 456);
+
+        let day: DateValue = FormattableDateValue::<StrictDate>::from(day).into();
+        let timestamp: DateValue =
+            FormattableDateValue::<StrictDateOptionalTime>::from(timestamp).into();
+
+        assert_eq!(DateValue::build(2015, 5, 13, 0, 0, 0, 0), day);
+        assert_eq!(DateValue::build(2015, 5, 13, 10, 5, 33, 456), timestamp);
+        assert_eq!(
+            "2015-05-13",
+            serde_json::to_value(&day.naive_utc().date()).unwrap()
+        );
+    }
+
     #[test]
     fn can_build_date_from_prim() {
         let date: Date<DefaultDateMapping> = Date::build(2015, 5, 13, 0, 0, 0, 0);
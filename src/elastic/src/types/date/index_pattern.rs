@@ -0,0 +1,556 @@
+use chrono::{
+    format::{
+        Item,
+        StrftimeItems,
+    },
+    DateTime,
+    Datelike,
+    Duration,
+    NaiveDate,
+    Timelike,
+    Utc,
+};
+use std::fmt::{
+    Display,
+    Formatter,
+    Result as FmtResult,
+};
+
+use super::{
+    format::{
+        DateFormat,
+        DateValue,
+        ParseError,
+    },
+    impls::{
+        DateExprOp,
+        DateExprOpUnit,
+    },
+};
+use crate::params::Index;
+
+/** The format used for `{now}` expressions, which matches the default for Elasticsearch date math index names. */
+const DEFAULT_EXPR_FORMAT: &str = "%Y.%m.%d";
+
+/**
+A template for the name of a time-based index, like `logs-{now/d}` or `articles-%Y.%m`.
+
+An `IndexPattern` is resolved to a concrete index name for a given date, so daily or monthly rolling indices
+can be named in one place and shared by every request that targets them.
+Templates can contain two kinds of placeholders:
+
+- `strftime` specifiers, like `%Y` or `%m`, which are formatted from the date.
+- date math expressions in braces, like `{now/d}` or `{now-1M/M}`, which are calculated from the date
+and formatted as `yyyy.MM.dd`, the same as a date math index name in Elasticsearch.
+Expressions support adding (`+1d`), subtracting (`-1d`) and rounding (`/d`) with the units `y`, `M`, `w`, `d`, `h`, `m` and `s`.
+
+# Examples
+
+Resolve the name of a monthly index for a document's date field:
+
+```
+# use elastic::types::prelude::*;
+# fn main() { run().unwrap() }
+# fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+let articles = IndexPattern::parse("articles-%Y.%m")?;
+
+let published: Date<DefaultDateMapping> = Date::build(2019, 03, 12, 8, 30, 0, 0);
+
+assert_eq!("articles-2019.03", articles.resolve(&published));
+# Ok(())
+# }
+```
+
+Resolve the name of yesterday's daily index:
+
+```
+# use elastic::types::prelude::*;
+# fn main() { run().unwrap() }
+# fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+let logs = IndexPattern::parse("logs-{now-1d/d}")?;
+
+let index = logs.now();
+# Ok(())
+# }
+```
+
+An expression can be used with the `#[elastic(index(expr = "..."))]` attribute to derive the index of a document from its date:
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# use elastic::types::prelude::*;
+# use elastic::params::Index;
+fn articles_index(published: &Date<DefaultDateMapping>) -> Index<'static> {
+    IndexPattern::parse("articles-%Y.%m")
+        .expect("invalid index pattern")
+        .resolve(published)
+}
+
+#[derive(Serialize, ElasticType)]
+#[elastic(index(expr = "articles_index(&self.published)"))]
+struct Article {
+    pub published: Date<DefaultDateMapping>,
+}
+# fn main() {}
+```
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct IndexPattern {
+    template: String,
+    parts: Vec<PatternPart>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum PatternPart {
+    Strftime(String),
+    Expr(Vec<DateExprOp>),
+}
+
+impl IndexPattern {
+    /**
+    Parse an index name template.
+
+    An error is returned if the template contains an invalid `strftime` specifier or date math expression.
+    */
+    pub fn parse(template: impl Into<String>) -> Result<Self, ParseError> {
+        let template = template.into();
+        let mut parts = Vec::new();
+
+        let mut rest = template.as_str();
+        while let Some(start) = rest.find('{') {
+            let end = rest[start..]
+                .find('}')
+                .map(|end| start + end)
+                .ok_or_else(|| format!("unclosed date math expression in `{}`", template))?;
+
+            if start > 0 {
+                parts.push(parse_strftime(&rest[..start])?);
+            }
+            parts.push(parse_expr(&rest[start + 1..end])?);
+
+            rest = &rest[end + 1..];
+        }
+
+        if !rest.is_empty() {
+            parts.push(parse_strftime(rest)?);
+        }
+
+        Ok(IndexPattern { template, parts })
+    }
+
+    /** Get the name of the index for the given date. */
+    pub fn resolve(&self, date: &DateTime<Utc>) -> Index<'static> {
+        let mut index = String::new();
+
+        for part in &self.parts {
+            match *part {
+                PatternPart::Strftime(ref format) => {
+                    index.push_str(&date.format(format).to_string())
+                }
+                PatternPart::Expr(ref ops) => {
+                    let date = ops.iter().fold(*date, |date, op| apply(date, op));
+                    index.push_str(&date.format(DEFAULT_EXPR_FORMAT).to_string())
+                }
+            }
+        }
+
+        Index::from(index)
+    }
+
+    /** Get the name of the index for the current date. */
+    pub fn now(&self) -> Index<'static> {
+        self.resolve(&DateValue::now())
+    }
+}
+
+impl Display for IndexPattern {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        self.template.fmt(f)
+    }
+}
+
+/**
+A [date math index name][docs] that's resolved by Elasticsearch, like `<logs-{now/d}>`.
+
+A date math index name is a static prefix followed by a date math expression anchored at `now`,
+with an optional date format and time zone.
+Elasticsearch calculates the date when it receives a request, so the index is always consistent with the cluster's clock.
+Use an [`IndexPattern`][IndexPattern] to resolve index names on the client instead.
+
+A `DateMathIndex` converts into an `Index`, so it can be used anywhere an index name is accepted.
+The angle brackets and other special characters are percent-encoded so the name can be used in a url.
+An [`AffixIndexNamer`][AffixIndexNamer] adds its prefix and suffix inside the angle brackets.
+
+# Examples
+
+Search today's logs index:
+
+```
+# use elastic::types::prelude::*;
+# use elastic::endpoints::SearchRequest;
+let index = DateMathIndex::now("logs-").round_day();
+
+assert_eq!("<logs-{now/d}>", index.to_string());
+
+let req = SearchRequest::for_index(index, "{}");
+
+assert_eq!("/%3Clogs-%7Bnow%2Fd%7D%3E/_search", req.url.as_ref());
+```
+
+Use a date format and time zone for the date in the index name:
+
+```
+# use elastic::types::prelude::*;
+let index = DateMathIndex::now("metrics-")
+    .sub_months(1)
+    .round_month()
+    .format("yyyy.MM")
+    .time_zone("+12:00");
+
+assert_eq!("<metrics-{now-1M/M{yyyy.MM|+12:00}}>", index.to_string());
+```
+
+[docs]: https://www.elastic.co/guide/en/elasticsearch/reference/master/date-math-index-names.html
+[IndexPattern]: struct.IndexPattern.html
+[AffixIndexNamer]: ../../client/struct.AffixIndexNamer.html
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct DateMathIndex {
+    prefix: String,
+    ops: Vec<DateExprOp>,
+    format: Option<String>,
+    time_zone: Option<String>,
+}
+
+impl DateMathIndex {
+    /** Create a date math index name with a static prefix and an expression anchored at `now`. */
+    pub fn now(prefix: impl Into<String>) -> Self {
+        DateMathIndex {
+            prefix: prefix.into(),
+            ops: Vec::new(),
+            format: None,
+            time_zone: None,
+        }
+    }
+
+    /**
+    Set the Elasticsearch format of the date in the index name, like `yyyy.MM`.
+
+    The default format is `yyyy.MM.dd`.
+    */
+    pub fn format(mut self, format: impl Into<String>) -> Self {
+        self.format = Some(format.into());
+        self
+    }
+
+    /**
+    Use the name of a date format for the date in the index name.
+
+    ```
+    # use elastic::types::prelude::*;
+    let index = DateMathIndex::now("logs-").round_day().named_format::<BasicDate>();
+
+    assert_eq!("<logs-{now/d{basic_date}}>", index.to_string());
+    ```
+    */
+    pub fn named_format<TFormat>(mut self) -> Self
+    where
+        TFormat: DateFormat,
+    {
+        self.format = Some(TFormat::name().to_owned());
+        self
+    }
+
+    /**
+    Set the time zone used to calculate the date, like `+12:00` or `Europe/Paris`.
+
+    The default time zone is UTC.
+    */
+    pub fn time_zone(mut self, time_zone: impl Into<String>) -> Self {
+        self.time_zone = Some(time_zone.into());
+        self
+    }
+
+    impl_expr_ops!(DateExprOpUnit::Year, add_years, sub_years, round_year);
+    impl_expr_ops!(DateExprOpUnit::Month, add_months, sub_months, round_month);
+    impl_expr_ops!(DateExprOpUnit::Week, add_weeks, sub_weeks, round_week);
+    impl_expr_ops!(DateExprOpUnit::Day, add_days, sub_days, round_day);
+    impl_expr_ops!(DateExprOpUnit::Hour, add_hours, sub_hours, round_hour);
+    impl_expr_ops!(
+        DateExprOpUnit::Minute,
+        add_minutes,
+        sub_minutes,
+        round_minute
+    );
+    impl_expr_ops!(
+        DateExprOpUnit::Second,
+        add_seconds,
+        sub_seconds,
+        round_second
+    );
+
+    /** The percent-encoded index name, which can be used in a url. */
+    pub fn encode(&self) -> String {
+        let name = self.to_string();
+        let mut encoded = String::with_capacity(name.len() * 2);
+
+        for b in name.bytes() {
+            match b {
+                b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'*' => {
+                    encoded.push(b as char)
+                }
+                b => encoded.push_str(&format!("%{:02X}", b)),
+            }
+        }
+
+        encoded
+    }
+}
+
+impl Display for DateMathIndex {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        f.write_str("<")?;
+
+        // Braces in the static part of the name need to be escaped
+        for c in self.prefix.chars() {
+            if c == '{' || c == '}' {
+                f.write_str("\\")?;
+            }
+            write!(f, "{}", c)?;
+        }
+
+        f.write_str("{now")?;
+        for op in &self.ops {
+            write!(f, "{}", op)?;
+        }
+
+        match (&self.format, &self.time_zone) {
+            (Some(format), Some(time_zone)) => write!(f, "{{{}|{}}}", format, time_zone)?,
+            (None, Some(time_zone)) => write!(f, "{{yyyy.MM.dd|{}}}", time_zone)?,
+            (Some(format), None) => write!(f, "{{{}}}", format)?,
+            (None, None) => (),
+        }
+
+        f.write_str("}>")
+    }
+}
+
+impl From<DateMathIndex> for Index<'static> {
+    fn from(index: DateMathIndex) -> Self {
+        Index::from(index.encode())
+    }
+}
+
+impl<'a> From<&'a DateMathIndex> for Index<'static> {
+    fn from(index: &'a DateMathIndex) -> Self {
+        Index::from(index.encode())
+    }
+}
+
+fn parse_strftime(format: &str) -> Result<PatternPart, ParseError> {
+    if StrftimeItems::new(format).any(|item| item == Item::Error) {
+        return Err(format!("invalid strftime specifier in `{}`", format).into());
+    }
+
+    Ok(PatternPart::Strftime(format.to_owned()))
+}
+
+fn parse_expr(expr: &str) -> Result<PatternPart, ParseError> {
+    let invalid = || ParseError::from(format!("invalid date math expression `{{{}}}`", expr));
+
+    if !expr.starts_with("now") {
+        return Err(invalid());
+    }
+
+    let mut ops = Vec::new();
+    let mut rest = &expr[3..];
+    while let Some(op) = rest.chars().next() {
+        if !"+-/".contains(op) {
+            return Err(invalid());
+        }
+
+        let digits = rest[1..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len() - 1);
+        let unit = rest[1 + digits..].chars().next().ok_or_else(invalid)?;
+        let unit = match unit {
+            'y' => DateExprOpUnit::Year,
+            'M' => DateExprOpUnit::Month,
+            'w' => DateExprOpUnit::Week,
+            'd' => DateExprOpUnit::Day,
+            'h' | 'H' => DateExprOpUnit::Hour,
+            'm' => DateExprOpUnit::Minute,
+            's' => DateExprOpUnit::Second,
+            _ => return Err(invalid()),
+        };
+
+        let size = || rest[1..1 + digits].parse::<usize>().map_err(|_| invalid());
+        ops.push(match op {
+            '+' => DateExprOp::Add(size()?, unit),
+            '-' => DateExprOp::Sub(size()?, unit),
+            '/' if digits == 0 => DateExprOp::Round(unit),
+            _ => return Err(invalid()),
+        });
+
+        rest = &rest[2 + digits..];
+    }
+
+    Ok(PatternPart::Expr(ops))
+}
+
+fn apply(date: DateTime<Utc>, op: &DateExprOp) -> DateTime<Utc> {
+    match *op {
+        DateExprOp::Add(size, unit) => shift(date, size as i64, unit),
+        DateExprOp::Sub(size, unit) => shift(date, -(size as i64), unit),
+        DateExprOp::Round(unit) => round(date, unit),
+    }
+}
+
+fn shift(date: DateTime<Utc>, size: i64, unit: DateExprOpUnit) -> DateTime<Utc> {
+    match unit {
+        DateExprOpUnit::Year => shift_months(date, size * 12),
+        DateExprOpUnit::Month => shift_months(date, size),
+        DateExprOpUnit::Week => date + Duration::weeks(size),
+        DateExprOpUnit::Day => date + Duration::days(size),
+        DateExprOpUnit::Hour => date + Duration::hours(size),
+        DateExprOpUnit::Minute => date + Duration::minutes(size),
+        DateExprOpUnit::Second => date + Duration::seconds(size),
+    }
+}
+
+// Shift a date by a number of months, clamping the day to the end of the month
+fn shift_months(date: DateTime<Utc>, months: i64) -> DateTime<Utc> {
+    let months = date.year() as i64 * 12 + date.month0() as i64 + months;
+    let (year, month) = (
+        months.div_euclid(12) as i32,
+        months.rem_euclid(12) as u32 + 1,
+    );
+
+    let day = (1..=date.day())
+        .rev()
+        .filter_map(|day| NaiveDate::from_ymd_opt(year, month, day))
+        .next()
+        .expect("every month has a first day");
+
+    DateTime::from_utc(day.and_time(date.time()), Utc)
+}
+
+fn round(date: DateTime<Utc>, unit: DateExprOpUnit) -> DateTime<Utc> {
+    let day = date.date().naive_utc();
+
+    let midnight = |day: NaiveDate| DateTime::from_utc(day.and_hms(0, 0, 0), Utc);
+
+    match unit {
+        DateExprOpUnit::Year => midnight(NaiveDate::from_ymd(date.year(), 1, 1)),
+        DateExprOpUnit::Month => midnight(NaiveDate::from_ymd(date.year(), date.month(), 1)),
+        DateExprOpUnit::Week => {
+            midnight(day - Duration::days(date.weekday().num_days_from_monday() as i64))
+        }
+        DateExprOpUnit::Day => midnight(day),
+        DateExprOpUnit::Hour => DateTime::from_utc(day.and_hms(date.hour(), 0, 0), Utc),
+        DateExprOpUnit::Minute => {
+            DateTime::from_utc(day.and_hms(date.hour(), date.minute(), 0), Utc)
+        }
+        DateExprOpUnit::Second => {
+            DateTime::from_utc(day.and_hms(date.hour(), date.minute(), date.second()), Utc)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::date::DateValue;
+
+    fn date() -> DateTime<Utc> {
+        *DateValue::build(2019, 3, 31, 14, 30, 15, 0)
+    }
+
+    #[test]
+    fn resolve_strftime() {
+        let pattern = IndexPattern::parse("articles-%Y.%m").unwrap();
+
+        assert_eq!("articles-2019.03", pattern.resolve(&date()));
+    }
+
+    #[test]
+    fn resolve_date_math() {
+        let cases = vec![
+            ("logs-{now}", "logs-2019.03.31"),
+            ("logs-{now/d}", "logs-2019.03.31"),
+            ("logs-{now/M}", "logs-2019.03.01"),
+            ("logs-{now/w}", "logs-2019.03.25"),
+            ("logs-{now/y}", "logs-2019.01.01"),
+            ("logs-{now-1d/d}", "logs-2019.03.30"),
+            ("logs-{now-1M}", "logs-2019.02.28"),
+            ("logs-{now+1y/M}", "logs-2020.03.01"),
+            ("logs-{now+12h}", "logs-2019.04.01"),
+            ("{now/M}-%H", "2019.03.01-14"),
+        ];
+
+        for (template, expected) in cases {
+            let pattern = IndexPattern::parse(template).unwrap();
+
+            assert_eq!(expected, pattern.resolve(&date()), "{}", template);
+        }
+    }
+
+    #[test]
+    fn parse_invalid_templates() {
+        let cases = vec![
+            "logs-{now/d",
+            "logs-{today}",
+            "logs-{now/2d}",
+            "logs-{now+d}",
+            "logs-{now+1q}",
+            "logs-%Q",
+        ];
+
+        for template in cases {
+            assert!(IndexPattern::parse(template).is_err(), "{}", template);
+        }
+    }
+
+    #[test]
+    fn date_math_index_name() {
+        let cases = vec![
+            (DateMathIndex::now("logs-"), "<logs-{now}>"),
+            (DateMathIndex::now("logs-").round_day(), "<logs-{now/d}>"),
+            (
+                DateMathIndex::now("logs-").add_hours(1).round_hour(),
+                "<logs-{now+1h/h}>",
+            ),
+            (
+                DateMathIndex::now("logs-").format("yyyy.MM"),
+                "<logs-{now{yyyy.MM}}>",
+            ),
+            (
+                DateMathIndex::now("logs-").time_zone("+12:00"),
+                "<logs-{now{yyyy.MM.dd|+12:00}}>",
+            ),
+            (
+                DateMathIndex::now("logs-{x}-").round_day(),
+                "<logs-\\{x\\}-{now/d}>",
+            ),
+        ];
+
+        for (index, expected) in cases {
+            assert_eq!(expected, index.to_string());
+        }
+    }
+
+    #[test]
+    fn date_math_index_is_encoded() {
+        let index = DateMathIndex::now("logs-")
+            .sub_months(1)
+            .round_month()
+            .format("yyyy.MM")
+            .time_zone("+12:00");
+
+        assert_eq!(
+            "%3Clogs-%7Bnow-1M%2FM%7Byyyy.MM%7C%2B12%3A00%7D%7D%3E",
+            &*Index::from(index)
+        );
+    }
+}
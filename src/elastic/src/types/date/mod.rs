@@ -14,11 +14,40 @@ The `Date<M>` type and `chrono`s `DateTime<Utc>` are the main `date` field types
 If the mapping and format aren't important, use `DateTime<Utc>`.
 If you need to specify mapping properties like `boost`, or use a specific format like `epoch_millis`, use `Date<M>`.
 
+## `NaiveDate` and `NaiveDateTime`
+
+`chrono`s `NaiveDate` and `NaiveDateTime` can also be used as `date` fields, mapped with the `strict_date` and
+`strict_date_optional_time` formats respectively.
+Elasticsearch stores every date as an instant in UTC, so naive dates are indexed as if they were in UTC:
+a `NaiveDate` becomes midnight UTC on that day, and a `NaiveDateTime` keeps its wall-clock time.
+Date times in a local timezone should be converted to UTC before they're indexed.
+
 ## `DateValue` and `FormattableDateValue<F>`
 
 The `DateValue` and `FormattableDateValue<F>` types are used in methods to represent dates that either don't have a format or have a specific format respectively.
 `Date` and `DateTime<Utc>` can freely convert to and from these types, so you probably won't need to interact with them directly.
 
+## `time::OffsetDateTime`
+
+With the `time` feature, a `time::OffsetDateTime` can be converted into a `DateValue`, and a `DateValue` or `Date<M>` can be converted back with `TryFrom`.
+The conversion to an `OffsetDateTime` fails if the year is outside the range supported by `time`.
+Dates are still formatted and parsed with `chrono`, so `Date<M>` is used as the field type:
+
+```
+# use elastic::types::prelude::*;
+# #[cfg(feature = "time")]
+# fn main() {
+use std::convert::TryFrom;
+use time::OffsetDateTime;
+
+let date: Date<DefaultDateMapping> = Date::new(DateValue::from(OffsetDateTime::now_utc()));
+
+let offset = OffsetDateTime::try_from(date).unwrap();
+# }
+# #[cfg(not(feature = "time"))]
+# fn main() {}
+```
+
 # Examples
 
 For defining your own date mapping, see [mapping details](mapping/trait.DateMapping.html#derive-mapping).
@@ -63,6 +92,17 @@ struct MyType {
 # }
 ```
 
+## Built-in Formats
+
+There's a format for each of the [built-in Elasticsearch formats](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping-date-format.html#built-in-date-formats),
+named after the format, like `BasicDateTime` for `basic_date_time`.
+The `date` and `date_time` formats are named `FullDate` and `FullDateTime` so they don't clash with other date types.
+Formats without a date, like `hour_minute`, parse times on the epoch date,
+and formats without a day, like `year_month` or `weekyear_week`, parse dates at the start of the period.
+
+Built-in formats only parse and format UTC dates with a `Z` time zone.
+The `strict_date_optional_time_nanos` format isn't supported.
+
 ## Creating Formats
 
 To make it easier to build your own date formats, derive `ElasticDateFormat` on a unit struct.
@@ -78,6 +118,9 @@ struct MyFormat;
 # }
 ```
 
+Check a format against dates formatted by Elasticsearch with [`check_test_vectors`](fn.check_test_vectors.html).
+Each built-in format has its own reference dates from `DateFormat::test_vectors`.
+
 You can also manually implement `DateFormat` and write your own arbitrary format/parse logic:
 
 ```
@@ -104,21 +147,72 @@ impl DateFormat for Rfc3339Format {
 # }
 ```
 
+Formats that aren't known until runtime, like a format from configuration, can be parsed into a
+[`CustomDateFormat`](struct.CustomDateFormat.html):
+
+```
+# use elastic::types::prelude::*;
+# fn main() { run().unwrap() }
+# fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+let format = CustomDateFormat::new("yyyy-MM-dd'T'HH:mm:ss")?;
+
+let date = format.parse("2019-03-12T08:30:00")?;
+# Ok(())
+# }
+```
+
+Fields that accept more than one format, like `strict_date_optional_time||epoch_millis`, can combine formats
+with [`Or`](struct.Or.html).
+
+# Deterministic dates
+
+`Date::now()` reads the current date from the system clock by default.
+Tests can install a [`FixedClock`](struct.FixedClock.html) on the current thread with [`with_clock`](fn.with_clock.html)
+so mappings and documents that use the current date serialise the same way on every run.
+
+# Time-based indices
+
+An [`IndexPattern`](struct.IndexPattern.html) names rolling indices from a date, like `logs-{now/d}` or `articles-%Y.%m`.
+The resolved index name can be passed to any request builder that accepts an index.
+
+A [`DateMathIndex`](struct.DateMathIndex.html) is a [date math index name](https://www.elastic.co/guide/en/elasticsearch/reference/master/date-math-index-names.html),
+like `<logs-{now/d}>`, that's resolved by Elasticsearch when the request is received instead of by the client when the request is built.
+
 # Links
 - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/date.html)
 */
 
 pub mod mapping;
 
+mod clock;
+mod custom;
 mod format;
 mod formats;
+#[macro_use]
 mod impls;
+mod index_pattern;
+#[cfg(feature = "time")]
+mod offset_date_time;
+mod or;
+mod vectors;
 pub use self::{
+    clock::{
+        with_clock,
+        Clock,
+        FixedClock,
+        SystemClock,
+    },
+    custom::*,
     format::*,
     formats::*,
     impls::*,
+    index_pattern::*,
+    or::*,
+    vectors::*,
 };
 
+pub(crate) use self::formats::built_in_parser;
+
 pub mod prelude {
     /*!
     Includes all types for the `date` type.
@@ -127,6 +221,12 @@ pub mod prelude {
     */
 
     pub use super::{
+        clock::{
+            Clock,
+            FixedClock,
+            SystemClock,
+        },
+        custom::*,
         format::{
             DateFormat,
             DateValue,
@@ -135,7 +235,10 @@ pub mod prelude {
         },
         formats::*,
         impls::*,
+        index_pattern::*,
         mapping::*,
+        or::*,
+        vectors::*,
         DefaultDateFormat,
     };
 }
@@ -0,0 +1,86 @@
+use super::{
+    mapping::DateMapping,
+    Date,
+    DateValue,
+};
+use chrono::{
+    DateTime,
+    NaiveDateTime,
+    Utc,
+};
+use std::convert::TryFrom;
+use time::{
+    error::ComponentRange,
+    OffsetDateTime,
+};
+
+const NANOS_PER_SEC: i128 = 1_000_000_000;
+
+impl From<OffsetDateTime> for DateValue {
+    fn from(date: OffsetDateTime) -> Self {
+        let nanos = date.unix_timestamp_nanos();
+
+        let secs = nanos.div_euclid(NANOS_PER_SEC) as i64;
+        let subsec_nanos = nanos.rem_euclid(NANOS_PER_SEC) as u32;
+
+        DateTime::from_utc(NaiveDateTime::from_timestamp(secs, subsec_nanos), Utc).into()
+    }
+}
+
+/** A `DateValue` can't be converted if its year is outside the range supported by the `time` crate. */
+impl TryFrom<DateValue> for OffsetDateTime {
+    type Error = ComponentRange;
+
+    fn try_from(date: DateValue) -> Result<Self, Self::Error> {
+        let nanos = i128::from(date.timestamp()) * NANOS_PER_SEC
+            + i128::from(date.timestamp_subsec_nanos());
+
+        OffsetDateTime::from_unix_timestamp_nanos(nanos)
+    }
+}
+
+impl<TMapping> TryFrom<Date<TMapping>> for OffsetDateTime
+where
+    TMapping: DateMapping,
+{
+    type Error = ComponentRange;
+
+    fn try_from(date: Date<TMapping>) -> Result<Self, Self::Error> {
+        OffsetDateTime::try_from(DateValue::from(date))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use time::{
+        OffsetDateTime,
+        UtcOffset,
+    };
+
+    use crate::types::prelude::*;
+
+    #[test]
+    fn offset_date_time_to_date() {
+        let offset = OffsetDateTime::from_unix_timestamp_nanos(1_431_621_908_886_000_000)
+            .unwrap()
+            .to_offset(UtcOffset::from_hms(2, 0, 0).unwrap());
+
+        let date: Date<DefaultDateMapping> = Date::new(DateValue::from(offset));
+
+        assert_eq!(
+            Date::<DefaultDateMapping>::build(2015, 5, 14, 16, 45, 8, 886),
+            date
+        );
+    }
+
+    #[test]
+    fn date_to_offset_date_time() {
+        let date: Date<DefaultDateMapping<EpochMillis>> =
+            Date::build(1969, 12, 31, 23, 59, 59, 500);
+
+        let offset = OffsetDateTime::try_from(date).unwrap();
+
+        assert_eq!(-500_000_000, offset.unix_timestamp_nanos());
+    }
+}
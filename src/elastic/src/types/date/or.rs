@@ -0,0 +1,149 @@
+use std::{
+    any::TypeId,
+    collections::BTreeMap,
+    marker::PhantomData,
+    sync::Mutex,
+};
+
+use super::{
+    DateFormat,
+    DateValue,
+    FormattedDate,
+    ParseError,
+};
+
+/**
+A date format that accepts either of two formats.
+
+Elasticsearch lets a `date` field accept several formats by separating them with `||`, like
+`strict_date_optional_time||epoch_millis`.
+`Or<F1, F2>` does the same for a `Date`: dates are parsed with `F1` first and then `F2`, and are always formatted with `F1`.
+The name of the format in the mapping is the names of `F1` and `F2` joined by `||`.
+
+More than two formats can be combined by nesting, like `Or<F1, Or<F2, F3>>`.
+
+# Examples
+
+```
+# #[macro_use] extern crate serde_json;
+# use elastic::types::prelude::*;
+# fn main() { run().unwrap() }
+# fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+type Timestamp = Date<DefaultDateMapping<Or<StrictDateOptionalTime, EpochMillis>>>;
+
+let from_str: Timestamp = serde_json::from_value(json!("2015-05-13T10:05:33.000Z"))?;
+let from_millis: Timestamp = serde_json::from_value(json!(1431511533000i64))?;
+
+assert_eq!(from_str, from_millis);
+assert_eq!(
+    "strict_date_optional_time||epoch_millis",
+    Or::<StrictDateOptionalTime, EpochMillis>::name()
+);
+# Ok(())
+# }
+```
+*/
+#[derive(PartialEq, Debug, Default, Clone, Copy)]
+pub struct Or<F1, F2> {
+    _f: PhantomData<(F1, F2)>,
+}
+
+impl<F1, F2> DateFormat for Or<F1, F2>
+where
+    F1: DateFormat + 'static,
+    F2: DateFormat + 'static,
+{
+    fn name() -> &'static str {
+        cached_name::<Self>(|| format!("{}||{}", F1::name(), F2::name()))
+    }
+
+    fn parse(date: &str) -> Result<DateValue, ParseError> {
+        F1::parse(date)
+            .or_else(|_| F2::parse(date))
+            .map_err(|_| format!("`{}` doesn't match any of `{}`", date, Self::name()).into())
+    }
+
+    fn format<'a>(date: &'a DateValue) -> FormattedDate<'a> {
+        F1::format(date)
+    }
+}
+
+/**
+Get the name of a combined format.
+
+Format names are `&'static str`, so the name for each combination of formats is built and leaked
+the first time it's requested and reused after that.
+*/
+fn cached_name<F>(name: impl FnOnce() -> String) -> &'static str
+where
+    F: 'static,
+{
+    static NAMES: Mutex<BTreeMap<TypeId, &'static str>> = Mutex::new(BTreeMap::new());
+
+    let mut names = NAMES.lock().unwrap_or_else(|e| e.into_inner());
+
+    *names
+        .entry(TypeId::of::<F>())
+        .or_insert_with(|| &*Box::leak(name().into_boxed_str()))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+
+    use crate::types::{
+        prelude::*,
+        private::field,
+    };
+
+    type Timestamp = Date<DefaultDateMapping<Or<StrictDateOptionalTime, EpochMillis>>>;
+
+    #[test]
+    fn or_name() {
+        assert_eq!(
+            "strict_date_optional_time||epoch_millis",
+            Or::<StrictDateOptionalTime, EpochMillis>::name()
+        );
+        assert_eq!(
+            "basic_date||epoch_second||epoch_millis",
+            Or::<BasicDate, Or<EpochSecond, EpochMillis>>::name()
+        );
+    }
+
+    #[test]
+    fn or_parses_either_format() {
+        let expected = DateValue::build(2015, 5, 13, 10, 5, 33, 0);
+
+        let from_str = Or::<StrictDateOptionalTime, EpochMillis>::parse("2015-05-13T10:05:33Z");
+        let from_millis = Or::<StrictDateOptionalTime, EpochMillis>::parse("1431511533000");
+
+        assert_eq!(expected, from_str.unwrap());
+        assert_eq!(expected, from_millis.unwrap());
+        assert!(Or::<StrictDateOptionalTime, EpochMillis>::parse("not a date").is_err());
+    }
+
+    #[test]
+    fn or_formats_with_first_format() {
+        let date: Timestamp = serde_json::from_str("1431511533000").unwrap();
+
+        let ser = serde_json::to_string(&date).unwrap();
+
+        assert_eq!(r#""2015-05-13T10:05:33.000Z""#, ser);
+    }
+
+    #[test]
+    fn or_mapping() {
+        let ser = serde_json::to_value(&field::serialize(DefaultDateMapping::<
+            Or<StrictDateOptionalTime, EpochMillis>,
+        >::default()))
+        .unwrap();
+
+        assert_eq!(
+            json!({
+                "type": "date",
+                "format": "strict_date_optional_time||epoch_millis"
+            }),
+            ser
+        );
+    }
+}
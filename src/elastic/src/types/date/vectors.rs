@@ -0,0 +1,427 @@
+use chrono::{
+    DateTime,
+    NaiveDateTime,
+    Utc,
+};
+
+use super::format::{
+    DateFormat,
+    DateValue,
+};
+
+/**
+A reference date for a date format.
+
+A test vector pairs a date formatted by Elasticsearch with the number of milliseconds since the epoch it represents.
+A format passes the vector if it parses the formatted date to the same instant, and formats that instant to the same string.
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DateFormatTestVector {
+    /** The date, formatted by Elasticsearch. */
+    pub formatted: &'static str,
+    /** The number of milliseconds since the epoch for the date. */
+    pub millis: i64,
+}
+
+impl DateFormatTestVector {
+    /** Create a test vector for a formatted date and the milliseconds since the epoch it represents. */
+    pub const fn new(formatted: &'static str, millis: i64) -> Self {
+        DateFormatTestVector { formatted, millis }
+    }
+}
+
+/**
+Check a date format against a set of test vectors.
+
+Each vector is parsed and formatted by the date format, and the first vector that doesn't round-trip is returned as an error.
+Use this to check a custom format against dates formatted by Elasticsearch using the same pattern.
+
+# Examples
+
+Check a builtin format against its own vectors:
+
+```
+# use elastic::types::prelude::*;
+assert_eq!(Ok(()), check_test_vectors::<BasicDateTime>(BasicDateTime::test_vectors()));
+```
+
+Check a custom format:
+
+```
+# #[macro_use] extern crate elastic_derive;
+# use elastic::types::prelude::*;
+#[derive(Default, ElasticDateFormat)]
+#[elastic(date_format = "dd/MM/yyyy HH:mm")]
+struct DayMonthYearFormat;
+
+# fn main() {
+let vectors = [DateFormatTestVector::new("13/05/2015 00:04", 1431475440000)];
+
+assert_eq!(Ok(()), check_test_vectors::<DayMonthYearFormat>(&vectors));
+# }
+```
+*/
+pub fn check_test_vectors<TFormat>(
+    vectors: &[DateFormatTestVector],
+) -> Result<(), DateFormatTestVectorError>
+where
+    TFormat: DateFormat,
+{
+    for vector in vectors {
+        let parsed =
+            TFormat::parse(vector.formatted).map_err(|err| DateFormatTestVectorError::Parse {
+                format: TFormat::name(),
+                formatted: vector.formatted,
+                reason: err.to_string(),
+            })?;
+
+        let millis = parsed.timestamp() * 1000 + i64::from(parsed.timestamp_subsec_millis());
+        if millis != vector.millis {
+            return Err(DateFormatTestVectorError::WrongDate {
+                format: TFormat::name(),
+                formatted: vector.formatted,
+                expected: vector.millis,
+                actual: millis,
+            });
+        }
+
+        let date = DateValue::from(DateTime::from_utc(
+            NaiveDateTime::from_timestamp(
+                vector.millis.div_euclid(1000),
+                (vector.millis.rem_euclid(1000) * 1_000_000) as u32,
+            ),
+            Utc,
+        ));
+
+        let formatted = TFormat::format(&date).to_string();
+        if formatted != vector.formatted {
+            return Err(DateFormatTestVectorError::WrongFormat {
+                format: TFormat::name(),
+                millis: vector.millis,
+                expected: vector.formatted,
+                actual: formatted,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+quick_error! {
+    /** A date format doesn't round-trip a test vector. */
+    #[derive(Debug, PartialEq)]
+    pub enum DateFormatTestVectorError {
+        /** The formatted date couldn't be parsed. */
+        Parse { format: &'static str, formatted: &'static str, reason: String } {
+            display("format `{}` failed to parse `{}`: {}", format, formatted, reason)
+        }
+        /** The formatted date was parsed to the wrong instant. */
+        WrongDate { format: &'static str, formatted: &'static str, expected: i64, actual: i64 } {
+            display("format `{}` parsed `{}` as {}ms but expected {}ms", format, formatted, actual, expected)
+        }
+        /** The instant was formatted to the wrong string. */
+        WrongFormat { format: &'static str, millis: i64, expected: &'static str, actual: String } {
+            display("format `{}` formatted {}ms as `{}` but expected `{}`", format, millis, actual, expected)
+        }
+    }
+}
+
+macro_rules! test_vectors {
+    ($(($formatted:expr, $millis:expr)),* $(,)*) => {
+        &[$(DateFormatTestVector::new($formatted, $millis)),*]
+    };
+}
+
+/**
+Get the test vectors for a built-in Elasticsearch format by its name, like `epoch_millis`.
+
+The dates were formatted by Elasticsearch using the format.
+*/
+pub(crate) fn built_in_test_vectors(name: &str) -> &'static [DateFormatTestVector] {
+    match name {
+        "basic_date" => test_vectors![("20150513", 1431475200000), ("20160229", 1456704000000)],
+        "basic_date_time" => test_vectors![
+            ("20150513T000405.123Z", 1431475445123),
+            ("20160229T235959.999Z", 1456790399999),
+        ],
+        "basic_date_time_no_millis" => test_vectors![
+            ("20150513T000405Z", 1431475445000),
+            ("20160229T235959Z", 1456790399000),
+        ],
+        "basic_ordinal_date" => {
+            test_vectors![("2015133", 1431475200000), ("2016060", 1456704000000)]
+        }
+        "basic_ordinal_date_time" => test_vectors![
+            ("2015133T000405.123Z", 1431475445123),
+            ("2016060T235959.999Z", 1456790399999),
+        ],
+        "basic_ordinal_date_time_no_millis" => test_vectors![
+            ("2015133T000405Z", 1431475445000),
+            ("2016060T235959Z", 1456790399000),
+        ],
+        "basic_t_time" => test_vectors![("T000405.123Z", 245123), ("T235959.999Z", 86399999)],
+        "basic_t_time_no_millis" => test_vectors![("T000405Z", 245000), ("T235959Z", 86399000)],
+        "basic_time" => test_vectors![("000405.123Z", 245123), ("235959.999Z", 86399999)],
+        "basic_time_no_millis" => test_vectors![("000405Z", 245000), ("235959Z", 86399000)],
+        "basic_week_date" | "strict_basic_week_date" => {
+            test_vectors![("2015W203", 1431475200000), ("2016W091", 1456704000000)]
+        }
+        "basic_week_date_time" | "strict_basic_week_date_time" => test_vectors![
+            ("2015W203T000405.123Z", 1431475445123),
+            ("2016W091T235959.999Z", 1456790399999),
+        ],
+        "basic_week_date_time_no_millis" | "strict_basic_week_date_time_no_millis" => {
+            test_vectors![
+                ("2015W203T000405Z", 1431475445000),
+                ("2016W091T235959Z", 1456790399000),
+            ]
+        }
+        "date" | "strict_date" | "strict_year_month_day" | "year_month_day" => {
+            test_vectors![("2015-05-13", 1431475200000), ("2016-02-29", 1456704000000)]
+        }
+        "date_hour" | "strict_date_hour" => test_vectors![
+            ("2015-05-13T00", 1431475200000),
+            ("2016-02-29T23", 1456786800000),
+        ],
+        "date_hour_minute" | "strict_date_hour_minute" => test_vectors![
+            ("2015-05-13T00:04", 1431475440000),
+            ("2016-02-29T23:59", 1456790340000),
+        ],
+        "date_hour_minute_second" | "strict_date_hour_minute_second" => test_vectors![
+            ("2015-05-13T00:04:05", 1431475445000),
+            ("2016-02-29T23:59:59", 1456790399000),
+        ],
+        "date_hour_minute_second_fraction"
+        | "date_hour_minute_second_millis"
+        | "strict_date_hour_minute_second_fraction"
+        | "strict_date_hour_minute_second_millis" => test_vectors![
+            ("2015-05-13T00:04:05.123", 1431475445123),
+            ("2016-02-29T23:59:59.999", 1456790399999),
+        ],
+        "date_optional_time" | "date_time" | "strict_date_optional_time" | "strict_date_time" => {
+            test_vectors![
+                ("2015-05-13T00:04:05.123Z", 1431475445123),
+                ("2016-02-29T23:59:59.999Z", 1456790399999),
+            ]
+        }
+        "date_time_no_millis" | "strict_date_time_no_millis" => test_vectors![
+            ("2015-05-13T00:04:05Z", 1431475445000),
+            ("2016-02-29T23:59:59Z", 1456790399000),
+        ],
+        "epoch_millis" => {
+            test_vectors![("1431475445123", 1431475445123), ("0", 0), ("-1500", -1500)]
+        }
+        "epoch_second" => test_vectors![("1431475445", 1431475445000), ("0", 0), ("-2", -2000)],
+        "hour" | "strict_hour" => test_vectors![("00", 0), ("23", 82800000)],
+        "hour_minute" | "strict_hour_minute" => {
+            test_vectors![("00:04", 240000), ("23:59", 86340000)]
+        }
+        "hour_minute_second" | "strict_hour_minute_second" => {
+            test_vectors![("00:04:05", 245000), ("23:59:59", 86399000)]
+        }
+        "hour_minute_second_fraction"
+        | "hour_minute_second_millis"
+        | "strict_hour_minute_second_fraction"
+        | "strict_hour_minute_second_millis" => {
+            test_vectors![("00:04:05.123", 245123), ("23:59:59.999", 86399999)]
+        }
+        "ordinal_date" | "strict_ordinal_date" => {
+            test_vectors![("2015-133", 1431475200000), ("2016-060", 1456704000000)]
+        }
+        "ordinal_date_time" | "strict_ordinal_date_time" => test_vectors![
+            ("2015-133T00:04:05.123Z", 1431475445123),
+            ("2016-060T23:59:59.999Z", 1456790399999),
+        ],
+        "ordinal_date_time_no_millis" | "strict_ordinal_date_time_no_millis" => test_vectors![
+            ("2015-133T00:04:05Z", 1431475445000),
+            ("2016-060T23:59:59Z", 1456790399000),
+        ],
+        "strict_t_time" | "t_time" => {
+            test_vectors![("T00:04:05.123Z", 245123), ("T23:59:59.999Z", 86399999)]
+        }
+        "strict_t_time_no_millis" | "t_time_no_millis" => {
+            test_vectors![("T00:04:05Z", 245000), ("T23:59:59Z", 86399000)]
+        }
+        "strict_time" | "time" => {
+            test_vectors![("00:04:05.123Z", 245123), ("23:59:59.999Z", 86399999)]
+        }
+        "strict_time_no_millis" | "time_no_millis" => {
+            test_vectors![("00:04:05Z", 245000), ("23:59:59Z", 86399000)]
+        }
+        "strict_week_date" | "strict_weekyear_week_day" | "week_date" | "weekyear_week_day" => {
+            test_vectors![("2015-W20-3", 1431475200000), ("2016-W09-1", 1456704000000)]
+        }
+        "strict_week_date_time" | "week_date_time" => test_vectors![
+            ("2015-W20-3T00:04:05.123Z", 1431475445123),
+            ("2016-W09-1T23:59:59.999Z", 1456790399999),
+        ],
+        "strict_week_date_time_no_millis" | "week_date_time_no_millis" => test_vectors![
+            ("2015-W20-3T00:04:05Z", 1431475445000),
+            ("2016-W09-1T23:59:59Z", 1456790399000),
+        ],
+        "strict_weekyear" | "weekyear" => {
+            test_vectors![("2015", 1419811200000), ("2016", 1451865600000)]
+        }
+        "strict_weekyear_week" | "weekyear_week" => {
+            test_vectors![("2015-W20", 1431302400000), ("2016-W09", 1456704000000)]
+        }
+        "strict_year" | "year" => test_vectors![("2015", 1420070400000), ("2016", 1451606400000)],
+        "strict_year_month" | "year_month" => {
+            test_vectors![("2015-05", 1430438400000), ("2016-02", 1454284800000)]
+        }
+        _ => &[],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::prelude::*;
+
+    macro_rules! check_built_in_formats {
+        ($($format:ty),*) => {
+            $(
+                let vectors = <$format>::test_vectors();
+
+                assert!(!vectors.is_empty(), "no test vectors for `{}`", <$format>::name());
+
+                if let Err(err) = check_test_vectors::<$format>(vectors) {
+                    panic!("{}", err);
+                }
+            )*
+        };
+    }
+
+    #[test]
+    fn built_in_formats_pass_test_vectors() {
+        check_built_in_formats!(
+            BasicDate,
+            BasicDateTime,
+            BasicDateTimeNoMillis,
+            BasicOrdinalDate,
+            BasicOrdinalDateTime,
+            BasicOrdinalDateTimeNoMillis,
+            BasicTTime,
+            BasicTTimeNoMillis,
+            BasicTime,
+            BasicTimeNoMillis,
+            BasicWeekDate,
+            BasicWeekDateTime,
+            BasicWeekDateTimeNoMillis,
+            DateHour,
+            DateHourMinute,
+            DateHourMinuteSecond,
+            DateHourMinuteSecondFraction,
+            DateHourMinuteSecondMillis,
+            DateOptionalTime,
+            DateTimeNoMillis,
+            EpochMillis,
+            EpochSecond,
+            FullDate,
+            FullDateTime,
+            Hour,
+            HourMinute,
+            HourMinuteSecond,
+            HourMinuteSecondFraction,
+            HourMinuteSecondMillis,
+            OrdinalDate,
+            OrdinalDateTime,
+            OrdinalDateTimeNoMillis,
+            StrictBasicWeekDate,
+            StrictBasicWeekDateTime,
+            StrictBasicWeekDateTimeNoMillis,
+            StrictDate,
+            StrictDateHour,
+            StrictDateHourMinute,
+            StrictDateHourMinuteSecond,
+            StrictDateHourMinuteSecondFraction,
+            StrictDateHourMinuteSecondMillis,
+            StrictDateOptionalTime,
+            StrictDateTime,
+            StrictDateTimeNoMillis,
+            StrictHour,
+            StrictHourMinute,
+            StrictHourMinuteSecond,
+            StrictHourMinuteSecondFraction,
+            StrictHourMinuteSecondMillis,
+            StrictOrdinalDate,
+            StrictOrdinalDateTime,
+            StrictOrdinalDateTimeNoMillis,
+            StrictTTime,
+            StrictTTimeNoMillis,
+            StrictTime,
+            StrictTimeNoMillis,
+            StrictWeekDate,
+            StrictWeekDateTime,
+            StrictWeekDateTimeNoMillis,
+            StrictWeekyear,
+            StrictWeekyearWeek,
+            StrictWeekyearWeekDay,
+            StrictYear,
+            StrictYearMonth,
+            StrictYearMonthDay,
+            TTime,
+            TTimeNoMillis,
+            Time,
+            TimeNoMillis,
+            WeekDate,
+            WeekDateTime,
+            WeekDateTimeNoMillis,
+            Weekyear,
+            WeekyearWeek,
+            WeekyearWeekDay,
+            Year,
+            YearMonth,
+            YearMonthDay
+        );
+    }
+
+    #[test]
+    fn custom_formats_have_no_test_vectors() {
+        assert!(ChronoFormat::test_vectors().is_empty());
+    }
+
+    #[test]
+    fn check_failing_test_vectors() {
+        let unparseable = check_test_vectors::<BasicDate>(&[DateFormatTestVector::new(
+            "2015-05-13",
+            1431475200000,
+        )]);
+
+        let wrong_date = check_test_vectors::<StrictDate>(&[DateFormatTestVector::new(
+            "2015-05-13",
+            1431475445123,
+        )]);
+
+        let wrong_format = check_test_vectors::<DateOptionalTime>(&[DateFormatTestVector::new(
+            "2015-05-13",
+            1431475200000,
+        )]);
+
+        match unparseable {
+            Err(DateFormatTestVectorError::Parse { format, .. }) => {
+                assert_eq!("basic_date", format)
+            }
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+
+        assert_eq!(
+            Err(DateFormatTestVectorError::WrongDate {
+                format: "strict_date",
+                formatted: "2015-05-13",
+                expected: 1431475445123,
+                actual: 1431475200000,
+            }),
+            wrong_date
+        );
+
+        assert_eq!(
+            Err(DateFormatTestVectorError::WrongFormat {
+                format: "date_optional_time",
+                millis: 1431475200000,
+                expected: "2015-05-13",
+                actual: "2015-05-13T00:00:00.000Z".to_owned(),
+            }),
+            wrong_format
+        );
+    }
+}
@@ -0,0 +1,374 @@
+/*!
+Dynamic templates for document mappings.
+
+Dynamic templates define custom mappings for fields that are added dynamically, based on rules that match
+the field's name, path or detected datatype.
+
+# Examples
+
+Map all new string fields as a `keyword` instead of `text`:
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# #[macro_use] extern crate serde_json;
+# use elastic::types::prelude::*;
+#[derive(Serialize, ElasticType)]
+#[elastic(mapping = "MyTypeMapping")]
+struct MyType {
+    pub title: String,
+}
+
+#[derive(Default)]
+struct MyTypeMapping;
+impl ObjectMapping for MyTypeMapping {
+    type Properties = MyType;
+
+    fn dynamic_templates() -> Option<Vec<DynamicTemplate>> {
+        Some(vec![
+            DynamicTemplate::new("strings_as_keywords")
+                .match_mapping_type(MatchMappingType::String)
+                .mapping(DefaultKeywordMapping),
+        ])
+    }
+}
+# fn main() {
+# let mapping = serde_json::to_value(&MyType::index_mapping()).unwrap();
+# let json = json!(
+{
+    "dynamic_templates": [
+        {
+            "strings_as_keywords": {
+                "match_mapping_type": "string",
+                "mapping": {
+                    "type": "keyword"
+                }
+            }
+        }
+    ],
+    "properties": {
+        "title": {
+            "type": "text",
+            "fields": {
+                "keyword": {
+                    "type": "keyword",
+                    "ignore_above": 256
+                }
+            }
+        }
+    }
+}
+# );
+# assert_eq!(json, mapping);
+# }
+```
+*/
+
+use serde::ser::{
+    Error as SerError,
+    Serialize,
+    SerializeMap,
+    SerializeStruct,
+    Serializer,
+};
+use serde_json::{
+    self,
+    Value,
+};
+
+use super::mapping::MapFieldType;
+use crate::types::private::field::{
+    FieldMapping,
+    FieldType,
+    SerializeFieldMapping,
+};
+
+/**
+The datatype detected by Elasticsearch for a dynamically added field.
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MatchMappingType {
+    /** Match any detected datatype. */
+    All,
+    /** `true` or `false`. */
+    Boolean,
+    /** A string that passes date detection. */
+    Date,
+    /** A number with a fractional part. */
+    Double,
+    /** A number without a fractional part. */
+    Long,
+    /** A JSON object. */
+    Object,
+    /** A string that doesn't pass date or numeric detection. */
+    String,
+}
+
+impl Serialize for MatchMappingType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(match *self {
+            MatchMappingType::All => "*",
+            MatchMappingType::Boolean => "boolean",
+            MatchMappingType::Date => "date",
+            MatchMappingType::Double => "double",
+            MatchMappingType::Long => "long",
+            MatchMappingType::Object => "object",
+            MatchMappingType::String => "string",
+        })
+    }
+}
+
+/**
+A named rule that maps dynamically added fields.
+
+A template matches a field when all of its rules match.
+The field is then mapped using the template's field mapping.
+*/
+#[derive(Clone)]
+pub struct DynamicTemplate {
+    name: &'static str,
+    match_mapping_type: Option<MatchMappingType>,
+    match_name: Option<&'static str>,
+    unmatch: Option<&'static str>,
+    path_match: Option<&'static str>,
+    path_unmatch: Option<&'static str>,
+    mapping: Option<fn() -> Result<Value, serde_json::Error>>,
+}
+
+impl DynamicTemplate {
+    /**
+    Create a new dynamic template with the given name.
+
+    The template won't match any fields until a field mapping is given with [`mapping`][mapping].
+
+    [mapping]: #method.mapping
+    */
+    pub fn new(name: &'static str) -> Self {
+        DynamicTemplate {
+            name,
+            match_mapping_type: None,
+            match_name: None,
+            unmatch: None,
+            path_match: None,
+            path_unmatch: None,
+            mapping: None,
+        }
+    }
+
+    /** Match fields with the given detected datatype. */
+    pub fn match_mapping_type(mut self, match_mapping_type: MatchMappingType) -> Self {
+        self.match_mapping_type = Some(match_mapping_type);
+        self
+    }
+
+    /** Match fields with a name that matches the given pattern, like `long_*`. */
+    pub fn match_name(mut self, pattern: &'static str) -> Self {
+        self.match_name = Some(pattern);
+        self
+    }
+
+    /** Exclude fields with a name that matches the given pattern, like `*_text`. */
+    pub fn unmatch(mut self, pattern: &'static str) -> Self {
+        self.unmatch = Some(pattern);
+        self
+    }
+
+    /** Match fields with a full dotted path that matches the given pattern, like `name.*`. */
+    pub fn path_match(mut self, pattern: &'static str) -> Self {
+        self.path_match = Some(pattern);
+        self
+    }
+
+    /** Exclude fields with a full dotted path that matches the given pattern, like `*.middle`. */
+    pub fn path_unmatch(mut self, pattern: &'static str) -> Self {
+        self.path_unmatch = Some(pattern);
+        self
+    }
+
+    /** Map matching fields using the given field mapping, like `DefaultKeywordMapping`. */
+    pub fn mapping<TMapping, TPivot>(mut self, _: TMapping) -> Self
+    where
+        TMapping: FieldMapping<TPivot>,
+        SerializeFieldMapping<TMapping, TPivot>: Serialize,
+    {
+        self.mapping = Some(serialize_mapping::<TMapping, TPivot>);
+        self
+    }
+
+    /**
+    Map matching fields using the mapping of the values in a map field, like `HashMap<String, Keyword<M>>`.
+
+    Maps are mapped as a dynamic `object`, so the mapping of their values only applies to new keys through a template.
+    Use [`path_match`][path_match] with the name of the map field to match its keys.
+    Derived document types can add this template for a field with the `#[elastic(map_values)]` attribute instead.
+
+    # Examples
+
+    ```
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # use std::collections::HashMap;
+    # use elastic::types::prelude::*;
+    type Labels = HashMap<String, Keyword<DefaultKeywordMapping>>;
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(mapping = "MyTypeMapping")]
+    struct MyType {
+        pub labels: Labels,
+    }
+
+    #[derive(Default)]
+    struct MyTypeMapping;
+    impl ObjectMapping for MyTypeMapping {
+        type Properties = MyType;
+
+        fn dynamic_templates() -> Option<Vec<DynamicTemplate>> {
+            Some(vec![
+                DynamicTemplate::new("labels")
+                    .path_match("labels.*")
+                    .map_values::<Labels, _, _>(),
+            ])
+        }
+    }
+    # fn main() {}
+    ```
+
+    [path_match]: #method.path_match
+    */
+    pub fn map_values<TField, TMapping, TPivot>(mut self) -> Self
+    where
+        TField: MapFieldType,
+        TField::Value: FieldType<TMapping, TPivot>,
+        TMapping: FieldMapping<TPivot>,
+        SerializeFieldMapping<TMapping, TPivot>: Serialize,
+    {
+        self.mapping = Some(serialize_mapping::<TMapping, TPivot>);
+        self
+    }
+}
+
+fn serialize_mapping<TMapping, TPivot>() -> Result<Value, serde_json::Error>
+where
+    TMapping: FieldMapping<TPivot>,
+    SerializeFieldMapping<TMapping, TPivot>: Serialize,
+{
+    serde_json::to_value(&SerializeFieldMapping::<TMapping, TPivot>::default())
+}
+
+struct DynamicTemplateRules<'a>(&'a DynamicTemplate);
+
+impl<'a> Serialize for DynamicTemplateRules<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let template = self.0;
+        let mapping = match template.mapping {
+            Some(mapping) => Some(mapping().map_err(S::Error::custom)?),
+            None => None,
+        };
+
+        let mut state = serializer.serialize_struct("dynamic_template", 6)?;
+
+        ser_field!(state, "match_mapping_type", template.match_mapping_type);
+        ser_field!(state, "match", template.match_name);
+        ser_field!(state, "unmatch", template.unmatch);
+        ser_field!(state, "path_match", template.path_match);
+        ser_field!(state, "path_unmatch", template.path_unmatch);
+        ser_field!(state, "mapping", mapping);
+
+        state.end()
+    }
+}
+
+impl Serialize for DynamicTemplate {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(Some(1))?;
+
+        state.serialize_entry(self.name, &DynamicTemplateRules(self))?;
+
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+    use std::collections::HashMap;
+
+    use crate::types::prelude::*;
+
+    #[test]
+    fn serialise_dynamic_template() {
+        let template = DynamicTemplate::new("longs_as_strings")
+            .match_mapping_type(MatchMappingType::String)
+            .match_name("long_*")
+            .unmatch("*_text")
+            .mapping(DefaultLongMapping);
+
+        let ser = serde_json::to_value(&template).unwrap();
+
+        let expected = json!({
+            "longs_as_strings": {
+                "match_mapping_type": "string",
+                "match": "long_*",
+                "unmatch": "*_text",
+                "mapping": {
+                    "type": "long"
+                }
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_dynamic_template_map_values() {
+        let template = DynamicTemplate::new("labels")
+            .path_match("labels.*")
+            .map_values::<HashMap<String, Keyword<DefaultKeywordMapping>>, _, _>();
+
+        let ser = serde_json::to_value(&template).unwrap();
+
+        let expected = json!({
+            "labels": {
+                "path_match": "labels.*",
+                "mapping": {
+                    "type": "keyword"
+                }
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_dynamic_template_path_match() {
+        let template = DynamicTemplate::new("full_name")
+            .path_match("name.*")
+            .path_unmatch("*.middle")
+            .match_mapping_type(MatchMappingType::All)
+            .mapping(DefaultKeywordMapping);
+
+        let ser = serde_json::to_value(&template).unwrap();
+
+        let expected = json!({
+            "full_name": {
+                "match_mapping_type": "*",
+                "path_match": "name.*",
+                "path_unmatch": "*.middle",
+                "mapping": {
+                    "type": "keyword"
+                }
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+}
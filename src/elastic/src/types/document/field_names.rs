@@ -0,0 +1,77 @@
+/**
+A document type with a generated `{Type}Fields` struct for the names of its fields.
+
+This trait is implemented by `#[derive(ElasticType)]` for types with an `#[elastic(field_names)]` attribute.
+The `{Type}Fields` struct has a constant for the name of each field,
+and a method for the path of each field from the root document.
+Fields that are objects with their own field names return the `{Type}Fields` of the object,
+so paths like `location.city` can be built without string literals.
+
+# Examples
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# use elastic::types::prelude::*;
+#[derive(Serialize, ElasticType)]
+#[elastic(data_type = "object", field_names)]
+pub struct Location {
+    pub city: String,
+}
+
+#[derive(Serialize, ElasticType)]
+#[elastic(field_names)]
+pub struct MyType {
+    #[elastic(field_names)]
+    pub location: Location,
+}
+
+# fn main() {
+assert_eq!("location.city", MyTypeFields::default().location().city());
+# }
+```
+*/
+pub trait FieldNames {
+    /** The type with the names of the fields. */
+    type Fields;
+
+    /**
+    Get the names of the fields at the given path.
+
+    The root document has an empty path.
+    */
+    fn fields_at(path: &str) -> Self::Fields;
+}
+
+impl<T> FieldNames for Option<T>
+where
+    T: FieldNames,
+{
+    type Fields = T::Fields;
+
+    fn fields_at(path: &str) -> Self::Fields {
+        T::fields_at(path)
+    }
+}
+
+impl<T> FieldNames for Vec<T>
+where
+    T: FieldNames,
+{
+    type Fields = T::Fields;
+
+    fn fields_at(path: &str) -> Self::Fields {
+        T::fields_at(path)
+    }
+}
+
+impl<T> FieldNames for Box<T>
+where
+    T: FieldNames,
+{
+    type Fields = T::Fields;
+
+    fn fields_at(path: &str) -> Self::Fields {
+        T::fields_at(path)
+    }
+}
@@ -1,13 +1,37 @@
 use super::mapping::{
+    Dynamic,
     ObjectFieldType,
     ObjectMapping,
     PropertiesMapping,
+    OBJECT_DATATYPE,
+};
+use crate::types::mappers::{
+    self,
+    MappingHash,
+};
+use serde::{
+    de::{
+        DeserializeOwned,
+        Error as DeError,
+    },
+    ser::SerializeStruct,
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+};
+use serde_json::{
+    self,
+    Map,
+    Value,
 };
-use serde::ser::SerializeStruct;
-use serde_json::Value;
 use std::{
     borrow::Cow,
     marker::PhantomData,
+    ops::{
+        Deref,
+        DerefMut,
+    },
 };
 
 #[doc(inline)]
@@ -39,6 +63,20 @@ pub trait DocumentType: ObjectFieldType {
         IndexDocumentMapping::default()
     }
 
+    /**
+    Get a fingerprint of the type mapping.
+
+    See [`mappers::mapping_hash`][mapping_hash] for details.
+
+    [mapping_hash]: ../mappers/fn.mapping_hash.html
+    */
+    fn mapping_hash() -> Result<MappingHash, serde_json::Error>
+    where
+        Self: Sized,
+    {
+        mappers::mapping_hash::<Self>()
+    }
+
     /** Get the name of the index this document belongs to. */
     fn index(&self) -> Index;
 
@@ -53,6 +91,77 @@ pub trait DocumentType: ObjectFieldType {
 
     /** Try get a statically known type this document belongs to. */
     fn partial_static_ty() -> Option<Type<'static>>;
+
+    /**
+    Run the [`BeforeIndex`][BeforeIndex] hook for this document, if it has one.
+
+    This method is called by the typed document APIs before a document is serialised.
+    Derived documents call their `BeforeIndex` implementation when they're annotated with `#[elastic(before_index)]`.
+
+    [BeforeIndex]: trait.BeforeIndex.html
+    */
+    fn before_index_hook(&mut self) {}
+
+    /**
+    Fill fields in the `_source` of this document that are missing or `null` with the `null_value` from their mapping.
+
+    This method is called when a document is deserialised as a [`WithNullValues`][WithNullValues].
+    Derived documents fill the fields that have a `#[elastic(default = "expression")]` attribute.
+
+    [WithNullValues]: struct.WithNullValues.html
+    */
+    fn fill_null_values(_: &mut Map<String, Value>) -> Result<(), serde_json::Error> {
+        Ok(())
+    }
+}
+
+/**
+A hook that can change a document before it's indexed.
+
+The hook is run by the typed document APIs, like `client.document().index(doc)`,
+and typed bulk `index`, `create` and `update` operations, including updates with `doc_as_upsert`.
+It's useful for stamping an `updated_at` field, computing derived fields, or redacting sensitive values
+in one place instead of at every call site.
+
+Raw requests and partial documents, like a `serde_json::Value` given to `update().doc(..)`, aren't passed through the hook.
+Borrowed documents can't be changed, so the hook isn't run for a `&TDocument`.
+A `Cow<TDocument>` is cloned if it needs to be changed.
+
+# Examples
+
+Derive `ElasticType` with a `#[elastic(before_index)]` attribute to run the hook:
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# use elastic::types::prelude::*;
+#[derive(Serialize, ElasticType)]
+#[elastic(before_index)]
+pub struct MyType {
+    pub title: String,
+    pub title_lowercase: String,
+}
+
+impl BeforeIndex for MyType {
+    fn before_index(&mut self) {
+        self.title_lowercase = self.title.to_lowercase();
+    }
+}
+# fn main() {
+let mut doc = MyType {
+    title: "Title".to_owned(),
+    title_lowercase: String::new(),
+};
+
+doc.before_index_hook();
+
+assert_eq!("title", doc.title_lowercase);
+# }
+```
+*/
+pub trait BeforeIndex {
+    /** Change the document before it's serialised. */
+    fn before_index(&mut self);
 }
 
 /**
@@ -136,6 +245,104 @@ impl ObjectFieldType for Value {
     type Mapping = ValueObjectMapping;
 }
 
+/**
+A semi-structured section of a document that's mapped as a dynamic `object`.
+
+A `serde_json::Value` field is mapped as a `nested` document without any properties.
+A `DynamicObject` is mapped as an `object` with `dynamic` set to `true` instead,
+so Elasticsearch adds mappings for new properties in the object as they're indexed.
+It's serialised and deserialised as the json object it wraps.
+
+# Examples
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# #[macro_use] extern crate serde_json;
+# use elastic::types::prelude::*;
+#[derive(Serialize, Deserialize, ElasticType)]
+pub struct MyType {
+    pub id: i32,
+    pub payload: DynamicObject,
+}
+
+# fn main() {
+# let mapping = serde_json::to_value(&MyType::index_mapping()).unwrap();
+# let expected = json!({
+#     "properties": {
+#         "id": { "type": "integer" },
+#         "payload": { "type": "object", "dynamic": true }
+#     }
+# });
+# assert_eq!(expected, mapping);
+let mut payload = DynamicObject::new();
+payload.insert("source".to_owned(), json!("webhook"));
+
+let doc = MyType { id: 1, payload };
+# }
+```
+*/
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DynamicObject(Map<String, Value>);
+
+impl DynamicObject {
+    /** Create an empty object. */
+    pub fn new() -> Self {
+        DynamicObject::default()
+    }
+
+    /** Get the inner json object. */
+    pub fn into_inner(self) -> Map<String, Value> {
+        self.0
+    }
+}
+
+impl From<Map<String, Value>> for DynamicObject {
+    fn from(object: Map<String, Value>) -> Self {
+        DynamicObject(object)
+    }
+}
+
+impl From<DynamicObject> for Value {
+    fn from(object: DynamicObject) -> Self {
+        Value::Object(object.0)
+    }
+}
+
+impl Deref for DynamicObject {
+    type Target = Map<String, Value>;
+
+    fn deref(&self) -> &Map<String, Value> {
+        &self.0
+    }
+}
+
+impl DerefMut for DynamicObject {
+    fn deref_mut(&mut self) -> &mut Map<String, Value> {
+        &mut self.0
+    }
+}
+
+/** Mapping for a [`DynamicObject`](struct.DynamicObject.html). */
+#[derive(Default)]
+pub struct DynamicObjectMapping;
+
+impl ObjectMapping for DynamicObjectMapping {
+    type Properties = EmptyPropertiesMapping;
+
+    fn data_type() -> &'static str {
+        OBJECT_DATATYPE
+    }
+
+    fn dynamic() -> Option<Dynamic> {
+        Some(Dynamic::True)
+    }
+}
+
+impl ObjectFieldType for DynamicObject {
+    type Mapping = DynamicObjectMapping;
+}
+
 /** Mapping for an anonymous json object. */
 #[derive(Default)]
 pub struct EmptyPropertiesMapping;
@@ -184,6 +391,10 @@ where
     fn partial_static_ty() -> Option<Type<'static>> {
         TDocument::partial_static_ty()
     }
+
+    fn fill_null_values(source: &mut Map<String, Value>) -> Result<(), serde_json::Error> {
+        TDocument::fill_null_values(source)
+    }
 }
 
 impl<'a, TObject, TMapping> ObjectFieldType for Cow<'a, TObject>
@@ -217,6 +428,14 @@ where
     fn partial_static_ty() -> Option<Type<'static>> {
         TDocument::partial_static_ty()
     }
+
+    fn before_index_hook(&mut self) {
+        self.to_mut().before_index_hook()
+    }
+
+    fn fill_null_values(source: &mut Map<String, Value>) -> Result<(), serde_json::Error> {
+        TDocument::fill_null_values(source)
+    }
 }
 
 impl<'a, TDocument> StaticIndex for &'a TDocument
@@ -255,10 +474,166 @@ where
     }
 }
 
+/**
+A document that fills fields missing from its `_source` with the `null_value` from their mapping when it's deserialised.
+
+Elasticsearch indexes a field with a `null_value` as that value when the field is `null`,
+but the `_source` that's returned in a hit is left unchanged.
+Deserialising a document as `WithNullValues` fills those fields in the same way before the document itself is deserialised,
+so the document that's read back matches the one that was indexed.
+Fields are only filled if they're at the top-level of the document.
+
+Filling fields is opt-in because it deserialises the `_source` into a `serde_json::Value` first.
+
+# Examples
+
+Search for documents and fill their `null_value`s:
+
+```no_run
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# use elastic::prelude::*;
+# fn main() { run().unwrap() }
+# fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+#[derive(Serialize, Deserialize, ElasticType)]
+pub struct MyType {
+    #[elastic(default = "Keyword::new(\"unknown\")")]
+    pub status: Keyword<DefaultKeywordMapping>,
+}
+
+# let client = SyncClientBuilder::new().build()?;
+let response = client
+    .document::<WithNullValues<MyType>>()
+    .search()
+    .send()?;
+
+for doc in response.documents() {
+    println!("{:?}", doc.status);
+}
+# Ok(())
+# }
+```
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct WithNullValues<TDocument>(pub TDocument);
+
+impl<TDocument> WithNullValues<TDocument> {
+    /** Get the inner document. */
+    pub fn into_inner(self) -> TDocument {
+        self.0
+    }
+}
+
+impl<TDocument> Deref for WithNullValues<TDocument> {
+    type Target = TDocument;
+
+    fn deref(&self) -> &TDocument {
+        &self.0
+    }
+}
+
+impl<TDocument> DerefMut for WithNullValues<TDocument> {
+    fn deref_mut(&mut self) -> &mut TDocument {
+        &mut self.0
+    }
+}
+
+impl<TDocument> Serialize for WithNullValues<TDocument>
+where
+    TDocument: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de, TDocument> Deserialize<'de> for WithNullValues<TDocument>
+where
+    TDocument: DocumentType + DeserializeOwned,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let mut source = Value::deserialize(deserializer)?;
+
+        if let Value::Object(ref mut fields) = source {
+            TDocument::fill_null_values(fields).map_err(D::Error::custom)?;
+        }
+
+        serde_json::from_value(source)
+            .map(WithNullValues)
+            .map_err(D::Error::custom)
+    }
+}
+
+impl<TObject, TMapping> ObjectFieldType for WithNullValues<TObject>
+where
+    TObject: ObjectFieldType<Mapping = TMapping>,
+    TMapping: ObjectMapping,
+{
+    type Mapping = TMapping;
+}
+
+impl<TDocument> DocumentType for WithNullValues<TDocument>
+where
+    TDocument: DocumentType,
+{
+    fn index(&self) -> Index {
+        self.0.index()
+    }
+
+    fn ty(&self) -> Type {
+        self.0.ty()
+    }
+
+    fn partial_id(&self) -> Option<Id> {
+        self.0.partial_id()
+    }
+
+    fn partial_static_index() -> Option<Index<'static>> {
+        TDocument::partial_static_index()
+    }
+
+    fn partial_static_ty() -> Option<Type<'static>> {
+        TDocument::partial_static_ty()
+    }
+
+    fn before_index_hook(&mut self) {
+        self.0.before_index_hook()
+    }
+
+    fn fill_null_values(source: &mut Map<String, Value>) -> Result<(), serde_json::Error> {
+        TDocument::fill_null_values(source)
+    }
+}
+
+impl<TDocument> StaticIndex for WithNullValues<TDocument>
+where
+    TDocument: StaticIndex,
+{
+    fn static_index() -> Index<'static> {
+        TDocument::static_index()
+    }
+}
+
+impl<TDocument> StaticType for WithNullValues<TDocument>
+where
+    TDocument: StaticType,
+{
+    fn static_ty() -> Type<'static> {
+        TDocument::static_ty()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{
         DocumentType,
+        FieldDocumentMapping,
         IndexDocumentMapping,
         StaticIndex,
         StaticType,
@@ -270,7 +645,13 @@ mod tests {
     };
     use std::{
         borrow::Cow,
-        collections::HashSet,
+        collections::{
+            BTreeMap,
+            HashMap,
+            HashSet,
+        },
+        rc::Rc,
+        sync::Arc,
     };
 
     // Make sure we can derive with no `uses`.
@@ -305,14 +686,15 @@ mod tests {
     }
 
     #[derive(Clone, Serialize, ElasticType)]
-    #[elastic(crate_root = "crate::types")]
+    #[elastic(crate_root = "crate::types", field_names)]
     pub struct SimpleType {
         pub field1: Date<DefaultDateMapping<EpochMillis>>,
+        #[elastic(field_names)]
         pub field2: SimpleNestedType,
     }
 
     #[derive(Clone, Serialize, ElasticType)]
-    #[elastic(crate_root = "crate::types")]
+    #[elastic(crate_root = "crate::types", field_names)]
     pub struct SimpleNestedType {
         pub field: i32,
     }
@@ -339,12 +721,36 @@ mod tests {
         }
     }
 
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    #[elastic(index = "articles", id = "article_id")]
+    pub struct NamedIdType {
+        pub article_id: String,
+        pub title: String,
+    }
+
     #[derive(PartialEq, Debug, Default)]
     pub struct ManualCustomTypeMapping;
     impl ObjectMapping for ManualCustomTypeMapping {
         type Properties = CustomType;
     }
 
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct UnsortedType {
+        pub zebra: i32,
+        pub apple: bool,
+        pub mango: SimpleNestedType,
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types", data_type = "object", recursion_limit = 3)]
+    pub struct RecursiveType {
+        pub id: i32,
+        pub children: Vec<RecursiveType>,
+        pub parent: Option<Box<RecursiveType>>,
+    }
+
     #[derive(Serialize, ElasticType)]
     #[elastic(crate_root = "crate::types")]
     pub struct Wrapped {
@@ -357,10 +763,178 @@ mod tests {
         pub field7: HashSet<String>,
     }
 
+    #[derive(Serialize, Deserialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct DynamicPayload {
+        pub field1: i32,
+        pub field2: DynamicObject,
+        pub field3: Option<DynamicObject>,
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct Arrays {
+        pub field1: [f32; 3],
+        pub field2: &'static [Keyword<DefaultKeywordMapping>],
+        pub field3: Option<[SimpleNestedType; 2]>,
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct Pointers {
+        pub field1: Box<i32>,
+        pub field2: Rc<Keyword<DefaultKeywordMapping>>,
+        pub field3: Arc<str>,
+        pub field4: Cow<'static, str>,
+        pub field5: Box<SimpleNestedType>,
+        pub field6: Option<Arc<Vec<bool>>>,
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct Maps {
+        pub field1: HashMap<String, Keyword<DefaultKeywordMapping>>,
+        pub field2: BTreeMap<String, i32>,
+        pub field3: Option<HashMap<String, SimpleNestedType>>,
+        pub field4: HashMap<String, Value>,
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct MapValues {
+        #[elastic(map_values)]
+        pub field1: HashMap<String, Keyword<DefaultKeywordMapping>>,
+        #[elastic(map_values)]
+        pub field2: Option<BTreeMap<String, i32>>,
+        pub field3: HashMap<String, Value>,
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    #[serde(rename_all = "lowercase")]
+    pub enum Status {
+        Active,
+        #[serde(rename = "on_hold")]
+        OnHold,
+        Closed,
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct WithEnum {
+        pub status: Status,
+        pub previous: Vec<Status>,
+    }
+
     #[derive(Serialize, ElasticType)]
     #[elastic(crate_root = "crate::types")]
     pub struct NoProps {}
 
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types", data_type = "object")]
+    pub struct ObjectType {
+        pub field: i32,
+    }
+
+    #[derive(Serialize, Deserialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct DefaultValueType {
+        #[elastic(default = "Keyword::new(\"unknown\")")]
+        pub status: Keyword<DefaultKeywordMapping>,
+        #[elastic(default = "Some(-1)")]
+        pub count: Option<i32>,
+        pub field: i32,
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types", field_names)]
+    pub struct RenamedFieldsType {
+        #[serde(rename = "serde_renamed")]
+        pub field1: i32,
+        #[elastic(rename = "elastic_renamed")]
+        pub field2: i32,
+        #[serde(rename = "serde_renamed_too")]
+        #[elastic(rename = "elastic_renamed_too")]
+        pub field3: i32,
+        #[elastic(skip)]
+        pub skipped: i32,
+        #[serde(skip_serializing)]
+        pub skipped_too: i32,
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types", field_names)]
+    #[serde(rename_all = "camelCase")]
+    pub struct CamelCaseType {
+        pub first_name: String,
+        #[serde(rename = "surname")]
+        pub last_name: String,
+        pub date_of_birth: Date<DefaultDateMapping>,
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct FieldMappingType {
+        #[elastic(mapping = "ContentMapping")]
+        pub content: String,
+        #[elastic(mapping = "CountMapping", default = "-1")]
+        pub count: i32,
+        pub title: String,
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct StringAttributesType {
+        #[elastic(ignore_above = 512)]
+        pub title: String,
+        #[elastic(keyword)]
+        pub tag: String,
+        #[elastic(keyword, ignore_above = 64)]
+        pub code: Option<String>,
+        #[elastic(no_keyword)]
+        pub body: &'static str,
+    }
+
+    #[derive(Serialize, Deserialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct AliasType {
+        #[serde(rename = "title")]
+        pub headline: String,
+        #[serde(skip)]
+        #[elastic(rename = "headline", alias_of = "title")]
+        pub headline_alias: (),
+    }
+
+    #[derive(Default)]
+    pub struct ContentMapping;
+    impl TextMapping for ContentMapping {
+        fn analyzer() -> Option<&'static str> {
+            Some("english")
+        }
+    }
+
+    #[derive(Default)]
+    pub struct CountMapping;
+    impl IntegerMapping for CountMapping {
+        fn doc_values() -> Option<bool> {
+            Some(false)
+        }
+    }
+
+    #[derive(Default)]
+    pub struct NestedInParentMapping;
+    impl ObjectMapping for NestedInParentMapping {
+        type Properties = NoProps;
+
+        fn include_in_parent() -> Option<bool> {
+            Some(true)
+        }
+
+        fn include_in_root() -> Option<bool> {
+            Some(false)
+        }
+    }
+
     #[derive(Default, Serialize)]
     pub struct Index {
         mappings: Mappings,
@@ -414,6 +988,17 @@ mod tests {
         assert_eq!("13", doc.partial_id().unwrap());
     }
 
+    #[test]
+    fn get_named_field_id() {
+        let doc = NamedIdType {
+            article_id: "article-1".to_owned(),
+            title: "A title".to_owned(),
+        };
+
+        assert_eq!("articles", NamedIdType::static_index());
+        assert_eq!("article-1", doc.partial_id().unwrap());
+    }
+
     #[test]
     fn derive_custom_type_mapping() {
         assert_eq!(
@@ -465,28 +1050,41 @@ mod tests {
     }
 
     #[test]
-    fn serialise_document_with_no_props() {
-        let ser = serde_json::to_value(&NoProps::index_mapping()).unwrap();
+    fn serialise_mapping_properties_in_declaration_order() {
+        let ser = serde_json::to_string(&UnsortedType::index_mapping()).unwrap();
 
-        let expected = json!({
-            "properties": {}
-        });
+        let expected = r#"{"properties":{"zebra":{"type":"integer"},"apple":{"type":"boolean"},"mango":{"type":"nested","properties":{"field":{"type":"integer"}}}}}"#;
 
         assert_eq!(expected, ser);
     }
 
     #[test]
-    fn serialise_document_for_custom_mapping() {
-        let ser = serde_json::to_value(&CustomType::index_mapping()).unwrap();
+    fn serialise_mapping_with_recursion_limit() {
+        let ser = serde_json::to_value(&RecursiveType::index_mapping()).unwrap();
+
+        let leaf = json!({ "type": "object", "properties": {} });
+        let inner = json!({
+            "type": "object",
+            "properties": {
+                "id": { "type": "integer" },
+                "children": leaf.clone(),
+                "parent": leaf
+            }
+        });
+        let outer = json!({
+            "type": "object",
+            "properties": {
+                "id": { "type": "integer" },
+                "children": inner.clone(),
+                "parent": inner
+            }
+        });
 
         let expected = json!({
             "properties": {
-                "field": {
-                    "type": "integer"
-                },
-                "renamed_field": {
-                    "type": "integer"
-                }
+                "id": { "type": "integer" },
+                "children": outer.clone(),
+                "parent": outer
             }
         });
 
@@ -494,8 +1092,37 @@ mod tests {
     }
 
     #[test]
-    fn serialise_mapping_with_wrapped_types() {
-        let ser = serde_json::to_value(&Wrapped::index_mapping()).unwrap();
+    fn serialise_document_with_no_props() {
+        let ser = serde_json::to_value(&NoProps::index_mapping()).unwrap();
+
+        let expected = json!({
+            "properties": {}
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_document_for_custom_mapping() {
+        let ser = serde_json::to_value(&CustomType::index_mapping()).unwrap();
+
+        let expected = json!({
+            "properties": {
+                "field": {
+                    "type": "integer"
+                },
+                "renamed_field": {
+                    "type": "integer"
+                }
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_mapping_with_wrapped_types() {
+        let ser = serde_json::to_value(&Wrapped::index_mapping()).unwrap();
 
         let expected = json!({
             "properties": {
@@ -543,6 +1170,444 @@ mod tests {
         assert_eq!(expected, ser);
     }
 
+    #[test]
+    fn serialise_mapping_with_dynamic_objects() {
+        let ser = serde_json::to_value(&DynamicPayload::index_mapping()).unwrap();
+
+        let expected = json!({
+            "properties": {
+                "field1": {
+                    "type": "integer"
+                },
+                "field2": {
+                    "type": "object",
+                    "dynamic": true
+                },
+                "field3": {
+                    "type": "object",
+                    "dynamic": true
+                }
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_dynamic_object() {
+        let mut payload = DynamicObject::new();
+        payload.insert("a".to_owned(), json!({ "b": [1, 2] }));
+
+        let doc = DynamicPayload {
+            field1: 1,
+            field2: payload.clone(),
+            field3: None,
+        };
+
+        let ser = serde_json::to_value(&doc).unwrap();
+        assert_eq!(
+            json!({ "field1": 1, "field2": { "a": { "b": [1, 2] } }, "field3": null }),
+            ser
+        );
+
+        let de: DynamicPayload = serde_json::from_value(ser).unwrap();
+        assert_eq!(payload, de.field2);
+    }
+
+    #[test]
+    fn serialise_mapping_with_arrays_and_slices() {
+        let ser = serde_json::to_value(&Arrays::index_mapping()).unwrap();
+
+        let expected = json!({
+            "properties": {
+                "field1": {
+                    "type": "float"
+                },
+                "field2": {
+                    "type": "keyword"
+                },
+                "field3": {
+                    "type": "nested",
+                    "properties": {
+                        "field": {
+                            "type": "integer"
+                        }
+                    }
+                }
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_mapping_with_pointer_types() {
+        let ser = serde_json::to_value(&Pointers::index_mapping()).unwrap();
+
+        let expected = json!({
+            "properties": {
+                "field1": {
+                    "type": "integer"
+                },
+                "field2": {
+                    "type": "keyword"
+                },
+                "field3": {
+                    "type": "text",
+                    "fields": {
+                        "keyword":{
+                            "type": "keyword",
+                            "ignore_above": 256
+                        }
+                    }
+                },
+                "field4": {
+                    "type": "text",
+                    "fields": {
+                        "keyword":{
+                            "type": "keyword",
+                            "ignore_above": 256
+                        }
+                    }
+                },
+                "field5": {
+                    "type": "nested",
+                    "properties": {
+                        "field": {
+                            "type": "integer"
+                        }
+                    }
+                },
+                "field6": {
+                    "type": "boolean"
+                }
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_mapping_with_maps() {
+        let ser = serde_json::to_value(&Maps::index_mapping()).unwrap();
+
+        let expected = json!({
+            "properties": {
+                "field1": {
+                    "type": "object"
+                },
+                "field2": {
+                    "type": "object"
+                },
+                "field3": {
+                    "type": "object"
+                },
+                "field4": {
+                    "type": "object"
+                }
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_mapping_with_map_values() {
+        let ser = serde_json::to_value(&MapValues::index_mapping()).unwrap();
+
+        let expected = json!({
+            "dynamic_templates": [
+                {
+                    "field1": {
+                        "path_match": "field1.*",
+                        "mapping": {
+                            "type": "keyword"
+                        }
+                    }
+                },
+                {
+                    "field2": {
+                        "path_match": "field2.*",
+                        "mapping": {
+                            "type": "integer"
+                        }
+                    }
+                }
+            ],
+            "properties": {
+                "field1": {
+                    "type": "object"
+                },
+                "field2": {
+                    "type": "object"
+                },
+                "field3": {
+                    "type": "object"
+                }
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_mapping_with_enum() {
+        let ser = serde_json::to_value(&WithEnum::index_mapping()).unwrap();
+
+        let expected = json!({
+            "_meta": {
+                "values": {
+                    "status": ["active", "on_hold", "closed"],
+                    "previous": ["active", "on_hold", "closed"]
+                }
+            },
+            "properties": {
+                "status": {
+                    "type": "keyword"
+                },
+                "previous": {
+                    "type": "keyword"
+                }
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_object_field_from_attribute() {
+        let ser = serde_json::to_value(&ObjectType::field_mapping()).unwrap();
+
+        let expected = json!({
+            "type": "object",
+            "properties": {
+                "field": {
+                    "type": "integer"
+                }
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_field_with_default_value() {
+        let ser = serde_json::to_value(&IndexDocumentMapping::<DefaultValueTypeMapping>::default())
+            .unwrap();
+
+        let expected = json!({
+            "properties": {
+                "status": {
+                    "type": "keyword",
+                    "null_value": "unknown"
+                },
+                "count": {
+                    "type": "integer",
+                    "null_value": -1
+                },
+                "field": {
+                    "type": "integer"
+                }
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn get_default_value_for_field() {
+        assert_eq!("unknown", DefaultValueType::default_status());
+        assert_eq!(Some(-1), DefaultValueType::default_count());
+    }
+
+    #[test]
+    fn deserialise_with_null_values() {
+        let doc: WithNullValues<DefaultValueType> =
+            serde_json::from_value(json!({ "count": null, "field": 1 })).unwrap();
+
+        assert_eq!("unknown", doc.status);
+        assert_eq!(Some(-1), doc.count);
+        assert_eq!(1, doc.field);
+    }
+
+    #[test]
+    fn deserialise_with_null_values_keeps_present_fields() {
+        let doc: WithNullValues<DefaultValueType> =
+            serde_json::from_value(json!({ "status": "active", "count": 3, "field": 1 })).unwrap();
+
+        assert_eq!("active", doc.status);
+        assert_eq!(Some(3), doc.count);
+    }
+
+    #[test]
+    fn serialise_mapping_with_renamed_and_skipped_fields() {
+        let ser = serde_json::to_value(&RenamedFieldsType::index_mapping()).unwrap();
+
+        let expected = json!({
+            "properties": {
+                "serde_renamed": {
+                    "type": "integer"
+                },
+                "elastic_renamed": {
+                    "type": "integer"
+                },
+                "elastic_renamed_too": {
+                    "type": "integer"
+                }
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_mapping_with_rename_all() {
+        let ser = serde_json::to_value(&CamelCaseType::index_mapping()).unwrap();
+
+        let props = ser["properties"].as_object().unwrap();
+        let mut names: Vec<&str> = props.keys().map(String::as_str).collect();
+        names.sort();
+
+        assert_eq!(vec!["dateOfBirth", "firstName", "surname"], names);
+    }
+
+    #[test]
+    fn field_name_constants() {
+        assert_eq!(
+            ("serde_renamed", "elastic_renamed", "elastic_renamed_too"),
+            (
+                RenamedFieldsTypeFields::FIELD1,
+                RenamedFieldsTypeFields::FIELD2,
+                RenamedFieldsTypeFields::FIELD3
+            )
+        );
+        assert_eq!(
+            ("firstName", "surname"),
+            (
+                CamelCaseTypeFields::FIRST_NAME,
+                CamelCaseTypeFields::LAST_NAME
+            )
+        );
+        assert_eq!("field", SimpleNestedTypeFields::FIELD);
+    }
+
+    #[test]
+    fn field_name_paths() {
+        assert_eq!("field1", SimpleTypeFields::default().field1());
+        assert_eq!("field2.field", SimpleTypeFields::default().field2().field());
+        assert_eq!("serde_renamed", RenamedFieldsTypeFields::default().field1());
+        assert_eq!(
+            "outer.surname",
+            <CamelCaseType as FieldNames>::fields_at("outer").last_name()
+        );
+    }
+
+    #[test]
+    fn serialise_string_fields_with_attributes() {
+        let ser = serde_json::to_value(&StringAttributesType::index_mapping()).unwrap();
+
+        let expected = json!({
+            "properties": {
+                "title": {
+                    "type": "text",
+                    "fields": {
+                        "keyword": {
+                            "type": "keyword",
+                            "ignore_above": 512
+                        }
+                    }
+                },
+                "tag": {
+                    "type": "keyword"
+                },
+                "code": {
+                    "type": "keyword",
+                    "ignore_above": 64
+                },
+                "body": {
+                    "type": "text"
+                }
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_alias_field() {
+        let mapping = serde_json::to_value(&AliasType::index_mapping()).unwrap();
+        let doc = serde_json::to_value(&AliasType {
+            headline: "Ferris".to_owned(),
+            headline_alias: (),
+        })
+        .unwrap();
+
+        let expected = json!({
+            "properties": {
+                "title": {
+                    "type": "text",
+                    "fields": {
+                        "keyword": {
+                            "type": "keyword",
+                            "ignore_above": 256
+                        }
+                    }
+                },
+                "headline": {
+                    "type": "alias",
+                    "path": "title"
+                }
+            }
+        });
+
+        assert_eq!(expected, mapping);
+        assert_eq!(json!({ "title": "Ferris" }), doc);
+    }
+
+    #[test]
+    fn serialise_field_with_mapping_from_attribute() {
+        let ser = serde_json::to_value(&FieldMappingType::index_mapping()).unwrap();
+
+        let expected = json!({
+            "properties": {
+                "content": {
+                    "type": "text",
+                    "analyzer": "english"
+                },
+                "count": {
+                    "type": "integer",
+                    "doc_values": false,
+                    "null_value": -1
+                },
+                "title": {
+                    "type": "text",
+                    "fields": {
+                        "keyword": {
+                            "type": "keyword",
+                            "ignore_above": 256
+                        }
+                    }
+                }
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_nested_field_include_in_parent() {
+        let ser = serde_json::to_value(&FieldDocumentMapping::<NestedInParentMapping>::default())
+            .unwrap();
+
+        let expected = json!({
+            "type": "nested",
+            "include_in_parent": true,
+            "include_in_root": false
+        });
+
+        assert_eq!(expected, ser);
+    }
+
     #[test]
     fn serialise_index_mapping() {
         let ser = serde_json::to_value(&Index::default()).unwrap();
@@ -571,6 +1636,194 @@ mod tests {
         assert_eq!(expected, ser);
     }
 
+    #[derive(Default)]
+    pub struct StrictTypeMapping;
+    impl ObjectMapping for StrictTypeMapping {
+        type Properties = SimpleNestedType;
+
+        fn dynamic() -> Option<Dynamic> {
+            Some(Dynamic::Strict)
+        }
+
+        fn enabled() -> Option<bool> {
+            Some(false)
+        }
+    }
+
+    #[test]
+    fn serialise_document_with_dynamic_and_enabled() {
+        let ser =
+            serde_json::to_value(&IndexDocumentMapping::<StrictTypeMapping>::default()).unwrap();
+
+        let expected = json!({
+            "dynamic": "strict",
+            "enabled": false,
+            "properties": {
+                "field": {
+                    "type": "integer"
+                }
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[derive(Default)]
+    pub struct SourceTypeMapping;
+    impl ObjectMapping for SourceTypeMapping {
+        type Properties = NoProps;
+
+        fn source() -> Option<SourceMapping> {
+            Some(SourceMapping {
+                includes: Some(&["field", "meta.*"]),
+                excludes: Some(&["meta.description"]),
+                ..Default::default()
+            })
+        }
+    }
+
+    #[test]
+    fn serialise_document_with_source() {
+        let ser =
+            serde_json::to_value(&IndexDocumentMapping::<SourceTypeMapping>::default()).unwrap();
+
+        let expected = json!({
+            "_source": {
+                "includes": ["field", "meta.*"],
+                "excludes": ["meta.description"]
+            },
+            "properties": {}
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[derive(Serialize, Deserialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct SourceExcludeType {
+        pub id: i32,
+        #[elastic(source_exclude)]
+        pub summary: Option<Keyword<DefaultKeywordMapping>>,
+        #[elastic(source_exclude)]
+        #[serde(default, rename = "rawBody")]
+        pub raw_body: String,
+    }
+
+    #[test]
+    fn serialise_document_with_source_excludes() {
+        let ser = serde_json::to_value(&SourceExcludeType::index_mapping()).unwrap();
+
+        let expected = json!({
+            "_source": {
+                "excludes": ["summary", "rawBody"]
+            },
+            "properties": {
+                "id": {
+                    "type": "integer"
+                },
+                "summary": {
+                    "type": "keyword"
+                },
+                "rawBody": {
+                    "type": "text",
+                    "fields": {
+                        "keyword": {
+                            "type": "keyword",
+                            "ignore_above": 256
+                        }
+                    }
+                }
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn deserialise_document_without_excluded_fields() {
+        let de: SourceExcludeType = serde_json::from_str(r#"{ "id": 1 }"#).unwrap();
+
+        assert_eq!(1, de.id);
+        assert!(de.summary.is_none());
+        assert_eq!("", de.raw_body);
+    }
+
+    #[derive(Default)]
+    pub struct MetaFieldsTypeMapping;
+    impl ObjectMapping for MetaFieldsTypeMapping {
+        type Properties = NoProps;
+
+        fn all() -> Option<AllMapping> {
+            Some(AllMapping {
+                enabled: Some(true),
+                store: Some(true),
+            })
+        }
+
+        fn field_names() -> Option<FieldNamesMapping> {
+            Some(FieldNamesMapping {
+                enabled: Some(false),
+            })
+        }
+
+        fn routing() -> Option<RoutingMapping> {
+            Some(RoutingMapping {
+                required: Some(true),
+            })
+        }
+    }
+
+    #[test]
+    fn serialise_document_with_meta_fields() {
+        let ser = serde_json::to_value(&IndexDocumentMapping::<MetaFieldsTypeMapping>::default())
+            .unwrap();
+
+        let expected = json!({
+            "_all": {
+                "enabled": true,
+                "store": true
+            },
+            "_field_names": {
+                "enabled": false
+            },
+            "_routing": {
+                "required": true
+            },
+            "properties": {}
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[derive(Default)]
+    pub struct MetaTypeMapping;
+    impl ObjectMapping for MetaTypeMapping {
+        type Properties = NoProps;
+
+        fn meta() -> Option<Value> {
+            Some(json!({
+                "version": 2,
+                "owner": "search-service"
+            }))
+        }
+    }
+
+    #[test]
+    fn serialise_document_with_meta() {
+        let ser =
+            serde_json::to_value(&IndexDocumentMapping::<MetaTypeMapping>::default()).unwrap();
+
+        let expected = json!({
+            "_meta": {
+                "version": 2,
+                "owner": "search-service"
+            },
+            "properties": {}
+        });
+
+        assert_eq!(expected, ser);
+    }
+
     #[test]
     fn serialise_mapping_dynamic() {
         let d_opts: Vec<String> = vec![Dynamic::True, Dynamic::False, Dynamic::Strict]
@@ -5,6 +5,16 @@ use serde::{
     Serialize,
     Serializer,
 };
+use serde_json::Value;
+use std::{
+    collections::{
+        BTreeMap,
+        HashMap,
+    },
+    hash::Hash,
+};
+
+use super::dynamic_templates::DynamicTemplate;
 
 /** A field that will be mapped as a nested document. */
 pub trait ObjectFieldType {
@@ -33,6 +43,8 @@ pub trait ObjectMapping {
     /**
     Whether or not new properties should be added dynamically to an existing object.
     Accepts `true` (default), `false` and `strict`.
+
+    This setting is also serialised at the root of an index mapping.
     */
     fn dynamic() -> Option<Dynamic> {
         None
@@ -41,6 +53,8 @@ pub trait ObjectMapping {
     /**
     Whether the JSON value given for the object field should be parsed and indexed
     (`true`, default) or completely ignored (`false`).
+
+    This setting only applies to `object` fields and the root of an index mapping.
     */
     fn enabled() -> Option<bool> {
         None
@@ -53,6 +67,106 @@ pub trait ObjectMapping {
     fn include_in_all() -> Option<bool> {
         None
     }
+
+    /**
+    Whether all fields in the nested object should also be added to the parent document as standard (flat) fields.
+    Only applies to `nested` objects.
+    */
+    fn include_in_parent() -> Option<bool> {
+        None
+    }
+
+    /**
+    Whether all fields in the nested object should also be added to the root document as standard (flat) fields.
+    Only applies to `nested` objects.
+    */
+    fn include_in_root() -> Option<bool> {
+        None
+    }
+
+    /**
+    Configuration for the `_source` meta-field, which stores the original JSON document.
+
+    This setting is only serialised at the root of an index mapping.
+    */
+    fn source() -> Option<SourceMapping> {
+        None
+    }
+
+    /**
+    Configuration for the `_all` meta-field, which concatenates the values of all other fields.
+
+    This setting is only serialised at the root of an index mapping.
+    */
+    fn all() -> Option<AllMapping> {
+        None
+    }
+
+    /**
+    Configuration for the `_field_names` meta-field, which indexes the names of fields with values.
+
+    This setting is only serialised at the root of an index mapping.
+    */
+    fn field_names() -> Option<FieldNamesMapping> {
+        None
+    }
+
+    /**
+    Configuration for the `_routing` meta-field, which stores the custom routing value for a document.
+
+    This setting is only serialised at the root of an index mapping.
+    */
+    fn routing() -> Option<RoutingMapping> {
+        None
+    }
+
+    /**
+    Custom metadata for the mapping, like a schema version or the name of the service that owns it.
+
+    Elasticsearch doesn't use the metadata itself, but it's returned when getting the mapping.
+    This setting is only serialised at the root of an index mapping.
+
+    # Examples
+
+    ```
+    # #[macro_use] extern crate serde_json;
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # use elastic::types::prelude::*;
+    # use serde_json::Value;
+    # #[derive(Serialize, ElasticType)]
+    # #[elastic(mapping = "MyTypeMapping")]
+    # struct MyType {}
+    #[derive(Default)]
+    struct MyTypeMapping;
+    impl ObjectMapping for MyTypeMapping {
+        type Properties = MyType;
+
+        fn meta() -> Option<Value> {
+            Some(json!({
+                "version": 2,
+                "owner": "search-service"
+            }))
+        }
+    }
+    # fn main() {}
+    ```
+    */
+    fn meta() -> Option<Value> {
+        None
+    }
+
+    /**
+    Templates for mapping fields that are added dynamically.
+
+    This setting is only serialised at the root of an index mapping.
+    See the [`dynamic_templates`][dynamic_templates] module for details.
+
+    [dynamic_templates]: ../dynamic_templates/index.html
+    */
+    fn dynamic_templates() -> Option<Vec<DynamicTemplate>> {
+        None
+    }
 }
 
 /**
@@ -76,6 +190,15 @@ pub trait PropertiesMapping {
     fn serialize_props<S>(state: &mut S) -> Result<(), S::Error>
     where
         S: SerializeStruct;
+
+    /**
+    The values each property field can take, for fields that only take a fixed set of values.
+
+    The values are listed in the `_meta` of the index mapping.
+    */
+    fn props_values() -> Vec<(&'static str, &'static [&'static str])> {
+        Vec::new()
+    }
 }
 
 /**
@@ -105,11 +228,229 @@ impl Serialize for Dynamic {
     }
 }
 
+/**
+A map with string keys, like a `HashMap<String, V>` or `BTreeMap<String, V>`.
+
+The keys of a map aren't known up front, so a map is mapped as an `object`
+and Elasticsearch adds each key to the mapping the first time it's indexed.
+Any serialisable value can be stored in a map, so the mapping of the values isn't part of the field mapping.
+If the values have a mapping, it can be applied to new keys with a dynamic template.
+See [`DynamicTemplate::map_values`][DynamicTemplate.map_values] for details.
+
+[DynamicTemplate.map_values]: ../dynamic_templates/struct.DynamicTemplate.html#method.map_values
+*/
+pub trait MapFieldType {
+    /** The type of the values in the map. */
+    type Value;
+}
+
+impl<K, V> MapFieldType for BTreeMap<K, V>
+where
+    K: AsRef<str> + Ord,
+{
+    type Value = V;
+}
+
+impl<K, V> MapFieldType for HashMap<K, V>
+where
+    K: AsRef<str> + Eq + Hash,
+{
+    type Value = V;
+}
+
+impl<TField> MapFieldType for Option<TField>
+where
+    TField: MapFieldType,
+{
+    type Value = TField::Value;
+}
+
+/**
+Configuration for the `_source` meta-field.
+
+# Examples
+
+Exclude a field from the stored `_source`:
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# use elastic::types::prelude::*;
+#[derive(Serialize, ElasticType)]
+#[elastic(mapping = "MyTypeMapping")]
+struct MyType {
+    pub large_field: String,
+}
+
+#[derive(Default)]
+struct MyTypeMapping;
+impl ObjectMapping for MyTypeMapping {
+    type Properties = MyType;
+
+    fn source() -> Option<SourceMapping> {
+        Some(SourceMapping {
+            excludes: Some(&["large_field"]),
+            ..Default::default()
+        })
+    }
+}
+# fn main() {}
+```
+*/
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SourceMapping {
+    /**
+    Whether the original JSON document should be stored.
+    Defaults to `true`.
+    */
+    pub enabled: Option<bool>,
+    /**
+    Fields to include in the stored `_source`.
+    Fields can be given as paths with wildcards, like `meta.*`.
+    */
+    pub includes: Option<&'static [&'static str]>,
+    /**
+    Fields to exclude from the stored `_source`.
+    Fields can be given as paths with wildcards, like `meta.*`.
+    */
+    pub excludes: Option<&'static [&'static str]>,
+}
+
+impl Serialize for SourceMapping {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("_source", 3)?;
+
+        ser_field!(state, "enabled", self.enabled);
+        ser_field!(state, "includes", self.includes);
+        ser_field!(state, "excludes", self.excludes);
+
+        state.end()
+    }
+}
+
+/**
+Configuration for the `_all` meta-field.
+
+# Examples
+
+Disable the `_all` field:
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# use elastic::types::prelude::*;
+#[derive(Serialize, ElasticType)]
+#[elastic(mapping = "MyTypeMapping")]
+struct MyType {
+    pub field: String,
+}
+
+#[derive(Default)]
+struct MyTypeMapping;
+impl ObjectMapping for MyTypeMapping {
+    type Properties = MyType;
+
+    fn all() -> Option<AllMapping> {
+        Some(AllMapping {
+            enabled: Some(false),
+            ..Default::default()
+        })
+    }
+}
+# fn main() {}
+```
+*/
+#[derive(Debug, Default, Clone, Copy)]
+pub struct AllMapping {
+    /**
+    Whether the `_all` field should be indexed.
+    Defaults to `true`.
+    */
+    pub enabled: Option<bool>,
+    /**
+    Whether the `_all` field value should be stored so it can be retrieved.
+    Defaults to `false`.
+    */
+    pub store: Option<bool>,
+}
+
+impl Serialize for AllMapping {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("_all", 2)?;
+
+        ser_field!(state, "enabled", self.enabled);
+        ser_field!(state, "store", self.store);
+
+        state.end()
+    }
+}
+
+/**
+Configuration for the `_field_names` meta-field.
+
+Disabling `_field_names` reduces indexing overhead, but `exists` queries will no longer work.
+*/
+#[derive(Debug, Default, Clone, Copy)]
+pub struct FieldNamesMapping {
+    /**
+    Whether the names of fields with values should be indexed.
+    Defaults to `true`.
+    */
+    pub enabled: Option<bool>,
+}
+
+impl Serialize for FieldNamesMapping {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("_field_names", 1)?;
+
+        ser_field!(state, "enabled", self.enabled);
+
+        state.end()
+    }
+}
+
+/**
+Configuration for the `_routing` meta-field.
+*/
+#[derive(Debug, Default, Clone, Copy)]
+pub struct RoutingMapping {
+    /**
+    Whether a custom routing value must be given when indexing, getting or deleting a document.
+    Defaults to `false`.
+    */
+    pub required: Option<bool>,
+}
+
+impl Serialize for RoutingMapping {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("_routing", 1)?;
+
+        ser_field!(state, "required", self.required);
+
+        state.end()
+    }
+}
+
+pub(crate) use self::private::IndexDocumentMappingWithoutMeta;
+
 mod private {
     use super::{
         ObjectFieldType,
         ObjectMapping,
         PropertiesMapping,
+        NESTED_DATATYPE,
         OBJECT_DATATYPE,
     };
     use crate::types::{
@@ -129,6 +470,10 @@ mod private {
         Serialize,
         Serializer,
     };
+    use serde_json::{
+        Map,
+        Value,
+    };
     use std::marker::PhantomData;
 
     #[derive(Default)]
@@ -185,7 +530,8 @@ mod private {
             let ty = <TMapping as ObjectMapping>::data_type();
             let props_len = <TMapping as ObjectMapping>::Properties::props_len();
 
-            let (is_object, has_props) = (ty == OBJECT_DATATYPE, props_len > 0);
+            let (is_object, is_nested, has_props) =
+                (ty == OBJECT_DATATYPE, ty == NESTED_DATATYPE, props_len > 0);
 
             let props_len = match (is_object, has_props) {
                 (true, true) => 5,
@@ -193,6 +539,8 @@ mod private {
                 (false, false) => 3,
             };
 
+            let props_len = if is_nested { props_len + 2 } else { props_len };
+
             let mut state = serializer.serialize_struct("mapping", props_len)?;
 
             state.serialize_field("type", ty)?;
@@ -204,6 +552,11 @@ mod private {
                 ser_field!(state, "enabled", TMapping::enabled());
             }
 
+            if is_nested {
+                ser_field!(state, "include_in_parent", TMapping::include_in_parent());
+                ser_field!(state, "include_in_root", TMapping::include_in_root());
+            }
+
             if has_props {
                 state.serialize_field("properties", &Properties::<TMapping> { _m: PhantomData })?;
             }
@@ -232,11 +585,92 @@ mod private {
         where
             S: Serializer,
         {
-            let mut state = serializer.serialize_struct("mapping", 1)?;
+            serialize_index_mapping::<TMapping, S>(serializer, true)
+        }
+    }
 
-            state.serialize_field("properties", &Properties::<TMapping> { _m: PhantomData })?;
+    /**
+    The index mapping for a type without its `_meta`.
 
-            state.end()
+    The `_meta` is left out so it can contain a fingerprint of the rest of the mapping.
+    */
+    pub(crate) struct IndexDocumentMappingWithoutMeta<TMapping>
+    where
+        TMapping: ObjectMapping,
+    {
+        _m: PhantomData<TMapping>,
+    }
+
+    impl<TMapping> Default for IndexDocumentMappingWithoutMeta<TMapping>
+    where
+        TMapping: ObjectMapping,
+    {
+        fn default() -> Self {
+            IndexDocumentMappingWithoutMeta { _m: PhantomData }
         }
     }
+
+    impl<TMapping> Serialize for IndexDocumentMappingWithoutMeta<TMapping>
+    where
+        TMapping: ObjectMapping,
+    {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serialize_index_mapping::<TMapping, S>(serializer, false)
+        }
+    }
+
+    // List the values of properties that only take a fixed set of values alongside any custom `_meta`
+    fn index_mapping_meta<TMapping>() -> Option<Value>
+    where
+        TMapping: ObjectMapping,
+    {
+        let meta = TMapping::meta();
+
+        let values = TMapping::Properties::props_values();
+        if values.is_empty() {
+            return meta;
+        }
+
+        let values: Map<String, Value> = values
+            .into_iter()
+            .map(|(field, values)| (field.to_owned(), Value::from(values)))
+            .collect();
+
+        match meta {
+            None => Some(json!({ "values": values })),
+            Some(Value::Object(mut meta)) => {
+                meta.entry("values").or_insert(Value::Object(values));
+                Some(Value::Object(meta))
+            }
+            meta => meta,
+        }
+    }
+
+    fn serialize_index_mapping<TMapping, S>(serializer: S, meta: bool) -> Result<S::Ok, S::Error>
+    where
+        TMapping: ObjectMapping,
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("mapping", 9)?;
+
+        ser_field!(state, "dynamic", TMapping::dynamic());
+        ser_field!(state, "enabled", TMapping::enabled());
+        ser_field!(state, "_source", TMapping::source());
+        ser_field!(state, "_all", TMapping::all());
+        ser_field!(state, "_field_names", TMapping::field_names());
+        ser_field!(state, "_routing", TMapping::routing());
+
+        if meta {
+            ser_field!(state, "_meta", index_mapping_meta::<TMapping>());
+        }
+
+        ser_field!(state, "dynamic_templates", TMapping::dynamic_templates());
+
+        state.serialize_field("properties", &Properties::<TMapping> { _m: PhantomData })?;
+
+        state.end()
+    }
 }
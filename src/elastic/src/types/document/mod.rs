@@ -71,6 +71,11 @@ This will produce the following field mapping:
 # }
 ```
 
+The `properties` of a derived mapping are always serialised in the order the fields are declared on the struct,
+so the same type produces the same mapping json in every build.
+Sub-fields, like the `keyword` field above, are serialised in order of their names.
+To compare mappings regardless of declaration order, render them with the [`TypeMapper`](../mappers/struct.TypeMapper.html), which sorts every key.
+
 It's also possible to adjust the mapping using the `#[elastic]` attribute.
 
 ### Specifying a default index name
@@ -159,6 +164,24 @@ pub struct MyType {
 ```
 
 The field annotated with `#[elastic(id)]` must satisfy `impl Into<Cow<'_, str>>`.
+The id field can also be named on the struct using the `#[elastic(id = "field")]` attribute:
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# #[macro_use] use elastic::types::prelude::*;
+#[derive(Serialize, ElasticType)]
+#[elastic(index = "articles", id = "my_id")]
+pub struct MyType {
+    pub my_id: String,
+    pub my_date: Date<DefaultDateMapping>,
+    pub my_string: String,
+    pub my_num: i32
+}
+# fn main() {
+# }
+```
+
 An id can also be calculated based on an expression function using the `#[elastic(id(expr = "expression"))]` attribute:
 
 ```
@@ -278,6 +301,99 @@ This will produce the following field mapping:
 # }
 ```
 
+### Map as an Object or Nested
+
+Derived document types are mapped as `nested` by default.
+Use `#[elastic(data_type="object")]` to map a type as a plain `object` instead:
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# #[macro_use] extern crate serde_json;
+# use elastic::types::prelude::*;
+#[derive(Serialize, ElasticType)]
+#[elastic(data_type="object")]
+pub struct MyType {
+    pub my_num: i32
+}
+# fn main() {
+# let mapping = serde_json::to_value(&MyType::field_mapping()).unwrap();
+# let json = json!(
+{
+    "type": "object",
+    "properties": {
+        "my_num": {
+            "type": "integer"
+        }
+    }
+}
+# );
+# assert_eq!(json, mapping);
+# }
+```
+
+Nested objects can also be flattened into their parent or root documents by overriding `include_in_parent` or `include_in_root` on a custom mapping.
+
+### Recursive Types
+
+A type that contains itself, like a comment with replies, would have a mapping that never ends.
+Use `#[elastic(recursion_limit = <depth>)]` to limit how many levels deep its properties are mapped:
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# #[macro_use] extern crate serde_json;
+# use elastic::types::prelude::*;
+#[derive(Serialize, ElasticType)]
+#[elastic(recursion_limit = 2)]
+pub struct Comment {
+    pub text: String,
+    pub replies: Vec<Comment>,
+}
+# fn main() {
+# let mapping = serde_json::to_value(&Comment::index_mapping()).unwrap();
+# let json = json!(
+{
+    "properties": {
+        "text": {
+            "type": "text",
+            "fields": {
+                "keyword": {
+                    "type": "keyword",
+                    "ignore_above": 256
+                }
+            }
+        },
+        "replies": {
+            "type": "nested",
+            "properties": {
+                "text": {
+                    "type": "text",
+                    "fields": {
+                        "keyword": {
+                            "type": "keyword",
+                            "ignore_above": 256
+                        }
+                    }
+                },
+                "replies": {
+                    "type": "nested",
+                    "properties": {}
+                }
+            }
+        }
+    }
+}
+# );
+# assert_eq!(json, mapping);
+# }
+```
+
+Past the limit the type is mapped without any properties, so deeper values are mapped according to its `dynamic` setting.
+Deriving a type with a field that contains the type itself without a `recursion_limit` fails to compile.
+Types that contain each other indirectly, like `Post { comments: Vec<Comment> }` and `Comment { post: Option<Post> }`,
+aren't detected, so one of them needs a `recursion_limit` attribute.
+
 ### Ignore or Rename Fields
 
 You can then serialise type mappings with `#[serde]` attributes:
@@ -298,9 +414,321 @@ pub struct MyType {
 # }
 ```
 
+Container-level `#[serde(rename_all = "...")]` rules are also applied to the names of mapped fields,
+so a `first_name` field on a type with `#[serde(rename_all = "camelCase")]` is mapped as `firstName`.
+
 > NOTE: Fields with a `#[serde(skip_deserializing)]` attribute will still be mapped, because they can
 still be indexed in Elasticsearch.
 
+If a type has its own `Serialize` implementation, or the `serde` attributes can't be used,
+the `#[elastic(rename = "name")]` and `#[elastic(skip)]` attributes rename or ignore a field in the mapping only.
+They take precedence over any `#[serde]` attributes:
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# #[macro_use] extern crate serde_json;
+# use elastic::types::prelude::*;
+#[derive(ElasticType)]
+pub struct MyType {
+    #[elastic(rename = "my_renamed_date")]
+    pub my_date: Date<DefaultDateMapping>,
+    #[elastic(skip)]
+    pub ignored: String,
+    pub my_num: i32
+}
+# fn main() {
+# let mapping = serde_json::to_value(&MyType::index_mapping()).unwrap();
+# let expected = json!({
+#     "properties": {
+#         "my_renamed_date": {
+#             "type": "date",
+#             "format": "basic_date_time"
+#         },
+#         "my_num": {
+#             "type": "integer"
+#         }
+#     }
+# });
+# assert_eq!(expected, mapping);
+# }
+```
+
+### Mapping String Fields
+
+`String` fields are mapped as `text` with a `keyword` sub-field that ignores values longer than 256 characters.
+The mapping of a string field can be changed without a custom mapping type using the following attributes:
+
+- `#[elastic(ignore_above = <len>)]` changes the length of values that are ignored by the `keyword` sub-field
+- `#[elastic(keyword)]` maps the field as a `keyword` instead of `text`.
+It can be combined with `ignore_above` to ignore long values in the `keyword` field
+- `#[elastic(no_keyword)]` maps the field as `text` without a `keyword` sub-field
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# #[macro_use] extern crate serde_json;
+# use elastic::types::prelude::*;
+#[derive(Serialize, ElasticType)]
+pub struct MyType {
+    #[elastic(ignore_above = 1024)]
+    pub title: String,
+    #[elastic(keyword)]
+    pub tag: String,
+    #[elastic(no_keyword)]
+    pub body: String,
+}
+# fn main() {
+# let mapping = serde_json::to_value(&MyType::index_mapping()).unwrap();
+# let json = json!(
+{
+    "properties": {
+        "title": {
+            "type": "text",
+            "fields": {
+                "keyword": {
+                    "type": "keyword",
+                    "ignore_above": 1024
+                }
+            }
+        },
+        "tag": {
+            "type": "keyword"
+        },
+        "body": {
+            "type": "text"
+        }
+    }
+}
+# );
+# assert_eq!(json, mapping);
+# }
+```
+
+These attributes can't be combined with `#[elastic(mapping = "<ident>")]`.
+
+### Aliasing Renamed Fields
+
+A field can be renamed without reindexing by keeping its old name as an `alias`.
+The `#[elastic(alias_of = "path")]` attribute maps a field as an alias to the field at `path`.
+An alias can't be indexed, so the field must also have a `#[serde(skip)]` attribute:
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# #[macro_use] extern crate serde_json;
+# use elastic::types::prelude::*;
+#[derive(Serialize, Deserialize, ElasticType)]
+pub struct MyType {
+    pub headline: String,
+    #[serde(skip)]
+    #[elastic(alias_of = "headline")]
+    pub title: (),
+}
+# fn main() {
+# let mapping = serde_json::to_value(&MyType::index_mapping()).unwrap();
+# assert_eq!(json!({ "type": "alias", "path": "headline" }), mapping["properties"]["title"]);
+# }
+```
+
+See the [`alias`](../alias/index.html) module for more details.
+
+### Default Field Values
+
+The `#[elastic(default = "expression")]` attribute sets a default value for a field.
+The default is used as the `null_value` in the field's mapping, so explicit `null`s are indexed as the default.
+A `default_{field}` function is also generated that returns the default, which can be given to `serde`:
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# #[macro_use] extern crate serde_json;
+# use elastic::types::prelude::*;
+#[derive(Serialize, Deserialize, ElasticType)]
+pub struct MyType {
+    #[serde(default = "MyType::default_status")]
+    #[elastic(default = "Keyword::new(\"unknown\")")]
+    pub status: Keyword<DefaultKeywordMapping>,
+}
+# fn main() {
+# let mapping = serde_json::to_value(&MyType::index_mapping()).unwrap();
+# let json = json!(
+{
+    "properties": {
+        "status": {
+            "type": "keyword",
+            "null_value": "unknown"
+        }
+    }
+}
+# );
+# assert_eq!(json, mapping);
+# }
+```
+
+Elasticsearch doesn't change the `_source` of a document when it uses a `null_value`, so a field that was `null` when it was indexed is still `null` when it's read back.
+Deserialise a document as a [`WithNullValues`](struct.WithNullValues.html) to fill fields that are missing or `null` with their defaults:
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# #[macro_use] extern crate serde_json;
+# use elastic::types::prelude::*;
+# #[derive(Serialize, Deserialize, ElasticType)]
+# pub struct MyType {
+#     #[elastic(default = "Keyword::new(\"unknown\")")]
+#     pub status: Keyword<DefaultKeywordMapping>,
+# }
+# fn main() {
+let doc: WithNullValues<MyType> = serde_json::from_value(json!({ "status": null })).unwrap();
+
+assert_eq!("unknown", doc.status);
+# }
+```
+
+### Changing Documents Before Indexing
+
+The `#[elastic(before_index)]` attribute runs the document's [`BeforeIndex`](trait.BeforeIndex.html) hook
+whenever it's indexed through the typed document APIs:
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# use elastic::types::prelude::*;
+#[derive(Serialize, ElasticType)]
+#[elastic(before_index)]
+pub struct MyType {
+    pub my_string: String,
+    pub updated_at: Date<DefaultDateMapping>,
+}
+
+impl BeforeIndex for MyType {
+    fn before_index(&mut self) {
+        self.updated_at = Date::now();
+    }
+}
+# fn main() {
+# }
+```
+
+### Querying Optional Fields
+
+An `optional_{field}` function is generated for each `Option` field that returns an [`OptionalField`](struct.OptionalField.html).
+It builds `exists` queries using the name of the field in the mapping:
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# #[macro_use] extern crate serde_json;
+# use elastic::types::prelude::*;
+#[derive(Serialize, ElasticType)]
+pub struct MyType {
+    pub my_string: String,
+    pub deleted_at: Option<Date<DefaultDateMapping>>,
+}
+
+# fn main() {
+let not_deleted = MyType::optional_deleted_at().missing();
+# }
+```
+
+### Referencing Field Names
+
+The `#[elastic(field_names)]` attribute generates a `{Type}Fields` struct alongside the mapping with a constant for the name of each field in the mapping.
+The constants are named after the fields on the struct and follow any renames,
+so queries, sorts and aggregations can use them instead of string literals.
+The struct is only generated for types with the attribute, so it can't clash with other types in the same module.
+
+Each field also has a method that returns its path from the root document.
+A field with its own `#[elastic(field_names)]` attribute returns the [`FieldNames`](trait.FieldNames.html) of its type instead,
+so the paths of fields on `object` or `nested` types are joined with a `.`:
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# #[macro_use] extern crate serde_json;
+# use elastic::types::prelude::*;
+#[derive(Serialize, ElasticType)]
+#[elastic(data_type = "object", field_names)]
+pub struct Location {
+    pub city: String,
+}
+
+#[derive(Serialize, ElasticType)]
+#[elastic(field_names)]
+#[serde(rename_all = "camelCase")]
+pub struct MyType {
+    pub created_at: Date<DefaultDateMapping>,
+    #[elastic(field_names)]
+    pub location: Location,
+}
+
+# fn main() {
+let aggs = json!({
+    "cities": { "terms": { "field": MyTypeFields::default().location().city() } },
+    "latest": { "max": { "field": MyTypeFields::CREATED_AT } }
+});
+# assert_eq!("createdAt", MyTypeFields::CREATED_AT);
+# assert_eq!("location.city", aggs["cities"]["terms"]["field"]);
+# }
+```
+
+A derive can't see the fields of other types, so the paths of nested fields are built when they're requested rather than being constants.
+
+### Excluding Large Fields From the Source
+
+Fields with a `#[elastic(source_exclude)]` attribute are excluded from the stored `_source` of the document.
+They're still indexed, so they can be searched, but they're missing from the `_source` of hits.
+So the document can be deserialised from a hit, an excluded field must be an `Option` or have a `#[serde(default)]` attribute:
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# #[macro_use] extern crate serde_json;
+# use elastic::types::prelude::*;
+#[derive(Serialize, Deserialize, ElasticType)]
+pub struct MyType {
+    pub title: String,
+    #[elastic(source_exclude)]
+    pub body: Option<String>,
+    #[elastic(source_exclude)]
+    #[serde(default)]
+    pub attachment: String,
+}
+# fn main() {
+# let mapping = serde_json::to_value(&MyType::index_mapping()).unwrap();
+# assert_eq!(json!(["body", "attachment"]), mapping["_source"]["excludes"]);
+# }
+```
+
+The `_source` is only configured for the root document, so excluding a field on a type that's used as an `object` or `nested` field has no effect.
+If the mapping is supplied by `#[elastic(mapping = "<ident>")]` then return the excludes from its [`source`](mapping/trait.ObjectMapping.html#method.source) method instead.
+
+### Mapping the Values of Maps
+
+Maps with string keys, like `HashMap<String, V>`, are mapped as an `object` and their keys are added to the mapping dynamically.
+Fields with a `#[elastic(map_values)]` attribute also get a [dynamic template](dynamic_templates/index.html) that maps each new key using the mapping of the map's values:
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# #[macro_use] extern crate serde_json;
+# use std::collections::HashMap;
+# use elastic::types::prelude::*;
+#[derive(Serialize, ElasticType)]
+pub struct MyType {
+    #[elastic(map_values)]
+    pub labels: HashMap<String, Keyword<DefaultKeywordMapping>>,
+}
+# fn main() {
+# let mapping = serde_json::to_value(&MyType::index_mapping()).unwrap();
+# assert_eq!(json!({ "type": "keyword" }), mapping["dynamic_templates"][0]["labels"]["mapping"]);
+# }
+```
+
+Dynamic templates are only configured for the root document, so like `source_exclude`, the attribute has no effect on a type that's used as an `object` or `nested` field.
+If the mapping is supplied by `#[elastic(mapping = "<ident>")]` then return the templates from its [`dynamic_templates`](mapping/trait.ObjectMapping.html#method.dynamic_templates) method instead.
+
 ## Limitations
 
 Automatically deriving mapping has the following limitations:
@@ -315,11 +743,18 @@ So you can't share `MyTypeMapping` between `MyType` and `MyOtherType`.
 - [Document Types](https://www.elastic.co/guide/en/elasticsearch/reference/master/mapping.html)
 */
 
+pub mod dynamic_templates;
 pub mod mapping;
 
 mod impls;
 pub use self::impls::*;
 
+mod optional;
+pub use self::optional::*;
+
+mod field_names;
+pub use self::field_names::*;
+
 pub mod prelude {
     /*!
     Includes all types for document types.
@@ -329,11 +764,17 @@ pub mod prelude {
 
     pub use super::{
         impls::{
+            BeforeIndex,
             DocumentType,
+            DynamicObject,
             IndexDocumentMapping,
             StaticIndex,
             StaticType,
+            WithNullValues,
         },
+        dynamic_templates::*,
+        field_names::FieldNames,
         mapping::*,
+        optional::OptionalField,
     };
 }
@@ -0,0 +1,139 @@
+use serde_json::Value;
+use std::marker::PhantomData;
+
+/**
+A marker for a document field that's an `Option<T>`.
+
+Derived documents have an `optional_{field}` function for each `Option` field that returns its marker.
+The marker builds `exists` queries for the field using its name in the mapping,
+so a "where field is set" or "where field is unset" condition follows renames and the optionality of the field.
+
+# Examples
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# #[macro_use] extern crate serde_json;
+# use elastic::types::prelude::*;
+#[derive(Serialize, ElasticType)]
+pub struct MyType {
+    pub id: String,
+    #[serde(rename = "emailAddress")]
+    pub email: Option<String>,
+}
+
+# fn main() {
+let query = json!({
+    "query": {
+        "bool": {
+            "filter": [ MyType::optional_email().missing() ]
+        }
+    }
+});
+# let expected = json!({
+#     "query": {
+#         "bool": {
+#             "filter": [
+#                 { "bool": { "must_not": [ { "exists": { "field": "emailAddress" } } ] } }
+#             ]
+#         }
+#     }
+# });
+# assert_eq!(expected, query);
+# }
+```
+*/
+pub struct OptionalField<TDocument> {
+    name: &'static str,
+    _m: PhantomData<TDocument>,
+}
+
+impl<TDocument> OptionalField<TDocument> {
+    /** Create a marker for the optional field with the given name. */
+    pub fn new(name: &'static str) -> Self {
+        OptionalField {
+            name,
+            _m: PhantomData,
+        }
+    }
+
+    /** The name of the field in the mapping. */
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /** An `exists` query that matches documents where the field has a value. */
+    pub fn exists(&self) -> Value {
+        json!({
+            "exists": {
+                "field": self.name
+            }
+        })
+    }
+
+    /** A `bool` query that matches documents where the field is missing or `null`. */
+    pub fn missing(&self) -> Value {
+        json!({
+            "bool": {
+                "must_not": [ self.exists() ]
+            }
+        })
+    }
+
+    /** An [`exists`][OptionalField.exists] query if `is_set` is `true` or a [`missing`][OptionalField.missing] query otherwise. */
+    pub fn is_set(&self, is_set: bool) -> Value {
+        if is_set {
+            self.exists()
+        } else {
+            self.missing()
+        }
+    }
+}
+
+impl<TDocument> Clone for OptionalField<TDocument> {
+    fn clone(&self) -> Self {
+        OptionalField::new(self.name)
+    }
+}
+
+impl<TDocument> Copy for OptionalField<TDocument> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::prelude::*;
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    #[serde(rename_all = "camelCase")]
+    pub struct OptionalFieldsType {
+        pub id: i32,
+        pub deleted_at: Option<String>,
+        #[elastic(rename = "tag_list")]
+        pub tags: std::option::Option<Vec<String>>,
+    }
+
+    #[test]
+    fn optional_field_names() {
+        assert_eq!(
+            "deletedAt",
+            OptionalFieldsType::optional_deleted_at().name()
+        );
+        assert_eq!("tag_list", OptionalFieldsType::optional_tags().name());
+    }
+
+    #[test]
+    fn optional_field_queries() {
+        let field = OptionalFieldsType::optional_deleted_at();
+
+        assert_eq!(
+            json!({ "exists": { "field": "deletedAt" } }),
+            field.exists()
+        );
+        assert_eq!(
+            json!({ "bool": { "must_not": [ { "exists": { "field": "deletedAt" } } ] } }),
+            field.missing()
+        );
+        assert_eq!(field.exists(), field.is_set(true));
+        assert_eq!(field.missing(), field.is_set(false));
+    }
+}
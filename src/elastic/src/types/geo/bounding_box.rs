@@ -0,0 +1,264 @@
+/*!
+A bounding box for geo queries and aggregations.
+
+A [`GeoBoundingBox`][GeoBoundingBox] is described by its `top_left` and `bottom_right` corners,
+like the box in a `geo_bounding_box` query or the bounds returned by a `geo_bounds` aggregation.
+
+[GeoBoundingBox]: struct.GeoBoundingBox.html
+*/
+
+use super::{
+    mapping::Distance,
+    point::{
+        mapping::{
+            DefaultGeoPointMapping,
+            GeoPointMapping,
+        },
+        GeoPoint,
+    },
+};
+
+/**
+A box described by its `top_left` and `bottom_right` corners.
+
+The corners are serialised using the format of the geo point mapping,
+so a box can be built using whichever format is most convenient.
+A box is deserialised from corners in any geo point format.
+
+If the left edge of the box is east of its right edge then the box crosses the 180th meridian.
+
+# Examples
+
+Use a bounding box in a `geo_bounding_box` query with corners formatted as strings:
+
+```
+# #[macro_use] extern crate serde_json;
+# use elastic::types::prelude::*;
+# fn main() { run().unwrap() }
+# fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+let bounds: GeoBoundingBox<DefaultGeoPointMapping<GeoPointString>> =
+    GeoBoundingBox::build((-74.1, 40.73), (-71.12, 40.01));
+
+let query = json!({
+    "query": {
+        "geo_bounding_box": {
+            "location": bounds
+        }
+    }
+});
+
+assert_eq!(
+    json!({ "top_left": "40.73,-74.1", "bottom_right": "40.01,-71.12" }),
+    query["query"]["geo_bounding_box"]["location"]
+);
+# Ok(())
+# }
+```
+
+Read the bounds from a `geo_bounds` aggregation:
+
+```
+# extern crate serde_json;
+# use elastic::types::prelude::*;
+# fn main() { run().unwrap() }
+# fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+let bounds: GeoBoundingBox = serde_json::from_str(r#"{
+    "top_left": { "lat": 48.86, "lon": 2.32 },
+    "bottom_right": { "lat": 48.84, "lon": 2.36 }
+}"#)?;
+
+let point: GeoPoint<DefaultGeoPointMapping> = GeoPoint::build(2.33, 48.85);
+
+assert!(bounds.contains(&point));
+# Ok(())
+# }
+```
+*/
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(bound(serialize = "", deserialize = ""))]
+pub struct GeoBoundingBox<TMapping = DefaultGeoPointMapping>
+where
+    TMapping: GeoPointMapping,
+{
+    top_left: GeoPoint<TMapping>,
+    bottom_right: GeoPoint<TMapping>,
+}
+
+impl<TMapping> GeoBoundingBox<TMapping>
+where
+    TMapping: GeoPointMapping,
+{
+    /** Create a bounding box from its top left and bottom right corners. */
+    pub fn new(top_left: GeoPoint<TMapping>, bottom_right: GeoPoint<TMapping>) -> Self {
+        GeoBoundingBox {
+            top_left,
+            bottom_right,
+        }
+    }
+
+    /**
+    Create a bounding box from the `(lon, lat)` coordinates of its top left and bottom right corners.
+
+    ```
+    # use elastic::types::prelude::*;
+    let bounds: GeoBoundingBox = GeoBoundingBox::build((-74.1, 40.73), (-71.12, 40.01));
+    ```
+    */
+    pub fn build(top_left: (f64, f64), bottom_right: (f64, f64)) -> Self {
+        GeoBoundingBox::new(
+            GeoPoint::build(top_left.0, top_left.1),
+            GeoPoint::build(bottom_right.0, bottom_right.1),
+        )
+    }
+
+    /**
+    Create the smallest bounding box around a point that contains a circle with the given radius.
+
+    ```
+    # use elastic::types::prelude::*;
+    let centre: GeoPoint<DefaultGeoPointMapping> = GeoPoint::build(-71.34, 41.12);
+
+    let bounds = GeoBoundingBox::around(&centre, Distance(5.0, DistanceUnit::Kilometers));
+
+    assert!(bounds.contains(&GeoPoint::<DefaultGeoPointMapping>::build(-71.3, 41.15)));
+    ```
+    */
+    pub fn around(centre: &GeoPoint<TMapping>, radius: Distance) -> Self {
+        const EARTH_RADIUS: f64 = 6_371_008.8;
+
+        let lat = centre.y();
+        let lon = centre.x();
+
+        let lat_delta = (radius.to_meters() / EARTH_RADIUS).to_degrees();
+        let top = (lat + lat_delta).min(90.0);
+        let bottom = (lat - lat_delta).max(-90.0);
+
+        // Near the poles the box covers every longitude
+        let (left, right) = if top >= 90.0 || bottom <= -90.0 {
+            (-180.0, 180.0)
+        } else {
+            let lon_delta = lat_delta / lat.to_radians().cos();
+
+            (wrap_lon(lon - lon_delta), wrap_lon(lon + lon_delta))
+        };
+
+        GeoBoundingBox::build((left, top), (right, bottom))
+    }
+
+    /** The top left corner of the box. */
+    pub fn top_left(&self) -> &GeoPoint<TMapping> {
+        &self.top_left
+    }
+
+    /** The bottom right corner of the box. */
+    pub fn bottom_right(&self) -> &GeoPoint<TMapping> {
+        &self.bottom_right
+    }
+
+    /** Whether the box crosses the 180th meridian. */
+    pub fn crosses_antimeridian(&self) -> bool {
+        self.top_left.x() > self.bottom_right.x()
+    }
+
+    /** Whether a point is inside the box or on its edge. */
+    pub fn contains<TPointMapping>(&self, point: &GeoPoint<TPointMapping>) -> bool
+    where
+        TPointMapping: GeoPointMapping,
+    {
+        let (left, top) = (self.top_left.x(), self.top_left.y());
+        let (right, bottom) = (self.bottom_right.x(), self.bottom_right.y());
+
+        let within_lat = point.y() <= top && point.y() >= bottom;
+        let within_lon = if self.crosses_antimeridian() {
+            point.x() >= left || point.x() <= right
+        } else {
+            point.x() >= left && point.x() <= right
+        };
+
+        within_lat && within_lon
+    }
+
+    /** Change the format/mapping of the corners of the box. */
+    pub fn remap<TNewMapping>(bounds: GeoBoundingBox<TMapping>) -> GeoBoundingBox<TNewMapping>
+    where
+        TNewMapping: GeoPointMapping,
+    {
+        GeoBoundingBox::new(
+            GeoPoint::remap(bounds.top_left),
+            GeoPoint::remap(bounds.bottom_right),
+        )
+    }
+}
+
+fn wrap_lon(lon: f64) -> f64 {
+    if lon > 180.0 {
+        lon - 360.0
+    } else if lon < -180.0 {
+        lon + 360.0
+    } else {
+        lon
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+
+    use crate::types::prelude::*;
+
+    #[test]
+    fn serialise_bounding_box() {
+        let bounds: GeoBoundingBox<DefaultGeoPointMapping<GeoPointObject>> =
+            GeoBoundingBox::build((-74.1, 40.73), (-71.12, 40.01));
+
+        let ser = serde_json::to_string(&bounds).unwrap();
+
+        assert_eq!(
+            r#"{"top_left":{"lat":40.73,"lon":-74.1},"bottom_right":{"lat":40.01,"lon":-71.12}}"#,
+            ser
+        );
+    }
+
+    #[test]
+    fn deserialise_bounding_box_any_format() {
+        let bounds: GeoBoundingBox =
+            serde_json::from_str(r#"{"top_left":"40.73,-74.1","bottom_right":[-71.12,40.01]}"#)
+                .unwrap();
+
+        assert_eq!(
+            GeoBoundingBox::build((-74.1, 40.73), (-71.12, 40.01)),
+            bounds
+        );
+    }
+
+    #[test]
+    fn contains_point() {
+        let bounds: GeoBoundingBox = GeoBoundingBox::build((-74.1, 40.73), (-71.12, 40.01));
+
+        assert!(bounds.contains(&GeoPoint::<DefaultGeoPointMapping>::build(-73.0, 40.5)));
+        assert!(!bounds.contains(&GeoPoint::<DefaultGeoPointMapping>::build(-70.0, 40.5)));
+        assert!(!bounds.contains(&GeoPoint::<DefaultGeoPointMapping>::build(-73.0, 41.0)));
+    }
+
+    #[test]
+    fn contains_point_across_antimeridian() {
+        let bounds: GeoBoundingBox = GeoBoundingBox::build((170.0, 10.0), (-170.0, -10.0));
+
+        assert!(bounds.crosses_antimeridian());
+        assert!(bounds.contains(&GeoPoint::<DefaultGeoPointMapping>::build(175.0, 0.0)));
+        assert!(bounds.contains(&GeoPoint::<DefaultGeoPointMapping>::build(-175.0, 0.0)));
+        assert!(!bounds.contains(&GeoPoint::<DefaultGeoPointMapping>::build(0.0, 0.0)));
+    }
+
+    #[test]
+    fn around_point() {
+        let centre: GeoPoint<DefaultGeoPointMapping> = GeoPoint::build(0.0, 0.0);
+
+        let bounds = GeoBoundingBox::around(&centre, Distance(111.195, DistanceUnit::Kilometers));
+
+        assert!((bounds.top_left().y() - 1.0).abs() < 0.001);
+        assert!((bounds.top_left().x() - -1.0).abs() < 0.001);
+        assert!((bounds.bottom_right().y() - -1.0).abs() < 0.001);
+        assert!((bounds.bottom_right().x() - 1.0).abs() < 0.001);
+    }
+}
@@ -1,18 +1,33 @@
 /*! Common mapping for the Elasticsearch `geo` types. */
 
 use serde::{
-    self,
+    de::{
+        Error as DeError,
+        Visitor,
+    },
+    Deserialize,
+    Deserializer,
     Serialize,
+    Serializer,
+};
+use std::{
+    fmt,
+    str::FromStr,
 };
 
 /** A unit of measure for distance. */
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum DistanceUnit {
     /** For `in`. */
     Inches,
     /** For `yd`. */
     Yards,
+    /** For `ft`. */
+    Feet,
     /** For `mi`. */
     Miles,
+    /** For `nmi`. */
+    NauticalMiles,
     /** For `km`. */
     Kilometers,
     /** For `m`. */
@@ -23,35 +38,264 @@ pub enum DistanceUnit {
     Millimeters,
 }
 
-/** A distance value paired with a unit of measure. */
-pub struct Distance(pub f32, pub DistanceUnit);
-
-impl ToString for Distance {
-    fn to_string(&self) -> String {
-        let value = self.0.to_string();
-        let unit = match self.1 {
+impl DistanceUnit {
+    /** The abbreviation Elasticsearch uses for the unit. */
+    pub fn as_str(&self) -> &'static str {
+        match *self {
             DistanceUnit::Inches => "in",
             DistanceUnit::Yards => "yd",
+            DistanceUnit::Feet => "ft",
             DistanceUnit::Miles => "mi",
+            DistanceUnit::NauticalMiles => "nmi",
             DistanceUnit::Kilometers => "km",
             DistanceUnit::Meters => "m",
             DistanceUnit::Centimeters => "cm",
             DistanceUnit::Millimeters => "mm",
-        };
+        }
+    }
+
+    /** The number of meters in one of the unit. */
+    pub fn meters(&self) -> f64 {
+        match *self {
+            DistanceUnit::Inches => 0.0254,
+            DistanceUnit::Yards => 0.9144,
+            DistanceUnit::Feet => 0.3048,
+            DistanceUnit::Miles => 1609.344,
+            DistanceUnit::NauticalMiles => 1852.0,
+            DistanceUnit::Kilometers => 1000.0,
+            DistanceUnit::Meters => 1.0,
+            DistanceUnit::Centimeters => 0.01,
+            DistanceUnit::Millimeters => 0.001,
+        }
+    }
+}
+
+impl fmt::Display for DistanceUnit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
 
-        let mut s = String::with_capacity(value.len() + unit.len());
-        s.push_str(&value);
-        s.push_str(unit);
+impl FromStr for DistanceUnit {
+    type Err = DistanceError;
 
-        s
+    fn from_str(unit: &str) -> Result<Self, Self::Err> {
+        match unit {
+            "in" | "inch" => Ok(DistanceUnit::Inches),
+            "yd" | "yards" => Ok(DistanceUnit::Yards),
+            "ft" | "feet" => Ok(DistanceUnit::Feet),
+            "mi" | "miles" => Ok(DistanceUnit::Miles),
+            "nmi" | "NM" => Ok(DistanceUnit::NauticalMiles),
+            "km" | "kilometers" => Ok(DistanceUnit::Kilometers),
+            "m" | "meters" => Ok(DistanceUnit::Meters),
+            "cm" | "centimeters" => Ok(DistanceUnit::Centimeters),
+            "mm" | "millimeters" => Ok(DistanceUnit::Millimeters),
+            _ => Err(DistanceError::UnknownUnit(unit.to_owned())),
+        }
+    }
+}
+
+impl Serialize for DistanceUnit {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for DistanceUnit {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let unit = String::deserialize(deserializer)?;
+
+        unit.parse().map_err(D::Error::custom)
+    }
+}
+
+/**
+A distance value paired with a unit of measure.
+
+Distances are serialised as a string with the abbreviation for the unit, like `"5km"`,
+which can be used for the `distance` of a `geo_distance` query or the `precision` of a `geo_shape` mapping.
+
+# Examples
+
+```
+# use elastic::types::prelude::*;
+# fn main() { run().unwrap() }
+# fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+let distance: Distance = "1.5km".parse()?;
+
+assert_eq!(Distance(1.5, DistanceUnit::Kilometers), distance);
+assert_eq!("1.5km", distance.to_string());
+# Ok(())
+# }
+```
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Distance(pub f32, pub DistanceUnit);
+
+impl Distance {
+    /** Convert the distance into meters. */
+    pub fn to_meters(&self) -> f64 {
+        f64::from(self.0) * self.1.meters()
+    }
+}
+
+impl fmt::Display for Distance {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}", self.0, self.1)
+    }
+}
+
+impl FromStr for Distance {
+    type Err = DistanceError;
+
+    fn from_str(distance: &str) -> Result<Self, Self::Err> {
+        let distance = distance.trim();
+
+        let split = distance
+            .find(|c: char| c.is_ascii_alphabetic())
+            .ok_or_else(|| DistanceError::UnknownUnit(String::new()))?;
+
+        let (value, unit) = distance.split_at(split);
+
+        let value = value
+            .trim()
+            .parse()
+            .map_err(|_| DistanceError::InvalidValue(distance.to_owned()))?;
+
+        Ok(Distance(value, unit.parse()?))
     }
 }
 
 impl Serialize for Distance {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
-        S: serde::Serializer,
+        S: Serializer,
     {
         serializer.serialize_str(&self.to_string())
     }
 }
+
+impl<'de> Deserialize<'de> for Distance {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct DistanceVisitor;
+        impl<'de> Visitor<'de> for DistanceVisitor {
+            type Value = Distance;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                write!(
+                    formatter,
+                    "a distance string like `5km` or a number of meters"
+                )
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Distance, E>
+            where
+                E: DeError,
+            {
+                value.parse().map_err(E::custom)
+            }
+
+            fn visit_f64<E>(self, value: f64) -> Result<Distance, E>
+            where
+                E: DeError,
+            {
+                Ok(Distance(value as f32, DistanceUnit::Meters))
+            }
+
+            fn visit_i64<E>(self, value: i64) -> Result<Distance, E>
+            where
+                E: DeError,
+            {
+                self.visit_f64(value as f64)
+            }
+
+            fn visit_u64<E>(self, value: u64) -> Result<Distance, E>
+            where
+                E: DeError,
+            {
+                self.visit_f64(value as f64)
+            }
+        }
+
+        deserializer.deserialize_any(DistanceVisitor)
+    }
+}
+
+quick_error! {
+    /** An error parsing a distance. */
+    #[derive(Debug, PartialEq)]
+    pub enum DistanceError {
+        /** The distance doesn't end with a known unit. */
+        UnknownUnit(unit: String) {
+            display("`{}` isn't a known distance unit", unit)
+        }
+        /** The distance doesn't start with a number. */
+        InvalidValue(distance: String) {
+            display("`{}` doesn't start with a valid number", distance)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+
+    use super::*;
+
+    #[test]
+    fn serialise_distance() {
+        let ser = serde_json::to_string(&Distance(1.5, DistanceUnit::NauticalMiles)).unwrap();
+
+        assert_eq!(r#""1.5nmi""#, ser);
+    }
+
+    #[test]
+    fn deserialise_distance() {
+        let distances: Vec<Distance> =
+            serde_json::from_str(r#"["5km", "12 miles", "0.5ft", 200]"#).unwrap();
+
+        assert_eq!(
+            vec![
+                Distance(5.0, DistanceUnit::Kilometers),
+                Distance(12.0, DistanceUnit::Miles),
+                Distance(0.5, DistanceUnit::Feet),
+                Distance(200.0, DistanceUnit::Meters),
+            ],
+            distances
+        );
+    }
+
+    #[test]
+    fn parse_invalid_distance() {
+        assert_eq!(
+            Err(DistanceError::UnknownUnit("lightyears".to_owned())),
+            "5lightyears".parse::<Distance>()
+        );
+        assert_eq!(
+            Err(DistanceError::UnknownUnit(String::new())),
+            "5".parse::<Distance>()
+        );
+        assert_eq!(
+            Err(DistanceError::InvalidValue("km".to_owned())),
+            "km".parse::<Distance>()
+        );
+    }
+
+    #[test]
+    fn distance_to_meters() {
+        assert_eq!(
+            1852.0,
+            Distance(1.0, DistanceUnit::NauticalMiles).to_meters()
+        );
+        assert_eq!(1500.0, Distance(1.5, DistanceUnit::Kilometers).to_meters());
+    }
+}
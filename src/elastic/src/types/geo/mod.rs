@@ -4,8 +4,12 @@ Implementation of the Elasticsearch `geo` types.
 Use [`point::GeoPoint`](point/struct.GeoPoint.html) for indexing simple geo points with an `x` and `y` coordinate.
 
 Use [`shape::GeoShape`](shape/struct.GeoShape.html) for indexing `geojson`.
+
+Use [`bounding_box::GeoBoundingBox`](bounding_box/struct.GeoBoundingBox.html) and [`mapping::Distance`](mapping/struct.Distance.html)
+for the values of geo queries and aggregations.
 */
 
+pub mod bounding_box;
 pub mod mapping;
 pub mod point;
 pub mod shape;
@@ -18,6 +22,7 @@ pub mod prelude {
     */
 
     pub use super::{
+        bounding_box::*,
         mapping::*,
         point::prelude::*,
         shape::prelude::*,
@@ -9,12 +9,13 @@ use geohash;
 use serde::{
     de::{
         Error as DeError,
+        IgnoredAny,
+        MapAccess,
         SeqAccess,
         Unexpected,
         Visitor,
     },
     ser::Error as SerError,
-    Deserialize,
     Deserializer,
     Serialize,
     Serializer,
@@ -27,11 +28,15 @@ use std::{
 /** The default `geo_point` format (`GeoPointArray`). */
 pub type DefaultGeoPointFormat = GeoPointArray;
 
-/** Geo-point expressed as an object, with `lat` and `lon` keys. */
+/**
+Geo-point expressed as an object, with `lat` and `lon` keys.
+
+Deserialising accepts any of the `geo_point` representations.
+*/
 #[derive(PartialEq, Debug, Default, Clone, Copy)]
 pub struct GeoPointObject;
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
 struct GeoPointObjectType {
     pub lat: f64,
     pub lon: f64,
@@ -42,9 +47,7 @@ impl GeoPointFormat for GeoPointObject {
     where
         D: Deserializer<'de>,
     {
-        let point = GeoPointObjectType::deserialize(deserializer)?;
-
-        Ok(Point::new(point.lon, point.lat))
+        parse_any(deserializer)
     }
 
     fn format<S, TMapping>(point: &Point, serializer: S) -> Result<S::Ok, S::Error>
@@ -60,7 +63,11 @@ impl GeoPointFormat for GeoPointObject {
     }
 }
 
-/** Geo-point expressed as a string with the format: `"lat,lon"`. */
+/**
+Geo-point expressed as a string with the format: `"lat,lon"`.
+
+Deserialising accepts any of the `geo_point` representations.
+*/
 #[derive(PartialEq, Debug, Default, Clone, Copy)]
 pub struct GeoPointString;
 impl GeoPointFormat for GeoPointString {
@@ -68,50 +75,7 @@ impl GeoPointFormat for GeoPointString {
     where
         D: Deserializer<'de>,
     {
-        struct PointVisitor;
-
-        impl<'de> Visitor<'de> for PointVisitor {
-            type Value = String;
-
-            fn expecting(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-                write!(formatter, "a json string formatted as 'y,x'")
-            }
-
-            fn visit_str<E>(self, value: &str) -> Result<String, E>
-            where
-                E: DeError,
-            {
-                Ok(String::from(value))
-            }
-
-            fn visit_string<E>(self, value: String) -> Result<String, E>
-            where
-                E: DeError,
-            {
-                Ok(value)
-            }
-        }
-
-        let fmtd = deserializer.deserialize_string(PointVisitor)?;
-
-        let xy: Vec<&str> = fmtd.split(",").collect();
-        if xy.len() != 2 {
-            return Err(D::Error::invalid_value(
-                Unexpected::Str(&fmtd),
-                &"point must be formatted as `'y,x'`",
-            ));
-        }
-
-        let x = match f64::from_str(xy[1]) {
-            Ok(x) => x,
-            Err(_) => return Err(D::Error::custom("`x` value must be a float")),
-        };
-        let y = match f64::from_str(xy[0]) {
-            Ok(y) => y,
-            Err(_) => return Err(D::Error::custom("`y` value must be a float")),
-        };
-
-        Ok(Point::new(x, y))
+        parse_any(deserializer)
     }
 
     fn format<S, TMapping>(point: &Point, serializer: S) -> Result<S::Ok, S::Error>
@@ -127,7 +91,11 @@ impl GeoPointFormat for GeoPointString {
     }
 }
 
-/** Geo-point expressed as a geohash. */
+/**
+Geo-point expressed as a geohash.
+
+Deserialising accepts any of the `geo_point` representations.
+*/
 #[derive(PartialEq, Debug, Default, Clone, Copy)]
 pub struct GeoPointHash;
 impl GeoPointFormat for GeoPointHash {
@@ -135,32 +103,7 @@ impl GeoPointFormat for GeoPointHash {
     where
         D: Deserializer<'de>,
     {
-        struct PointVisitor;
-        impl<'de> Visitor<'de> for PointVisitor {
-            type Value = String;
-
-            fn expecting(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-                write!(formatter, "a json string containing a geohash")
-            }
-
-            fn visit_str<E>(self, value: &str) -> Result<String, E>
-            where
-                E: DeError,
-            {
-                Ok(String::from(value))
-            }
-
-            fn visit_string<E>(self, value: String) -> Result<String, E>
-            where
-                E: DeError,
-            {
-                Ok(value)
-            }
-        }
-
-        let hash = deserializer.deserialize_string(PointVisitor)?;
-        let (coord, _, _) = geohash::decode(&hash).map_err(|e| D::Error::custom(e))?;
-        Ok(Point::new(coord.x, coord.y))
+        parse_any(deserializer)
     }
 
     fn format<S, TMapping>(point: &Point, serializer: S) -> Result<S::Ok, S::Error>
@@ -185,7 +128,11 @@ impl GeoPointFormat for GeoPointHash {
     }
 }
 
-/** Geo-point expressed as an array with the format: `[lon, lat]` */
+/**
+Geo-point expressed as an array with the format: `[lon, lat]`
+
+Deserialising accepts any of the `geo_point` representations.
+*/
 #[derive(PartialEq, Debug, Default, Clone, Copy)]
 pub struct GeoPointArray;
 impl GeoPointFormat for GeoPointArray {
@@ -193,52 +140,129 @@ impl GeoPointFormat for GeoPointArray {
     where
         D: Deserializer<'de>,
     {
-        struct PointVisitor;
-        impl<'de> Visitor<'de> for PointVisitor {
-            type Value = Point;
+        parse_any(deserializer)
+    }
 
-            fn expecting(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-                write!(formatter, "an array with 2 numbers")
-            }
+    fn format<S, TMapping>(point: &Point, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        TMapping: GeoPointMapping<Format = Self>,
+        S: Serializer,
+    {
+        [point.x(), point.y()].serialize(serializer)
+    }
+}
 
-            fn visit_seq<S>(self, mut visitor: S) -> Result<Self::Value, S::Error>
-            where
-                S: SeqAccess<'de>,
-            {
-                let mut values = Vec::with_capacity(2);
-
-                while let Some(value) = visitor.next_element()? {
-                    if values.len() == 2 {
-                        Err(S::Error::invalid_value(
-                            Unexpected::Seq,
-                            &"a json array with 2 values",
-                        ))?;
-                    }
+/**
+Parse a point from any of the representations supported by Elasticsearch:
+
+- an object with `lat` and `lon` keys
+- a string with the format `"lat,lon"`
+- a geohash string
+- an array with the format `[lon, lat]`
+*/
+fn parse_any<'de, D>(deserializer: D) -> Result<Point, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct PointVisitor;
+    impl<'de> Visitor<'de> for PointVisitor {
+        type Value = Point;
+
+        fn expecting(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+            write!(
+                formatter,
+                "a geo point as an object, a 'y,x' string, a geohash or an array with 2 numbers"
+            )
+        }
 
-                    values.push(value);
-                }
+        fn visit_str<E>(self, value: &str) -> Result<Point, E>
+        where
+            E: DeError,
+        {
+            if value.contains(',') {
+                parse_lat_lon(value)
+            } else {
+                let (coord, _, _) = geohash::decode(value).map_err(|e| E::custom(e))?;
+                Ok(Point::new(coord.x, coord.y))
+            }
+        }
+
+        fn visit_seq<S>(self, mut visitor: S) -> Result<Point, S::Error>
+        where
+            S: SeqAccess<'de>,
+        {
+            let mut values = Vec::with_capacity(2);
 
-                if values.len() != 2 {
+            while let Some(value) = visitor.next_element()? {
+                if values.len() == 2 {
                     Err(S::Error::invalid_value(
                         Unexpected::Seq,
                         &"a json array with 2 values",
                     ))?;
                 }
 
-                Ok(Point::new(values[0], values[1]))
+                values.push(value);
+            }
+
+            if values.len() != 2 {
+                Err(S::Error::invalid_value(
+                    Unexpected::Seq,
+                    &"a json array with 2 values",
+                ))?;
             }
+
+            Ok(Point::new(values[0], values[1]))
         }
 
-        deserializer.deserialize_any(PointVisitor)
+        fn visit_map<M>(self, mut visitor: M) -> Result<Point, M::Error>
+        where
+            M: MapAccess<'de>,
+        {
+            let mut lat = None;
+            let mut lon = None;
+
+            while let Some(key) = visitor.next_key::<String>()? {
+                match key.as_str() {
+                    "lat" => lat = Some(visitor.next_value::<f64>()?),
+                    "lon" => lon = Some(visitor.next_value::<f64>()?),
+                    _ => {
+                        visitor.next_value::<IgnoredAny>()?;
+                    }
+                }
+            }
+
+            let lat = lat.ok_or_else(|| M::Error::missing_field("lat"))?;
+            let lon = lon.ok_or_else(|| M::Error::missing_field("lon"))?;
+
+            Ok(Point::new(lon, lat))
+        }
     }
 
-    fn format<S, TMapping>(point: &Point, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        TMapping: GeoPointMapping<Format = Self>,
-        S: Serializer,
-    {
-        [point.x(), point.y()].serialize(serializer)
+    deserializer.deserialize_any(PointVisitor)
+}
+
+fn parse_lat_lon<E>(fmtd: &str) -> Result<Point, E>
+where
+    E: DeError,
+{
+    let xy: Vec<&str> = fmtd.split(",").collect();
+    if xy.len() != 2 {
+        return Err(E::invalid_value(
+            Unexpected::Str(fmtd),
+            &"point must be formatted as `'y,x'`",
+        ));
     }
+
+    let x = match f64::from_str(xy[1].trim()) {
+        Ok(x) => x,
+        Err(_) => return Err(E::custom("`x` value must be a float")),
+    };
+    let y = match f64::from_str(xy[0].trim()) {
+        Ok(y) => y,
+        Err(_) => return Err(E::custom("`y` value must be a float")),
+    };
+
+    Ok(Point::new(x, y))
 }
 
 #[cfg(test)]
@@ -323,4 +347,48 @@ mod tests {
         assert!(de.is_err());
     }
 
+    #[test]
+    fn any_format_is_accepted() {
+        let cases = vec![
+            r#"{"lat":41.12,"lon":-71.34}"#,
+            r#""41.12,-71.34""#,
+            r#"[-71.34,41.12]"#,
+        ];
+
+        for case in cases {
+            let object: GeoPoint<DefaultGeoPointMapping<GeoPointObject>> =
+                serde_json::from_str(case).unwrap();
+            let string: GeoPoint<DefaultGeoPointMapping<GeoPointString>> =
+                serde_json::from_str(case).unwrap();
+            let array: GeoPoint<DefaultGeoPointMapping<GeoPointArray>> =
+                serde_json::from_str(case).unwrap();
+            let hash: GeoPoint<DefaultGeoPointMapping<GeoPointHash>> =
+                serde_json::from_str(case).unwrap();
+
+            for point in &[object.x(), string.x(), array.x(), hash.x()] {
+                assert_eq!(-71.34, *point, "{}", case);
+            }
+        }
+
+        let array: GeoPoint<DefaultGeoPointMapping<GeoPointArray>> =
+            serde_json::from_str(r#""drm3btev3e86""#).unwrap();
+
+        assert_eq!(
+            (-71.34000012651086, 41.12000000663102),
+            (array.x(), array.y())
+        );
+        assert_eq!(
+            r#"[-71.34000012651086,41.12000000663102]"#,
+            serde_json::to_string(&array).unwrap()
+        );
+    }
+
+    #[test]
+    fn object_with_missing_lon() {
+        let de = serde_json::from_str::<GeoPoint<DefaultGeoPointMapping<GeoPointObject>>>(
+            r#"{"lat":41.12}"#,
+        );
+
+        assert!(de.is_err());
+    }
 }
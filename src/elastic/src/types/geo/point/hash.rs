@@ -0,0 +1,283 @@
+use geohash;
+use serde::{
+    de::{
+        Error as DeError,
+        Visitor,
+    },
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+};
+use std::{
+    fmt,
+    str::FromStr,
+};
+
+use super::{
+    Coordinate,
+    Point,
+};
+
+/** The maximum precision of a geohash supported by Elasticsearch. */
+pub const MAX_GEOHASH_PRECISION: usize = 12;
+
+/**
+A geohash.
+
+A geohash encodes a point as a short string, where each character narrows the area the hash covers.
+The number of characters in a geohash is its precision.
+Geohashes can be used anywhere Elasticsearch expects a geo point, like the corners of a `geo_bounding_box` query,
+and are returned by the `geohash_grid` aggregation.
+
+# Examples
+
+Encode a point as a geohash and use it in a query:
+
+```
+# #[macro_use] extern crate serde_json;
+# use elastic::types::prelude::*;
+# fn main() { run().unwrap() }
+# fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+let point: GeoPoint<DefaultGeoPointMapping> = GeoPoint::build(-71.34, 41.12);
+
+let hash = point.to_geohash(5)?;
+
+let query = json!({
+    "query": {
+        "geo_bounding_box": {
+            "location": {
+                "top_left": hash,
+                "bottom_right": hash
+            }
+        }
+    }
+});
+
+assert_eq!("drm3b", hash.as_str());
+assert_eq!(json!("drm3b"), query["query"]["geo_bounding_box"]["location"]["top_left"]);
+# Ok(())
+# }
+```
+
+Parse a geohash from a `geohash_grid` bucket key:
+
+```
+# use elastic::types::prelude::*;
+# fn main() { run().unwrap() }
+# fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+let hash: Geohash = "drm3b".parse()?;
+
+let point: GeoPoint<DefaultGeoPointMapping> = GeoPoint::from_geohash(hash.as_str())?;
+
+assert_eq!(5, hash.precision());
+# Ok(())
+# }
+```
+*/
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Geohash(String);
+
+impl Geohash {
+    /**
+    Encode a point as a geohash with the given precision.
+
+    The precision must be between `1` and [`MAX_GEOHASH_PRECISION`][MAX_GEOHASH_PRECISION].
+
+    [MAX_GEOHASH_PRECISION]: constant.MAX_GEOHASH_PRECISION.html
+    */
+    pub fn encode<I>(point: I, precision: usize) -> Result<Self, GeohashError>
+    where
+        I: Into<Point>,
+    {
+        if precision < 1 || precision > MAX_GEOHASH_PRECISION {
+            return Err(GeohashError::InvalidPrecision(precision));
+        }
+
+        let point = point.into();
+
+        let hash = geohash::encode(
+            Coordinate {
+                x: point.x(),
+                y: point.y(),
+            },
+            precision,
+        )
+        .map_err(|_| GeohashError::InvalidPoint(point.x(), point.y()))?;
+
+        Ok(Geohash(hash))
+    }
+
+    /** Parse a geohash, checking that it's valid. */
+    pub fn parse<I>(hash: I) -> Result<Self, GeohashError>
+    where
+        I: Into<String>,
+    {
+        let hash = hash.into();
+
+        if hash.is_empty() || hash.len() > MAX_GEOHASH_PRECISION {
+            return Err(GeohashError::InvalidHash(hash));
+        }
+
+        match geohash::decode(&hash) {
+            Ok(_) => Ok(Geohash(hash)),
+            Err(_) => Err(GeohashError::InvalidHash(hash)),
+        }
+    }
+
+    /** The geohash as a string. */
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /** The precision of the geohash, which is the number of characters in it. */
+    pub fn precision(&self) -> usize {
+        self.0.len()
+    }
+
+    /** Decode the point at the centre of the area covered by the geohash. */
+    pub fn to_point(&self) -> Point {
+        let (coord, _, _) = geohash::decode(&self.0).expect("a geohash is always valid");
+
+        Point::new(coord.x, coord.y)
+    }
+}
+
+impl fmt::Display for Geohash {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl FromStr for Geohash {
+    type Err = GeohashError;
+
+    fn from_str(hash: &str) -> Result<Self, Self::Err> {
+        Geohash::parse(hash)
+    }
+}
+
+impl From<Geohash> for String {
+    fn from(hash: Geohash) -> String {
+        hash.0
+    }
+}
+
+impl From<Geohash> for Point {
+    fn from(hash: Geohash) -> Point {
+        hash.to_point()
+    }
+}
+
+impl Serialize for Geohash {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Geohash {
+    fn deserialize<D>(deserializer: D) -> Result<Geohash, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct GeohashVisitor;
+        impl<'de> Visitor<'de> for GeohashVisitor {
+            type Value = Geohash;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                write!(formatter, "a geohash string")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Geohash, E>
+            where
+                E: DeError,
+            {
+                Geohash::parse(value).map_err(|e| E::custom(e))
+            }
+        }
+
+        deserializer.deserialize_str(GeohashVisitor)
+    }
+}
+
+quick_error! {
+    /** An error encoding or parsing a geohash. */
+    #[derive(Debug, PartialEq)]
+    pub enum GeohashError {
+        /** The geohash is empty, too long, or contains characters that aren't part of the geohash alphabet. */
+        InvalidHash(hash: String) {
+            display("`{}` isn't a valid geohash", hash)
+        }
+        /** The precision is outside the range supported by Elasticsearch. */
+        InvalidPrecision(precision: usize) {
+            display("geohash precision must be between 1 and {}, but was {}", MAX_GEOHASH_PRECISION, precision)
+        }
+        /** The point is outside the range of valid coordinates. */
+        InvalidPoint(x: f64, y: f64) {
+            display("`({}, {})` isn't a valid coordinate", x, y)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+
+    use crate::types::prelude::*;
+
+    #[test]
+    fn encode_point() {
+        let point: GeoPoint<DefaultGeoPointMapping> = GeoPoint::build(-71.34, 41.12);
+
+        let hash = point.to_geohash(12).unwrap();
+
+        assert_eq!("drm3btev3e86", hash.as_str());
+        assert_eq!(12, hash.precision());
+    }
+
+    #[test]
+    fn decode_point() {
+        let point: GeoPoint<DefaultGeoPointMapping> =
+            GeoPoint::from_geohash("drm3btev3e86").unwrap();
+
+        assert!((point.x() - -71.34).abs() < 0.0001);
+        assert!((point.y() - 41.12).abs() < 0.0001);
+    }
+
+    #[test]
+    fn encode_invalid_precision() {
+        let point: GeoPoint<DefaultGeoPointMapping> = GeoPoint::build(-71.34, 41.12);
+
+        assert_eq!(
+            GeohashError::InvalidPrecision(0),
+            point.to_geohash(0).unwrap_err()
+        );
+        assert_eq!(
+            GeohashError::InvalidPrecision(13),
+            point.to_geohash(13).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn parse_invalid_hash() {
+        assert!(Geohash::parse("").is_err());
+        assert!(Geohash::parse("drm3a").is_err());
+        assert!(Geohash::parse("drm3btev3e86d").is_err());
+        assert!(GeoPoint::<DefaultGeoPointMapping>::from_geohash("41.12").is_err());
+    }
+
+    #[test]
+    fn serialise_geohash() {
+        let hash = Geohash::parse("drm3b").unwrap();
+
+        let ser = serde_json::to_string(&hash).unwrap();
+        let de: Geohash = serde_json::from_str(&ser).unwrap();
+
+        assert_eq!(r#""drm3b""#, ser);
+        assert_eq!(hash, de);
+        assert!(serde_json::from_str::<Geohash>(r#""drm3a""#).is_err());
+    }
+}
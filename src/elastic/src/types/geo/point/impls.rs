@@ -5,6 +5,8 @@ use super::{
     },
     Coordinate,
     GeoPointFormat,
+    Geohash,
+    GeohashError,
     Geometry,
     Point,
 };
@@ -137,6 +139,46 @@ where
     {
         GeoPoint::new(point.value)
     }
+
+    /**
+    Creates a `GeoPoint` from the centre of the area covered by a geohash.
+
+    # Examples
+
+    ```
+    # use elastic::types::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+    let point: GeoPoint<DefaultGeoPointMapping> = GeoPoint::from_geohash("drm3btev3e86")?;
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn from_geohash(hash: &str) -> Result<Self, GeohashError> {
+        Ok(GeoPoint::new(Geohash::parse(hash)?.to_point()))
+    }
+
+    /**
+    Encode this point as a geohash with the given precision.
+
+    The precision must be between `1` and `12`.
+
+    # Examples
+
+    ```
+    # use elastic::types::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+    let point: GeoPoint<DefaultGeoPointMapping> = GeoPoint::build(-71.34, 41.12);
+
+    assert_eq!("drm3btev3e86", point.to_geohash(12)?.as_str());
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn to_geohash(&self, precision: usize) -> Result<Geohash, GeohashError> {
+        Geohash::encode(self.value.clone(), precision)
+    }
 }
 
 impl<TMapping> GeoPointFieldType<TMapping> for GeoPoint<TMapping> where TMapping: GeoPointMapping {}
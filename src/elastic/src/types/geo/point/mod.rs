@@ -49,9 +49,39 @@ impl GeoPointFieldType<DefaultGeoPointMapping<GeoPointObject>> for MyGeoPointFie
 # }
 ```
 
+# Source formats
+
+Elasticsearch accepts geo points in a few different representations.
+The `Format` of a mapping decides which one is used when a point is serialised:
+
+- [`GeoPointArray`][GeoPointArray] (the default): `[lon, lat]`
+- [`GeoPointObject`][GeoPointObject]: `{ "lat": lat, "lon": lon }`
+- [`GeoPointString`][GeoPointString]: `"lat,lon"`
+- [`GeoPointHash`][GeoPointHash]: a geohash, like `"drm3btev3e86"`
+
+Every format accepts any of these representations when a point is deserialised,
+so documents indexed by other clients can be read regardless of the format they used.
+
+```
+# extern crate serde_json;
+# use elastic::types::prelude::*;
+# fn main() { run().unwrap() }
+# fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+let point: GeoPoint<DefaultGeoPointMapping<GeoPointObject>> = serde_json::from_str("[-71.34, 41.12]")?;
+
+assert_eq!(r#"{"lat":41.12,"lon":-71.34}"#, serde_json::to_string(&point)?);
+# Ok(())
+# }
+```
+
 # Links
 
 - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/geo-point.html)
+
+[GeoPointArray]: struct.GeoPointArray.html
+[GeoPointObject]: struct.GeoPointObject.html
+[GeoPointString]: struct.GeoPointString.html
+[GeoPointHash]: struct.GeoPointHash.html
 */
 
 use geo::{
@@ -68,11 +98,13 @@ pub mod mapping;
 
 mod format;
 mod formats;
+mod hash;
 mod impls;
 
 pub use self::{
     format::*,
     formats::*,
+    hash::*,
     impls::*,
 };
 
@@ -86,6 +118,7 @@ pub mod prelude {
     pub use super::{
         format::*,
         formats::*,
+        hash::*,
         impls::*,
         mapping::*,
         DefaultGeoPointFormat,
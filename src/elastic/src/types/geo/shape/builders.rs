@@ -0,0 +1,389 @@
+use geo::{
+    Coordinate,
+    Geometry as GeoEnum,
+    Line,
+    LineString,
+    MultiLineString,
+    MultiPoint,
+    MultiPolygon,
+    Point,
+    Polygon,
+};
+use geojson::{
+    Geometry,
+    PolygonType,
+    Position,
+    Value,
+};
+use std::f64::consts::PI;
+
+use super::{
+    mapping::GeoShapeMapping,
+    GeoShape,
+};
+use crate::types::geo::mapping::Distance;
+
+/**
+The number of sides of the polygon that approximates a circle built with [`GeoShape::circle`][GeoShape.circle].
+
+[GeoShape.circle]: struct.GeoShape.html#method.circle
+*/
+pub const CIRCLE_SEGMENTS: usize = 64;
+
+// The mean radius of the earth in meters, as used by Elasticsearch
+const EARTH_MEAN_RADIUS: f64 = 6_371_008.771_4;
+
+impl<TMapping> GeoShape<TMapping>
+where
+    TMapping: GeoShapeMapping,
+{
+    /**
+    Creates a rectangle from its top left and bottom right corners.
+
+    Points are given as `(lon, lat)`.
+    The rectangle is a polygon with edges along lines of latitude and longitude.
+
+    # Examples
+
+    ```
+    # #[macro_use] extern crate serde_json;
+    # use elastic::types::prelude::*;
+    # fn main() {
+    let shape: GeoShape<DefaultGeoShapeMapping> = GeoShape::envelope((-45.0, 45.0), (45.0, -45.0));
+
+    assert_eq!(
+        json!({
+            "type": "Polygon",
+            "coordinates": [[[-45.0, 45.0], [-45.0, -45.0], [45.0, -45.0], [45.0, 45.0], [-45.0, 45.0]]]
+        }),
+        serde_json::to_value(&shape).unwrap()
+    );
+    # }
+    ```
+    */
+    pub fn envelope(top_left: (f64, f64), bottom_right: (f64, f64)) -> Self {
+        let (left, top) = top_left;
+        let (right, bottom) = bottom_right;
+
+        GeoShape::polygon(
+            vec![(left, top), (left, bottom), (right, bottom), (right, top)],
+            Vec::new(),
+        )
+    }
+
+    /**
+    Creates a circle from its centre and radius.
+
+    The centre is given as `(lon, lat)`.
+    Circles aren't part of GeoJSON, so the circle is approximated by a polygon with [`CIRCLE_SEGMENTS`][CIRCLE_SEGMENTS] sides,
+    like the Elasticsearch `circle` ingest processor does.
+
+    # Examples
+
+    ```
+    # use elastic::types::prelude::*;
+    let shape: GeoShape<DefaultGeoShapeMapping> = GeoShape::circle((-71.34, 41.12), Distance(5.0, DistanceUnit::Kilometers));
+    ```
+
+    [CIRCLE_SEGMENTS]: constant.CIRCLE_SEGMENTS.html
+    */
+    pub fn circle(centre: (f64, f64), radius: Distance) -> Self {
+        let (lon, lat) = (centre.0.to_radians(), centre.1.to_radians());
+        let angle = radius.to_meters() / EARTH_MEAN_RADIUS;
+
+        // Walk the bearings anticlockwise so the ring follows the right-hand rule
+        let ring = (0..CIRCLE_SEGMENTS).map(|i| {
+            let bearing = 2.0 * PI * ((CIRCLE_SEGMENTS - i) as f64) / (CIRCLE_SEGMENTS as f64);
+
+            let point_lat =
+                (lat.sin() * angle.cos() + lat.cos() * angle.sin() * bearing.cos()).asin();
+            let point_lon = lon
+                + (bearing.sin() * angle.sin() * lat.cos())
+                    .atan2(angle.cos() - lat.sin() * point_lat.sin());
+
+            (point_lon.to_degrees(), point_lat.to_degrees())
+        });
+
+        GeoShape::polygon(ring, Vec::new())
+    }
+
+    /**
+    Creates a polygon from an exterior ring and any number of interior rings (holes).
+
+    Points are given as `(lon, lat)`.
+    Rings are closed automatically if their last point isn't the same as their first.
+
+    # Examples
+
+    ```
+    # use elastic::types::prelude::*;
+    let shape: GeoShape<DefaultGeoShapeMapping> = GeoShape::polygon(
+        vec![(100.0, 0.0), (101.0, 0.0), (101.0, 1.0), (100.0, 1.0)],
+        vec![vec![(100.2, 0.2), (100.2, 0.8), (100.8, 0.8), (100.8, 0.2)]],
+    );
+    ```
+    */
+    pub fn polygon<TRing>(exterior: TRing, interiors: Vec<TRing>) -> Self
+    where
+        TRing: IntoIterator<Item = (f64, f64)>,
+    {
+        GeoShape::new(Geometry::new(Value::Polygon(polygon(exterior, interiors))))
+    }
+
+    /**
+    Creates a multi polygon from a set of polygons.
+
+    Each polygon is an exterior ring and any number of interior rings, like [`GeoShape::polygon`][GeoShape.polygon].
+
+    # Examples
+
+    ```
+    # use elastic::types::prelude::*;
+    let shape: GeoShape<DefaultGeoShapeMapping> = GeoShape::multi_polygon(vec![
+        (vec![(102.0, 2.0), (103.0, 2.0), (103.0, 3.0), (102.0, 3.0)], vec![]),
+        (vec![(100.0, 0.0), (101.0, 0.0), (101.0, 1.0), (100.0, 1.0)], vec![]),
+    ]);
+    ```
+
+    [GeoShape.polygon]: #method.polygon
+    */
+    pub fn multi_polygon<TPolygons, TRing>(polygons: TPolygons) -> Self
+    where
+        TPolygons: IntoIterator<Item = (TRing, Vec<TRing>)>,
+        TRing: IntoIterator<Item = (f64, f64)>,
+    {
+        let polygons = polygons
+            .into_iter()
+            .map(|(exterior, interiors)| polygon(exterior, interiors))
+            .collect();
+
+        GeoShape::new(Geometry::new(Value::MultiPolygon(polygons)))
+    }
+}
+
+fn polygon<TRing>(exterior: TRing, interiors: Vec<TRing>) -> PolygonType
+where
+    TRing: IntoIterator<Item = (f64, f64)>,
+{
+    let mut rings = vec![ring(exterior)];
+    rings.extend(interiors.into_iter().map(ring));
+
+    rings
+}
+
+fn ring<TRing>(points: TRing) -> Vec<Position>
+where
+    TRing: IntoIterator<Item = (f64, f64)>,
+{
+    let mut ring: Vec<Position> = points.into_iter().map(|(x, y)| vec![x, y]).collect();
+
+    let closed = ring.first() == ring.last();
+    if !closed {
+        let first = ring[0].clone();
+        ring.push(first);
+    }
+
+    ring
+}
+
+fn position(coord: &Coordinate<f64>) -> Position {
+    vec![coord.x, coord.y]
+}
+
+fn line_string(line: &LineString<f64>) -> Vec<Position> {
+    line.0.iter().map(position).collect()
+}
+
+fn polygon_rings(polygon: &Polygon<f64>) -> PolygonType {
+    let mut rings = vec![line_string(polygon.exterior())];
+    rings.extend(polygon.interiors().iter().map(line_string));
+
+    rings
+}
+
+fn value(geometry: &GeoEnum<f64>) -> Value {
+    match *geometry {
+        GeoEnum::Point(ref point) => Value::Point(position(&point.0)),
+        GeoEnum::Line(ref line) => {
+            Value::LineString(vec![position(&line.start), position(&line.end)])
+        }
+        GeoEnum::LineString(ref line) => Value::LineString(line_string(line)),
+        GeoEnum::Polygon(ref polygon) => Value::Polygon(polygon_rings(polygon)),
+        GeoEnum::MultiPoint(ref points) => {
+            Value::MultiPoint(points.0.iter().map(|point| position(&point.0)).collect())
+        }
+        GeoEnum::MultiLineString(ref lines) => {
+            Value::MultiLineString(lines.0.iter().map(line_string).collect())
+        }
+        GeoEnum::MultiPolygon(ref polygons) => {
+            Value::MultiPolygon(polygons.0.iter().map(polygon_rings).collect())
+        }
+        GeoEnum::GeometryCollection(ref collection) => Value::GeometryCollection(
+            collection
+                .0
+                .iter()
+                .map(|geometry| Geometry::new(value(geometry)))
+                .collect(),
+        ),
+    }
+}
+
+macro_rules! impl_from_geo {
+    ($($geo_ty:ident),*) => {
+        $(
+            impl<TMapping> From<$geo_ty<f64>> for GeoShape<TMapping>
+            where
+                TMapping: GeoShapeMapping,
+            {
+                fn from(geometry: $geo_ty<f64>) -> Self {
+                    GeoShape::new(Geometry::new(value(&GeoEnum::from(geometry))))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_geo!(
+    Point,
+    Line,
+    LineString,
+    Polygon,
+    MultiPoint,
+    MultiLineString,
+    MultiPolygon
+);
+
+impl<TMapping> From<GeoEnum<f64>> for GeoShape<TMapping>
+where
+    TMapping: GeoShapeMapping,
+{
+    fn from(geometry: GeoEnum<f64>) -> Self {
+        GeoShape::new(Geometry::new(value(&geometry)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use geo::{
+        Coordinate,
+        LineString,
+        Point,
+        Polygon,
+    };
+    use geojson::{
+        Geometry,
+        Value,
+    };
+    use serde_json;
+
+    use crate::types::prelude::*;
+
+    type Shape = GeoShape<DefaultGeoShapeMapping>;
+
+    #[test]
+    fn build_envelope() {
+        let shape = Shape::envelope((-45.0, 45.0), (45.0, -45.0));
+
+        assert_eq!(
+            json!({
+                "type": "Polygon",
+                "coordinates": [[[-45.0, 45.0], [-45.0, -45.0], [45.0, -45.0], [45.0, 45.0], [-45.0, 45.0]]]
+            }),
+            serde_json::to_value(&shape).unwrap()
+        );
+    }
+
+    #[test]
+    fn build_circle() {
+        let shape = Shape::circle((0.0, 0.0), Distance(111.195, DistanceUnit::Kilometers));
+
+        let geometry: &Geometry = &shape;
+
+        let ring = match geometry.value {
+            Value::Polygon(ref rings) => rings[0].clone(),
+            _ => panic!("expected a polygon"),
+        };
+
+        assert_eq!(CIRCLE_SEGMENTS + 1, ring.len());
+        assert_eq!(ring[0], ring[CIRCLE_SEGMENTS]);
+
+        // The first point is due north of the centre, by about 1 degree of latitude
+        assert!(ring[0][0].abs() < 0.0001);
+        assert!((ring[0][1] - 1.0).abs() < 0.001);
+
+        // The ring is anticlockwise, so the next point is west of the first
+        assert!(ring[1][0] < 0.0);
+    }
+
+    #[test]
+    fn build_polygon_closes_rings() {
+        let shape = Shape::polygon(
+            vec![
+                (100.0, 0.0),
+                (101.0, 0.0),
+                (101.0, 1.0),
+                (100.0, 1.0),
+                (100.0, 0.0),
+            ],
+            vec![vec![(100.2, 0.2), (100.2, 0.8), (100.8, 0.8)]],
+        );
+
+        assert_eq!(
+            json!({
+                "type": "Polygon",
+                "coordinates": [
+                    [[100.0, 0.0], [101.0, 0.0], [101.0, 1.0], [100.0, 1.0], [100.0, 0.0]],
+                    [[100.2, 0.2], [100.2, 0.8], [100.8, 0.8], [100.2, 0.2]]
+                ]
+            }),
+            serde_json::to_value(&shape).unwrap()
+        );
+    }
+
+    #[test]
+    fn build_multi_polygon() {
+        let shape = Shape::multi_polygon(vec![
+            (vec![(102.0, 2.0), (103.0, 2.0), (103.0, 3.0)], vec![]),
+            (vec![(100.0, 0.0), (101.0, 0.0), (101.0, 1.0)], vec![]),
+        ]);
+
+        assert_eq!(
+            json!({
+                "type": "MultiPolygon",
+                "coordinates": [
+                    [[[102.0, 2.0], [103.0, 2.0], [103.0, 3.0], [102.0, 2.0]]],
+                    [[[100.0, 0.0], [101.0, 0.0], [101.0, 1.0], [100.0, 0.0]]]
+                ]
+            }),
+            serde_json::to_value(&shape).unwrap()
+        );
+    }
+
+    #[test]
+    fn convert_from_geo() {
+        let point: Shape = Point::new(1.0, 2.0).into();
+
+        let polygon: Shape = Polygon::new(
+            LineString(vec![
+                Coordinate { x: 0.0, y: 0.0 },
+                Coordinate { x: 1.0, y: 0.0 },
+                Coordinate { x: 1.0, y: 1.0 },
+                Coordinate { x: 0.0, y: 0.0 },
+            ]),
+            vec![],
+        )
+        .into();
+
+        assert_eq!(
+            json!({ "type": "Point", "coordinates": [1.0, 2.0] }),
+            serde_json::to_value(&point).unwrap()
+        );
+        assert_eq!(
+            json!({
+                "type": "Polygon",
+                "coordinates": [[[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 0.0]]]
+            }),
+            serde_json::to_value(&polygon).unwrap()
+        );
+    }
+}
@@ -147,6 +147,7 @@ pub struct DefaultGeoShapeMapping;
 impl GeoShapeMapping for DefaultGeoShapeMapping {}
 
 /** Name of the `PrefixTree` implementation to be used. */
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Tree {
     /** For `GeohashPrefixTree`. */
     Geohash,
@@ -167,6 +168,7 @@ impl Serialize for Tree {
 }
 
 /** The strategy defines the approach for how to represent shapes at indexing and search time. */
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Strategy {
     /** Recursive strategy supports all shape types. */
     Recursive,
@@ -194,6 +196,7 @@ each of which can be specified in a few different ways.
 The default orientation (counterclockwise) complies with the OGC standard which defines outer
 ring vertices in counterclockwise order with inner ring(s) vertices (holes) in clockwise order.
 */
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Orientation {
     /** For `cw`. */
     Clockwise,
@@ -346,4 +349,38 @@ mod tests {
         assert_eq!(expected, ser);
     }
 
+    #[test]
+    fn serialise_mapping_quadtree_precision() {
+        #[derive(Default, Clone)]
+        pub struct QuadtreeMapping;
+        impl GeoShapeMapping for QuadtreeMapping {
+            fn tree() -> Option<Tree> {
+                Some(Tree::QuadPrefix)
+            }
+
+            fn precision() -> Option<Distance> {
+                Some(Distance(1.5, DistanceUnit::Kilometers))
+            }
+
+            fn distance_error_pct() -> Option<f32> {
+                Some(0.25)
+            }
+
+            fn points_only() -> Option<bool> {
+                Some(true)
+            }
+        }
+
+        let ser = serde_json::to_value(&field::serialize(QuadtreeMapping)).unwrap();
+
+        let expected = json!({
+            "type": "geo_shape",
+            "tree": "quadtree",
+            "precision": "1.5km",
+            "distance_error_pct": 0.25,
+            "points_only": true
+        });
+
+        assert_eq!(expected, ser);
+    }
 }
@@ -47,15 +47,41 @@ impl GeoShapeFieldType<DefaultGeoShapeMapping> for MyGeoShapeField {}
 # }
 ```
 
+Build shapes without writing GeoJSON by hand:
+
+```
+# use elastic::types::prelude::*;
+let envelope: GeoShape<DefaultGeoShapeMapping> = GeoShape::envelope((-45.0, 45.0), (45.0, -45.0));
+let circle: GeoShape<DefaultGeoShapeMapping> = GeoShape::circle((-71.34, 41.12), Distance(5.0, DistanceUnit::Kilometers));
+```
+
+Polygons can be [validated][GeoShape.validate] before they're indexed.
+
+Shapes can also be converted from the `geo` crate's geometry types, like `geo::Polygon`,
+and parsed from or rendered as Well-Known Text with the `wkt` feature.
+
 # Links
 
 - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/geo-shape.html)
+
+[GeoShape.validate]: struct.GeoShape.html#method.validate
 */
 
 pub mod mapping;
 
+mod builders;
 mod impls;
-pub use self::impls::*;
+mod validate;
+#[cfg(feature = "wkt")]
+mod wkt;
+
+pub use self::{
+    builders::*,
+    impls::*,
+    validate::*,
+};
+#[cfg(feature = "wkt")]
+pub use self::wkt::*;
 
 pub mod prelude {
     /*!
@@ -65,7 +91,12 @@ pub mod prelude {
     */
 
     pub use super::{
+        builders::*,
         impls::*,
         mapping::*,
+        validate::*,
     };
+
+    #[cfg(feature = "wkt")]
+    pub use super::wkt::*;
 }
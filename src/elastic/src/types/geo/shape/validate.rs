@@ -0,0 +1,377 @@
+use geojson::{
+    Geometry,
+    PolygonType,
+    Position,
+    Value,
+};
+
+use super::{
+    mapping::{
+        GeoShapeMapping,
+        Orientation,
+    },
+    GeoShape,
+};
+
+impl<TMapping> GeoShape<TMapping>
+where
+    TMapping: GeoShapeMapping,
+{
+    /**
+    Check that the polygons in this shape are well-formed before indexing it.
+
+    Elasticsearch rejects malformed polygons with a `400` response that doesn't always say what's wrong with them.
+    This method checks the rings of each polygon in the shape, including polygons nested in collections, and returns the first problem found:
+
+    - each ring must have at least 4 positions
+    - each ring must be closed, so its first and last positions are the same
+    - a ring must not intersect itself
+    - the exterior ring must follow the [`orientation`][GeoShapeMapping.orientation] of the mapping and holes must go the other way.
+    Without an orientation exterior rings must be counterclockwise
+
+    Polygons are numbered in the order they appear in the shape and the exterior ring of a polygon is ring `0`.
+    Validation is opt-in because it walks every segment of every ring.
+
+    # Examples
+
+    ```
+    # use elastic::types::prelude::*;
+    // This ring is clockwise, but the default orientation is counterclockwise
+    let shape: GeoShape<DefaultGeoShapeMapping> = GeoShape::polygon(
+        vec![(0.0, 0.0), (0.0, 1.0), (1.0, 1.0), (1.0, 0.0)],
+        vec![],
+    );
+
+    assert_eq!(
+        Err(GeoShapeError::WrongOrientation {
+            polygon: 0,
+            ring: 0,
+            expected: Orientation::CounterClockwise,
+        }),
+        shape.validate()
+    );
+    ```
+
+    [GeoShapeMapping.orientation]: mapping/trait.GeoShapeMapping.html#method.orientation
+    */
+    pub fn validate(&self) -> Result<(), GeoShapeError> {
+        let geometry: &Geometry = self;
+        let orientation = TMapping::orientation().unwrap_or(Orientation::CounterClockwise);
+
+        validate_value(&geometry.value, orientation, &mut 0)
+    }
+}
+
+fn validate_value(
+    value: &Value,
+    orientation: Orientation,
+    polygons: &mut usize,
+) -> Result<(), GeoShapeError> {
+    match *value {
+        Value::Polygon(ref polygon) => validate_polygon(polygon, orientation, polygons),
+        Value::MultiPolygon(ref multi) => {
+            for polygon in multi {
+                validate_polygon(polygon, orientation, polygons)?;
+            }
+
+            Ok(())
+        }
+        Value::GeometryCollection(ref geometries) => {
+            for geometry in geometries {
+                validate_value(&geometry.value, orientation, polygons)?;
+            }
+
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
+
+fn validate_polygon(
+    rings: &PolygonType,
+    orientation: Orientation,
+    polygons: &mut usize,
+) -> Result<(), GeoShapeError> {
+    let polygon = *polygons;
+    *polygons += 1;
+
+    for (ring, positions) in rings.iter().enumerate() {
+        let points = positions.iter().map(point).collect::<Result<Vec<_>, _>>()?;
+
+        if points.len() < 4 {
+            return Err(GeoShapeError::TooFewPositions {
+                polygon,
+                ring,
+                len: points.len(),
+            });
+        }
+
+        if points.first() != points.last() {
+            return Err(GeoShapeError::UnclosedRing { polygon, ring });
+        }
+
+        if intersects_itself(&points) {
+            return Err(GeoShapeError::SelfIntersection { polygon, ring });
+        }
+
+        let expected = match (ring, orientation) {
+            (0, orientation) => orientation,
+            (_, Orientation::CounterClockwise) => Orientation::Clockwise,
+            (_, Orientation::Clockwise) => Orientation::CounterClockwise,
+        };
+
+        if ring_orientation(&points) != expected {
+            return Err(GeoShapeError::WrongOrientation {
+                polygon,
+                ring,
+                expected,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+type Point = (f64, f64);
+
+fn point(position: &Position) -> Result<Point, GeoShapeError> {
+    match position.as_slice() {
+        [x, y, ..] if x.is_finite() && y.is_finite() => Ok((*x, *y)),
+        _ => Err(GeoShapeError::InvalidPosition(position.clone())),
+    }
+}
+
+// Use the sign of the shoelace formula for the area of the ring.
+// Positive areas are counterclockwise.
+fn ring_orientation(points: &[Point]) -> Orientation {
+    let area: f64 = points
+        .windows(2)
+        .map(|edge| edge[0].0 * edge[1].1 - edge[1].0 * edge[0].1)
+        .sum();
+
+    if area > 0.0 {
+        Orientation::CounterClockwise
+    } else {
+        Orientation::Clockwise
+    }
+}
+
+fn intersects_itself(points: &[Point]) -> bool {
+    let segments: Vec<_> = points.windows(2).map(|edge| (edge[0], edge[1])).collect();
+    let last = segments.len() - 1;
+
+    for (i, a) in segments.iter().enumerate() {
+        // Neighbouring segments share a position, including the first and last segments of the closed ring
+        for (j, b) in segments.iter().enumerate().skip(i + 2) {
+            if i == 0 && j == last {
+                continue;
+            }
+
+            if segments_intersect(*a, *b) {
+                return true;
+            }
+        }
+    }
+
+    false
+}
+
+fn segments_intersect((p1, p2): (Point, Point), (q1, q2): (Point, Point)) -> bool {
+    let d1 = direction(q1, q2, p1);
+    let d2 = direction(q1, q2, p2);
+    let d3 = direction(p1, p2, q1);
+    let d4 = direction(p1, p2, q2);
+
+    if ((d1 > 0.0 && d2 < 0.0) || (d1 < 0.0 && d2 > 0.0))
+        && ((d3 > 0.0 && d4 < 0.0) || (d3 < 0.0 && d4 > 0.0))
+    {
+        return true;
+    }
+
+    (d1 == 0.0 && on_segment(q1, q2, p1))
+        || (d2 == 0.0 && on_segment(q1, q2, p2))
+        || (d3 == 0.0 && on_segment(p1, p2, q1))
+        || (d4 == 0.0 && on_segment(p1, p2, q2))
+}
+
+fn direction(a: Point, b: Point, c: Point) -> f64 {
+    (b.0 - a.0) * (c.1 - a.1) - (b.1 - a.1) * (c.0 - a.0)
+}
+
+// Whether a point that's collinear with a segment is within its bounds
+fn on_segment(a: Point, b: Point, p: Point) -> bool {
+    p.0 >= a.0.min(b.0) && p.0 <= a.0.max(b.0) && p.1 >= a.1.min(b.1) && p.1 <= a.1.max(b.1)
+}
+
+quick_error! {
+    /** A problem with a polygon in a geo shape. */
+    #[derive(Debug, PartialEq)]
+    pub enum GeoShapeError {
+        /** A ring has fewer than the 4 positions needed to close a triangle. */
+        TooFewPositions { polygon: usize, ring: usize, len: usize } {
+            display("ring {} of polygon {} has {} positions but needs at least 4", ring, polygon, len)
+        }
+        /** The first and last positions of a ring are different. */
+        UnclosedRing { polygon: usize, ring: usize } {
+            display("ring {} of polygon {} isn't closed", ring, polygon)
+        }
+        /** A ring doesn't follow the orientation of the mapping. */
+        WrongOrientation { polygon: usize, ring: usize, expected: Orientation } {
+            display("ring {} of polygon {} should be {:?}", ring, polygon, expected)
+        }
+        /** A ring crosses or touches itself. */
+        SelfIntersection { polygon: usize, ring: usize } {
+            display("ring {} of polygon {} intersects itself", ring, polygon)
+        }
+        /** A position doesn't have a finite `x` and `y` coordinate. */
+        InvalidPosition(position: Position) {
+            display("`{:?}` isn't a valid position", position)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use geojson::{
+        Geometry,
+        Value,
+    };
+
+    use crate::types::prelude::*;
+
+    type Shape = GeoShape<DefaultGeoShapeMapping>;
+
+    #[derive(Default, Clone)]
+    struct ClockwiseMapping;
+    impl GeoShapeMapping for ClockwiseMapping {
+        fn orientation() -> Option<Orientation> {
+            Some(Orientation::Clockwise)
+        }
+    }
+
+    fn polygon(rings: Vec<Vec<(f64, f64)>>) -> Value {
+        Value::Polygon(
+            rings
+                .into_iter()
+                .map(|ring| ring.into_iter().map(|(x, y)| vec![x, y]).collect())
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn built_shapes_are_valid() {
+        assert_eq!(
+            Ok(()),
+            Shape::envelope((-45.0, 45.0), (45.0, -45.0)).validate()
+        );
+        assert_eq!(
+            Ok(()),
+            Shape::circle((-71.34, 41.12), Distance(5.0, DistanceUnit::Kilometers)).validate()
+        );
+    }
+
+    #[test]
+    fn polygon_with_hole_is_valid() {
+        let shape = Shape::polygon(
+            vec![(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)],
+            vec![vec![(2.0, 2.0), (2.0, 8.0), (8.0, 8.0), (8.0, 2.0)]],
+        );
+
+        assert_eq!(Ok(()), shape.validate());
+    }
+
+    #[test]
+    fn clockwise_mapping() {
+        let shape: GeoShape<ClockwiseMapping> =
+            GeoShape::polygon(vec![(0.0, 0.0), (0.0, 1.0), (1.0, 1.0), (1.0, 0.0)], vec![]);
+
+        assert_eq!(Ok(()), shape.validate());
+    }
+
+    #[test]
+    fn hole_with_wrong_orientation() {
+        let shape = Shape::polygon(
+            vec![(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)],
+            vec![vec![(2.0, 2.0), (8.0, 2.0), (8.0, 8.0), (2.0, 8.0)]],
+        );
+
+        assert_eq!(
+            Err(GeoShapeError::WrongOrientation {
+                polygon: 0,
+                ring: 1,
+                expected: Orientation::Clockwise,
+            }),
+            shape.validate()
+        );
+    }
+
+    #[test]
+    fn unclosed_ring() {
+        let shape = Shape::new(Geometry::new(polygon(vec![vec![
+            (0.0, 0.0),
+            (1.0, 0.0),
+            (1.0, 1.0),
+            (0.0, 1.0),
+        ]])));
+
+        assert_eq!(
+            Err(GeoShapeError::UnclosedRing {
+                polygon: 0,
+                ring: 0
+            }),
+            shape.validate()
+        );
+    }
+
+    #[test]
+    fn too_few_positions() {
+        let shape = Shape::new(Geometry::new(polygon(vec![vec![
+            (0.0, 0.0),
+            (1.0, 0.0),
+            (0.0, 0.0),
+        ]])));
+
+        assert_eq!(
+            Err(GeoShapeError::TooFewPositions {
+                polygon: 0,
+                ring: 0,
+                len: 3
+            }),
+            shape.validate()
+        );
+    }
+
+    #[test]
+    fn self_intersection() {
+        // A bow tie, where the second and fourth edges cross
+        let bow_tie = vec![(0.0, 0.0), (2.0, 0.0), (0.0, 2.0), (2.0, 2.0)];
+
+        let shape = Shape::multi_polygon(vec![
+            (vec![(5.0, 5.0), (6.0, 5.0), (6.0, 6.0), (5.0, 6.0)], vec![]),
+            (bow_tie, vec![]),
+        ]);
+
+        assert_eq!(
+            Err(GeoShapeError::SelfIntersection {
+                polygon: 1,
+                ring: 0
+            }),
+            shape.validate()
+        );
+    }
+
+    #[test]
+    fn invalid_position() {
+        let shape = Shape::new(Geometry::new(Value::Polygon(vec![vec![
+            vec![0.0, 0.0],
+            vec![1.0],
+            vec![1.0, 1.0],
+            vec![0.0, 0.0],
+        ]])));
+
+        assert_eq!(
+            Err(GeoShapeError::InvalidPosition(vec![1.0])),
+            shape.validate()
+        );
+    }
+}
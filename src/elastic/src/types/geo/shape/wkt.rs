@@ -0,0 +1,454 @@
+use geojson::{
+    Geometry,
+    PolygonType,
+    Position,
+    Value,
+};
+use std::{
+    fmt::Write,
+    iter::Peekable,
+    str::CharIndices,
+};
+
+use super::{
+    mapping::GeoShapeMapping,
+    GeoShape,
+};
+
+impl<TMapping> GeoShape<TMapping>
+where
+    TMapping: GeoShapeMapping,
+{
+    /**
+    Parse a shape from Well-Known Text (WKT).
+
+    The `POINT`, `LINESTRING`, `POLYGON`, `MULTIPOINT`, `MULTILINESTRING`, `MULTIPOLYGON` and `GEOMETRYCOLLECTION` types are supported,
+    along with the Elasticsearch `BBOX (minLon, maxLon, maxLat, minLat)` extension, which is parsed as a polygon.
+    Empty geometries can't be represented as GeoJSON, so they aren't supported.
+
+    This method requires the `wkt` feature.
+
+    # Examples
+
+    ```
+    # #[macro_use] extern crate serde_json;
+    # use elastic::types::prelude::*;
+    # fn main() { run().unwrap() }
+    # fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+    let shape: GeoShape<DefaultGeoShapeMapping> = GeoShape::from_wkt("LINESTRING (-77.03653 38.897676, -77.009051 38.889939)")?;
+
+    assert_eq!(
+        json!({
+            "type": "LineString",
+            "coordinates": [[-77.03653, 38.897676], [-77.009051, 38.889939]]
+        }),
+        serde_json::to_value(&shape)?
+    );
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn from_wkt(wkt: &str) -> Result<Self, WktError> {
+        let mut parser = Parser::new(wkt);
+
+        let geometry = parser.geometry()?;
+        parser.end()?;
+
+        Ok(GeoShape::new(geometry))
+    }
+
+    /**
+    Render the shape as Well-Known Text (WKT).
+
+    This method requires the `wkt` feature.
+
+    # Examples
+
+    ```
+    # use elastic::types::prelude::*;
+    let shape: GeoShape<DefaultGeoShapeMapping> = GeoShape::envelope((-45.0, 45.0), (45.0, -45.0));
+
+    assert_eq!("POLYGON ((-45 45, -45 -45, 45 -45, 45 45, -45 45))", shape.to_wkt());
+    ```
+    */
+    pub fn to_wkt(&self) -> String {
+        let geometry: &Geometry = self;
+
+        let mut wkt = String::new();
+        write_value(&mut wkt, &geometry.value);
+
+        wkt
+    }
+}
+
+fn write_value(wkt: &mut String, value: &Value) {
+    match *value {
+        Value::Point(ref position) => {
+            wkt.push_str("POINT (");
+            write_position(wkt, position);
+            wkt.push(')');
+        }
+        Value::MultiPoint(ref positions) => {
+            wkt.push_str("MULTIPOINT ");
+            write_list(wkt, positions, |wkt, position| {
+                wkt.push('(');
+                write_position(wkt, position);
+                wkt.push(')');
+            });
+        }
+        Value::LineString(ref positions) => {
+            wkt.push_str("LINESTRING ");
+            write_list(wkt, positions, write_position);
+        }
+        Value::MultiLineString(ref lines) => {
+            wkt.push_str("MULTILINESTRING ");
+            write_list(wkt, lines, |wkt, line| {
+                write_list(wkt, line, write_position)
+            });
+        }
+        Value::Polygon(ref rings) => {
+            wkt.push_str("POLYGON ");
+            write_polygon(wkt, rings);
+        }
+        Value::MultiPolygon(ref polygons) => {
+            wkt.push_str("MULTIPOLYGON ");
+            write_list(wkt, polygons, write_polygon);
+        }
+        Value::GeometryCollection(ref geometries) => {
+            wkt.push_str("GEOMETRYCOLLECTION ");
+            write_list(wkt, geometries, |wkt, geometry| {
+                write_value(wkt, &geometry.value)
+            });
+        }
+    }
+}
+
+fn write_polygon(wkt: &mut String, rings: &PolygonType) {
+    write_list(wkt, rings, |wkt, ring| {
+        write_list(wkt, ring, write_position)
+    });
+}
+
+fn write_list<T, F>(wkt: &mut String, items: &[T], write_item: F)
+where
+    F: Fn(&mut String, &T),
+{
+    wkt.push('(');
+
+    for (i, item) in items.iter().enumerate() {
+        if i > 0 {
+            wkt.push_str(", ");
+        }
+
+        write_item(wkt, item);
+    }
+
+    wkt.push(')');
+}
+
+fn write_position(wkt: &mut String, position: &Position) {
+    for (i, ordinate) in position.iter().enumerate() {
+        if i > 0 {
+            wkt.push(' ');
+        }
+
+        write!(wkt, "{}", ordinate).expect("writing to a string failed");
+    }
+}
+
+struct Parser<'a> {
+    wkt: &'a str,
+    chars: Peekable<CharIndices<'a>>,
+}
+
+impl<'a> Parser<'a> {
+    fn new(wkt: &'a str) -> Self {
+        Parser {
+            wkt,
+            chars: wkt.char_indices().peekable(),
+        }
+    }
+
+    fn geometry(&mut self) -> Result<Geometry, WktError> {
+        let value = match self.word()?.to_uppercase().as_str() {
+            "POINT" => {
+                self.open()?;
+                let position = self.position()?;
+                self.close()?;
+
+                Value::Point(position)
+            }
+            "MULTIPOINT" => Value::MultiPoint(self.list(|parser| {
+                // Points in a multi point may or may not be wrapped in parentheses
+                if parser.peek() == Some('(') {
+                    parser.open()?;
+                    let position = parser.position()?;
+                    parser.close()?;
+
+                    Ok(position)
+                } else {
+                    parser.position()
+                }
+            })?),
+            "LINESTRING" => Value::LineString(self.list(Parser::position)?),
+            "MULTILINESTRING" => {
+                Value::MultiLineString(self.list(|parser| parser.list(Parser::position))?)
+            }
+            "POLYGON" => Value::Polygon(self.polygon()?),
+            "MULTIPOLYGON" => Value::MultiPolygon(self.list(Parser::polygon)?),
+            "GEOMETRYCOLLECTION" => Value::GeometryCollection(self.list(Parser::geometry)?),
+            "BBOX" => {
+                let bounds = self.list(Parser::number)?;
+
+                match bounds[..] {
+                    [left, right, top, bottom] => Value::Polygon(vec![vec![
+                        vec![left, top],
+                        vec![left, bottom],
+                        vec![right, bottom],
+                        vec![right, top],
+                        vec![left, top],
+                    ]]),
+                    _ => return Err(self.error("a `BBOX` with 4 values")),
+                }
+            }
+            _ => return Err(self.error("a geometry type")),
+        };
+
+        Ok(Geometry::new(value))
+    }
+
+    fn polygon(&mut self) -> Result<PolygonType, WktError> {
+        self.list(|parser| parser.list(Parser::position))
+    }
+
+    fn list<T, F>(&mut self, mut item: F) -> Result<Vec<T>, WktError>
+    where
+        F: FnMut(&mut Self) -> Result<T, WktError>,
+    {
+        self.open()?;
+
+        let mut items = vec![item(self)?];
+        while self.peek() == Some(',') {
+            self.chars.next();
+            items.push(item(self)?);
+        }
+
+        self.close()?;
+
+        Ok(items)
+    }
+
+    fn position(&mut self) -> Result<Position, WktError> {
+        let mut position = vec![self.number()?, self.number()?];
+
+        // An optional `z` ordinate
+        if self.peek().map(is_number_start).unwrap_or(false) {
+            position.push(self.number()?);
+        }
+
+        Ok(position)
+    }
+
+    fn number(&mut self) -> Result<f64, WktError> {
+        let token = self.token(|c| is_number_start(c) || c == 'e' || c == 'E');
+
+        token.parse().map_err(|_| self.error("a number"))
+    }
+
+    fn word(&mut self) -> Result<&'a str, WktError> {
+        let token = self.token(|c| c.is_ascii_alphabetic());
+
+        if token.is_empty() {
+            Err(self.error("a geometry type"))
+        } else {
+            Ok(token)
+        }
+    }
+
+    fn open(&mut self) -> Result<(), WktError> {
+        self.expect('(')
+    }
+
+    fn close(&mut self) -> Result<(), WktError> {
+        self.expect(')')
+    }
+
+    fn end(&mut self) -> Result<(), WktError> {
+        match self.peek() {
+            None => Ok(()),
+            Some(_) => Err(self.error("the end of the input")),
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), WktError> {
+        if self.peek() == Some(expected) {
+            self.chars.next();
+            Ok(())
+        } else {
+            Err(self.error(&format!("`{}`", expected)))
+        }
+    }
+
+    fn token<F>(&mut self, is_token: F) -> &'a str
+    where
+        F: Fn(char) -> bool,
+    {
+        self.skip_whitespace();
+
+        let start = self.position_in_input();
+        while self
+            .chars
+            .peek()
+            .map(|&(_, c)| is_token(c))
+            .unwrap_or(false)
+        {
+            self.chars.next();
+        }
+
+        &self.wkt[start..self.position_in_input()]
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.skip_whitespace();
+
+        self.chars.peek().map(|&(_, c)| c)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self
+            .chars
+            .peek()
+            .map(|&(_, c)| c.is_whitespace())
+            .unwrap_or(false)
+        {
+            self.chars.next();
+        }
+    }
+
+    fn position_in_input(&mut self) -> usize {
+        self.chars.peek().map(|&(i, _)| i).unwrap_or(self.wkt.len())
+    }
+
+    fn error(&mut self, expected: &str) -> WktError {
+        WktError::Parse {
+            expected: expected.to_owned(),
+            position: self.position_in_input(),
+        }
+    }
+}
+
+fn is_number_start(c: char) -> bool {
+    c.is_ascii_digit() || c == '-' || c == '+' || c == '.'
+}
+
+quick_error! {
+    /** An error parsing Well-Known Text (WKT). */
+    #[derive(Debug, PartialEq)]
+    pub enum WktError {
+        /** The input isn't valid WKT, or uses a type that isn't supported. */
+        Parse { expected: String, position: usize } {
+            display("expected {} at position {}", expected, position)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+
+    use crate::types::prelude::*;
+
+    type Shape = GeoShape<DefaultGeoShapeMapping>;
+
+    fn parse(wkt: &str) -> serde_json::Value {
+        serde_json::to_value(&Shape::from_wkt(wkt).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn parse_point() {
+        assert_eq!(
+            json!({ "type": "Point", "coordinates": [-77.03653, 38.897676] }),
+            parse("POINT (-77.03653 38.897676)")
+        );
+        assert_eq!(
+            json!({ "type": "Point", "coordinates": [1.0, 2.0, 3.0] }),
+            parse("point(1 2 3)")
+        );
+    }
+
+    #[test]
+    fn parse_multi_point() {
+        let expected = json!({ "type": "MultiPoint", "coordinates": [[102.0, 2.0], [103.0, 2.0]] });
+
+        assert_eq!(expected, parse("MULTIPOINT ((102 2), (103 2))"));
+        assert_eq!(expected, parse("MULTIPOINT (102 2, 103 2)"));
+    }
+
+    #[test]
+    fn parse_polygon_with_hole() {
+        assert_eq!(
+            json!({
+                "type": "Polygon",
+                "coordinates": [
+                    [[100.0, 0.0], [101.0, 0.0], [101.0, 1.0], [100.0, 1.0], [100.0, 0.0]],
+                    [[100.2, 0.2], [100.8, 0.2], [100.8, 0.8], [100.2, 0.8], [100.2, 0.2]]
+                ]
+            }),
+            parse("POLYGON ((100.0 0.0, 101.0 0.0, 101.0 1.0, 100.0 1.0, 100.0 0.0), (100.2 0.2, 100.8 0.2, 100.8 0.8, 100.2 0.8, 100.2 0.2))")
+        );
+    }
+
+    #[test]
+    fn parse_geometry_collection() {
+        assert_eq!(
+            json!({
+                "type": "GeometryCollection",
+                "geometries": [
+                    { "type": "Point", "coordinates": [100.0, 0.0] },
+                    { "type": "LineString", "coordinates": [[101.0, 0.0], [102.0, 1.0]] }
+                ]
+            }),
+            parse("GEOMETRYCOLLECTION (POINT (100.0 0.0), LINESTRING (101.0 0.0, 102.0 1.0))")
+        );
+    }
+
+    #[test]
+    fn parse_bbox() {
+        assert_eq!(
+            Shape::envelope((-45.0, 45.0), (45.0, -45.0)),
+            Shape::from_wkt("BBOX (-45.0, 45.0, 45.0, -45.0)").unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert_eq!(
+            WktError::Parse {
+                expected: "`)`".to_owned(),
+                position: 14
+            },
+            Shape::from_wkt("POINT (1.0 2.0").unwrap_err()
+        );
+        assert!(Shape::from_wkt("CIRCLE (1 2)").is_err());
+        assert!(Shape::from_wkt("POINT EMPTY").is_err());
+        assert!(Shape::from_wkt("POINT (1 a)").is_err());
+        assert!(Shape::from_wkt("POINT (1 2) POINT (3 4)").is_err());
+    }
+
+    #[test]
+    fn wkt_round_trip() {
+        let wkts = vec![
+            "POINT (1.5 2)",
+            "MULTIPOINT ((1 2), (3 4))",
+            "LINESTRING (1 2, 3 4)",
+            "MULTILINESTRING ((1 2, 3 4), (5 6, 7 8))",
+            "POLYGON ((0 0, 1 0, 1 1, 0 0))",
+            "MULTIPOLYGON (((0 0, 1 0, 1 1, 0 0)), ((2 2, 3 2, 3 3, 2 2)))",
+            "GEOMETRYCOLLECTION (POINT (1 2), LINESTRING (1 2, 3 4))",
+        ];
+
+        for wkt in wkts {
+            assert_eq!(wkt, Shape::from_wkt(wkt).unwrap().to_wkt());
+        }
+    }
+}
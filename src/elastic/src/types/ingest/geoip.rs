@@ -0,0 +1,157 @@
+/*!
+The fields added by the `geoip` processor.
+
+# Examples
+
+A document for access logs that are enriched by a pipeline with `geoip` and `user_agent` processors:
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# use elastic::types::prelude::*;
+#[derive(Serialize, Deserialize, ElasticType)]
+pub struct AccessLog {
+    pub client_ip: std::net::Ipv4Addr,
+    pub geoip: Option<GeoIp>,
+    pub user_agent: Option<UserAgent>,
+}
+# fn main() {}
+```
+
+# Links
+
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/geoip-processor.html)
+*/
+
+use crate::types::{
+    geo::point::{
+        mapping::DefaultGeoPointMapping,
+        GeoPoint,
+        GeoPointObject,
+    },
+    ip::mapping::DefaultIpMapping,
+    string::keyword::mapping::DefaultKeywordMapping,
+};
+
+/**
+The location of an IP address, added by the `geoip` processor.
+
+Which fields are set depends on the `properties` of the processor and the database it uses.
+The default properties set the continent, country, region, city and location,
+and the ASN database sets the `asn`, `organization_name` and `network`.
+Names and codes are mapped as `keyword` fields and the `location` is mapped as a `geo_point`.
+
+# Examples
+
+```
+# extern crate serde_json;
+# use elastic::types::prelude::*;
+# fn main() { run().unwrap() }
+# fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+let geoip: GeoIp = serde_json::from_str(r#"{
+    "continent_name": "North America",
+    "country_iso_code": "US",
+    "region_iso_code": "US-CA",
+    "region_name": "California",
+    "city_name": "Mountain View",
+    "location": { "lat": 37.386, "lon": -122.0838 }
+}"#)?;
+
+assert_eq!(Some("US"), geoip.country_iso_code.as_ref().map(String::as_str));
+# Ok(())
+# }
+```
+*/
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ElasticType)]
+#[elastic(crate_root = "crate::types", data_type = "object")]
+pub struct GeoIp {
+    /** The IP address that was looked up. */
+    #[elastic(mapping = "DefaultIpMapping")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ip: Option<String>,
+    /** The name of the continent, like `Europe`. */
+    #[elastic(mapping = "DefaultKeywordMapping")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub continent_name: Option<String>,
+    /** The ISO 3166 code of the country, like `DE`. */
+    #[elastic(mapping = "DefaultKeywordMapping")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub country_iso_code: Option<String>,
+    /** The name of the country, like `Germany`. */
+    #[elastic(mapping = "DefaultKeywordMapping")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub country_name: Option<String>,
+    /** The ISO 3166-2 code of the region, like `DE-BE`. */
+    #[elastic(mapping = "DefaultKeywordMapping")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub region_iso_code: Option<String>,
+    /** The name of the region, like `Land Berlin`. */
+    #[elastic(mapping = "DefaultKeywordMapping")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub region_name: Option<String>,
+    /** The name of the city, like `Berlin`. */
+    #[elastic(mapping = "DefaultKeywordMapping")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub city_name: Option<String>,
+    /** The IANA time zone of the location, like `Europe/Berlin`. */
+    #[elastic(mapping = "DefaultKeywordMapping")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+    /** The approximate location of the IP address. */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<GeoPoint<DefaultGeoPointMapping<GeoPointObject>>>,
+    /** The autonomous system number of the network the IP address is in. */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub asn: Option<i64>,
+    /** The name of the organisation that owns the autonomous system. */
+    #[elastic(mapping = "DefaultKeywordMapping")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization_name: Option<String>,
+    /** The network the IP address is in, in CIDR notation. */
+    #[elastic(mapping = "DefaultKeywordMapping")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+
+    use crate::types::prelude::*;
+
+    #[test]
+    fn deserialise_geoip() {
+        let geoip: GeoIp = serde_json::from_str(
+            r#"{
+                "continent_name": "Europe",
+                "country_iso_code": "DE",
+                "country_name": "Germany",
+                "region_iso_code": "DE-BE",
+                "region_name": "Land Berlin",
+                "city_name": "Berlin",
+                "location": { "lat": 52.5167, "lon": 13.4 },
+                "asn": 3320,
+                "organization_name": "Deutsche Telekom AG"
+            }"#,
+        )
+        .unwrap();
+
+        let location = geoip.location.as_ref().unwrap();
+
+        assert_eq!(Some("Berlin"), geoip.city_name.as_ref().map(String::as_str));
+        assert_eq!((13.4, 52.5167), (location.x(), location.y()));
+        assert_eq!(Some(3320), geoip.asn);
+        assert_eq!(None, geoip.timezone);
+    }
+
+    #[test]
+    fn serialise_geoip_mapping() {
+        let mapping = serde_json::to_value(&GeoIp::index_mapping()).unwrap();
+        let properties = &mapping["properties"];
+
+        assert_eq!(json!({ "type": "ip" }), properties["ip"]);
+        assert_eq!(json!({ "type": "keyword" }), properties["country_iso_code"]);
+        assert_eq!(json!({ "type": "geo_point" }), properties["location"]);
+        assert_eq!(json!({ "type": "long" }), properties["asn"]);
+    }
+}
@@ -0,0 +1,52 @@
+/*!
+Types for the fields added to documents by ingest processors.
+
+When a document is indexed through an ingest pipeline, processors can enrich it with new fields.
+These types can be used as fields of a document to deserialise what the processors add instead of using a `serde_json::Value`.
+Each type is mapped as an `object` with the same fields the processor produces.
+
+Processor | Default target field | Type
+--------- | -------------------- | ----
+`geoip` | `geoip` | [`GeoIp`](geoip/struct.GeoIp.html)
+`user_agent` | `user_agent` | [`UserAgent`](user_agent/struct.UserAgent.html)
+
+The `geoip` types need the `geo` feature.
+See the [`geoip`](geoip/index.html) module for an example that uses them.
+
+# Examples
+
+A document for access logs that are enriched by a pipeline with a `user_agent` processor:
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# use elastic::types::prelude::*;
+#[derive(Serialize, Deserialize, ElasticType)]
+pub struct AccessLog {
+    pub client_ip: std::net::Ipv4Addr,
+    pub user_agent: Option<UserAgent>,
+}
+# fn main() {}
+```
+
+# Links
+
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/ingest-processors.html)
+*/
+
+#[cfg(feature = "geo")]
+pub mod geoip;
+pub mod user_agent;
+
+pub mod prelude {
+    /*!
+    Includes all types for the fields added by ingest processors.
+
+    This is a convenience module to make it easy to build mappings for multiple types without too many `use` statements.
+    */
+
+    pub use super::user_agent::*;
+
+    #[cfg(feature = "geo")]
+    pub use super::geoip::*;
+}
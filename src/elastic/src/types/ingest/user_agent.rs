@@ -0,0 +1,154 @@
+/*!
+The fields added by the `user_agent` processor.
+
+# Links
+
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/user-agent-processor.html)
+*/
+
+use crate::types::string::keyword::mapping::DefaultKeywordMapping;
+
+/**
+A parsed user agent, added by the `user_agent` processor.
+
+Which fields are set depends on the `properties` of the processor and what could be parsed from the user agent.
+Names and versions are mapped as `keyword` fields.
+
+# Examples
+
+```
+# extern crate serde_json;
+# use elastic::types::prelude::*;
+# fn main() { run().unwrap() }
+# fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+let user_agent: UserAgent = serde_json::from_str(r#"{
+    "name": "Chrome",
+    "original": "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_10_5) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/51.0.2704.103 Safari/537.36",
+    "version": "51.0.2704.103",
+    "os": {
+        "name": "Mac OS X",
+        "version": "10.10.5",
+        "full": "Mac OS X 10.10.5"
+    },
+    "device": {
+        "name": "Mac"
+    }
+}"#)?;
+
+assert_eq!(
+    Some("Mac OS X".to_owned()),
+    user_agent.os.and_then(|os| os.name)
+);
+# Ok(())
+# }
+```
+*/
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ElasticType)]
+#[elastic(crate_root = "crate::types", data_type = "object")]
+pub struct UserAgent {
+    /** The unparsed user agent. */
+    #[elastic(mapping = "DefaultKeywordMapping")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub original: Option<String>,
+    /** The name of the browser or client, like `Firefox`. */
+    #[elastic(mapping = "DefaultKeywordMapping")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /** The version of the browser or client. */
+    #[elastic(mapping = "DefaultKeywordMapping")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    /** The operating system the browser or client is running on. */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub os: Option<UserAgentOs>,
+    /** The device the browser or client is running on. */
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub device: Option<UserAgentDevice>,
+}
+
+/** The operating system in a parsed user agent. */
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ElasticType)]
+#[elastic(crate_root = "crate::types", data_type = "object")]
+pub struct UserAgentOs {
+    /** The name of the operating system, like `Windows`. */
+    #[elastic(mapping = "DefaultKeywordMapping")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /** The version of the operating system. */
+    #[elastic(mapping = "DefaultKeywordMapping")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    /** The name and version of the operating system, like `Windows 10`. */
+    #[elastic(mapping = "DefaultKeywordMapping")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub full: Option<String>,
+}
+
+/** The device in a parsed user agent. */
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ElasticType)]
+#[elastic(crate_root = "crate::types", data_type = "object")]
+pub struct UserAgentDevice {
+    /** The name of the device, like `iPhone`. */
+    #[elastic(mapping = "DefaultKeywordMapping")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+
+    use crate::types::prelude::*;
+
+    #[test]
+    fn deserialise_user_agent() {
+        let user_agent: UserAgent = serde_json::from_str(
+            r#"{
+                "name": "Firefox",
+                "original": "Mozilla/5.0 (Windows NT 10.0; Win64; x64; rv:68.0) Gecko/20100101 Firefox/68.0",
+                "version": "68.0",
+                "os": { "name": "Windows", "version": "10", "full": "Windows 10" },
+                "device": { "name": "Other" }
+            }"#,
+        )
+        .unwrap();
+
+        let os = user_agent.os.unwrap();
+
+        assert_eq!(Some("Firefox".to_owned()), user_agent.name);
+        assert_eq!(Some("Windows 10".to_owned()), os.full);
+        assert_eq!(
+            Some("Other".to_owned()),
+            user_agent.device.and_then(|device| device.name)
+        );
+    }
+
+    #[test]
+    fn serialise_user_agent_mapping() {
+        let mapping = serde_json::to_value(&UserAgent::index_mapping()).unwrap();
+
+        let expected = json!({
+            "properties": {
+                "original": { "type": "keyword" },
+                "name": { "type": "keyword" },
+                "version": { "type": "keyword" },
+                "os": {
+                    "type": "object",
+                    "properties": {
+                        "name": { "type": "keyword" },
+                        "version": { "type": "keyword" },
+                        "full": { "type": "keyword" }
+                    }
+                },
+                "device": {
+                    "type": "object",
+                    "properties": {
+                        "name": { "type": "keyword" }
+                    }
+                }
+            }
+        });
+
+        assert_eq!(expected, mapping);
+    }
+}
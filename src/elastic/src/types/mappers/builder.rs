@@ -0,0 +1,364 @@
+use serde::ser::{
+    Serialize,
+    Serializer,
+};
+use serde_json::{
+    self,
+    Value,
+};
+
+use super::model::{
+    FieldModel,
+    MappingModel,
+};
+use crate::types::document::mapping::{
+    Dynamic,
+    NESTED_DATATYPE,
+    OBJECT_DATATYPE,
+};
+
+/**
+A builder for a document mapping that's assembled at runtime.
+
+The mappings for types in this crate are derived at compile time, which doesn't work when the fields
+of a document come from configuration.
+A `MappingBuilder` can be used instead, and added to the body of a create index request alongside static mappings
+with [`IndexMapper.runtime_mapping`][IndexMapper.runtime_mapping].
+A builder can also start from the [model][model] of a static mapping to add fields to it.
+
+# Examples
+
+```
+# #[macro_use] extern crate serde_json;
+# use elastic::types::prelude::*;
+# use elastic::types::mappers::{FieldBuilder, MappingBuilder};
+# fn main() { run().unwrap() }
+# fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+let mapping = MappingBuilder::new()
+    .dynamic(Dynamic::Strict)
+    .field(
+        "title",
+        FieldBuilder::text()
+            .analyzer("english")
+            .field("raw", FieldBuilder::keyword().ignore_above(256)),
+    )
+    .field("likes", FieldBuilder::integer());
+
+assert_eq!(
+    json!({
+        "dynamic": "strict",
+        "properties": {
+            "title": {
+                "type": "text",
+                "analyzer": "english",
+                "fields": {
+                    "raw": { "type": "keyword", "ignore_above": 256 }
+                }
+            },
+            "likes": { "type": "integer" }
+        }
+    }),
+    serde_json::to_value(&mapping)?
+);
+# Ok(())
+# }
+```
+
+[IndexMapper.runtime_mapping]: struct.IndexMapper.html#method.runtime_mapping
+[model]: fn.model.html
+*/
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MappingBuilder {
+    model: MappingModel,
+}
+
+/**
+A builder for a single field in a [`MappingBuilder`][MappingBuilder].
+
+Options that don't have their own method can be set with [`option`][FieldBuilder.option].
+
+[MappingBuilder]: struct.MappingBuilder.html
+[FieldBuilder.option]: #method.option
+*/
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FieldBuilder {
+    model: FieldModel,
+}
+
+impl MappingBuilder {
+    /** Create a new mapping without any fields. */
+    pub fn new() -> Self {
+        MappingBuilder::default()
+    }
+
+    /** Whether or not new fields should be added to the mapping dynamically. */
+    pub fn dynamic(self, dynamic: Dynamic) -> Self {
+        self.option("dynamic", dynamic_value(dynamic))
+    }
+
+    /**
+    Set an option at the root of the mapping, like `_source` or `_meta`.
+
+    Setting an option to `null` will remove it.
+    */
+    pub fn option(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.model.set_option(key, value.into());
+        self
+    }
+
+    /** Add a field to the mapping, replacing any field with the same name. */
+    pub fn field(mut self, name: impl Into<String>, field: FieldBuilder) -> Self {
+        self.model.properties.insert(name.into(), field.model);
+        self
+    }
+
+    /** Build an introspection model for the mapping. */
+    pub fn build(self) -> MappingModel {
+        self.model
+    }
+}
+
+macro_rules! field_ctors {
+    ($($(#[$attr:meta])* $ctor:ident => $data_type:expr,)*) => {
+        $(
+            $(#[$attr])*
+            pub fn $ctor() -> Self {
+                FieldBuilder::new($data_type)
+            }
+        )*
+    };
+}
+
+impl FieldBuilder {
+    /** Create a new field with the given Elasticsearch datatype. */
+    pub fn new(data_type: impl Into<String>) -> Self {
+        FieldBuilder {
+            model: FieldModel {
+                data_type: Some(data_type.into()),
+                ..Default::default()
+            },
+        }
+    }
+
+    field_ctors! {
+        /** Create a new `text` field. */
+        text => "text",
+        /** Create a new `keyword` field. */
+        keyword => "keyword",
+        /** Create a new `long` field. */
+        long => "long",
+        /** Create a new `integer` field. */
+        integer => "integer",
+        /** Create a new `short` field. */
+        short => "short",
+        /** Create a new `byte` field. */
+        byte => "byte",
+        /** Create a new `double` field. */
+        double => "double",
+        /** Create a new `float` field. */
+        float => "float",
+        /** Create a new `boolean` field. */
+        boolean => "boolean",
+        /** Create a new `date` field. */
+        date => "date",
+        /** Create a new `ip` field. */
+        ip => "ip",
+        /** Create a new `geo_point` field. */
+        geo_point => "geo_point",
+        /** Create a new `geo_shape` field. */
+        geo_shape => "geo_shape",
+        /** Create a new `object` field. Properties can be added with [`property`](#method.property). */
+        object => OBJECT_DATATYPE,
+        /** Create a new `percolator` field, which stores queries that documents can be matched against. */
+        percolator => "percolator",
+        /** Create a new `nested` field. Properties can be added with [`property`](#method.property). */
+        nested => NESTED_DATATYPE,
+    }
+
+    /**
+    Set an option for the field.
+
+    Setting an option to `null` will remove it.
+    */
+    pub fn option(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.model.set_option(key, value.into());
+        self
+    }
+
+    /** The analyzer used for the field, at index time and search time. */
+    pub fn analyzer(self, analyzer: impl Into<String>) -> Self {
+        self.option("analyzer", analyzer.into())
+    }
+
+    /** The analyzer used for the field at search time. */
+    pub fn search_analyzer(self, analyzer: impl Into<String>) -> Self {
+        self.option("search_analyzer", analyzer.into())
+    }
+
+    /** The normalizer used for a `keyword` field. */
+    pub fn normalizer(self, normalizer: impl Into<String>) -> Self {
+        self.option("normalizer", normalizer.into())
+    }
+
+    /** Don't index or store `keyword` values longer than this limit. */
+    pub fn ignore_above(self, ignore_above: u32) -> Self {
+        self.option("ignore_above", ignore_above)
+    }
+
+    /** The date format, or formats, for a `date` field. */
+    pub fn format(self, format: impl Into<String>) -> Self {
+        self.option("format", format.into())
+    }
+
+    /** Whether or not the field is searchable. */
+    pub fn index(self, index: bool) -> Self {
+        self.option("index", index)
+    }
+
+    /** Whether or not the field is stored on disk in a column-stride fashion for sorting and aggregations. */
+    pub fn doc_values(self, doc_values: bool) -> Self {
+        self.option("doc_values", doc_values)
+    }
+
+    /** Whether or not the field value is stored and retrievable separately from the `_source`. */
+    pub fn store(self, store: bool) -> Self {
+        self.option("store", store)
+    }
+
+    /** A value to index in place of an explicit `null`. */
+    pub fn null_value(self, null_value: impl Into<Value>) -> Self {
+        self.option("null_value", null_value)
+    }
+
+    /** Copy the value of the field into a group field, which can then be queried as a single field. */
+    pub fn copy_to(self, copy_to: impl Into<String>) -> Self {
+        self.option("copy_to", copy_to.into())
+    }
+
+    /** Whether or not new properties should be added to an `object` or `nested` field dynamically. */
+    pub fn dynamic(self, dynamic: Dynamic) -> Self {
+        self.option("dynamic", dynamic_value(dynamic))
+    }
+
+    /** Add a sub-field that indexes the same value in a different way, like a `keyword` for a `text` field. */
+    pub fn field(mut self, name: impl Into<String>, field: FieldBuilder) -> Self {
+        self.model.fields.insert(name.into(), field.model);
+        self
+    }
+
+    /** Add a property to an `object` or `nested` field. */
+    pub fn property(mut self, name: impl Into<String>, field: FieldBuilder) -> Self {
+        self.model.properties.insert(name.into(), field.model);
+        self
+    }
+
+    /** Build an introspection model for the field. */
+    pub fn build(self) -> FieldModel {
+        self.model
+    }
+}
+
+fn dynamic_value(dynamic: Dynamic) -> Value {
+    serde_json::to_value(dynamic).expect("dynamic is always serialisable")
+}
+
+impl From<MappingModel> for MappingBuilder {
+    fn from(model: MappingModel) -> Self {
+        MappingBuilder { model }
+    }
+}
+
+impl Serialize for MappingBuilder {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.model.serialize(serializer)
+    }
+}
+
+impl Serialize for FieldBuilder {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.model.serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+
+    use super::*;
+    use crate::types::{
+        mappers,
+        prelude::*,
+    };
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct StaticType {
+        pub title: String,
+        pub likes: i32,
+    }
+
+    #[test]
+    fn serialise_runtime_mapping() {
+        let mapping = MappingBuilder::new()
+            .dynamic(Dynamic::Strict)
+            .field(
+                "title",
+                FieldBuilder::text()
+                    .analyzer("english")
+                    .field("keyword", FieldBuilder::keyword().ignore_above(256)),
+            )
+            .field(
+                "author",
+                FieldBuilder::object()
+                    .dynamic(Dynamic::False)
+                    .property("name", FieldBuilder::keyword().doc_values(false)),
+            )
+            .field("published", FieldBuilder::date().format("epoch_millis"));
+
+        let ser = serde_json::to_value(&mapping).unwrap();
+
+        let expected = json!({
+            "dynamic": "strict",
+            "properties": {
+                "title": {
+                    "type": "text",
+                    "analyzer": "english",
+                    "fields": {
+                        "keyword": { "type": "keyword", "ignore_above": 256 }
+                    }
+                },
+                "author": {
+                    "type": "object",
+                    "dynamic": false,
+                    "properties": {
+                        "name": { "type": "keyword", "doc_values": false }
+                    }
+                },
+                "published": { "type": "date", "format": "epoch_millis" }
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn runtime_mapping_matches_static_mapping() {
+        let runtime = MappingBuilder::new()
+            .field(
+                "title",
+                FieldBuilder::text().field("keyword", FieldBuilder::keyword().ignore_above(256)),
+            )
+            .field("likes", FieldBuilder::integer())
+            .build();
+
+        let expected = mappers::model::<StaticType>().unwrap();
+
+        assert!(mappers::diff(&expected, &runtime).is_empty());
+    }
+}
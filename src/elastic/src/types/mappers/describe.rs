@@ -0,0 +1,317 @@
+use serde_json::{
+    self,
+    Value,
+};
+use std::{
+    collections::BTreeMap,
+    fmt,
+};
+
+use super::model::{
+    model,
+    MappingModel,
+};
+use crate::types::document::DocumentType;
+
+/**
+Describe the mapping for a document type.
+
+The description contains a row for each field in the mapping, including sub-fields and the properties of inner objects.
+It can be formatted as a table to generate schema documentation.
+
+# Examples
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# use elastic::types::prelude::*;
+# use elastic::types::mappers;
+# fn main() { run().unwrap() }
+# fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+#[derive(Serialize, ElasticType)]
+struct MyType {
+    pub id: i32,
+    pub title: String,
+    pub timestamp: Date<DefaultDateMapping>,
+}
+
+let description = mappers::describe::<MyType>()?;
+
+println!("{}", description);
+# Ok(())
+# }
+```
+
+This will print the following table:
+
+```text
+| field         | type    | format          | analyzer | options          |
+| ------------- | ------- | --------------- | -------- | ---------------- |
+| id            | integer |                 |          |                  |
+| timestamp     | date    | basic_date_time |          |                  |
+| title         | text    |                 |          |                  |
+| title.keyword | keyword |                 |          | ignore_above=256 |
+```
+*/
+pub fn describe<TDocument>() -> Result<MappingDescription, serde_json::Error>
+where
+    TDocument: DocumentType,
+{
+    Ok(MappingDescription::from_model(&model::<TDocument>()?))
+}
+
+/**
+A flattened description of the fields in a mapping.
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct MappingDescription {
+    fields: Vec<FieldDescription>,
+}
+
+/**
+A description of a single field in a mapping.
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldDescription {
+    /** The full dotted path to the field, like `title.keyword`. */
+    pub path: String,
+    /** The Elasticsearch datatype of the field, like `keyword`. */
+    pub data_type: String,
+    /** The date format for the field. */
+    pub format: Option<String>,
+    /** The analyzer for the field. */
+    pub analyzer: Option<String>,
+    /** Any other mapping parameters for the field. */
+    pub options: BTreeMap<String, Value>,
+}
+
+impl MappingDescription {
+    /**
+    Describe a serialised mapping.
+
+    The mapping is expected to have the same shape as a type mapping in a create index or put mapping request,
+    with a root `properties` object.
+    */
+    pub fn from_value(mapping: &Value) -> Self {
+        Self::from_model(&MappingModel::from_value(mapping))
+    }
+
+    /** Describe a mapping model. */
+    pub fn from_model(model: &MappingModel) -> Self {
+        let mut fields = Vec::new();
+
+        model.walk(|path, field| {
+            let mut options = field.options().clone();
+
+            let format = options.remove("format");
+            let analyzer = options.remove("analyzer");
+
+            fields.push(FieldDescription {
+                path: path.to_owned(),
+                data_type: field.data_type().to_owned(),
+                format: format
+                    .as_ref()
+                    .and_then(Value::as_str)
+                    .map(ToOwned::to_owned),
+                analyzer: analyzer
+                    .as_ref()
+                    .and_then(Value::as_str)
+                    .map(ToOwned::to_owned),
+                options,
+            });
+        });
+
+        MappingDescription { fields }
+    }
+
+    /** Get the described fields. */
+    pub fn fields(&self) -> &[FieldDescription] {
+        &self.fields
+    }
+
+    /** Get the description for the field at the given dotted path. */
+    pub fn field(&self, path: &str) -> Option<&FieldDescription> {
+        self.fields.iter().find(|field| field.path == path)
+    }
+}
+
+impl FieldDescription {
+    fn options_string(&self) -> String {
+        self.options
+            .iter()
+            .map(|(key, value)| match *value {
+                Value::String(ref value) => format!("{}={}", key, value),
+                ref value => format!("{}={}", key, value),
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+impl fmt::Display for MappingDescription {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let header = ["field", "type", "format", "analyzer", "options"];
+
+        let rows: Vec<[String; 5]> = self
+            .fields
+            .iter()
+            .map(|field| {
+                [
+                    field.path.clone(),
+                    field.data_type.clone(),
+                    field.format.clone().unwrap_or_default(),
+                    field.analyzer.clone().unwrap_or_default(),
+                    field.options_string(),
+                ]
+            })
+            .collect();
+
+        let mut widths = [0; 5];
+        for (i, width) in widths.iter_mut().enumerate() {
+            *width = rows
+                .iter()
+                .map(|row| row[i].chars().count())
+                .chain(Some(header[i].len()))
+                .max()
+                .unwrap_or_default();
+        }
+
+        let write_row = |f: &mut fmt::Formatter, row: &[&str]| -> fmt::Result {
+            for (cell, &width) in row.iter().zip(widths.iter()) {
+                write!(f, "| {:width$} ", cell, width = width)?;
+            }
+            writeln!(f, "|")
+        };
+
+        write_row(f, &header)?;
+
+        let separators: Vec<String> = widths.iter().map(|width| "-".repeat(*width)).collect();
+        write_row(
+            f,
+            &separators.iter().map(String::as_str).collect::<Vec<_>>(),
+        )?;
+
+        for row in &rows {
+            write_row(f, &row.iter().map(String::as_str).collect::<Vec<_>>())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::prelude::*;
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct DescribedType {
+        pub id: i32,
+        pub title: String,
+        pub timestamp: Date<DefaultDateMapping>,
+        pub inner: InnerType,
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types", data_type = "object")]
+    pub struct InnerType {
+        pub flag: bool,
+    }
+
+    #[test]
+    fn describe_fields() {
+        let description = describe::<DescribedType>().unwrap();
+
+        let paths: Vec<&str> = description
+            .fields()
+            .iter()
+            .map(|field| field.path.as_str())
+            .collect();
+
+        assert_eq!(
+            vec![
+                "id",
+                "inner",
+                "inner.flag",
+                "timestamp",
+                "title",
+                "title.keyword",
+            ],
+            paths
+        );
+
+        let timestamp = description.field("timestamp").unwrap();
+        assert_eq!("date", timestamp.data_type);
+        assert_eq!(
+            Some("basic_date_time"),
+            timestamp.format.as_ref().map(String::as_str)
+        );
+
+        let keyword = description.field("title.keyword").unwrap();
+        assert_eq!("keyword", keyword.data_type);
+        assert_eq!(Some(&json!(256)), keyword.options.get("ignore_above"));
+    }
+
+    #[test]
+    fn describe_value() {
+        let mapping = json!({
+            "properties": {
+                "title": {
+                    "type": "text",
+                    "analyzer": "english",
+                    "index": false
+                },
+                "meta": {
+                    "properties": {
+                        "tag": {
+                            "type": "keyword"
+                        }
+                    }
+                }
+            }
+        });
+
+        let description = MappingDescription::from_value(&mapping);
+
+        assert_eq!("object", description.field("meta").unwrap().data_type);
+        assert_eq!("keyword", description.field("meta.tag").unwrap().data_type);
+        assert_eq!(
+            Some("english"),
+            description
+                .field("title")
+                .unwrap()
+                .analyzer
+                .as_ref()
+                .map(String::as_str)
+        );
+    }
+
+    #[test]
+    fn display_table() {
+        let mapping = json!({
+            "properties": {
+                "title": {
+                    "type": "text",
+                    "analyzer": "english",
+                    "index": false
+                },
+                "timestamp": {
+                    "type": "date",
+                    "format": "epoch_millis"
+                }
+            }
+        });
+
+        let table = MappingDescription::from_value(&mapping).to_string();
+
+        let expected = concat!(
+            "| field     | type | format       | analyzer | options     |\n",
+            "| --------- | ---- | ------------ | -------- | ----------- |\n",
+            "| timestamp | date | epoch_millis |          |             |\n",
+            "| title     | text |              | english  | index=false |\n",
+        );
+
+        assert_eq!(expected, table);
+    }
+}
@@ -0,0 +1,271 @@
+use serde_json::Value;
+use std::{
+    collections::{
+        BTreeMap,
+        BTreeSet,
+    },
+    fmt,
+};
+
+use super::model::{
+    FieldModel,
+    MappingModel,
+};
+
+/**
+Compare an expected mapping with an actual one, like the mapping that's live on a cluster.
+
+Fields are compared by their full dotted path.
+Differences are returned in path order, with the datatype of a field compared before its options.
+
+# Examples
+
+```
+# #[macro_use] extern crate serde_json;
+# use elastic::types::mappers::{self, FieldDiffKind, MappingModel};
+# fn main() {
+let expected = MappingModel::from_value(&json!({
+    "properties": {
+        "title": { "type": "text" }
+    }
+}));
+
+let actual = MappingModel::from_value(&json!({
+    "properties": {
+        "title": { "type": "keyword" }
+    }
+}));
+
+let diff = mappers::diff(&expected, &actual);
+
+assert_eq!("title", diff[0].path);
+assert_eq!(
+    FieldDiffKind::DataType {
+        expected: "text".to_owned(),
+        actual: "keyword".to_owned(),
+    },
+    diff[0].kind
+);
+# }
+```
+*/
+pub fn diff(expected: &MappingModel, actual: &MappingModel) -> Vec<FieldDiff> {
+    let expected = fields(expected);
+    let mut actual = fields(actual);
+
+    let mut diffs = Vec::new();
+
+    for (path, expected) in expected {
+        match actual.remove(&path) {
+            Some(actual) => diff_field(&path, expected, actual, &mut diffs),
+            None => diffs.push(FieldDiff::new(&path, FieldDiffKind::Missing)),
+        }
+    }
+
+    for path in actual.keys() {
+        diffs.push(FieldDiff::new(path, FieldDiffKind::Unexpected));
+    }
+
+    diffs.sort_by(|a, b| a.path.cmp(&b.path));
+    diffs
+}
+
+fn fields(model: &MappingModel) -> BTreeMap<String, &FieldModel> {
+    let mut fields = BTreeMap::new();
+    model.walk(|path, field| {
+        fields.insert(path.to_owned(), field);
+    });
+
+    fields
+}
+
+fn diff_field(path: &str, expected: &FieldModel, actual: &FieldModel, diffs: &mut Vec<FieldDiff>) {
+    if expected.data_type() != actual.data_type() {
+        diffs.push(FieldDiff::new(
+            path,
+            FieldDiffKind::DataType {
+                expected: expected.data_type().to_owned(),
+                actual: actual.data_type().to_owned(),
+            },
+        ));
+    }
+
+    let options = expected
+        .options()
+        .keys()
+        .chain(actual.options().keys())
+        .collect::<BTreeSet<_>>();
+
+    for option in options {
+        let (expected, actual) = (expected.option(option), actual.option(option));
+
+        if expected != actual {
+            diffs.push(FieldDiff::new(
+                path,
+                FieldDiffKind::Option {
+                    option: option.clone(),
+                    expected: expected.cloned(),
+                    actual: actual.cloned(),
+                },
+            ));
+        }
+    }
+}
+
+/** A difference between a field in two mappings. */
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldDiff {
+    /** The full dotted path to the field, like `title.keyword`. */
+    pub path: String,
+    /** How the field differs. */
+    pub kind: FieldDiffKind,
+}
+
+impl FieldDiff {
+    fn new(path: &str, kind: FieldDiffKind) -> Self {
+        FieldDiff {
+            path: path.to_owned(),
+            kind,
+        }
+    }
+}
+
+/** How a field differs between two mappings. */
+#[derive(Debug, Clone, PartialEq)]
+pub enum FieldDiffKind {
+    /** The field is expected but isn't in the actual mapping. */
+    Missing,
+    /** The field is in the actual mapping but isn't expected, like a dynamically mapped field. */
+    Unexpected,
+    /** The field has a different datatype. */
+    DataType { expected: String, actual: String },
+    /** The field has a different value for an option, or the option is only set in one of the mappings. */
+    Option {
+        option: String,
+        expected: Option<Value>,
+        actual: Option<Value>,
+    },
+}
+
+impl fmt::Display for FieldDiff {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.kind {
+            FieldDiffKind::Missing => write!(f, "`{}` is missing", self.path),
+            FieldDiffKind::Unexpected => write!(f, "`{}` is unexpected", self.path),
+            FieldDiffKind::DataType {
+                ref expected,
+                ref actual,
+            } => write!(
+                f,
+                "`{}` has type `{}`, expected `{}`",
+                self.path, actual, expected
+            ),
+            FieldDiffKind::Option {
+                ref option,
+                ref expected,
+                ref actual,
+            } => {
+                let display = |value: &Option<Value>| match *value {
+                    Some(ref value) => value.to_string(),
+                    None => "unset".to_owned(),
+                };
+
+                write!(
+                    f,
+                    "`{}.{}` is {}, expected {}",
+                    self.path,
+                    option,
+                    display(actual),
+                    display(expected)
+                )
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diff_mappings() {
+        let expected = MappingModel::from_value(&json!({
+            "properties": {
+                "title": {
+                    "type": "text",
+                    "analyzer": "english",
+                    "fields": {
+                        "keyword": { "type": "keyword" }
+                    }
+                },
+                "count": { "type": "integer" },
+                "timestamp": { "type": "date" }
+            }
+        }));
+
+        let actual = MappingModel::from_value(&json!({
+            "properties": {
+                "title": {
+                    "type": "text",
+                    "fields": {
+                        "keyword": { "type": "keyword" }
+                    }
+                },
+                "count": { "type": "long" },
+                "extra": { "type": "keyword" }
+            }
+        }));
+
+        let diff = diff(&expected, &actual);
+
+        let expected = vec![
+            FieldDiff::new(
+                "count",
+                FieldDiffKind::DataType {
+                    expected: "integer".to_owned(),
+                    actual: "long".to_owned(),
+                },
+            ),
+            FieldDiff::new("extra", FieldDiffKind::Unexpected),
+            FieldDiff::new("timestamp", FieldDiffKind::Missing),
+            FieldDiff::new(
+                "title",
+                FieldDiffKind::Option {
+                    option: "analyzer".to_owned(),
+                    expected: Some(json!("english")),
+                    actual: None,
+                },
+            ),
+        ];
+
+        assert_eq!(expected, diff);
+    }
+
+    #[test]
+    fn diff_same_mapping() {
+        let mapping = MappingModel::from_value(&json!({
+            "properties": {
+                "title": { "type": "text" }
+            }
+        }));
+
+        assert!(diff(&mapping, &mapping).is_empty());
+    }
+
+    #[test]
+    fn format_diff() {
+        let diff = FieldDiff::new(
+            "title",
+            FieldDiffKind::Option {
+                option: "analyzer".to_owned(),
+                expected: Some(json!("english")),
+                actual: None,
+            },
+        );
+
+        assert_eq!(
+            "`title.analyzer` is unset, expected \"english\"",
+            diff.to_string()
+        );
+    }
+}
@@ -0,0 +1,254 @@
+use serde::de::{
+    Deserialize,
+    Deserializer,
+};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+use super::model::MappingModel;
+use crate::types::document::DEFAULT_DOC_TYPE;
+
+/** Keys that can only appear at the root of a typeless mapping. */
+const ROOT_OPTIONS: &[&str] = &[
+    "properties",
+    "dynamic",
+    "dynamic_templates",
+    "dynamic_date_formats",
+    "date_detection",
+    "numeric_detection",
+    "enabled",
+    "_source",
+    "_all",
+    "_field_names",
+    "_routing",
+    "_meta",
+];
+
+/**
+The mappings that are live on a cluster.
+
+A `DynamicMapping` can be deserialised from the response to a [get mapping request][docs-get-mapping].
+It contains a [`MappingModel`][MappingModel] for each type in each index, so the mappings can be inspected
+without string-matching json.
+
+Typeless mappings, where the `mappings` for an index contain the `properties` directly, are keyed by `_doc`.
+
+# Examples
+
+Get the type of a field in the live mapping of an index:
+
+```no_run
+# use elastic::prelude::*;
+# use elastic::types::mappers::DynamicMapping;
+# fn main() { run().unwrap() }
+# fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+# let client = SyncClientBuilder::new().build()?;
+let mapping = client
+    .request(IndicesGetMappingRequest::for_index("myindex"))
+    .send()?
+    .into_response::<DynamicMapping>()?;
+
+let title = mapping
+    .index("myindex")
+    .and_then(|index| index.ty("_doc"))
+    .and_then(|mapping| mapping.field("title"));
+
+if let Some(title) = title {
+    println!("title is mapped as {}", title.data_type());
+}
+# Ok(())
+# }
+```
+
+[docs-get-mapping]: https://www.elastic.co/guide/en/elasticsearch/reference/master/indices-get-mapping.html
+[MappingModel]: struct.MappingModel.html
+*/
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DynamicMapping {
+    indices: BTreeMap<String, DynamicIndexMapping>,
+}
+
+/**
+The mappings for the types in a single index.
+*/
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DynamicIndexMapping {
+    types: BTreeMap<String, MappingModel>,
+}
+
+impl DynamicMapping {
+    /**
+    Build the mappings from the body of a get mapping response.
+
+    Any values that aren't objects are ignored.
+    */
+    pub fn from_value(response: &Value) -> Self {
+        let indices = response
+            .as_object()
+            .map(|indices| {
+                indices
+                    .iter()
+                    .filter_map(|(name, index)| {
+                        index.get("mappings").map(|mappings| {
+                            (name.clone(), DynamicIndexMapping::from_value(mappings))
+                        })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        DynamicMapping { indices }
+    }
+
+    /** Get the mappings for each index. */
+    pub fn indices(&self) -> &BTreeMap<String, DynamicIndexMapping> {
+        &self.indices
+    }
+
+    /** Get the mappings for an index. */
+    pub fn index(&self, index: &str) -> Option<&DynamicIndexMapping> {
+        self.indices.get(index)
+    }
+}
+
+impl DynamicIndexMapping {
+    /**
+    Build the mappings from the `mappings` object for an index.
+
+    The mappings can either be typeless, with `properties` at the root, or keyed by type name.
+    */
+    pub fn from_value(mappings: &Value) -> Self {
+        let mut types = BTreeMap::new();
+
+        if let Some(mappings) = mappings.as_object() {
+            let is_typeless = mappings.is_empty()
+                || mappings
+                    .keys()
+                    .any(|key| ROOT_OPTIONS.contains(&key.as_str()))
+                || mappings.values().any(|mapping| !mapping.is_object());
+
+            if is_typeless {
+                types.insert(
+                    DEFAULT_DOC_TYPE.to_owned(),
+                    MappingModel::from_value(&Value::Object(mappings.clone())),
+                );
+            } else {
+                for (ty, mapping) in mappings {
+                    types.insert(ty.clone(), MappingModel::from_value(mapping));
+                }
+            }
+        }
+
+        DynamicIndexMapping { types }
+    }
+
+    /** Get the mappings for each type in the index. */
+    pub fn types(&self) -> &BTreeMap<String, MappingModel> {
+        &self.types
+    }
+
+    /** Get the mapping for a type in the index. */
+    pub fn ty(&self, ty: &str) -> Option<&MappingModel> {
+        self.types.get(ty)
+    }
+}
+
+impl<'de> Deserialize<'de> for DynamicMapping {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Value::deserialize(deserializer).map(|response| DynamicMapping::from_value(&response))
+    }
+}
+
+impl<'de> Deserialize<'de> for DynamicIndexMapping {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Value::deserialize(deserializer).map(|mappings| DynamicIndexMapping::from_value(&mappings))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+
+    use super::*;
+
+    #[test]
+    fn deserialise_typeless_mapping() {
+        let mapping: DynamicMapping = serde_json::from_value(json!({
+            "logs-1": {
+                "mappings": {
+                    "dynamic": "strict",
+                    "properties": {
+                        "message": {
+                            "type": "text"
+                        }
+                    }
+                }
+            }
+        }))
+        .unwrap();
+
+        let doc = mapping
+            .index("logs-1")
+            .and_then(|index| index.ty("_doc"))
+            .unwrap();
+
+        assert_eq!(Some(&json!("strict")), doc.options().get("dynamic"));
+        assert_eq!("text", doc.field("message").unwrap().data_type());
+    }
+
+    #[test]
+    fn deserialise_typed_mapping() {
+        let mapping: DynamicMapping = serde_json::from_value(json!({
+            "blog": {
+                "mappings": {
+                    "post": {
+                        "properties": {
+                            "title": {
+                                "type": "text"
+                            }
+                        }
+                    },
+                    "comment": {
+                        "properties": {
+                            "likes": {
+                                "type": "integer"
+                            }
+                        }
+                    }
+                }
+            }
+        }))
+        .unwrap();
+
+        let blog = mapping.index("blog").unwrap();
+
+        assert_eq!(2, blog.types().len());
+        assert_eq!(
+            "integer",
+            blog.ty("comment")
+                .unwrap()
+                .field("likes")
+                .unwrap()
+                .data_type()
+        );
+    }
+
+    #[test]
+    fn deserialise_empty_mapping() {
+        let mapping: DynamicMapping =
+            serde_json::from_value(json!({ "empty": { "mappings": {} } })).unwrap();
+
+        let empty = mapping
+            .index("empty")
+            .and_then(|index| index.ty("_doc"))
+            .unwrap();
+
+        assert!(empty.properties().is_empty());
+    }
+}
@@ -0,0 +1,200 @@
+use serde_json::{
+    self,
+    Value,
+};
+use std::fmt;
+
+use super::canonicalize;
+use crate::types::document::{
+    mapping::IndexDocumentMappingWithoutMeta,
+    DocumentType,
+};
+
+/**
+Get a fingerprint of the mapping for a document type.
+
+The fingerprint is a hash of a canonical serialisation of the mapping, so it only changes when the mapping does.
+The `_meta` of the mapping isn't included in the fingerprint, so the fingerprint can be stored there and compared
+with the fingerprint of the current mapping to detect indices that were created with an older mapping.
+
+# Examples
+
+Store the fingerprint of a mapping in its `_meta`:
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# #[macro_use] extern crate serde_json;
+# use serde_json::Value;
+# use elastic::types::prelude::*;
+# use elastic::types::mappers;
+#[derive(Serialize, ElasticType)]
+#[elastic(mapping = "MyTypeMapping")]
+struct MyType {
+    pub title: String,
+}
+
+#[derive(Default)]
+struct MyTypeMapping;
+impl ObjectMapping for MyTypeMapping {
+    type Properties = MyType;
+
+    fn meta() -> Option<Value> {
+        let hash = mappers::mapping_hash::<MyType>().ok()?;
+
+        Some(json!({ "mapping_hash": hash.to_string() }))
+    }
+}
+# fn main() {}
+```
+*/
+pub fn mapping_hash<TDocument>() -> Result<MappingHash, serde_json::Error>
+where
+    TDocument: DocumentType,
+{
+    let mapping =
+        serde_json::to_value(&IndexDocumentMappingWithoutMeta::<TDocument::Mapping>::default())?;
+
+    Ok(MappingHash::from_value(&mapping))
+}
+
+/**
+A fingerprint of a mapping.
+
+The fingerprint is formatted as a hex string, like `2f8c61a0b97e4d13`.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MappingHash(u64);
+
+impl MappingHash {
+    /**
+    Get the fingerprint of a serialised mapping.
+
+    Any `_meta` at the root of the mapping is ignored.
+    */
+    pub fn from_value(mapping: &Value) -> Self {
+        let mut mapping = mapping.clone();
+        if let Value::Object(ref mut mapping) = mapping {
+            mapping.remove("_meta");
+        }
+
+        let mut hasher = Fnv1a::new();
+        hasher.write(canonicalize(mapping).to_string().as_bytes());
+
+        MappingHash(hasher.finish())
+    }
+
+    /** Get the fingerprint as a number. */
+    pub fn as_u64(&self) -> u64 {
+        self.0
+    }
+}
+
+impl fmt::Display for MappingHash {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:016x}", self.0)
+    }
+}
+
+/**
+The 64bit FNV-1a hash.
+
+This is used instead of the standard library hasher because its output needs to be stable between releases.
+*/
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    fn new() -> Self {
+        Fnv1a(0xcbf2_9ce4_8422_2325)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Value;
+
+    use super::*;
+    use crate::types::prelude::*;
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types", mapping = "HashedTypeMapping")]
+    pub struct HashedType {
+        pub title: String,
+    }
+
+    #[derive(Default)]
+    pub struct HashedTypeMapping;
+    impl ObjectMapping for HashedTypeMapping {
+        type Properties = HashedType;
+
+        fn meta() -> Option<Value> {
+            let hash = mapping_hash::<HashedType>().unwrap();
+
+            Some(json!({ "mapping_hash": hash.to_string() }))
+        }
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct OtherType {
+        pub title: i32,
+    }
+
+    #[test]
+    fn hash_ignores_key_order_and_meta() {
+        let a = MappingHash::from_value(&json!({
+            "_meta": { "version": 1 },
+            "properties": {
+                "a": { "type": "keyword" },
+                "b": { "type": "text", "index": false }
+            }
+        }));
+
+        let b = MappingHash::from_value(&json!({
+            "properties": {
+                "b": { "index": false, "type": "text" },
+                "a": { "type": "keyword" }
+            },
+            "_meta": { "version": 2 }
+        }));
+
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn hash_changes_with_mapping() {
+        let hashed = mapping_hash::<HashedType>().unwrap();
+        let other = mapping_hash::<OtherType>().unwrap();
+
+        assert_ne!(hashed, other);
+    }
+
+    #[test]
+    fn hash_from_document_type() {
+        assert_eq!(
+            mapping_hash::<HashedType>().unwrap(),
+            HashedType::mapping_hash().unwrap()
+        );
+    }
+
+    #[test]
+    fn hash_stored_in_meta_matches_mapping() {
+        let mapping = serde_json::to_value(&HashedType::index_mapping()).unwrap();
+
+        let stored = mapping["_meta"]["mapping_hash"].as_str().unwrap();
+
+        assert_eq!(MappingHash::from_value(&mapping).to_string(), stored);
+        assert_eq!(16, stored.len());
+    }
+}
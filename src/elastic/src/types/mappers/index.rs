@@ -0,0 +1,444 @@
+use serde::ser::{
+    Error as SerError,
+    Serialize,
+    SerializeMap,
+    Serializer,
+};
+use serde_json::{
+    self,
+    Value,
+};
+use std::collections::BTreeMap;
+
+use super::{
+    builder::MappingBuilder,
+    model::MappingModel,
+    overrides::IndexOverrides,
+};
+use crate::types::{
+    document::{
+        mapping::ObjectMapping,
+        DocumentType,
+        DEFAULT_DOC_TYPE,
+    },
+    settings::IndexSettings,
+};
+
+/**
+A builder for the complete body of a create index request.
+
+The body can contain the mappings for several types, along with settings and aliases for the index.
+
+# Examples
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# extern crate serde_json;
+# use elastic::types::prelude::*;
+# use elastic::types::mappers::IndexMapper;
+# fn main() { run().unwrap() }
+# fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+#[derive(Serialize, ElasticType)]
+#[elastic(ty = "post")]
+struct Post {
+    pub title: String,
+}
+
+#[derive(Serialize, ElasticType)]
+#[elastic(ty = "comment")]
+struct Comment {
+    pub body: String,
+}
+
+let body = IndexMapper::new()
+    .settings(IndexSettings::new().number_of_shards(3))
+    .alias("blog")
+    .document::<Post>()
+    .document::<Comment>();
+
+let body = serde_json::to_string(&body)?;
+# Ok(())
+# }
+```
+*/
+#[derive(Clone, Default)]
+pub struct IndexMapper {
+    settings: Option<IndexSettings>,
+    aliases: BTreeMap<String, Value>,
+    mappings: Vec<(String, MappingSource)>,
+    overrides: IndexOverrides,
+}
+
+#[derive(Clone)]
+enum MappingSource {
+    Static(fn() -> Result<MappingModel, serde_json::Error>),
+    Runtime(MappingModel),
+}
+
+impl IndexMapper {
+    /** Create a new, empty create index body. */
+    pub fn new() -> Self {
+        IndexMapper::default()
+    }
+
+    /** The settings for the index. */
+    pub fn settings(mut self, settings: IndexSettings) -> Self {
+        self.settings = Some(settings);
+        self
+    }
+
+    /** Add an alias for the index. */
+    pub fn alias(self, name: impl Into<String>) -> Self {
+        self.alias_with(name, json!({}))
+    }
+
+    /**
+    Add an alias for the index with additional options, like a `filter` or `routing`.
+
+    The `options` are the body of the alias in the create index request.
+    */
+    pub fn alias_with(mut self, name: impl Into<String>, options: Value) -> Self {
+        self.aliases.insert(name.into(), options);
+        self
+    }
+
+    /**
+    Add the mapping for a document type.
+
+    The mapping is added under the document's static type name, or `_doc` if the type name isn't static.
+    Adding a mapping for a type name that's already in the body will replace it.
+    */
+    pub fn document<TDocument>(self) -> Self
+    where
+        TDocument: DocumentType,
+    {
+        let ty = TDocument::partial_static_ty()
+            .map(|ty| ty.to_string())
+            .unwrap_or_else(|| DEFAULT_DOC_TYPE.to_owned());
+
+        self.mapping::<TDocument::Mapping>(ty)
+    }
+
+    /**
+    Add an object mapping under the given type name.
+
+    Adding a mapping for a type name that's already in the body will replace it.
+    */
+    pub fn mapping<TMapping>(mut self, ty: impl Into<String>) -> Self
+    where
+        TMapping: ObjectMapping,
+    {
+        self.push_mapping(
+            ty.into(),
+            MappingSource::Static(MappingModel::from_mapping::<TMapping>),
+        )
+    }
+
+    /**
+    Add a mapping that's assembled at runtime under the given type name.
+
+    Runtime mappings can be mixed with static ones in the same body.
+    Adding a mapping for a type name that's already in the body will replace it.
+    See [`MappingBuilder`][MappingBuilder] for more details.
+
+    [MappingBuilder]: struct.MappingBuilder.html
+    */
+    pub fn runtime_mapping(self, ty: impl Into<String>, mapping: MappingBuilder) -> Self {
+        self.push_mapping(ty.into(), MappingSource::Runtime(mapping.build()))
+    }
+
+    fn push_mapping(mut self, ty: String, mapping: MappingSource) -> Self {
+        self.mappings.retain(|&(ref existing, _)| *existing != ty);
+        self.mappings.push((ty, mapping));
+        self
+    }
+
+    /**
+    Overlay runtime configuration onto the settings and mappings when they're serialised.
+
+    Overriding a type name or field that isn't in the body is an error when the body is serialised.
+    See [`IndexOverrides`][IndexOverrides] for more details.
+
+    [IndexOverrides]: struct.IndexOverrides.html
+    */
+    pub fn overrides(mut self, overrides: IndexOverrides) -> Self {
+        self.overrides = overrides;
+        self
+    }
+}
+
+struct Mappings<'a> {
+    mappings: &'a [(String, MappingSource)],
+    overrides: &'a IndexOverrides,
+}
+
+impl<'a> Serialize for Mappings<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if let Some(ty) = self.overrides.types().find(|ty| {
+            !self
+                .mappings
+                .iter()
+                .any(|&(ref existing, _)| existing == ty)
+        }) {
+            return Err(S::Error::custom(format_args!(
+                "cannot override the mapping for `{}` because it isn't in the index",
+                ty
+            )));
+        }
+
+        let mut state = serializer.serialize_map(Some(self.mappings.len()))?;
+
+        for &(ref ty, ref mapping) in self.mappings {
+            let mut model = match *mapping {
+                MappingSource::Static(mapping) => mapping().map_err(S::Error::custom)?,
+                MappingSource::Runtime(ref model) => model.clone(),
+            };
+
+            self.overrides
+                .apply_mapping(ty, &mut model)
+                .map_err(S::Error::custom)?;
+
+            state.serialize_entry(ty, &model)?;
+        }
+
+        state.end()
+    }
+}
+
+impl Serialize for IndexMapper {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(None)?;
+
+        let settings = match self.settings {
+            Some(ref settings) => Some(self.overrides.apply_settings(settings.clone())),
+            None if self.overrides.has_settings() => {
+                Some(self.overrides.apply_settings(IndexSettings::new()))
+            }
+            None => None,
+        };
+
+        if let Some(ref settings) = settings {
+            state.serialize_entry("settings", settings)?;
+        }
+
+        if !self.aliases.is_empty() {
+            state.serialize_entry("aliases", &self.aliases)?;
+        }
+
+        state.serialize_entry(
+            "mappings",
+            &Mappings {
+                mappings: &self.mappings,
+                overrides: &self.overrides,
+            },
+        )?;
+
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+
+    use super::*;
+    use crate::types::{
+        mappers::{
+            FieldBuilder,
+            MappingOverrides,
+        },
+        prelude::*,
+    };
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types", ty = "post")]
+    pub struct Post {
+        pub title: String,
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types", ty = "comment")]
+    pub struct Comment {
+        pub likes: i32,
+    }
+
+    #[test]
+    fn serialise_index_body() {
+        let body = IndexMapper::new()
+            .settings(IndexSettings::new().number_of_shards(3))
+            .alias("blog")
+            .alias_with(
+                "popular",
+                json!({ "filter": { "range": { "likes": { "gte": 10 } } } }),
+            )
+            .document::<Post>()
+            .document::<Comment>();
+
+        let ser = serde_json::to_value(&body).unwrap();
+
+        let expected = json!({
+            "settings": {
+                "index": {
+                    "number_of_shards": 3
+                }
+            },
+            "aliases": {
+                "blog": {},
+                "popular": {
+                    "filter": { "range": { "likes": { "gte": 10 } } }
+                }
+            },
+            "mappings": {
+                "post": {
+                    "properties": {
+                        "title": {
+                            "type": "text",
+                            "fields": {
+                                "keyword": {
+                                    "type": "keyword",
+                                    "ignore_above": 256
+                                }
+                            }
+                        }
+                    }
+                },
+                "comment": {
+                    "properties": {
+                        "likes": {
+                            "type": "integer"
+                        }
+                    }
+                }
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_mapping_under_type_name() {
+        let body = IndexMapper::new()
+            .mapping::<CommentMapping>("reply")
+            .mapping::<CommentMapping>("reply");
+
+        let ser = serde_json::to_value(&body).unwrap();
+
+        assert_eq!(
+            json!({
+                "mappings": {
+                    "reply": {
+                        "properties": {
+                            "likes": {
+                                "type": "integer"
+                            }
+                        }
+                    }
+                }
+            }),
+            ser
+        );
+    }
+
+    #[test]
+    fn serialise_index_body_with_overrides() {
+        let body = IndexMapper::new().document::<Post>().overrides(
+            IndexOverrides::new()
+                .setting("number_of_shards", 5)
+                .mapping(
+                    "post",
+                    MappingOverrides::new()
+                        .field("title", "analyzer", "english")
+                        .field("title.keyword", "ignore_above", 512),
+                ),
+        );
+
+        let ser = serde_json::to_value(&body).unwrap();
+
+        assert_eq!(
+            json!({
+                "settings": {
+                    "index": {
+                        "number_of_shards": 5
+                    }
+                },
+                "mappings": {
+                    "post": {
+                        "properties": {
+                            "title": {
+                                "type": "text",
+                                "analyzer": "english",
+                                "fields": {
+                                    "keyword": {
+                                        "type": "keyword",
+                                        "ignore_above": 512
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }),
+            ser
+        );
+    }
+
+    #[test]
+    fn serialise_index_body_with_missing_override() {
+        let body = IndexMapper::new()
+            .document::<Post>()
+            .overrides(IndexOverrides::new().mapping("comment", MappingOverrides::new()));
+
+        assert!(serde_json::to_value(&body).is_err());
+    }
+
+    #[test]
+    fn serialise_runtime_mapping_with_static_mapping() {
+        let body = IndexMapper::new()
+            .document::<Post>()
+            .runtime_mapping(
+                "reply",
+                MappingBuilder::new().field("likes", FieldBuilder::long()),
+            )
+            .overrides(IndexOverrides::new().mapping(
+                "reply",
+                MappingOverrides::new().field("likes", "type", "integer"),
+            ));
+
+        let ser = serde_json::to_value(&body).unwrap();
+
+        assert_eq!(
+            json!({
+                "mappings": {
+                    "post": {
+                        "properties": {
+                            "title": {
+                                "type": "text",
+                                "fields": {
+                                    "keyword": {
+                                        "type": "keyword",
+                                        "ignore_above": 256
+                                    }
+                                }
+                            }
+                        }
+                    },
+                    "reply": {
+                        "properties": {
+                            "likes": {
+                                "type": "integer"
+                            }
+                        }
+                    }
+                }
+            }),
+            ser
+        );
+    }
+}
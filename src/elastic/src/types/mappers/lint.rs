@@ -0,0 +1,313 @@
+use serde_json::{
+    self,
+    Value,
+};
+use std::fmt;
+
+use super::model::{
+    model,
+    FieldModel,
+    MappingModel,
+};
+use crate::types::document::DocumentType;
+
+/**
+Check the mapping for a document type for options that are deprecated or discouraged in a version of Elasticsearch.
+
+The version is the major version of the cluster the mapping is targeting, like `6`.
+The checks are run over the serialised mapping, so they flag options set by mapping traits that haven't been
+updated yet, such as an index-time `boost` or a legacy `string` field.
+
+# Examples
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# use elastic::types::prelude::*;
+# use elastic::types::mappers;
+# fn main() { run().unwrap() }
+# fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+#[derive(Serialize, ElasticType)]
+struct MyType {
+    pub title: Text<TitleMapping>,
+}
+
+#[derive(Default)]
+struct TitleMapping;
+impl TextMapping for TitleMapping {
+    fn boost() -> Option<f32> {
+        Some(2.0)
+    }
+}
+
+let warnings = mappers::lint::<MyType>(6)?;
+
+assert_eq!(1, warnings.len());
+assert_eq!("title", warnings[0].path);
+assert_eq!("boost", warnings[0].option);
+# Ok(())
+# }
+```
+*/
+pub fn lint<TDocument>(version: u32) -> Result<Vec<MappingWarning>, serde_json::Error>
+where
+    TDocument: DocumentType,
+{
+    Ok(lint_model(&model::<TDocument>()?, version))
+}
+
+/**
+Check a mapping model for options that are deprecated or discouraged in a version of Elasticsearch.
+
+The version is the major version of the cluster the mapping is targeting, like `6`.
+Warnings are returned in the order the fields are visited by [`MappingModel::walk`][MappingModel.walk],
+after any warnings for the root of the mapping.
+
+[MappingModel.walk]: struct.MappingModel.html#method.walk
+*/
+pub fn lint_model(model: &MappingModel, version: u32) -> Vec<MappingWarning> {
+    let mut warnings = Vec::new();
+
+    if model.options().contains_key("_all") {
+        if let Some(kind) = since(version, 6, 7) {
+            warnings.push(MappingWarning::new(
+                "",
+                "_all",
+                kind,
+                "the `_all` field is deprecated in 6.x and removed in 7.0, use `copy_to` instead",
+            ));
+        }
+    }
+
+    model.walk(|path, field| lint_field(path, field, version, &mut warnings));
+
+    warnings
+}
+
+fn lint_field(path: &str, field: &FieldModel, version: u32, warnings: &mut Vec<MappingWarning>) {
+    if field.data_type() == "string" && version >= 5 {
+        warnings.push(MappingWarning::new(
+            path,
+            "type",
+            MappingWarningKind::Removed,
+            "the `string` type is removed in 5.0, use `text` or `keyword` instead",
+        ));
+    }
+
+    if field.option("boost").is_some() {
+        if let Some(kind) = since(version, 5, 8) {
+            warnings.push(MappingWarning::new(
+                path,
+                "boost",
+                kind,
+                "index-time boosting is deprecated in 5.0, boost fields at query time instead",
+            ));
+        }
+    }
+
+    if field.option("include_in_all").is_some() {
+        if let Some(kind) = since(version, 6, 7) {
+            warnings.push(MappingWarning::new(
+                path,
+                "include_in_all",
+                kind,
+                "`include_in_all` is deprecated in 6.0 and removed in 7.0, use `copy_to` instead",
+            ));
+        }
+    }
+
+    if let Some(&Value::String(_)) = field.option("index") {
+        if version >= 5 {
+            warnings.push(MappingWarning::new(
+                path,
+                "index",
+                MappingWarningKind::Removed,
+                "`analyzed`, `not_analyzed` and `no` are removed in 5.0, `index` must be `true` or `false`",
+            ));
+        }
+    }
+
+    if field.option("precision_step").is_some() {
+        if let Some(kind) = since(version, 5, 6) {
+            warnings.push(MappingWarning::new(
+                path,
+                "precision_step",
+                kind,
+                "`precision_step` is ignored by points based numeric fields in 5.0",
+            ));
+        }
+    }
+
+    if field.option("payloads").is_some() && version >= 5 {
+        warnings.push(MappingWarning::new(
+            path,
+            "payloads",
+            MappingWarningKind::Removed,
+            "completion suggester `payloads` are removed in 5.0, use `_source` instead",
+        ));
+    }
+
+    if field.data_type() == "text" {
+        if let Some(&Value::Bool(true)) = field.option("fielddata") {
+            warnings.push(MappingWarning::new(
+                path,
+                "fielddata",
+                MappingWarningKind::Discouraged,
+                "`fielddata` on `text` fields can use a lot of heap, use a `keyword` sub-field for sorting and aggregations",
+            ));
+        }
+    }
+}
+
+// Get the kind of warning for an option that was deprecated in one major version and removed in a later one
+fn since(version: u32, deprecated: u32, removed: u32) -> Option<MappingWarningKind> {
+    if version >= removed {
+        Some(MappingWarningKind::Removed)
+    } else if version >= deprecated {
+        Some(MappingWarningKind::Deprecated)
+    } else {
+        None
+    }
+}
+
+/** A warning about an option in a mapping. */
+#[derive(Debug, Clone, PartialEq)]
+pub struct MappingWarning {
+    /** The full dotted path to the field, like `title.keyword`, or an empty string for the root of the mapping. */
+    pub path: String,
+    /** The mapping option the warning applies to, like `boost`. */
+    pub option: String,
+    /** How serious the warning is. */
+    pub kind: MappingWarningKind,
+    /** A description of the problem and how to fix it. */
+    pub message: String,
+}
+
+impl MappingWarning {
+    fn new(path: &str, option: &str, kind: MappingWarningKind, message: &str) -> Self {
+        MappingWarning {
+            path: path.to_owned(),
+            option: option.to_owned(),
+            kind,
+            message: message.to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for MappingWarning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let path = if self.path.is_empty() {
+            "<root>"
+        } else {
+            &self.path
+        };
+
+        write!(
+            f,
+            "{} `{}.{}`: {}",
+            self.kind, path, self.option, self.message
+        )
+    }
+}
+
+/** How serious a mapping warning is. */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MappingWarningKind {
+    /** The option is discouraged but still supported. */
+    Discouraged,
+    /** The option is deprecated and will be removed in a later version. */
+    Deprecated,
+    /** The option is removed, so the mapping will be rejected or the option ignored. */
+    Removed,
+}
+
+impl fmt::Display for MappingWarningKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match *self {
+            MappingWarningKind::Discouraged => "discouraged",
+            MappingWarningKind::Deprecated => "deprecated",
+            MappingWarningKind::Removed => "removed",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lint_deprecated_field_options() {
+        let model = MappingModel::from_value(&json!({
+            "_all": { "enabled": false },
+            "properties": {
+                "title": {
+                    "type": "string",
+                    "index": "not_analyzed",
+                    "boost": 2.0,
+                    "include_in_all": false
+                },
+                "count": {
+                    "type": "integer"
+                }
+            }
+        }));
+
+        let warnings = lint_model(&model, 6);
+        let warnings: Vec<(&str, &str, MappingWarningKind)> = warnings
+            .iter()
+            .map(|warning| (warning.path.as_str(), warning.option.as_str(), warning.kind))
+            .collect();
+
+        let expected = vec![
+            ("", "_all", MappingWarningKind::Deprecated),
+            ("title", "type", MappingWarningKind::Removed),
+            ("title", "boost", MappingWarningKind::Deprecated),
+            ("title", "include_in_all", MappingWarningKind::Deprecated),
+            ("title", "index", MappingWarningKind::Removed),
+        ];
+
+        assert_eq!(expected, warnings);
+    }
+
+    #[test]
+    fn lint_depends_on_version() {
+        let model = MappingModel::from_value(&json!({
+            "properties": {
+                "title": {
+                    "type": "text",
+                    "include_in_all": true
+                }
+            }
+        }));
+
+        assert!(lint_model(&model, 5).is_empty());
+        assert_eq!(MappingWarningKind::Removed, lint_model(&model, 7)[0].kind);
+    }
+
+    #[test]
+    fn lint_document_type() {
+        #[derive(Serialize, ElasticType)]
+        #[elastic(crate_root = "crate::types")]
+        pub struct LintedType {
+            pub title: String,
+            pub count: i32,
+        }
+
+        assert!(lint::<LintedType>(7).unwrap().is_empty());
+    }
+
+    #[test]
+    fn format_warning() {
+        let warning = MappingWarning::new(
+            "title",
+            "boost",
+            MappingWarningKind::Deprecated,
+            "use query-time boosting",
+        );
+
+        assert_eq!(
+            "deprecated `title.boost`: use query-time boosting",
+            warning.to_string()
+        );
+    }
+}
@@ -0,0 +1,75 @@
+/*!
+Tools for inspecting and rendering document mappings.
+
+The functions in this module work with the serialised form of a mapping, so they can be used for both
+the mappings of types in this crate and mappings fetched from a cluster.
+A mapping is first parsed from its serialised form into a [`MappingModel`][MappingModel],
+which other tools can traverse without re-implementing the structure of a mapping.
+The mappings that are live on a cluster can be deserialised from a get mapping response into a
+[`DynamicMapping`][DynamicMapping].
+
+An [`IndexMapper`][IndexMapper] combines the mappings for several document types with settings and aliases
+into the body of a create index request.
+Mappings whose fields aren't known until runtime can be assembled with a [`MappingBuilder`][MappingBuilder]
+and added to an `IndexMapper` alongside static ones.
+
+The [`lint`][lint] function checks a mapping for options that are deprecated in the version of Elasticsearch it targets,
+and the [`diff`][diff] function compares two mappings field by field.
+Problems that would cause a mapping to be rejected, like empty field names or too many fields,
+can be found before it's sent to a cluster with [`validate`][validate].
+Queries can be checked against a mapping for common mistakes, like searching fields that don't exist,
+with [`lint_query`][lint_query].
+
+A [`TypeRegistry`][TypeRegistry] collects all of the document types used by an application so their mappings can be
+created, described and compared together.
+Runtime configuration, like analyzer names or shard counts from a configuration file, can be overlaid onto
+compile-time mappings with [`IndexOverrides`][IndexOverrides].
+
+Mappings can be rendered as canonical JSON with sorted keys, either compact or pretty-printed,
+with [`TypeMapper`][TypeMapper] so they diff cleanly in version control and golden tests.
+Large mappings can also be written straight to a file or socket.
+
+[MappingModel]: struct.MappingModel.html
+[DynamicMapping]: struct.DynamicMapping.html
+[IndexMapper]: struct.IndexMapper.html
+[MappingBuilder]: struct.MappingBuilder.html
+[lint]: fn.lint.html
+[diff]: fn.diff.html
+[validate]: fn.validate.html
+[lint_query]: fn.lint_query.html
+[TypeRegistry]: struct.TypeRegistry.html
+[IndexOverrides]: struct.IndexOverrides.html
+[TypeMapper]: struct.TypeMapper.html
+*/
+
+mod builder;
+mod describe;
+mod diff;
+mod dynamic;
+mod hash;
+mod index;
+mod lint;
+mod model;
+mod overrides;
+mod query;
+mod registry;
+mod render;
+mod validate;
+
+pub use self::{
+    builder::*,
+    describe::*,
+    diff::*,
+    dynamic::*,
+    hash::*,
+    index::*,
+    lint::*,
+    model::*,
+    overrides::*,
+    query::*,
+    registry::*,
+    render::*,
+    validate::*,
+};
+
+pub(crate) use self::render::canonicalize;
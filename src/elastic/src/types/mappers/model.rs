@@ -0,0 +1,509 @@
+use serde::{
+    de::{
+        Deserialize,
+        Deserializer,
+    },
+    ser::{
+        Serialize,
+        SerializeMap,
+        Serializer,
+    },
+};
+use serde_json::{
+    self,
+    Map,
+    Value,
+};
+use std::collections::BTreeMap;
+
+use crate::types::document::{
+    mapping::ObjectMapping,
+    DocumentType,
+    IndexDocumentMapping,
+};
+
+/**
+Build an introspection model for the mapping of a document type.
+
+The model is built from the serialised mapping, so it contains exactly what would be sent to Elasticsearch.
+
+# Examples
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# use elastic::types::prelude::*;
+# use elastic::types::mappers;
+# fn main() { run().unwrap() }
+# fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+#[derive(Serialize, ElasticType)]
+struct MyType {
+    pub title: String,
+}
+
+let model = mappers::model::<MyType>()?;
+
+let keyword = model.field("title.keyword").unwrap();
+
+assert_eq!("keyword", keyword.data_type());
+assert_eq!(
+    Some(256),
+    keyword.option("ignore_above").and_then(|value| value.as_u64())
+);
+# Ok(())
+# }
+```
+*/
+pub fn model<TDocument>() -> Result<MappingModel, serde_json::Error>
+where
+    TDocument: DocumentType,
+{
+    MappingModel::from_mapping::<TDocument::Mapping>()
+}
+
+/**
+An introspection model for a document mapping.
+
+The model is a tree of fields, where each field has a datatype and a map of options.
+It can be serialised back into the same mapping it was built from.
+*/
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MappingModel {
+    pub(super) options: BTreeMap<String, Value>,
+    pub(super) properties: BTreeMap<String, FieldModel>,
+}
+
+/**
+An introspection model for a single field in a mapping.
+*/
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FieldModel {
+    pub(super) data_type: Option<String>,
+    pub(super) options: BTreeMap<String, Value>,
+    pub(super) fields: BTreeMap<String, FieldModel>,
+    pub(super) properties: BTreeMap<String, FieldModel>,
+}
+
+impl MappingModel {
+    /**
+    Build a model from the serialised index mapping of an object mapping.
+
+    The model is derived from the serialised mapping, so it's never a separate source of truth for what's sent to Elasticsearch.
+    */
+    pub fn from_mapping<TMapping>() -> Result<Self, serde_json::Error>
+    where
+        TMapping: ObjectMapping,
+    {
+        let mapping = serde_json::to_value(&IndexDocumentMapping::<TMapping>::default())?;
+
+        Ok(MappingModel::from_value(&mapping))
+    }
+
+    /**
+    Build a model from a serialised mapping.
+
+    The mapping is expected to have the same shape as a type mapping in a create index or put mapping request,
+    with a root `properties` object.
+    Any values that aren't objects are ignored.
+    */
+    pub fn from_value(mapping: &Value) -> Self {
+        let mut model = MappingModel::default();
+
+        if let Some(mapping) = mapping.as_object() {
+            for (key, value) in mapping {
+                match key.as_str() {
+                    "properties" => model.properties = properties_from_value(value),
+                    _ => {
+                        model.options.insert(key.clone(), value.clone());
+                    }
+                }
+            }
+        }
+
+        model
+    }
+
+    /** Get the options at the root of the mapping, like `dynamic` or `_source`. */
+    pub fn options(&self) -> &BTreeMap<String, Value> {
+        &self.options
+    }
+
+    /** Get the top-level fields in the mapping. */
+    pub fn properties(&self) -> &BTreeMap<String, FieldModel> {
+        &self.properties
+    }
+
+    /**
+    Get a field by its full dotted path, like `title.keyword`.
+
+    The path can refer to both the properties of objects and the sub-fields of a field.
+    */
+    pub fn field(&self, path: &str) -> Option<&FieldModel> {
+        let mut parts = path.split('.');
+
+        let mut field = self.properties.get(parts.next()?)?;
+        for part in parts {
+            field = field
+                .properties
+                .get(part)
+                .or_else(|| field.fields.get(part))?;
+        }
+
+        Some(field)
+    }
+
+    /** Get a mutable reference to a field by its full dotted path, like `title.keyword`. */
+    pub fn field_mut(&mut self, path: &str) -> Option<&mut FieldModel> {
+        let mut parts = path.split('.');
+
+        let mut field = self.properties.get_mut(parts.next()?)?;
+        for part in parts {
+            field = if field.properties.contains_key(part) {
+                field.properties.get_mut(part)
+            } else {
+                field.fields.get_mut(part)
+            }?;
+        }
+
+        Some(field)
+    }
+
+    /**
+    Set an option at the root of the mapping.
+
+    Setting an option to `null` will remove it.
+    */
+    pub fn set_option(&mut self, key: impl Into<String>, value: Value) {
+        set_option(&mut self.options, key.into(), value)
+    }
+
+    /**
+    Visit every field in the mapping along with its full dotted path.
+
+    Fields are visited depth-first, with a field visited before its sub-fields and then its properties.
+    */
+    pub fn walk<F>(&self, mut f: F)
+    where
+        F: FnMut(&str, &FieldModel),
+    {
+        fn walk_properties<F>(
+            parent: Option<&str>,
+            properties: &BTreeMap<String, FieldModel>,
+            f: &mut F,
+        ) where
+            F: FnMut(&str, &FieldModel),
+        {
+            for (name, field) in properties {
+                let path = match parent {
+                    Some(parent) => format!("{}.{}", parent, name),
+                    None => name.clone(),
+                };
+
+                f(&path, field);
+
+                walk_properties(Some(&path), &field.fields, f);
+                walk_properties(Some(&path), &field.properties, f);
+            }
+        }
+
+        walk_properties(None, &self.properties, &mut f)
+    }
+}
+
+impl FieldModel {
+    /**
+    Build a model from a serialised field mapping.
+
+    Any values that aren't objects are ignored.
+    */
+    pub fn from_value(mapping: &Value) -> Self {
+        let mut model = FieldModel::default();
+
+        if let Some(mapping) = mapping.as_object() {
+            for (key, value) in mapping {
+                match (key.as_str(), value) {
+                    ("type", &Value::String(ref data_type)) => {
+                        model.data_type = Some(data_type.clone())
+                    }
+                    ("fields", _) => model.fields = properties_from_value(value),
+                    ("properties", _) => model.properties = properties_from_value(value),
+                    _ => {
+                        model.options.insert(key.clone(), value.clone());
+                    }
+                }
+            }
+        }
+
+        model
+    }
+
+    /**
+    Get the Elasticsearch datatype of the field, like `keyword`.
+
+    Fields without an explicit type are `object`s.
+    */
+    pub fn data_type(&self) -> &str {
+        self.data_type
+            .as_ref()
+            .map(String::as_str)
+            .unwrap_or("object")
+    }
+
+    /** Whether the field is an `object` or `nested` field that can have properties. */
+    pub fn is_object(&self) -> bool {
+        match self.data_type() {
+            "object" | "nested" => true,
+            _ => false,
+        }
+    }
+
+    /** Get all of the options for the field, except `type`, `fields` and `properties`. */
+    pub fn options(&self) -> &BTreeMap<String, Value> {
+        &self.options
+    }
+
+    /** Get an option for the field, like `format` or `analyzer`. */
+    pub fn option(&self, key: &str) -> Option<&Value> {
+        self.options.get(key)
+    }
+
+    /**
+    Set an option for the field, like `analyzer` or `ignore_above`.
+
+    Setting the `type` option will change the datatype of the field.
+    Setting an option to `null` will remove it.
+    */
+    pub fn set_option(&mut self, key: impl Into<String>, value: Value) {
+        match (key.into(), value) {
+            (ref key, Value::String(data_type)) if key == "type" => {
+                self.data_type = Some(data_type)
+            }
+            (ref key, Value::Null) if key == "type" => self.data_type = None,
+            (key, value) => set_option(&mut self.options, key, value),
+        }
+    }
+
+    /** Get the sub-fields of the field, like `keyword` for a `text` field. */
+    pub fn fields(&self) -> &BTreeMap<String, FieldModel> {
+        &self.fields
+    }
+
+    /** Get the properties of an `object` or `nested` field. */
+    pub fn properties(&self) -> &BTreeMap<String, FieldModel> {
+        &self.properties
+    }
+}
+
+fn set_option(options: &mut BTreeMap<String, Value>, key: String, value: Value) {
+    match value {
+        Value::Null => {
+            options.remove(&key);
+        }
+        value => {
+            options.insert(key, value);
+        }
+    }
+}
+
+fn properties_from_value(properties: &Value) -> BTreeMap<String, FieldModel> {
+    properties
+        .as_object()
+        .map(|properties: &Map<String, Value>| {
+            properties
+                .iter()
+                .filter(|&(_, mapping)| mapping.is_object())
+                .map(|(name, mapping)| (name.clone(), FieldModel::from_value(mapping)))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+impl Serialize for MappingModel {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(None)?;
+
+        for (key, value) in &self.options {
+            state.serialize_entry(key, value)?;
+        }
+
+        state.serialize_entry("properties", &self.properties)?;
+
+        state.end()
+    }
+}
+
+impl Serialize for FieldModel {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(None)?;
+
+        if let Some(ref data_type) = self.data_type {
+            state.serialize_entry("type", data_type)?;
+        }
+
+        for (key, value) in &self.options {
+            state.serialize_entry(key, value)?;
+        }
+
+        if !self.fields.is_empty() {
+            state.serialize_entry("fields", &self.fields)?;
+        }
+
+        if !self.properties.is_empty() {
+            state.serialize_entry("properties", &self.properties)?;
+        }
+
+        state.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for MappingModel {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Value::deserialize(deserializer).map(|mapping| MappingModel::from_value(&mapping))
+    }
+}
+
+impl<'de> Deserialize<'de> for FieldModel {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Value::deserialize(deserializer).map(|mapping| FieldModel::from_value(&mapping))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::prelude::*;
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct ModelledType {
+        pub title: String,
+        pub inner: ModelledInnerType,
+        #[elastic(source_exclude)]
+        pub body: Option<String>,
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct ModelledInnerType {
+        pub count: i64,
+    }
+
+    #[test]
+    fn model_round_trips_mapping() {
+        let mapping = serde_json::to_value(&ModelledType::index_mapping()).unwrap();
+
+        let model = MappingModel::from_value(&mapping);
+
+        assert_eq!(mapping, serde_json::to_value(&model).unwrap());
+    }
+
+    #[test]
+    fn model_from_mapping() {
+        let mapping = serde_json::to_value(&ModelledType::index_mapping()).unwrap();
+
+        let model = MappingModel::from_mapping::<ModelledTypeMapping>().unwrap();
+
+        assert_eq!(MappingModel::from_value(&mapping), model);
+        assert_eq!(
+            Some(&json!({ "excludes": ["body"] })),
+            model.options().get("_source")
+        );
+    }
+
+    #[test]
+    fn model_deserialise() {
+        let mapping = serde_json::to_value(&ModelledType::index_mapping()).unwrap();
+
+        let model: MappingModel = serde_json::from_value(mapping.clone()).unwrap();
+
+        assert_eq!(MappingModel::from_value(&mapping), model);
+    }
+
+    #[test]
+    fn model_get_field() {
+        let model = model::<ModelledType>().unwrap();
+
+        assert_eq!("text", model.field("title").unwrap().data_type());
+        assert_eq!("keyword", model.field("title.keyword").unwrap().data_type());
+        assert_eq!("long", model.field("inner.count").unwrap().data_type());
+        assert!(model.field("inner").unwrap().is_object());
+        assert!(model.field("missing").is_none());
+        assert!(model.field("title.missing").is_none());
+    }
+
+    #[test]
+    fn model_set_options() {
+        let mut model = model::<ModelledType>().unwrap();
+
+        model.set_option("dynamic", json!("strict"));
+
+        {
+            let keyword = model.field_mut("title.keyword").unwrap();
+            keyword.set_option("ignore_above", json!(512));
+            keyword.set_option("type", json!("text"));
+        }
+
+        model
+            .field_mut("inner.count")
+            .unwrap()
+            .set_option("doc_values", Value::Null);
+
+        assert_eq!(Some(&json!("strict")), model.options().get("dynamic"));
+        assert_eq!("text", model.field("title.keyword").unwrap().data_type());
+        assert_eq!(
+            Some(&json!(512)),
+            model.field("title.keyword").unwrap().option("ignore_above")
+        );
+        assert!(model.field_mut("title.missing").is_none());
+    }
+
+    #[test]
+    fn model_walk() {
+        let model = model::<ModelledType>().unwrap();
+
+        let mut paths = Vec::new();
+        model.walk(|path, _| paths.push(path.to_owned()));
+
+        assert_eq!(
+            vec![
+                "body",
+                "body.keyword",
+                "inner",
+                "inner.count",
+                "title",
+                "title.keyword"
+            ],
+            paths
+        );
+    }
+
+    #[test]
+    fn model_field_without_type_is_object() {
+        let model = MappingModel::from_value(&json!({
+            "properties": {
+                "meta": {
+                    "properties": {
+                        "tag": {
+                            "type": "keyword"
+                        }
+                    }
+                }
+            }
+        }));
+
+        assert_eq!("object", model.field("meta").unwrap().data_type());
+        assert_eq!("keyword", model.field("meta.tag").unwrap().data_type());
+    }
+}
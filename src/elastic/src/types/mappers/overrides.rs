@@ -0,0 +1,389 @@
+use serde_json::Value;
+use std::{
+    collections::BTreeMap,
+    error::Error as StdError,
+    fmt,
+};
+
+use super::model::MappingModel;
+use crate::types::settings::IndexSettings;
+
+/**
+Runtime configuration that's overlaid onto the mappings and settings for an index before they're serialised.
+
+Mappings are usually defined at compile time, but some options, like analyzer names, shard counts or
+`ignore_above` values, can depend on the environment an application is deployed to.
+Overrides can be built in code or deserialised from a configuration file, and applied to an
+[`IndexMapper`][IndexMapper] or an index in a [`TypeRegistry`][TypeRegistry].
+
+Overrides deserialise from a structure like:
+
+```json
+{
+    "settings": {
+        "number_of_shards": 5
+    },
+    "mappings": {
+        "_doc": {
+            "options": { "dynamic": "strict" },
+            "fields": {
+                "title": { "analyzer": "english" },
+                "title.keyword": { "ignore_above": 512 }
+            }
+        }
+    }
+}
+```
+
+Settings are set on the index the same way as [`IndexSettings.setting`][IndexSettings.setting], so they replace any
+settings with the same key.
+Field options are set on the field at the given dotted path, and setting an option to `null` removes it.
+Overriding a field that isn't in the mapping is an error.
+
+# Examples
+
+Load overrides from a file and apply them to the body of a create index request:
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# extern crate serde_json;
+# use elastic::types::prelude::*;
+# use elastic::types::mappers::{IndexMapper, IndexOverrides};
+# fn main() { run().unwrap() }
+# fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+#[derive(Serialize, ElasticType)]
+struct Post {
+    pub title: String,
+}
+
+# let config = r#"{ "mappings": { "_doc": { "fields": { "title": { "analyzer": "english" } } } } }"#;
+let overrides: IndexOverrides = serde_json::from_str(config)?;
+
+let body = IndexMapper::new()
+    .document::<Post>()
+    .overrides(overrides);
+
+let body = serde_json::to_string(&body)?;
+# Ok(())
+# }
+```
+
+Build the same overrides in code:
+
+```
+# use elastic::types::mappers::{IndexOverrides, MappingOverrides};
+# fn main() {
+let overrides = IndexOverrides::new()
+    .setting("number_of_shards", 5)
+    .mapping(
+        "_doc",
+        MappingOverrides::new().field("title", "analyzer", "english"),
+    );
+# }
+```
+
+[IndexMapper]: struct.IndexMapper.html
+[TypeRegistry]: struct.TypeRegistry.html
+[IndexSettings.setting]: ../settings/struct.IndexSettings.html#method.setting
+*/
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct IndexOverrides {
+    settings: BTreeMap<String, Value>,
+    mappings: BTreeMap<String, MappingOverrides>,
+}
+
+/**
+Runtime configuration that's overlaid onto a single mapping.
+
+See [`IndexOverrides`][IndexOverrides] for more details.
+
+[IndexOverrides]: struct.IndexOverrides.html
+*/
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct MappingOverrides {
+    options: BTreeMap<String, Value>,
+    fields: BTreeMap<String, BTreeMap<String, Value>>,
+}
+
+impl IndexOverrides {
+    /** Create a new, empty set of overrides. */
+    pub fn new() -> Self {
+        IndexOverrides::default()
+    }
+
+    /** Override an index setting, like `number_of_shards`. */
+    pub fn setting(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.settings.insert(key.into(), value.into());
+        self
+    }
+
+    /** Override the mapping for a type name. */
+    pub fn mapping(mut self, ty: impl Into<String>, overrides: MappingOverrides) -> Self {
+        self.mappings.insert(ty.into(), overrides);
+        self
+    }
+
+    /** Whether there are any settings or mappings to override. */
+    pub fn is_empty(&self) -> bool {
+        self.settings.is_empty() && self.mappings.values().all(MappingOverrides::is_empty)
+    }
+
+    /** Whether there are any settings to override. */
+    pub fn has_settings(&self) -> bool {
+        !self.settings.is_empty()
+    }
+
+    /** Apply the setting overrides to the settings for an index. */
+    pub fn apply_settings(&self, settings: IndexSettings) -> IndexSettings {
+        self.settings
+            .iter()
+            .fold(settings, |settings, (key, value)| {
+                settings.setting(key.clone(), value.clone())
+            })
+    }
+
+    /** Apply the mapping overrides for a type name to its mapping. */
+    pub fn apply_mapping(&self, ty: &str, model: &mut MappingModel) -> Result<(), OverrideError> {
+        match self.mappings.get(ty) {
+            Some(overrides) => overrides.apply(model).map_err(|err| err.in_type(ty)),
+            None => Ok(()),
+        }
+    }
+
+    /** Get the type names that have mapping overrides. */
+    pub fn types(&self) -> impl Iterator<Item = &str> {
+        self.mappings.keys().map(|ty| ty.as_str())
+    }
+}
+
+impl MappingOverrides {
+    /** Create a new, empty set of overrides. */
+    pub fn new() -> Self {
+        MappingOverrides::default()
+    }
+
+    /** Override an option at the root of the mapping, like `dynamic`. */
+    pub fn option(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.options.insert(key.into(), value.into());
+        self
+    }
+
+    /** Override an option for the field at the given dotted path, like `title.keyword`. */
+    pub fn field(
+        mut self,
+        path: impl Into<String>,
+        key: impl Into<String>,
+        value: impl Into<Value>,
+    ) -> Self {
+        self.fields
+            .entry(path.into())
+            .or_insert_with(BTreeMap::new)
+            .insert(key.into(), value.into());
+        self
+    }
+
+    /** Whether there are any options to override. */
+    pub fn is_empty(&self) -> bool {
+        self.options.is_empty() && self.fields.is_empty()
+    }
+
+    /**
+    Apply the overrides to a mapping.
+
+    An error is returned if a field isn't in the mapping.
+    The mapping isn't changed if an error is returned.
+    */
+    pub fn apply(&self, model: &mut MappingModel) -> Result<(), OverrideError> {
+        if let Some(path) = self.fields.keys().find(|path| model.field(path).is_none()) {
+            return Err(OverrideError::missing_field(path));
+        }
+
+        for (key, value) in &self.options {
+            model.set_option(key.clone(), value.clone());
+        }
+
+        for (path, options) in &self.fields {
+            let field = model.field_mut(path).expect("field was checked");
+
+            for (key, value) in options {
+                field.set_option(key.clone(), value.clone());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/** An error applying overrides to a mapping. */
+#[derive(Debug, Clone, PartialEq)]
+pub struct OverrideError {
+    ty: Option<String>,
+    path: String,
+}
+
+impl OverrideError {
+    fn missing_field(path: &str) -> Self {
+        OverrideError {
+            ty: None,
+            path: path.to_owned(),
+        }
+    }
+
+    fn in_type(mut self, ty: &str) -> Self {
+        self.ty = Some(ty.to_owned());
+        self
+    }
+
+    /** The type name of the mapping that was overridden, if it's known. */
+    pub fn ty(&self) -> Option<&str> {
+        self.ty.as_ref().map(|ty| ty.as_str())
+    }
+
+    /** The dotted path to the field that isn't in the mapping. */
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+}
+
+impl fmt::Display for OverrideError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.ty {
+            Some(ref ty) => write!(
+                f,
+                "cannot override `{}` because it isn't in the mapping for `{}`",
+                self.path, ty
+            ),
+            None => write!(
+                f,
+                "cannot override `{}` because it isn't in the mapping",
+                self.path
+            ),
+        }
+    }
+}
+
+impl StdError for OverrideError {
+    fn description(&self) -> &str {
+        "cannot override a field that isn't in the mapping"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+
+    use super::*;
+
+    fn model() -> MappingModel {
+        MappingModel::from_value(&json!({
+            "properties": {
+                "title": {
+                    "type": "text",
+                    "fields": {
+                        "keyword": { "type": "keyword", "ignore_above": 256 }
+                    }
+                },
+                "likes": { "type": "integer", "doc_values": false }
+            }
+        }))
+    }
+
+    #[test]
+    fn deserialise_overrides() {
+        let overrides: IndexOverrides = serde_json::from_value(json!({
+            "settings": { "number_of_shards": 5 },
+            "mappings": {
+                "_doc": {
+                    "options": { "dynamic": "strict" },
+                    "fields": {
+                        "title.keyword": { "ignore_above": 512 }
+                    }
+                }
+            }
+        }))
+        .unwrap();
+
+        let expected = IndexOverrides::new()
+            .setting("number_of_shards", 5)
+            .mapping(
+                "_doc",
+                MappingOverrides::new().option("dynamic", "strict").field(
+                    "title.keyword",
+                    "ignore_above",
+                    512,
+                ),
+            );
+
+        assert_eq!(expected, overrides);
+    }
+
+    #[test]
+    fn apply_mapping_overrides() {
+        let overrides = MappingOverrides::new()
+            .option("dynamic", "strict")
+            .field("title", "analyzer", "english")
+            .field("title.keyword", "ignore_above", 512)
+            .field("likes", "doc_values", Value::Null);
+
+        let mut model = model();
+        overrides.apply(&mut model).unwrap();
+
+        assert_eq!(
+            json!({
+                "dynamic": "strict",
+                "properties": {
+                    "title": {
+                        "type": "text",
+                        "analyzer": "english",
+                        "fields": {
+                            "keyword": { "type": "keyword", "ignore_above": 512 }
+                        }
+                    },
+                    "likes": { "type": "integer" }
+                }
+            }),
+            serde_json::to_value(&model).unwrap()
+        );
+    }
+
+    #[test]
+    fn apply_mapping_overrides_missing_field() {
+        let overrides = IndexOverrides::new().mapping(
+            "_doc",
+            MappingOverrides::new().option("dynamic", "strict").field(
+                "title.missing",
+                "ignore_above",
+                512,
+            ),
+        );
+
+        let mut model = model();
+        let err = overrides.apply_mapping("_doc", &mut model).unwrap_err();
+
+        assert_eq!(Some("_doc"), err.ty());
+        assert_eq!("title.missing", err.path());
+        assert_eq!(None, model.options().get("dynamic"));
+    }
+
+    #[test]
+    fn apply_settings_overrides() {
+        let overrides = IndexOverrides::new()
+            .setting("number_of_shards", 5)
+            .setting("translog.durability", "async");
+
+        let settings = overrides.apply_settings(IndexSettings::new().number_of_shards(3));
+
+        assert_eq!(
+            json!({
+                "index": {
+                    "number_of_shards": 5,
+                    "translog.durability": "async"
+                }
+            }),
+            serde_json::to_value(&settings).unwrap()
+        );
+    }
+}
@@ -0,0 +1,514 @@
+use serde_json::{
+    self,
+    Value,
+};
+use std::fmt;
+
+use super::model::{
+    model,
+    FieldModel,
+    MappingModel,
+};
+use crate::types::{
+    date::{
+        built_in_parser,
+        CustomDateFormat,
+    },
+    document::DocumentType,
+};
+
+/** The date format Elasticsearch uses for `date` fields that don't set one. */
+const DEFAULT_DATE_FORMAT: &str = "strict_date_optional_time||epoch_millis";
+
+/** Queries that match exact terms, so they won't match values in analyzed `text` fields. */
+const TERM_LEVEL_QUERIES: &[&str] = &["term", "terms", "prefix", "wildcard", "regexp", "fuzzy"];
+
+/** Queries whose bodies are keyed by the name of the field they search. */
+const FIELD_QUERIES: &[&str] = &[
+    "term",
+    "terms",
+    "prefix",
+    "wildcard",
+    "regexp",
+    "fuzzy",
+    "range",
+    "match",
+    "match_phrase",
+    "match_phrase_prefix",
+];
+
+/** The parameters of a `range` query that are bounds. */
+const RANGE_BOUNDS: &[&str] = &["gt", "gte", "lt", "lte", "from", "to"];
+
+/**
+Check a query against the mapping for a document type for common mistakes.
+
+The query can either be a full search request body, or just the query.
+Each leaf query is checked for:
+
+- fields that aren't in the mapping
+- term-level queries, like `term` or `prefix`, on analyzed `text` fields
+- date values that don't parse under the format of a `date` field
+
+Date math values, like `now-1d`, aren't checked.
+
+# Examples
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# #[macro_use] extern crate serde_json;
+# use elastic::types::prelude::*;
+# use elastic::types::mappers::{self, QueryWarningKind};
+# fn main() { run().unwrap() }
+# fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+#[derive(Serialize, ElasticType)]
+struct MyType {
+    pub title: String,
+}
+
+let warnings = mappers::lint_query::<MyType>(&json!({
+    "query": {
+        "term": { "title": "Rust" }
+    }
+}))?;
+
+assert_eq!(1, warnings.len());
+assert_eq!("title", warnings[0].field);
+assert_eq!(QueryWarningKind::TermOnText, warnings[0].kind);
+# Ok(())
+# }
+```
+*/
+pub fn lint_query<TDocument>(query: &Value) -> Result<Vec<QueryWarning>, serde_json::Error>
+where
+    TDocument: DocumentType,
+{
+    Ok(lint_query_model(&model::<TDocument>()?, query))
+}
+
+/**
+Check a query against a mapping model for common mistakes.
+
+Warnings are returned in the order the leaf queries appear in the query.
+See [`lint_query`][lint_query] for the checks that are made.
+
+[lint_query]: fn.lint_query.html
+*/
+pub fn lint_query_model(model: &MappingModel, query: &Value) -> Vec<QueryWarning> {
+    let mut warnings = Vec::new();
+
+    let (path, query) = match query.get("query") {
+        Some(query) => ("query", query),
+        None => ("", query),
+    };
+
+    lint_value(model, path, query, &mut warnings);
+
+    warnings
+}
+
+fn lint_value(model: &MappingModel, path: &str, value: &Value, warnings: &mut Vec<QueryWarning>) {
+    match *value {
+        Value::Object(ref query) => {
+            for (key, body) in query {
+                let path = join(path, key);
+
+                if FIELD_QUERIES.contains(&key.as_str()) {
+                    lint_field_query(model, &path, key, body, warnings);
+                } else if key == "exists" {
+                    if let Some(field) = body.get("field").and_then(Value::as_str) {
+                        lint_field(model, &path, field, warnings);
+                    }
+                } else {
+                    lint_value(model, &path, body, warnings);
+                }
+            }
+        }
+        Value::Array(ref queries) => {
+            for (i, query) in queries.iter().enumerate() {
+                lint_value(model, &format!("{}[{}]", path, i), query, warnings);
+            }
+        }
+        _ => (),
+    }
+}
+
+fn lint_field_query(
+    model: &MappingModel,
+    path: &str,
+    query: &str,
+    body: &Value,
+    warnings: &mut Vec<QueryWarning>,
+) {
+    let body = match body.as_object() {
+        Some(body) => body,
+        None => return,
+    };
+
+    // Query parameters like `boost` sit alongside the field in a `terms` query
+    for (name, value) in body.iter().filter(|&(name, _)| !is_param(query, name)) {
+        let field = match lint_field(model, path, name, warnings) {
+            Some(field) => field,
+            None => continue,
+        };
+
+        if field.data_type() == "text" && TERM_LEVEL_QUERIES.contains(&query) {
+            warnings.push(QueryWarning::new(
+                path,
+                name,
+                QueryWarningKind::TermOnText,
+                &format!(
+                    "a `{}` query on an analyzed `text` field won't match values that weren't analyzed, use a `match` query or a `keyword` field instead",
+                    query
+                ),
+            ));
+        }
+
+        if field.data_type() == "date" {
+            lint_dates(path, name, field, query, value, warnings);
+        }
+    }
+}
+
+fn lint_field<'a>(
+    model: &'a MappingModel,
+    path: &str,
+    name: &str,
+    warnings: &mut Vec<QueryWarning>,
+) -> Option<&'a FieldModel> {
+    // Metadata fields and wildcard patterns aren't in the mapping
+    if name.starts_with('_') || name.contains('*') {
+        return None;
+    }
+
+    let field = model.field(name);
+
+    if field.is_none() {
+        warnings.push(QueryWarning::new(
+            path,
+            name,
+            QueryWarningKind::UnknownField,
+            "the field isn't in the mapping, so the query won't match any documents",
+        ));
+    }
+
+    field
+}
+
+fn lint_dates(
+    path: &str,
+    name: &str,
+    field: &FieldModel,
+    query: &str,
+    value: &Value,
+    warnings: &mut Vec<QueryWarning>,
+) {
+    let format = value
+        .get("format")
+        .or_else(|| field.option("format"))
+        .and_then(Value::as_str)
+        .unwrap_or(DEFAULT_DATE_FORMAT);
+
+    let dates: Vec<&Value> = match query {
+        "range" => RANGE_BOUNDS
+            .iter()
+            .filter_map(|bound| value.get(bound))
+            .collect(),
+        "terms" => value
+            .as_array()
+            .map(|values| values.iter().collect())
+            .unwrap_or_default(),
+        _ => vec![value
+            .get("value")
+            .or_else(|| value.get("query"))
+            .unwrap_or(value)],
+    };
+
+    for date in dates {
+        let date = match *date {
+            Value::String(ref date) => date.clone(),
+            Value::Number(ref date) => date.to_string(),
+            _ => continue,
+        };
+
+        if is_date_math(&date) {
+            continue;
+        }
+
+        if let Some(false) = parses(format, &date) {
+            warnings.push(QueryWarning::new(
+                path,
+                name,
+                QueryWarningKind::InvalidDate,
+                &format!("`{}` doesn't parse under the `{}` format", date, format),
+            ));
+        }
+    }
+}
+
+/**
+Whether a date parses under any of the formats in an Elasticsearch format string.
+
+Returns `None` if none of the formats are supported, so the date can't be checked.
+*/
+fn parses(format: &str, date: &str) -> Option<bool> {
+    let mut checked = false;
+
+    for name in format.split("||") {
+        let parsed = match built_in_parser(name) {
+            Some(parse) => parse(date).is_ok(),
+            None => match CustomDateFormat::new(name) {
+                Ok(format) => format.parse(date).is_ok(),
+                Err(_) => continue,
+            },
+        };
+
+        if parsed {
+            return Some(true);
+        }
+
+        checked = true;
+    }
+
+    if checked {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+fn is_date_math(date: &str) -> bool {
+    date.starts_with("now") || date.contains("||")
+}
+
+fn is_param(query: &str, name: &str) -> bool {
+    match query {
+        "terms" => name == "boost",
+        _ => false,
+    }
+}
+
+fn join(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_owned()
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
+
+/** A warning about a leaf query that won't behave as expected against a mapping. */
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueryWarning {
+    /** The dotted path to the leaf query in the request, like `query.bool.must[0].term`. */
+    pub path: String,
+    /** The field the leaf query searches, like `title`. */
+    pub field: String,
+    /** The kind of mistake. */
+    pub kind: QueryWarningKind,
+    /** A description of the problem and how to fix it. */
+    pub message: String,
+}
+
+impl QueryWarning {
+    fn new(path: &str, field: &str, kind: QueryWarningKind, message: &str) -> Self {
+        QueryWarning {
+            path: path.to_owned(),
+            field: field.to_owned(),
+            kind,
+            message: message.to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for QueryWarning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} at `{}` on `{}`: {}",
+            self.kind, self.path, self.field, self.message
+        )
+    }
+}
+
+/** The kind of mistake in a query. */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QueryWarningKind {
+    /** The field isn't in the mapping. */
+    UnknownField,
+    /** A term-level query searches an analyzed `text` field. */
+    TermOnText,
+    /** A date value doesn't parse under the format of a `date` field. */
+    InvalidDate,
+}
+
+impl fmt::Display for QueryWarningKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match *self {
+            QueryWarningKind::UnknownField => "unknown field",
+            QueryWarningKind::TermOnText => "term query on text",
+            QueryWarningKind::InvalidDate => "invalid date",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn model() -> MappingModel {
+        MappingModel::from_value(&json!({
+            "properties": {
+                "title": {
+                    "type": "text",
+                    "fields": {
+                        "keyword": { "type": "keyword" }
+                    }
+                },
+                "published": {
+                    "type": "date",
+                    "format": "yyyy-MM-dd"
+                },
+                "updated": {
+                    "type": "date"
+                },
+                "author": {
+                    "type": "object",
+                    "properties": {
+                        "name": { "type": "keyword" }
+                    }
+                }
+            }
+        }))
+    }
+
+    fn lint(query: Value) -> Vec<(String, String, QueryWarningKind)> {
+        lint_query_model(&model(), &query)
+            .into_iter()
+            .map(|warning| (warning.path, warning.field, warning.kind))
+            .collect()
+    }
+
+    #[test]
+    fn lint_valid_query() {
+        let warnings = lint(json!({
+            "query": {
+                "bool": {
+                    "must": [
+                        { "match": { "title": "rust" } },
+                        { "term": { "title.keyword": "Rust" } },
+                        { "terms": { "author.name": ["a", "b"], "boost": 2.0 } },
+                        { "exists": { "field": "_id" } }
+                    ],
+                    "filter": [
+                        { "range": { "published": { "gte": "2019-01-01", "lt": "now/d" } } },
+                        { "range": { "updated": { "gte": 1431511533000i64 } } }
+                    ]
+                }
+            },
+            "size": 10
+        }));
+
+        assert!(warnings.is_empty(), "{:?}", warnings);
+    }
+
+    #[test]
+    fn lint_unknown_fields() {
+        let warnings = lint(json!({
+            "bool": {
+                "must": [
+                    { "match": { "titel": "rust" } },
+                    { "exists": { "field": "author.email" } }
+                ]
+            }
+        }));
+
+        let expected = vec![
+            (
+                "bool.must[0].match".to_owned(),
+                "titel".to_owned(),
+                QueryWarningKind::UnknownField,
+            ),
+            (
+                "bool.must[1].exists".to_owned(),
+                "author.email".to_owned(),
+                QueryWarningKind::UnknownField,
+            ),
+        ];
+
+        assert_eq!(expected, warnings);
+    }
+
+    #[test]
+    fn lint_term_on_text() {
+        let warnings = lint(json!({
+            "query": {
+                "bool": {
+                    "should": [
+                        { "term": { "title": { "value": "Rust" } } },
+                        { "prefix": { "title": "Ru" } }
+                    ]
+                }
+            }
+        }));
+
+        let expected = vec![
+            (
+                "query.bool.should[0].term".to_owned(),
+                "title".to_owned(),
+                QueryWarningKind::TermOnText,
+            ),
+            (
+                "query.bool.should[1].prefix".to_owned(),
+                "title".to_owned(),
+                QueryWarningKind::TermOnText,
+            ),
+        ];
+
+        assert_eq!(expected, warnings);
+    }
+
+    #[test]
+    fn lint_invalid_dates() {
+        let warnings = lint(json!({
+            "bool": {
+                "filter": [
+                    { "range": { "published": { "gte": "2019-01-01T00:00:00", "lte": "2019-02-01" } } },
+                    { "term": { "updated": "yesterday" } },
+                    { "range": { "published": { "gte": "01/02/2019", "format": "dd/MM/yyyy" } } }
+                ]
+            }
+        }));
+
+        let expected = vec![
+            (
+                "bool.filter[0].range".to_owned(),
+                "published".to_owned(),
+                QueryWarningKind::InvalidDate,
+            ),
+            (
+                "bool.filter[1].term".to_owned(),
+                "updated".to_owned(),
+                QueryWarningKind::InvalidDate,
+            ),
+        ];
+
+        assert_eq!(expected, warnings);
+    }
+
+    #[test]
+    fn lint_document_type() {
+        #[derive(Serialize, ElasticType)]
+        #[elastic(crate_root = "crate::types")]
+        pub struct LintedType {
+            pub title: String,
+        }
+
+        let warnings = lint_query::<LintedType>(&json!({
+            "query": { "term": { "title.keyword": "Rust" } }
+        }))
+        .unwrap();
+
+        assert!(warnings.is_empty());
+    }
+}
@@ -0,0 +1,355 @@
+use std::collections::BTreeMap;
+
+use super::{
+    describe::MappingDescription,
+    diff::{
+        diff,
+        FieldDiff,
+    },
+    dynamic::DynamicMapping,
+    index::IndexMapper,
+    model::{
+        model,
+        MappingModel,
+    },
+    overrides::IndexOverrides,
+};
+use crate::types::{
+    document::{
+        StaticIndex,
+        DEFAULT_DOC_TYPE,
+    },
+    settings::IndexSettings,
+};
+
+/**
+A registry of the document types used by an application.
+
+Document types are registered once, then the registry can be used to work with all of their mappings together,
+like building the body of a create index request for each index, describing every schema or comparing every
+mapping with the mappings that are live on a cluster.
+Each document type must have a static index.
+
+A `SyncClient` can [create any missing indices][Client.ensure_indices] or [diff every mapping][Client.diff_mappings] in a registry.
+
+# Examples
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# use elastic::types::prelude::*;
+# use elastic::types::mappers::TypeRegistry;
+# fn main() { run().unwrap() }
+# fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+#[derive(Serialize, ElasticType)]
+#[elastic(index = "posts")]
+struct Post {
+    pub title: String,
+}
+
+#[derive(Serialize, ElasticType)]
+#[elastic(index = "users")]
+struct User {
+    pub name: String,
+}
+
+let registry = TypeRegistry::new()
+    .register::<Post>()
+    .register::<User>()
+    .index_settings("posts", IndexSettings::new().number_of_shards(3));
+
+for (ty, description) in registry.describe()? {
+    println!("{}/{}\n{}", ty.index(), ty.ty(), description);
+}
+# Ok(())
+# }
+```
+
+[Client.ensure_indices]: ../../client/struct.Client.html#method.ensure_indices
+[Client.diff_mappings]: ../../client/struct.Client.html#method.diff_mappings
+*/
+#[derive(Clone, Default)]
+pub struct TypeRegistry {
+    types: Vec<RegisteredType>,
+    settings: BTreeMap<String, IndexSettings>,
+    overrides: BTreeMap<String, IndexOverrides>,
+}
+
+/** A document type in a [`TypeRegistry`][TypeRegistry]. */
+#[derive(Clone)]
+pub struct RegisteredType {
+    index: String,
+    ty: String,
+    model: fn() -> Result<MappingModel, serde_json::Error>,
+    add_to: fn(IndexMapper) -> IndexMapper,
+}
+
+impl TypeRegistry {
+    /** Create a new, empty registry. */
+    pub fn new() -> Self {
+        TypeRegistry::default()
+    }
+
+    /**
+    Register a document type.
+
+    The document type is registered under its static index and its static type name, or `_doc` if the type name isn't static.
+    Registering a document type with the same index and type name as one that's already registered will replace it.
+    */
+    pub fn register<TDocument>(mut self) -> Self
+    where
+        TDocument: StaticIndex,
+    {
+        let index = TDocument::static_index().to_string();
+        let ty = TDocument::partial_static_ty()
+            .map(|ty| ty.to_string())
+            .unwrap_or_else(|| DEFAULT_DOC_TYPE.to_owned());
+
+        self.types
+            .retain(|registered| registered.index != index || registered.ty != ty);
+        self.types.push(RegisteredType {
+            index,
+            ty,
+            model: model::<TDocument>,
+            add_to: IndexMapper::document::<TDocument>,
+        });
+        self
+    }
+
+    /** Set the settings to create an index with. */
+    pub fn index_settings(mut self, index: impl Into<String>, settings: IndexSettings) -> Self {
+        self.settings.insert(index.into(), settings);
+        self
+    }
+
+    /**
+    Set runtime configuration to overlay onto the settings and mappings for an index.
+
+    See [`IndexOverrides`][IndexOverrides] for more details.
+
+    [IndexOverrides]: struct.IndexOverrides.html
+    */
+    pub fn index_overrides(mut self, index: impl Into<String>, overrides: IndexOverrides) -> Self {
+        self.overrides.insert(index.into(), overrides);
+        self
+    }
+
+    /** Get the registered document types, in the order they were registered. */
+    pub fn types(&self) -> &[RegisteredType] {
+        &self.types
+    }
+
+    /**
+    Get the body of a create index request for each index in the registry.
+
+    Each body contains the settings for the index and the mappings for every document type registered in it,
+    with any overrides for the index applied.
+    */
+    pub fn indices(&self) -> BTreeMap<String, IndexMapper> {
+        let mut indices = BTreeMap::new();
+
+        for registered in &self.types {
+            let mapper = indices.remove(&registered.index).unwrap_or_else(|| {
+                let mapper = match self.settings.get(&registered.index) {
+                    Some(settings) => IndexMapper::new().settings(settings.clone()),
+                    None => IndexMapper::new(),
+                };
+
+                match self.overrides.get(&registered.index) {
+                    Some(overrides) => mapper.overrides(overrides.clone()),
+                    None => mapper,
+                }
+            });
+
+            indices.insert(registered.index.clone(), (registered.add_to)(mapper));
+        }
+
+        indices
+    }
+
+    /** Describe the mapping for each registered document type. */
+    pub fn describe(
+        &self,
+    ) -> Result<Vec<(&RegisteredType, MappingDescription)>, serde_json::Error> {
+        self.types
+            .iter()
+            .map(|registered| {
+                Ok((
+                    registered,
+                    MappingDescription::from_model(&registered.model()?),
+                ))
+            })
+            .collect()
+    }
+
+    /**
+    Compare the mapping for each registered document type with the mappings that are live on a cluster.
+
+    Document types whose mapping matches the live mapping aren't included in the result.
+    If an index or type isn't in the live mappings then all of its fields are missing.
+    */
+    pub fn diff(
+        &self,
+        live: &DynamicMapping,
+    ) -> Result<Vec<(&RegisteredType, Vec<FieldDiff>)>, serde_json::Error> {
+        let empty = MappingModel::default();
+
+        let mut diffs = Vec::new();
+        for registered in &self.types {
+            let actual = live
+                .index(&registered.index)
+                .and_then(|index| index.ty(&registered.ty))
+                .unwrap_or(&empty);
+
+            let fields = diff(&registered.model()?, actual);
+
+            if !fields.is_empty() {
+                diffs.push((registered, fields));
+            }
+        }
+
+        Ok(diffs)
+    }
+}
+
+impl RegisteredType {
+    /** The static index of the document type. */
+    pub fn index(&self) -> &str {
+        &self.index
+    }
+
+    /** The static type name of the document type. */
+    pub fn ty(&self) -> &str {
+        &self.ty
+    }
+
+    /** Build an introspection model for the mapping of the document type. */
+    pub fn model(&self) -> Result<MappingModel, serde_json::Error> {
+        (self.model)()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+
+    use super::*;
+    use crate::types::prelude::*;
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types", index = "blog", ty = "post")]
+    pub struct Post {
+        pub title: String,
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types", index = "blog", ty = "comment")]
+    pub struct Comment {
+        pub likes: i32,
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types", index = "users")]
+    pub struct User {
+        pub age: i32,
+    }
+
+    fn registry() -> TypeRegistry {
+        TypeRegistry::new()
+            .register::<Post>()
+            .register::<Comment>()
+            .register::<User>()
+            .register::<User>()
+            .index_settings("users", IndexSettings::new().number_of_shards(1))
+    }
+
+    #[test]
+    fn register_types() {
+        let types: Vec<(&str, &str)> = registry()
+            .types()
+            .iter()
+            .map(|ty| (ty.index(), ty.ty()))
+            .collect();
+
+        assert_eq!(
+            vec![("blog", "post"), ("blog", "comment"), ("users", "_doc")],
+            types
+        );
+    }
+
+    #[test]
+    fn serialise_indices() {
+        let indices = registry().indices();
+
+        let blog = serde_json::to_value(&indices["blog"]).unwrap();
+        let users = serde_json::to_value(&indices["users"]).unwrap();
+
+        assert_eq!(2, indices.len());
+        assert_eq!(
+            json!({
+                "mappings": {
+                    "post": {
+                        "properties": {
+                            "title": {
+                                "type": "text",
+                                "fields": {
+                                    "keyword": { "type": "keyword", "ignore_above": 256 }
+                                }
+                            }
+                        }
+                    },
+                    "comment": {
+                        "properties": {
+                            "likes": { "type": "integer" }
+                        }
+                    }
+                }
+            }),
+            blog
+        );
+        assert_eq!(
+            json!({
+                "settings": { "index": { "number_of_shards": 1 } },
+                "mappings": {
+                    "_doc": { "properties": { "age": { "type": "integer" } } }
+                }
+            }),
+            users
+        );
+    }
+
+    #[test]
+    fn diff_live_mappings() {
+        let live = DynamicMapping::from_value(&json!({
+            "blog": {
+                "mappings": {
+                    "post": {
+                        "properties": {
+                            "title": {
+                                "type": "text",
+                                "fields": {
+                                    "keyword": { "type": "keyword", "ignore_above": 256 }
+                                }
+                            }
+                        }
+                    },
+                    "comment": {
+                        "properties": {
+                            "likes": { "type": "long" }
+                        }
+                    }
+                }
+            }
+        }));
+
+        let registry = registry();
+        let diffs = registry.diff(&live).unwrap();
+
+        let diffs: Vec<(&str, &str, usize)> = diffs
+            .iter()
+            .map(|&(ty, ref fields)| (ty.index(), ty.ty(), fields.len()))
+            .collect();
+
+        assert_eq!(vec![("blog", "comment", 1), ("users", "_doc", 1)], diffs);
+    }
+}
@@ -0,0 +1,234 @@
+use serde::Serialize;
+use serde_json::{
+    self,
+    Map,
+    Value,
+};
+use std::io::Write;
+
+use crate::types::document::DocumentType;
+
+/**
+Render the mapping for a document type as JSON.
+
+The output is canonical: the keys of every object are sorted, so the same mapping always renders the same way.
+That makes rendered mappings suitable for checking into version control or comparing in golden tests.
+
+# Examples
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# use elastic::types::prelude::*;
+# use elastic::types::mappers::TypeMapper;
+# fn main() { run().unwrap() }
+# fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+#[derive(Serialize, ElasticType)]
+struct MyType {
+    pub id: i32,
+}
+
+let mapping = TypeMapper::to_string_pretty::<MyType>()?;
+
+assert_eq!(
+    r#"{
+  "properties": {
+    "id": {
+      "type": "integer"
+    }
+  }
+}"#,
+    mapping
+);
+# Ok(())
+# }
+```
+*/
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TypeMapper;
+
+impl TypeMapper {
+    /** Get the canonical mapping for a document type as a JSON value. */
+    pub fn to_value<TDocument>() -> Result<Value, serde_json::Error>
+    where
+        TDocument: DocumentType,
+    {
+        to_canonical_value(&TDocument::index_mapping())
+    }
+
+    /** Render the mapping for a document type as compact JSON. */
+    pub fn to_string<TDocument>() -> Result<String, serde_json::Error>
+    where
+        TDocument: DocumentType,
+    {
+        to_canonical_string(&TDocument::index_mapping())
+    }
+
+    /** Render the mapping for a document type as indented JSON. */
+    pub fn to_string_pretty<TDocument>() -> Result<String, serde_json::Error>
+    where
+        TDocument: DocumentType,
+    {
+        to_canonical_string_pretty(&TDocument::index_mapping())
+    }
+
+    /** Render the mapping for a document type as compact JSON bytes. */
+    pub fn to_vec<TDocument>() -> Result<Vec<u8>, serde_json::Error>
+    where
+        TDocument: DocumentType,
+    {
+        serde_json::to_vec(&Self::to_value::<TDocument>()?)
+    }
+
+    /**
+    Write the mapping for a document type as compact JSON.
+
+    The mapping is written straight to the writer, so large mappings can be streamed to a file or socket
+    without buffering the rendered string first.
+    */
+    pub fn to_writer<TDocument, W>(writer: W) -> Result<(), serde_json::Error>
+    where
+        TDocument: DocumentType,
+        W: Write,
+    {
+        serde_json::to_writer(writer, &Self::to_value::<TDocument>()?)
+    }
+
+    /** Write the mapping for a document type as indented JSON. */
+    pub fn to_writer_pretty<TDocument, W>(writer: W) -> Result<(), serde_json::Error>
+    where
+        TDocument: DocumentType,
+        W: Write,
+    {
+        serde_json::to_writer_pretty(writer, &Self::to_value::<TDocument>()?)
+    }
+}
+
+/**
+Serialise a value into canonical JSON, where the keys of every object are sorted.
+
+This can be used for any of the mappers in this module, like an [`IndexMapper`][IndexMapper], or for request bodies.
+
+[IndexMapper]: struct.IndexMapper.html
+*/
+pub fn to_canonical_value<T>(value: &T) -> Result<Value, serde_json::Error>
+where
+    T: Serialize + ?Sized,
+{
+    Ok(canonicalize(serde_json::to_value(value)?))
+}
+
+/** Serialise a value as compact canonical JSON, where the keys of every object are sorted. */
+pub fn to_canonical_string<T>(value: &T) -> Result<String, serde_json::Error>
+where
+    T: Serialize + ?Sized,
+{
+    serde_json::to_string(&to_canonical_value(value)?)
+}
+
+/** Serialise a value as indented canonical JSON, where the keys of every object are sorted. */
+pub fn to_canonical_string_pretty<T>(value: &T) -> Result<String, serde_json::Error>
+where
+    T: Serialize + ?Sized,
+{
+    serde_json::to_string_pretty(&to_canonical_value(value)?)
+}
+
+// Objects are sorted explicitly instead of relying on the map used by `serde_json`,
+// which keeps insertion order when its `preserve_order` feature is enabled by another crate
+pub(crate) fn canonicalize(value: Value) -> Value {
+    match value {
+        Value::Object(object) => {
+            let mut entries: Vec<(String, Value)> = object.into_iter().collect();
+            entries.sort_by(|&(ref a, _), &(ref b, _)| a.cmp(b));
+
+            Value::Object(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| (key, canonicalize(value)))
+                    .collect::<Map<String, Value>>(),
+            )
+        }
+        Value::Array(values) => Value::Array(values.into_iter().map(canonicalize).collect()),
+        value => value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{
+        mappers::IndexMapper,
+        prelude::*,
+    };
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct RenderedType {
+        pub title: String,
+        pub id: i32,
+    }
+
+    #[test]
+    fn render_type_mapping() {
+        let compact = TypeMapper::to_string::<RenderedType>().unwrap();
+
+        let expected = r#"{"properties":{"id":{"type":"integer"},"title":{"fields":{"keyword":{"ignore_above":256,"type":"keyword"}},"type":"text"}}}"#;
+
+        assert_eq!(expected, compact);
+        assert_eq!(
+            TypeMapper::to_value::<RenderedType>().unwrap(),
+            serde_json::from_str::<Value>(&TypeMapper::to_string_pretty::<RenderedType>().unwrap())
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn write_type_mapping() {
+        let mut compact = Vec::new();
+        TypeMapper::to_writer::<RenderedType, _>(&mut compact).unwrap();
+
+        let mut pretty = Vec::new();
+        TypeMapper::to_writer_pretty::<RenderedType, _>(&mut pretty).unwrap();
+
+        assert_eq!(TypeMapper::to_vec::<RenderedType>().unwrap(), compact);
+        assert_eq!(
+            TypeMapper::to_string::<RenderedType>()
+                .unwrap()
+                .into_bytes(),
+            compact
+        );
+        assert_eq!(
+            TypeMapper::to_string_pretty::<RenderedType>()
+                .unwrap()
+                .into_bytes(),
+            pretty
+        );
+    }
+
+    #[test]
+    fn render_canonical_index() {
+        let body = IndexMapper::new()
+            .alias("b")
+            .alias("a")
+            .document::<RenderedType>();
+
+        let pretty = to_canonical_string_pretty(&body).unwrap();
+
+        assert!(pretty.find("\"a\"").unwrap() < pretty.find("\"b\"").unwrap());
+        assert_eq!(
+            to_canonical_string(&body).unwrap(),
+            serde_json::to_string(&to_canonical_value(&body).unwrap()).unwrap()
+        );
+    }
+
+    #[test]
+    fn canonicalize_nested_values() {
+        let value = canonicalize(json!({
+            "b": [{ "d": 1, "c": 2 }],
+            "a": null
+        }));
+
+        assert_eq!(r#"{"a":null,"b":[{"c":2,"d":1}]}"#, value.to_string());
+    }
+}
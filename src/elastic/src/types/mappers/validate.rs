@@ -0,0 +1,516 @@
+use serde_json::{
+    self,
+    Value,
+};
+use std::{
+    collections::BTreeMap,
+    fmt,
+    net::IpAddr,
+};
+
+use super::model::{
+    model,
+    FieldModel,
+    MappingModel,
+};
+use crate::types::document::DocumentType;
+
+/**
+Check the mapping for a document type for problems that would cause Elasticsearch to reject it.
+
+The checks are run over the serialised mapping, so problems are found before the mapping is sent to a cluster.
+A mapping is rejected if:
+
+- a field name is empty or has an empty segment, like `.title` or `meta..title`
+- objects are nested deeper than the [`depth`][MappingLimits.depth] limit
+- there are more fields than the [`total_fields`][MappingLimits.total_fields] limit
+- there are more `nested` fields than the [`nested_fields`][MappingLimits.nested_fields] limit
+- a `null_value` isn't a value the field can index, like `"none"` for an `integer` field
+- a `format` is set on a field that isn't a `date`
+
+A `null_value` is coerced the same way Elasticsearch coerces it,
+so values like `"5"` for an `integer` field or `5` for a `keyword` field are valid.
+
+An empty list means the mapping is valid.
+
+# Examples
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# use elastic::types::prelude::*;
+# use elastic::types::mappers::{self, MappingErrorKind, MappingLimits};
+# fn main() { run().unwrap() }
+# fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+#[derive(Serialize, ElasticType)]
+struct MyType {
+    pub title: String,
+    pub count: i32,
+}
+
+let limits = MappingLimits {
+    total_fields: 2,
+    ..Default::default()
+};
+
+let errors = mappers::validate::<MyType>(&limits)?;
+
+// `title`, `title.keyword` and `count` are 3 fields
+assert_eq!(1, errors.len());
+assert_eq!(MappingErrorKind::TooManyFields, errors[0].kind);
+# Ok(())
+# }
+```
+
+[MappingLimits.depth]: struct.MappingLimits.html#structfield.depth
+[MappingLimits.total_fields]: struct.MappingLimits.html#structfield.total_fields
+[MappingLimits.nested_fields]: struct.MappingLimits.html#structfield.nested_fields
+*/
+pub fn validate<TDocument>(limits: &MappingLimits) -> Result<Vec<MappingError>, serde_json::Error>
+where
+    TDocument: DocumentType,
+{
+    Ok(validate_model(&model::<TDocument>()?, limits))
+}
+
+/**
+Check a mapping model for problems that would cause Elasticsearch to reject it.
+
+Errors for individual fields are returned in the order the fields are visited by [`MappingModel::walk`][MappingModel.walk],
+followed by any errors for limits on the mapping as a whole.
+
+[MappingModel.walk]: struct.MappingModel.html#method.walk
+*/
+pub fn validate_model(model: &MappingModel, limits: &MappingLimits) -> Vec<MappingError> {
+    let mut errors = Vec::new();
+
+    let mut counts = FieldCounts::default();
+    validate_properties(None, model.properties(), &mut counts, &mut errors);
+
+    let depth = depth(model.properties());
+    if depth > limits.depth {
+        errors.push(MappingError::new(
+            "",
+            MappingErrorKind::TooDeep,
+            format!(
+                "objects are nested {} levels deep, but the limit is {}",
+                depth, limits.depth
+            ),
+        ));
+    }
+
+    if counts.total_fields > limits.total_fields {
+        errors.push(MappingError::new(
+            "",
+            MappingErrorKind::TooManyFields,
+            format!(
+                "the mapping has {} fields, but the limit is {}",
+                counts.total_fields, limits.total_fields
+            ),
+        ));
+    }
+
+    if counts.nested_fields > limits.nested_fields {
+        errors.push(MappingError::new(
+            "",
+            MappingErrorKind::TooManyNestedFields,
+            format!(
+                "the mapping has {} `nested` fields, but the limit is {}",
+                counts.nested_fields, limits.nested_fields
+            ),
+        ));
+    }
+
+    errors
+}
+
+#[derive(Default)]
+struct FieldCounts {
+    total_fields: usize,
+    nested_fields: usize,
+}
+
+// Fields are visited in the same order as `MappingModel::walk`, but the names are checked before they're joined into a path
+fn validate_properties(
+    parent: Option<&str>,
+    properties: &BTreeMap<String, FieldModel>,
+    counts: &mut FieldCounts,
+    errors: &mut Vec<MappingError>,
+) {
+    for (name, field) in properties {
+        let path = match parent {
+            Some(parent) => format!("{}.{}", parent, name),
+            None => name.clone(),
+        };
+
+        counts.total_fields += 1;
+        if field.data_type() == "nested" {
+            counts.nested_fields += 1;
+        }
+
+        validate_field(name, &path, field, errors);
+
+        validate_properties(Some(&path), field.fields(), counts, errors);
+        validate_properties(Some(&path), field.properties(), counts, errors);
+    }
+}
+
+fn validate_field(name: &str, path: &str, field: &FieldModel, errors: &mut Vec<MappingError>) {
+    if name.trim().is_empty() {
+        errors.push(MappingError::new(
+            path,
+            MappingErrorKind::InvalidName,
+            "field names can't be empty or only whitespace",
+        ));
+    } else if name.split('.').any(str::is_empty) {
+        errors.push(MappingError::new(
+            path,
+            MappingErrorKind::InvalidName,
+            "field names can't start or end with a `.` or have an empty segment like `a..b`",
+        ));
+    }
+
+    if let Some(null_value) = field.option("null_value") {
+        if !accepts_null_value(field.data_type(), null_value) {
+            errors.push(MappingError::new(
+                path,
+                MappingErrorKind::InvalidNullValue,
+                format!(
+                    "`{}` isn't a valid `null_value` for a `{}` field",
+                    null_value,
+                    field.data_type()
+                ),
+            ));
+        }
+    }
+
+    if field.option("format").is_some() {
+        match field.data_type() {
+            "date" | "date_nanos" | "date_range" => (),
+            data_type => errors.push(MappingError::new(
+                path,
+                MappingErrorKind::InvalidFormat,
+                format!("a `format` can't be set on a `{}` field", data_type),
+            )),
+        }
+    }
+}
+
+// Whether a `null_value` can be indexed in a field with the given datatype
+// Values are coerced like Elasticsearch does when it parses a `null_value`,
+// so a numeric string is valid for a numeric field and any scalar is valid for a `keyword` field
+fn accepts_null_value(data_type: &str, null_value: &Value) -> bool {
+    match data_type {
+        "long" | "integer" | "short" | "byte" => match coerce_number(null_value) {
+            // Integers can't have a decimal part, even when it's coerced from a string
+            Some(n) => {
+                let (min, max) = integer_range(data_type);
+                n.fract() == 0.0 && n >= min && n <= max
+            }
+            None => false,
+        },
+        "double" | "float" | "half_float" | "scaled_float" => coerce_number(null_value).is_some(),
+        "boolean" => match *null_value {
+            Value::Bool(_) => true,
+            Value::String(ref value) => value == "true" || value == "false",
+            _ => false,
+        },
+        "keyword" => match *null_value {
+            Value::String(_) | Value::Number(_) | Value::Bool(_) => true,
+            _ => false,
+        },
+        "ip" => match *null_value {
+            Value::String(ref value) => value.parse::<IpAddr>().is_ok(),
+            _ => false,
+        },
+        "date" => match *null_value {
+            Value::String(_) | Value::Number(_) => true,
+            _ => false,
+        },
+        "geo_point" => match *null_value {
+            Value::String(_) | Value::Array(_) | Value::Object(_) => true,
+            _ => false,
+        },
+        // Fields like `text` don't support a `null_value` at all
+        _ => false,
+    }
+}
+
+// Get a number, or a string that Elasticsearch will parse as a number
+fn coerce_number(value: &Value) -> Option<f64> {
+    match *value {
+        Value::Number(ref value) => value.as_f64(),
+        Value::String(ref value) => value
+            .trim()
+            .parse::<f64>()
+            .ok()
+            .filter(|value| value.is_finite()),
+        _ => None,
+    }
+}
+
+// Get the smallest and largest values of an integer datatype
+fn integer_range(data_type: &str) -> (f64, f64) {
+    match data_type {
+        "byte" => (i8::min_value() as f64, i8::max_value() as f64),
+        "short" => (i16::min_value() as f64, i16::max_value() as f64),
+        "integer" => (i32::min_value() as f64, i32::max_value() as f64),
+        _ => (i64::min_value() as f64, i64::max_value() as f64),
+    }
+}
+
+// Get how many levels of objects are nested in the properties, where the root properties are the first level
+fn depth(properties: &BTreeMap<String, FieldModel>) -> usize {
+    properties
+        .values()
+        .map(|field| {
+            if field.is_object() {
+                1 + depth(field.properties())
+            } else {
+                1
+            }
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+/**
+Limits on the size of a mapping.
+
+The defaults are the same as the defaults for the `index.mapping.*.limit` settings in Elasticsearch.
+Override them to match the settings of the index the mapping is for.
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MappingLimits {
+    /** The maximum number of fields, including sub-fields and objects. Like `index.mapping.total_fields.limit`. */
+    pub total_fields: usize,
+    /** The maximum depth of objects, where root fields are at a depth of `1`. Like `index.mapping.depth.limit`. */
+    pub depth: usize,
+    /** The maximum number of `nested` fields. Like `index.mapping.nested_fields.limit`. */
+    pub nested_fields: usize,
+}
+
+impl Default for MappingLimits {
+    fn default() -> Self {
+        MappingLimits {
+            total_fields: 1000,
+            depth: 20,
+            nested_fields: 50,
+        }
+    }
+}
+
+/** A problem with a mapping that would cause Elasticsearch to reject it. */
+#[derive(Debug, Clone, PartialEq)]
+pub struct MappingError {
+    /** The full dotted path to the field, like `title.keyword`, or an empty string for the mapping as a whole. */
+    pub path: String,
+    /** The kind of problem. */
+    pub kind: MappingErrorKind,
+    /** A description of the problem. */
+    pub message: String,
+}
+
+impl MappingError {
+    fn new(path: &str, kind: MappingErrorKind, message: impl Into<String>) -> Self {
+        MappingError {
+            path: path.to_owned(),
+            kind,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for MappingError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let path = if self.path.is_empty() {
+            "<root>"
+        } else {
+            &self.path
+        };
+
+        write!(f, "{} `{}`: {}", self.kind, path, self.message)
+    }
+}
+
+/** The kind of problem with a mapping. */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MappingErrorKind {
+    /** A field name is empty or has an empty segment. */
+    InvalidName,
+    /** Objects are nested deeper than the limit. */
+    TooDeep,
+    /** There are more fields than the limit. */
+    TooManyFields,
+    /** There are more `nested` fields than the limit. */
+    TooManyNestedFields,
+    /** A `null_value` can't be indexed in the field. */
+    InvalidNullValue,
+    /** A `format` is set on a field that doesn't support it. */
+    InvalidFormat,
+}
+
+impl fmt::Display for MappingErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match *self {
+            MappingErrorKind::InvalidName => "invalid name",
+            MappingErrorKind::TooDeep => "too deep",
+            MappingErrorKind::TooManyFields => "too many fields",
+            MappingErrorKind::TooManyNestedFields => "too many nested fields",
+            MappingErrorKind::InvalidNullValue => "invalid null_value",
+            MappingErrorKind::InvalidFormat => "invalid format",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_field_names() {
+        let model = MappingModel::from_value(&json!({
+            "properties": {
+                "": { "type": "keyword" },
+                ".title": { "type": "keyword" },
+                "meta": {
+                    "properties": {
+                        " ": { "type": "keyword" },
+                        "a..b": { "type": "keyword" },
+                        "title": { "type": "keyword" }
+                    }
+                }
+            }
+        }));
+
+        let errors = validate_model(&model, &Default::default());
+        let errors: Vec<(&str, MappingErrorKind)> = errors
+            .iter()
+            .map(|error| (error.path.as_str(), error.kind))
+            .collect();
+
+        let expected = vec![
+            ("", MappingErrorKind::InvalidName),
+            (".title", MappingErrorKind::InvalidName),
+            ("meta. ", MappingErrorKind::InvalidName),
+            ("meta.a..b", MappingErrorKind::InvalidName),
+        ];
+
+        assert_eq!(expected, errors);
+    }
+
+    #[test]
+    fn validate_null_value_and_format() {
+        let model = MappingModel::from_value(&json!({
+            "properties": {
+                "count": { "type": "integer", "null_value": "none" },
+                "ratio": { "type": "float", "null_value": 0.5 },
+                "title": { "type": "text", "null_value": "" },
+                "created": { "type": "date", "format": "epoch_millis", "null_value": 0 },
+                "code": { "type": "keyword", "format": "yyyy" }
+            }
+        }));
+
+        let errors = validate_model(&model, &Default::default());
+        let errors: Vec<(&str, MappingErrorKind)> = errors
+            .iter()
+            .map(|error| (error.path.as_str(), error.kind))
+            .collect();
+
+        let expected = vec![
+            ("code", MappingErrorKind::InvalidFormat),
+            ("count", MappingErrorKind::InvalidNullValue),
+            ("title", MappingErrorKind::InvalidNullValue),
+        ];
+
+        assert_eq!(expected, errors);
+    }
+
+    #[test]
+    fn validate_coerced_null_values() {
+        let model = MappingModel::from_value(&json!({
+            "properties": {
+                "a": { "type": "integer", "null_value": "5" },
+                "b": { "type": "long", "null_value": 5.0 },
+                "c": { "type": "float", "null_value": " 0.5 " },
+                "d": { "type": "keyword", "null_value": 5 },
+                "e": { "type": "boolean", "null_value": "false" },
+                "f": { "type": "ip", "null_value": "127.0.0.1" },
+                "g": { "type": "integer", "null_value": 5.5 },
+                "h": { "type": "byte", "null_value": "128" },
+                "i": { "type": "boolean", "null_value": "no" },
+                "j": { "type": "ip", "null_value": "localhost" },
+                "k": { "type": "keyword", "null_value": ["a"] }
+            }
+        }));
+
+        let errors = validate_model(&model, &Default::default());
+        let errors: Vec<&str> = errors.iter().map(|error| error.path.as_str()).collect();
+
+        assert_eq!(vec!["g", "h", "i", "j", "k"], errors);
+    }
+
+    #[test]
+    fn validate_limits() {
+        let model = MappingModel::from_value(&json!({
+            "properties": {
+                "a": {
+                    "type": "nested",
+                    "properties": {
+                        "b": {
+                            "type": "nested",
+                            "properties": {
+                                "c": { "type": "integer" }
+                            }
+                        }
+                    }
+                }
+            }
+        }));
+
+        let limits = MappingLimits {
+            total_fields: 2,
+            depth: 2,
+            nested_fields: 1,
+        };
+
+        let kinds: Vec<MappingErrorKind> = validate_model(&model, &limits)
+            .iter()
+            .map(|error| error.kind)
+            .collect();
+
+        assert_eq!(
+            vec![
+                MappingErrorKind::TooDeep,
+                MappingErrorKind::TooManyFields,
+                MappingErrorKind::TooManyNestedFields
+            ],
+            kinds
+        );
+        assert!(validate_model(&model, &Default::default()).is_empty());
+    }
+
+    #[test]
+    fn validate_document_type() {
+        #[derive(Serialize, ElasticType)]
+        #[elastic(crate_root = "crate::types")]
+        pub struct ValidatedType {
+            pub title: String,
+            pub count: i32,
+        }
+
+        assert!(validate::<ValidatedType>(&Default::default())
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn format_error() {
+        let error = MappingError::new("count", MappingErrorKind::InvalidNullValue, "use a number");
+
+        assert_eq!(
+            "invalid null_value `count`: use a number",
+            error.to_string()
+        );
+    }
+}
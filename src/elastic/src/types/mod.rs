@@ -38,6 +38,7 @@ The following table illustrates the types provided by `elastic`:
  Elasticsearch Type  | Rust Type (Default Mapping) | Crate     | Rust Type (Custom Mapping)                               | Format Type
  ------------------- | --------------------------- | --------- | -------------------------------------------------------- | -----------------
  `object`            | -                           | -         | type implementing [`DocumentType<M>`][document-mod]      | -
+ `object` (dynamic)  | `HashMap<String, V>`        | `std`     | -                                                        | -
  `integer`           | `i32`                       | `std`     | [`Integer<M>`][number-mod]                               | -
  `long`              | `i64`                       | `std`     | [`Long<M>`][number-mod]                                  | -
  `short`             | `i16`                       | `std`     | [`Short<M>`][number-mod]                                 | -
@@ -51,6 +52,20 @@ The following table illustrates the types provided by `elastic`:
  `date`              | `DateTime<UTC>`             | `chrono`  | [`Date<M>`][date-mod]                                    | `DateFormat`
  `geo_point`         | `Point`                     | `geo`     | [`GeoPoint<M>`][geopoint-mod]                            | `GeoPointFormat`
  `geo_shape`         | -                           | `geojson` | [`GeoShape<M>`][geoshape-mod]                            | -
+ `alias`             | -                           | -         | [`AliasMapping`][alias-mod]                              | -
+
+Arrays and optional values, like `Vec<T>`, `HashSet<T>`, `[T; N]`, `&[T]` and `Option<T>`, are mapped the same as the type they contain.
+`serde` only serialises fixed-size arrays with up to 32 elements, so larger values like embeddings should use a `Vec<T>` or `&[T]`.
+So are smart pointers, like `Box<T>`, `Rc<T>`, `Arc<T>` and `Cow<'a, T>`.
+The shared string types `Box<str>`, `Rc<str>`, `Arc<str>` and `Cow<'a, str>` are mapped the same as `String`.
+
+The `geo_point` and `geo_shape` types are only available with the `geo` feature, which is enabled by default.
+Documents that only contain scalar fields can disable default features to avoid building the `geo`, `geohash` and `geojson` crates:
+
+```toml
+[dependencies]
+elastic = { version = "*", default-features = false }
+```
 
 ## Mapping
 
@@ -230,6 +245,87 @@ Serialising `MyType`s mapping will produce the following json:
 # }
 ```
 
+Enums where every variant is a unit can also derive `ElasticType` to be mapped as a `keyword`.
+Variants are indexed by the names they're serialised with, so any `serde` renames apply.
+The names of the variants are listed in the `_meta` of the index mapping for documents that contain the enum:
+
+```
+# #[macro_use] extern crate elastic_derive;
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate serde_json;
+# use elastic::prelude::*;
+#[derive(Serialize, Deserialize, ElasticType)]
+#[serde(rename_all = "snake_case")]
+enum Status {
+    Active,
+    Suspended,
+    #[serde(rename = "closed_by_user")]
+    Closed,
+}
+# #[derive(Serialize, Deserialize, ElasticType)]
+# struct MyType {
+#     status: Status
+# }
+# fn main() { run().unwrap() }
+# fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+# let mapping = serde_json::to_value(&MyType::index_mapping())?;
+# let expected = json!({
+#     "_meta": {
+#         "values": {
+#             "status": ["active", "suspended", "closed_by_user"]
+#         }
+#     },
+#     "properties": {
+#         "status": {
+#             "type": "keyword"
+#         }
+#     }
+# });
+# assert_eq!(expected, mapping);
+# Ok(())
+# }
+```
+
+## Override the mapping for a field
+
+Fields can keep a plain `std` type but use a custom mapping with an `#[elastic(mapping = "<ident>")]` attribute,
+instead of wrapping the field in a type like `Text<M>`.
+The mapping must be compatible with the way the field is serialised:
+
+```
+# #[macro_use] extern crate elastic_derive;
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate serde_json;
+# use elastic::prelude::*;
+#[derive(Serialize, Deserialize, ElasticType)]
+struct MyType {
+    #[elastic(mapping = "ContentMapping")]
+    content: String,
+}
+
+#[derive(Default)]
+struct ContentMapping;
+impl TextMapping for ContentMapping {
+    fn analyzer() -> Option<&'static str> {
+        Some("english")
+    }
+}
+# fn main() { run().unwrap() }
+# fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+# let mapping = serde_json::to_value(&MyType::index_mapping())?;
+# let expected = json!({
+#     "properties": {
+#         "content": {
+#             "type": "text",
+#             "analyzer": "english"
+#         }
+#     }
+# });
+# assert_eq!(expected, mapping);
+# Ok(())
+# }
+```
+
 [serde]: https://serde.rs
 
 [document-mod]: document/index.html
@@ -240,18 +336,25 @@ Serialising `MyType`s mapping will produce the following json:
 [date-mod]: date/index.html
 [geopoint-mod]: geo/point/index.html
 [geoshape-mod]: geo/shape/index.html
+[alias-mod]: alias/index.html
 */
 
 #[macro_use]
 mod private;
 
+pub mod alias;
 pub mod boolean;
 pub mod date;
 pub mod document;
+#[cfg(feature = "geo")]
 pub mod geo;
+pub mod ingest;
 pub mod ip;
+pub mod mappers;
 pub mod number;
+pub mod settings;
 pub mod string;
+pub mod template;
 
 #[doc(hidden)]
 pub mod __derive;
@@ -266,11 +369,16 @@ pub mod prelude {
     pub use super::document::prelude::*;
 
     pub use super::{
+        alias::prelude::*,
         boolean::prelude::*,
         date::prelude::*,
-        geo::prelude::*,
+        ingest::prelude::*,
         ip::prelude::*,
         number::prelude::*,
+        settings::prelude::*,
         string::prelude::*,
     };
+
+    #[cfg(feature = "geo")]
+    pub use super::geo::prelude::*;
 }
@@ -0,0 +1,277 @@
+use serde::{
+    de::Error as DeError,
+    ser::Error as SerError,
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+};
+use std::{
+    convert::TryFrom,
+    error::Error,
+    fmt,
+};
+
+use super::{
+    impls::{
+        Byte,
+        Short,
+    },
+    mapping::{
+        ByteMapping,
+        ShortMapping,
+    },
+};
+
+macro_rules! bounded_number_type {
+    ($wrapper_ty:ident, $mapping_ty:ident, $std_ty:ident, $es_ty:expr, [$($from_ty:ident),*]) => {
+        impl<TMapping> $wrapper_ty<TMapping>
+        where
+            TMapping: $mapping_ty,
+        {
+            /** Creates a new number with the given mapping, or an error if the value is out of range for the field. */
+            pub fn try_new<I>(num: I) -> Result<$wrapper_ty<TMapping>, NumberOverflowError>
+            where
+                I: Into<i64>,
+            {
+                let num = check_bounds(
+                    $es_ty,
+                    i64::from($std_ty::MIN),
+                    i64::from($std_ty::MAX),
+                    num.into(),
+                )?;
+
+                Ok($wrapper_ty::new(num as $std_ty))
+            }
+        }
+
+        $(
+            impl<TMapping> TryFrom<$from_ty> for $wrapper_ty<TMapping>
+            where
+                TMapping: $mapping_ty,
+            {
+                type Error = NumberOverflowError;
+
+                fn try_from(num: $from_ty) -> Result<Self, Self::Error> {
+                    $wrapper_ty::try_new(num)
+                }
+            }
+        )*
+    };
+}
+
+bounded_number_type!(Short, ShortMapping, i16, "short", [i32, i64]);
+bounded_number_type!(Byte, ByteMapping, i8, "byte", [i16, i32, i64]);
+
+fn check_bounds(
+    ty: &'static str,
+    min: i64,
+    max: i64,
+    value: i64,
+) -> Result<i64, NumberOverflowError> {
+    if value < min || value > max {
+        Err(NumberOverflowError {
+            ty,
+            value,
+            min,
+            max,
+        })
+    } else {
+        Ok(value)
+    }
+}
+
+/**
+A number is out of range for the field it's mapped to.
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct NumberOverflowError {
+    ty: &'static str,
+    value: i64,
+    min: i64,
+    max: i64,
+}
+
+impl NumberOverflowError {
+    /** The Elasticsearch type of the field, like `byte` or `short`. */
+    pub fn ty(&self) -> &'static str {
+        self.ty
+    }
+
+    /** The value that's out of range. */
+    pub fn value(&self) -> i64 {
+        self.value
+    }
+
+    /** The smallest value the field accepts. */
+    pub fn min(&self) -> i64 {
+        self.min
+    }
+
+    /** The largest value the field accepts. */
+    pub fn max(&self) -> i64 {
+        self.max
+    }
+}
+
+impl fmt::Display for NumberOverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "`{}` is out of range for a `{}` field, which must be between {} and {}",
+            self.value, self.ty, self.min, self.max
+        )
+    }
+}
+
+impl Error for NumberOverflowError {
+    fn description(&self) -> &str {
+        "number out of range"
+    }
+}
+
+pub mod checked {
+    /*!
+    Bounds checks for wider integers mapped as a `byte` or `short`.
+
+    The `Byte<M>` and `Short<M>` wrappers can't hold a value that's out of range for their field.
+    A document that keeps an `i32` or `i64` and maps it as a `byte` or `short` can opt-in to checking its value
+    when it's serialised or deserialised with `#[serde(with)]`, so a bad value fails on the client instead of when it's indexed:
+
+    ```
+    # #[macro_use] extern crate serde_derive;
+    # #[macro_use] extern crate elastic_derive;
+    # extern crate serde_json;
+    # use elastic::types::prelude::*;
+    #[derive(Serialize, Deserialize, ElasticType)]
+    pub struct MyType {
+        #[elastic(mapping = "DefaultShortMapping")]
+        #[serde(with = "elastic::types::number::checked::short")]
+        pub level: i32,
+    }
+
+    # fn main() {
+    let doc = MyType { level: 40_000 };
+
+    assert!(serde_json::to_string(&doc).is_err());
+    assert!(serde_json::from_str::<MyType>(r#"{"level":40000}"#).is_err());
+    # }
+    ```
+    */
+
+    macro_rules! checked_number {
+        ($name:ident, $std_ty:ident, $es_ty:expr) => {
+            /** Check a number is in range for its field. Use with `#[serde(with)]`. */
+            pub mod $name {
+                use super::super::*;
+
+                /** Serialise the number, or fail if it's out of range. */
+                pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    T: Copy + Into<i64>,
+                    S: Serializer,
+                {
+                    let value = check_bounds(
+                        $es_ty,
+                        i64::from($std_ty::MIN),
+                        i64::from($std_ty::MAX),
+                        (*value).into(),
+                    )
+                    .map_err(S::Error::custom)?;
+
+                    value.serialize(serializer)
+                }
+
+                /** Deserialise the number, or fail if it's out of range. */
+                pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+                where
+                    T: TryFrom<i64>,
+                    D: Deserializer<'de>,
+                {
+                    let value = check_bounds(
+                        $es_ty,
+                        i64::from($std_ty::MIN),
+                        i64::from($std_ty::MAX),
+                        i64::deserialize(deserializer)?,
+                    )
+                    .map_err(D::Error::custom)?;
+
+                    T::try_from(value)
+                        .map_err(|_| D::Error::custom(format!("`{}` is out of range", value)))
+                }
+            }
+        };
+    }
+
+    checked_number!(short, i16, "short");
+    checked_number!(byte, i8, "byte");
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+    use std::convert::TryFrom;
+
+    use crate::types::prelude::*;
+
+    #[derive(Serialize, Deserialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct CheckedNumbers {
+        #[elastic(mapping = "DefaultByteMapping")]
+        #[serde(with = "crate::types::number::checked::byte")]
+        pub level: i32,
+        #[elastic(mapping = "DefaultShortMapping")]
+        #[serde(with = "crate::types::number::checked::short")]
+        pub port: i64,
+    }
+
+    #[test]
+    fn try_new_in_range() {
+        let byte = Byte::<DefaultByteMapping>::try_new(127).unwrap();
+        let short = Short::<DefaultShortMapping>::try_new(-32_768i64).unwrap();
+
+        assert_eq!((127i8, -32_768i16), (*byte, *short));
+    }
+
+    #[test]
+    fn try_new_out_of_range() {
+        let err = Byte::<DefaultByteMapping>::try_new(128).unwrap_err();
+
+        assert_eq!(
+            ("byte", 128, -128, 127),
+            (err.ty(), err.value(), err.min(), err.max())
+        );
+
+        assert!(Short::<DefaultShortMapping>::try_new(40_000).is_err());
+    }
+
+    #[test]
+    fn try_from_wider_integers() {
+        assert!(Byte::<DefaultByteMapping>::try_from(-128i16).is_ok());
+        assert!(Byte::<DefaultByteMapping>::try_from(-129i32).is_err());
+        assert!(Short::<DefaultShortMapping>::try_from(32_767i64).is_ok());
+        assert!(Short::<DefaultShortMapping>::try_from(i64::max_value()).is_err());
+    }
+
+    #[test]
+    fn checked_numbers_in_range() {
+        let doc: CheckedNumbers = serde_json::from_str(r#"{"level":-5,"port":9200}"#).unwrap();
+
+        assert_eq!((-5, 9200), (doc.level, doc.port));
+        assert_eq!(
+            r#"{"level":-5,"port":9200}"#,
+            serde_json::to_string(&doc).unwrap()
+        );
+    }
+
+    #[test]
+    fn checked_numbers_out_of_range() {
+        let doc = CheckedNumbers {
+            level: 300,
+            port: 9200,
+        };
+
+        assert!(serde_json::to_string(&doc).is_err());
+        assert!(serde_json::from_str::<CheckedNumbers>(r#"{"level":1,"port":70000}"#).is_err());
+    }
+}
@@ -80,6 +80,7 @@ number_type!(Short, ShortMapping, ShortFieldType, i16);
 number_type!(Byte, ByteMapping, ByteFieldType, i8);
 number_type!(Float, FloatMapping, FloatFieldType, f32);
 number_type!(Double, DoubleMapping, DoubleFieldType, f64);
+number_type!(ScaledFloat, ScaledFloatMapping, ScaledFloatFieldType, f64);
 
 #[cfg(test)]
 mod tests {
@@ -111,6 +112,14 @@ mod tests {
     struct MyDoubleMapping;
     impl DoubleMapping for MyDoubleMapping {}
 
+    #[derive(Default)]
+    struct MyScaledFloatMapping;
+    impl ScaledFloatMapping for MyScaledFloatMapping {
+        fn scaling_factor() -> f64 {
+            10.0
+        }
+    }
+
     #[test]
     fn can_change_number_mapping() {
         fn takes_custom_mapping(_: Integer<MyIntegerMapping>) -> bool {
@@ -178,4 +187,55 @@ mod tests {
             (*int_de, *long_de, *short_de, *byte_de, *float_de, *double_de)
         );
     }
+
+    #[test]
+    fn serialise_scaled_float() {
+        let num = ScaledFloat::<MyScaledFloatMapping>::new(1.01f64);
+
+        assert_eq!("1.01", serde_json::to_string(&num).unwrap());
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn serialise_decimal_as_scaled_float() {
+        use rust_decimal::Decimal;
+        use std::str::FromStr;
+
+        #[derive(Serialize, Deserialize, ElasticType)]
+        #[elastic(crate_root = "crate::types")]
+        struct Payment {
+            amount: Decimal,
+            #[elastic(mapping = "MyScaledFloatMapping")]
+            rate: Decimal,
+        }
+
+        let mapping = serde_json::to_value(&Payment::index_mapping()).unwrap();
+
+        assert_eq!(
+            json!({
+                "properties": {
+                    "amount": {
+                        "type": "scaled_float",
+                        "scaling_factor": 100.0
+                    },
+                    "rate": {
+                        "type": "scaled_float",
+                        "scaling_factor": 10.0
+                    }
+                }
+            }),
+            mapping
+        );
+
+        let amount = Decimal::from_str("10.07").unwrap();
+        let payment = Payment {
+            amount,
+            rate: Decimal::from_str("0.1").unwrap(),
+        };
+
+        let ser = serde_json::to_string(&payment).unwrap();
+        let de: Payment = serde_json::from_str(&ser).unwrap();
+
+        assert_eq!(amount, de.amount);
+    }
 }
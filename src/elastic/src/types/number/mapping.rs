@@ -219,6 +219,140 @@ number_mapping!(
     private_f64
 );
 
+/** A field that will be mapped as a `scaled_float`. */
+pub trait ScaledFloatFieldType<TMapping> {}
+
+/**
+Base `scaled_float` mapping.
+
+A `scaled_float` is indexed as a `long` that's the value multiplied by the [`scaling_factor`][ScaledFloatMapping.scaling_factor].
+It's useful for values with a fixed number of decimal places, like monetary amounts.
+
+[ScaledFloatMapping.scaling_factor]: #tymethod.scaling_factor
+*/
+pub trait ScaledFloatMapping
+where
+    Self: Default,
+{
+    /**
+    The factor values are multiplied by before they're indexed.
+
+    A value of `100` will keep 2 decimal places, so `1.234` is indexed as `123`.
+    */
+    fn scaling_factor() -> f64;
+
+    /** Try to convert strings to numbers. Accepts `true` (default) and `false`. */
+    fn coerce() -> Option<bool> {
+        None
+    }
+
+    /** Field-level index time boosting. Accepts a floating point number, defaults to `1.0`. */
+    fn boost() -> Option<f32> {
+        None
+    }
+
+    /**
+    Should the field be stored on disk in a column-stride fashion,
+    so that it can later be used for sorting, aggregations, or scripting?
+    Accepts `true` (default) or `false`.
+    */
+    fn doc_values() -> Option<bool> {
+        None
+    }
+
+    /**
+    If `true`, malformed numbers are ignored. If `false` (default),
+    malformed numbers throw an exception and reject the whole document.
+    */
+    fn ignore_malformed() -> Option<bool> {
+        None
+    }
+
+    /** Should the field be searchable? Accepts `true` (default) and `false`. */
+    fn index() -> Option<bool> {
+        None
+    }
+
+    /**
+    Accepts a numeric value which is substituted for any explicit null values.
+    Defaults to `null`, which means the field is treated as missing.
+    */
+    fn null_value() -> Option<f64> {
+        None
+    }
+
+    /**
+    Whether the field value should be stored and retrievable separately from the `_source` field.
+    Accepts true or false (default).
+    */
+    fn store() -> Option<bool> {
+        None
+    }
+}
+
+mod private_scaled_float {
+    use super::{
+        ScaledFloatFieldType,
+        ScaledFloatMapping,
+    };
+    use crate::types::private::field::{
+        FieldMapping,
+        FieldType,
+        SerializeFieldMapping,
+        StaticSerialize,
+    };
+    use serde::{
+        ser::SerializeStruct,
+        Serialize,
+    };
+
+    #[derive(Default)]
+    pub struct ScaledFloatFormat;
+
+    impl<TField, TMapping> FieldType<TMapping, ScaledFloatFormat> for TField
+    where
+        TField: ScaledFloatFieldType<TMapping> + Serialize,
+        TMapping: ScaledFloatMapping,
+    {
+    }
+
+    impl<TMapping> FieldMapping<ScaledFloatFormat> for TMapping
+    where
+        TMapping: ScaledFloatMapping,
+    {
+        type SerializeFieldMapping = SerializeFieldMapping<TMapping, ScaledFloatFormat>;
+
+        fn data_type() -> &'static str {
+            "scaled_float"
+        }
+    }
+
+    impl<TMapping> StaticSerialize for SerializeFieldMapping<TMapping, ScaledFloatFormat>
+    where
+        TMapping: FieldMapping<ScaledFloatFormat> + ScaledFloatMapping,
+    {
+        fn static_serialize<S>(serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: ::serde::Serializer,
+        {
+            let mut state = serializer.serialize_struct("mapping", 9)?;
+
+            state.serialize_field("type", TMapping::data_type())?;
+            state.serialize_field("scaling_factor", &TMapping::scaling_factor())?;
+
+            ser_field!(state, "coerce", TMapping::coerce());
+            ser_field!(state, "boost", TMapping::boost());
+            ser_field!(state, "doc_values", TMapping::doc_values());
+            ser_field!(state, "ignore_malformed", TMapping::ignore_malformed());
+            ser_field!(state, "index", TMapping::index());
+            ser_field!(state, "null_value", TMapping::null_value());
+            ser_field!(state, "store", TMapping::store());
+
+            state.end()
+        }
+    }
+}
+
 /** Default mapping for an `integer` type. */
 #[derive(PartialEq, Debug, Default, Clone, Copy)]
 pub struct DefaultIntegerMapping;
@@ -256,6 +390,22 @@ pub struct DefaultDoubleMapping;
 impl DoubleMapping for DefaultDoubleMapping {}
 impl DoubleFieldType<DefaultDoubleMapping> for f64 {}
 
+/**
+Default mapping for a `scaled_float` type.
+
+Values are scaled by `100`, so they keep 2 decimal places.
+*/
+#[derive(PartialEq, Debug, Default, Clone, Copy)]
+pub struct DefaultScaledFloatMapping;
+impl ScaledFloatMapping for DefaultScaledFloatMapping {
+    fn scaling_factor() -> f64 {
+        100.0
+    }
+}
+
+#[cfg(feature = "decimal")]
+impl ScaledFloatFieldType<DefaultScaledFloatMapping> for rust_decimal::Decimal {}
+
 #[cfg(test)]
 mod tests {
     use serde_json;
@@ -453,6 +603,22 @@ mod tests {
         }
     }
 
+    #[derive(Default, Clone)]
+    pub struct MyScaledFloatMapping;
+    impl ScaledFloatMapping for MyScaledFloatMapping {
+        fn scaling_factor() -> f64 {
+            1000.0
+        }
+
+        fn coerce() -> Option<bool> {
+            Some(false)
+        }
+
+        fn null_value() -> Option<f64> {
+            Some(0.0)
+        }
+    }
+
     #[test]
     fn serialise_mapping_integer_default() {
         let ser = serde_json::to_value(&field::serialize(DefaultIntegerMapping)).unwrap();
@@ -619,4 +785,30 @@ mod tests {
 
         assert_eq!(expected, ser);
     }
+
+    #[test]
+    fn serialise_mapping_scaled_float_default() {
+        let ser = serde_json::to_value(&field::serialize(DefaultScaledFloatMapping)).unwrap();
+
+        let expected = json!({
+            "type": "scaled_float",
+            "scaling_factor": 100.0
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_mapping_scaled_float_custom() {
+        let ser = serde_json::to_value(&field::serialize(MyScaledFloatMapping)).unwrap();
+
+        let expected = json!({
+            "type": "scaled_float",
+            "scaling_factor": 1000.0,
+            "coerce": false,
+            "null_value": 0.0
+        });
+
+        assert_eq!(expected, ser);
+    }
 }
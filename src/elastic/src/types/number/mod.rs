@@ -12,6 +12,8 @@ Rust | Elasticsearch
 `f64` | `double`
 `f32` | `float`
 
+There's no Rust primitive for a `scaled_float`, so it needs a [`ScaledFloat`](struct.ScaledFloat.html) with a mapping that sets the `scaling_factor`.
+
 For mapping a number with the default mapping, you can use the Rust primitive.
 If you need to use a custom mapping, then there is an `Elastic*` type for each number.
 
@@ -54,6 +56,50 @@ impl IntegerFieldType<DefaultIntegerMapping> for MyIntegerField {}
 # }
 ```
 
+With the `decimal` feature, a `rust_decimal::Decimal` is mapped as a `scaled_float` with a `scaling_factor` of `100`.
+A `Decimal` is serialised as a string, which Elasticsearch converts to a number when the field is indexed,
+so the value read back from the `_source` has the same precision as the one that was indexed.
+Use a custom `ScaledFloatMapping` to change the `scaling_factor`:
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# #[cfg(feature = "decimal")]
+# fn main() {
+# extern crate rust_decimal;
+# use elastic::types::prelude::*;
+use rust_decimal::Decimal;
+
+#[derive(Default)]
+struct ExchangeRateMapping;
+impl ScaledFloatMapping for ExchangeRateMapping {
+    fn scaling_factor() -> f64 {
+        10000.0
+    }
+}
+
+#[derive(Serialize, Deserialize, ElasticType)]
+struct Payment {
+    amount: Decimal,
+    #[elastic(mapping = "ExchangeRateMapping")]
+    exchange_rate: Decimal,
+}
+# }
+# #[cfg(not(feature = "decimal"))]
+# fn main() {}
+```
+
+`Byte<M>` and `Short<M>` can be created from wider integers with `try_new` or `TryFrom`,
+which return a [`NumberOverflowError`](struct.NumberOverflowError.html) if the value is out of range for the field:
+
+```
+# use elastic::types::prelude::*;
+assert!(Byte::<DefaultByteMapping>::try_new(100).is_ok());
+assert!(Byte::<DefaultByteMapping>::try_new(300).is_err());
+```
+
+To check an `i32` or `i64` that's mapped as a `byte` or `short`, see the [`checked`](checked/index.html) module.
+
 # Links
 
 - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/number.html)
@@ -61,8 +107,15 @@ impl IntegerFieldType<DefaultIntegerMapping> for MyIntegerField {}
 
 pub mod mapping;
 
+mod bounds;
 mod impls;
-pub use self::impls::*;
+pub use self::{
+    bounds::{
+        checked,
+        NumberOverflowError,
+    },
+    impls::*,
+};
 
 pub mod prelude {
     /*!
@@ -72,6 +125,7 @@ pub mod prelude {
     */
 
     pub use super::{
+        bounds::NumberOverflowError,
         impls::*,
         mapping::*,
     };
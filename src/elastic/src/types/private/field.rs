@@ -26,6 +26,10 @@ pub trait FieldType<TMapping, TPivot>
 where
     TMapping: FieldMapping<TPivot>,
 {
+    /** The values the field can take, if it only takes a fixed set of values. */
+    fn field_values() -> Option<&'static [&'static str]> {
+        None
+    }
 }
 
 /** The base representation of an Elasticsearch data type mapping. */
@@ -4,6 +4,7 @@ use serde::{
     Serializer,
 };
 use std::{
+    borrow::Cow,
     collections::{
         BTreeMap,
         HashMap,
@@ -11,6 +12,8 @@ use std::{
     },
     hash::Hash,
     marker::PhantomData,
+    rc::Rc,
+    sync::Arc,
 };
 
 use super::field::{
@@ -49,13 +52,19 @@ impl StaticSerialize for SerializeFieldMapping<DefaultMapping, ()> {
 /**
 A type that inherits its mapping from an inner value.
 */
-pub trait WrappedFieldType<TMapping, TPivot> {}
+pub trait WrappedFieldType<TMapping, TPivot> {
+    /** The values the inner field can take, if it only takes a fixed set of values. */
+    fn field_values() -> Option<&'static [&'static str]> {
+        None
+    }
+}
 
 /**
-Mapping for a wrapped value, like an array or optional type.
+Mapping for a wrapped value, like an array, slice, optional type or smart pointer.
 
 In Elasticsearch, arrays and optional types aren't special, anything can be indexed as an array or null.
 So the mapping for an array or optional type is just the mapping for the type it contains.
+Pointers like `Box`, `Rc`, `Arc` and `Cow` are serialised as the value they point to, so they're mapped the same way.
 */
 #[derive(Debug, Default, Clone)]
 pub struct WrappedMapping<TMapping, TPivot>
@@ -112,25 +121,97 @@ where
     TField: WrappedFieldType<TMapping, TPivot>,
     TMapping: FieldMapping<TPivot>,
 {
+    fn field_values() -> Option<&'static [&'static str]> {
+        <TField as WrappedFieldType<TMapping, TPivot>>::field_values()
+    }
 }
 
 impl<TField, TMapping, TPivot> WrappedFieldType<TMapping, TPivot> for Vec<TField>
 where
     TField: FieldType<TMapping, TPivot>,
     TMapping: FieldMapping<TPivot>,
-{
+{{
+    fn field_values() -> Option<&'static [&'static str]> {
+        TField::field_values()
+    }
 }
 
 impl<TField, TMapping, TPivot> WrappedFieldType<TMapping, TPivot> for HashSet<TField>
 where
     TField: FieldType<TMapping, TPivot>,
     TMapping: FieldMapping<TPivot>,
-{
+{{
+    fn field_values() -> Option<&'static [&'static str]> {
+        TField::field_values()
+    }
+}
+
+impl<TField, TMapping, TPivot, const N: usize> WrappedFieldType<TMapping, TPivot> for [TField; N]
+where
+    TField: FieldType<TMapping, TPivot>,
+    TMapping: FieldMapping<TPivot>,
+{{
+    fn field_values() -> Option<&'static [&'static str]> {
+        TField::field_values()
+    }
+}
+
+impl<'a, TField, TMapping, TPivot> WrappedFieldType<TMapping, TPivot> for &'a [TField]
+where
+    TField: FieldType<TMapping, TPivot>,
+    TMapping: FieldMapping<TPivot>,
+{{
+    fn field_values() -> Option<&'static [&'static str]> {
+        TField::field_values()
+    }
 }
 
 impl<TField, TMapping, TPivot> WrappedFieldType<TMapping, TPivot> for Option<TField>
 where
     TField: FieldType<TMapping, TPivot>,
     TMapping: FieldMapping<TPivot>,
-{
+{{
+    fn field_values() -> Option<&'static [&'static str]> {
+        TField::field_values()
+    }
+}
+
+impl<TField, TMapping, TPivot> WrappedFieldType<TMapping, TPivot> for Box<TField>
+where
+    TField: FieldType<TMapping, TPivot>,
+    TMapping: FieldMapping<TPivot>,
+{{
+    fn field_values() -> Option<&'static [&'static str]> {
+        TField::field_values()
+    }
+}
+
+impl<TField, TMapping, TPivot> WrappedFieldType<TMapping, TPivot> for Rc<TField>
+where
+    TField: FieldType<TMapping, TPivot>,
+    TMapping: FieldMapping<TPivot>,
+{{
+    fn field_values() -> Option<&'static [&'static str]> {
+        TField::field_values()
+    }
+}
+
+impl<TField, TMapping, TPivot> WrappedFieldType<TMapping, TPivot> for Arc<TField>
+where
+    TField: FieldType<TMapping, TPivot>,
+    TMapping: FieldMapping<TPivot>,
+{{
+    fn field_values() -> Option<&'static [&'static str]> {
+        TField::field_values()
+    }
+}
+
+impl<'a, TField, TMapping, TPivot> WrappedFieldType<TMapping, TPivot> for Cow<'a, TField>
+where
+    TField: FieldType<TMapping, TPivot> + Clone,
+    TMapping: FieldMapping<TPivot>,
+{{
+    fn field_values() -> Option<&'static [&'static str]> {
+        TField::field_values()
+    }
 }
@@ -0,0 +1,226 @@
+/*!
+Names of the builtin Elasticsearch analyzers.
+
+Builtin analyzers don't need to be defined in the [`Analysis`][Analysis] settings for an index before a field can use them.
+Use these names instead of string literals in mappings:
+
+```
+# use elastic::types::prelude::*;
+# use elastic::types::settings::analysis::builtin::{self, LanguageAnalyzer};
+#[derive(Default)]
+struct TitleMapping;
+impl TextMapping for TitleMapping {
+    fn analyzer() -> Option<&'static str> {
+        Some(LanguageAnalyzer::English.name())
+    }
+
+    fn search_quote_analyzer() -> Option<&'static str> {
+        Some(builtin::STANDARD)
+    }
+}
+```
+
+# Links
+
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/analysis-analyzers.html)
+
+[Analysis]: ../struct.Analysis.html
+*/
+
+/** The `standard` analyzer, which is used by default. */
+pub const STANDARD: &str = "standard";
+
+/** The `simple` analyzer, which splits text on anything that isn't a letter and lowercases it. */
+pub const SIMPLE: &str = "simple";
+
+/** The `whitespace` analyzer, which splits text on whitespace. */
+pub const WHITESPACE: &str = "whitespace";
+
+/** The `stop` analyzer, which is like the `simple` analyzer but also removes stop words. */
+pub const STOP: &str = "stop";
+
+/** The `keyword` analyzer, which returns the whole text as a single token. */
+pub const KEYWORD: &str = "keyword";
+
+/** The `pattern` analyzer, which splits text using a regular expression. */
+pub const PATTERN: &str = "pattern";
+
+/** The `fingerprint` analyzer, which builds a single token for duplicate detection. */
+pub const FINGERPRINT: &str = "fingerprint";
+
+const ANALYZERS: [&str; 7] = [
+    STANDARD,
+    SIMPLE,
+    WHITESPACE,
+    STOP,
+    KEYWORD,
+    PATTERN,
+    FINGERPRINT,
+];
+
+/**
+A builtin language analyzer.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum LanguageAnalyzer {
+    /** The `arabic` analyzer. */
+    Arabic,
+    /** The `armenian` analyzer. */
+    Armenian,
+    /** The `basque` analyzer. */
+    Basque,
+    /** The `bengali` analyzer. */
+    Bengali,
+    /** The `brazilian` analyzer. */
+    Brazilian,
+    /** The `bulgarian` analyzer. */
+    Bulgarian,
+    /** The `catalan` analyzer. */
+    Catalan,
+    /** The `cjk` analyzer, for Chinese, Japanese and Korean. */
+    Cjk,
+    /** The `czech` analyzer. */
+    Czech,
+    /** The `danish` analyzer. */
+    Danish,
+    /** The `dutch` analyzer. */
+    Dutch,
+    /** The `english` analyzer. */
+    English,
+    /** The `estonian` analyzer. */
+    Estonian,
+    /** The `finnish` analyzer. */
+    Finnish,
+    /** The `french` analyzer. */
+    French,
+    /** The `galician` analyzer. */
+    Galician,
+    /** The `german` analyzer. */
+    German,
+    /** The `greek` analyzer. */
+    Greek,
+    /** The `hindi` analyzer. */
+    Hindi,
+    /** The `hungarian` analyzer. */
+    Hungarian,
+    /** The `indonesian` analyzer. */
+    Indonesian,
+    /** The `irish` analyzer. */
+    Irish,
+    /** The `italian` analyzer. */
+    Italian,
+    /** The `latvian` analyzer. */
+    Latvian,
+    /** The `lithuanian` analyzer. */
+    Lithuanian,
+    /** The `norwegian` analyzer. */
+    Norwegian,
+    /** The `persian` analyzer. */
+    Persian,
+    /** The `portuguese` analyzer. */
+    Portuguese,
+    /** The `romanian` analyzer. */
+    Romanian,
+    /** The `russian` analyzer. */
+    Russian,
+    /** The `sorani` analyzer, for Central Kurdish. */
+    Sorani,
+    /** The `spanish` analyzer. */
+    Spanish,
+    /** The `swedish` analyzer. */
+    Swedish,
+    /** The `thai` analyzer. */
+    Thai,
+    /** The `turkish` analyzer. */
+    Turkish,
+}
+
+impl LanguageAnalyzer {
+    /** All of the builtin language analyzers. */
+    pub fn all() -> &'static [LanguageAnalyzer] {
+        use self::LanguageAnalyzer::*;
+
+        &[
+            Arabic, Armenian, Basque, Bengali, Brazilian, Bulgarian, Catalan, Cjk, Czech, Danish,
+            Dutch, English, Estonian, Finnish, French, Galician, German, Greek, Hindi, Hungarian,
+            Indonesian, Irish, Italian, Latvian, Lithuanian, Norwegian, Persian, Portuguese,
+            Romanian, Russian, Sorani, Spanish, Swedish, Thai, Turkish,
+        ]
+    }
+
+    /** The name of the analyzer. */
+    pub fn name(&self) -> &'static str {
+        match *self {
+            LanguageAnalyzer::Arabic => "arabic",
+            LanguageAnalyzer::Armenian => "armenian",
+            LanguageAnalyzer::Basque => "basque",
+            LanguageAnalyzer::Bengali => "bengali",
+            LanguageAnalyzer::Brazilian => "brazilian",
+            LanguageAnalyzer::Bulgarian => "bulgarian",
+            LanguageAnalyzer::Catalan => "catalan",
+            LanguageAnalyzer::Cjk => "cjk",
+            LanguageAnalyzer::Czech => "czech",
+            LanguageAnalyzer::Danish => "danish",
+            LanguageAnalyzer::Dutch => "dutch",
+            LanguageAnalyzer::English => "english",
+            LanguageAnalyzer::Estonian => "estonian",
+            LanguageAnalyzer::Finnish => "finnish",
+            LanguageAnalyzer::French => "french",
+            LanguageAnalyzer::Galician => "galician",
+            LanguageAnalyzer::German => "german",
+            LanguageAnalyzer::Greek => "greek",
+            LanguageAnalyzer::Hindi => "hindi",
+            LanguageAnalyzer::Hungarian => "hungarian",
+            LanguageAnalyzer::Indonesian => "indonesian",
+            LanguageAnalyzer::Irish => "irish",
+            LanguageAnalyzer::Italian => "italian",
+            LanguageAnalyzer::Latvian => "latvian",
+            LanguageAnalyzer::Lithuanian => "lithuanian",
+            LanguageAnalyzer::Norwegian => "norwegian",
+            LanguageAnalyzer::Persian => "persian",
+            LanguageAnalyzer::Portuguese => "portuguese",
+            LanguageAnalyzer::Romanian => "romanian",
+            LanguageAnalyzer::Russian => "russian",
+            LanguageAnalyzer::Sorani => "sorani",
+            LanguageAnalyzer::Spanish => "spanish",
+            LanguageAnalyzer::Swedish => "swedish",
+            LanguageAnalyzer::Thai => "thai",
+            LanguageAnalyzer::Turkish => "turkish",
+        }
+    }
+}
+
+/** Whether an analyzer with the given name is builtin. */
+pub fn is_builtin(name: &str) -> bool {
+    ANALYZERS.contains(&name)
+        || LanguageAnalyzer::all()
+            .iter()
+            .any(|analyzer| analyzer.name() == name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builtin_analyzers() {
+        assert!(is_builtin(STANDARD));
+        assert!(is_builtin(LanguageAnalyzer::French.name()));
+        assert!(is_builtin("cjk"));
+
+        assert!(!is_builtin("standrad"));
+        assert!(!is_builtin("content_text"));
+    }
+
+    #[test]
+    fn language_analyzer_names_are_unique() {
+        let mut names: Vec<_> = LanguageAnalyzer::all()
+            .iter()
+            .map(|analyzer| analyzer.name())
+            .collect();
+        names.sort();
+        names.dedup();
+
+        assert_eq!(LanguageAnalyzer::all().len(), names.len());
+    }
+}
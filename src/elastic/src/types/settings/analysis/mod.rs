@@ -0,0 +1,1154 @@
+/*!
+Analysis settings for custom analyzers, tokenizers, token filters and character filters.
+
+Analysis components are defined with a name in the index settings,
+and field mappings can then refer to analyzers by that name.
+
+# Examples
+
+Define a `content_text` analyzer that strips html and lowercases tokens:
+
+```
+# #[macro_use] extern crate serde_json;
+# use elastic::types::prelude::*;
+# fn main() {
+let analysis = Analysis::new()
+    .analyzer(
+        "content_text",
+        CustomAnalyzer::new("standard")
+            .char_filter("html_strip")
+            .filter("lowercase"),
+    );
+
+let settings = IndexSettings::new().analysis(analysis);
+# let ser = serde_json::to_value(&settings).unwrap();
+# let expected = json!({
+#     "index": {},
+#     "analysis": {
+#         "analyzer": {
+#             "content_text": {
+#                 "type": "custom",
+#                 "tokenizer": "standard",
+#                 "filter": ["lowercase"],
+#                 "char_filter": ["html_strip"]
+#             }
+#         }
+#     }
+# });
+# assert_eq!(expected, ser);
+# }
+```
+
+Define an autocomplete analyzer using an `edge_ngram` tokenizer:
+
+```
+# use elastic::types::prelude::*;
+let analysis = Analysis::new()
+    .tokenizer(
+        "autocomplete",
+        EdgeNGramTokenizer {
+            min_gram: Some(2),
+            max_gram: Some(10),
+            token_chars: Some(vec![TokenChars::Letter, TokenChars::Digit]),
+        },
+    )
+    .analyzer(
+        "autocomplete",
+        CustomAnalyzer::new("autocomplete").filter("lowercase"),
+    );
+```
+
+Builtin analyzers like `standard` or `english` don't need to be defined.
+Their names are in the [`builtin`][builtin] module.
+
+# Links
+
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/analysis.html)
+
+[builtin]: builtin/index.html
+*/
+
+pub mod builtin;
+
+use serde::ser::{
+    Serialize,
+    SerializeMap,
+    SerializeStruct,
+    Serializer,
+};
+use std::{
+    collections::BTreeMap,
+    fmt,
+};
+
+/**
+The name of the normalizer defined by [`Analysis::lowercase_normalizer`][Analysis.lowercase_normalizer].
+
+[Analysis.lowercase_normalizer]: struct.Analysis.html#method.lowercase_normalizer
+*/
+pub const LOWERCASE_NORMALIZER: &str = "lowercase_normalizer";
+
+/**
+A set of named analysis components.
+*/
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Analysis {
+    analyzers: BTreeMap<String, CustomAnalyzer>,
+    normalizers: BTreeMap<String, CustomNormalizer>,
+    tokenizers: BTreeMap<String, Tokenizer>,
+    filters: BTreeMap<String, TokenFilter>,
+    char_filters: BTreeMap<String, CharFilter>,
+}
+
+impl Analysis {
+    /** Create a new empty set of analysis components. */
+    pub fn new() -> Self {
+        Analysis::default()
+    }
+
+    /** Define an analyzer with the given name. */
+    pub fn analyzer(mut self, name: impl Into<String>, analyzer: CustomAnalyzer) -> Self {
+        self.analyzers.insert(name.into(), analyzer);
+        self
+    }
+
+    /** Define a normalizer with the given name. */
+    pub fn normalizer(mut self, name: impl Into<String>, normalizer: CustomNormalizer) -> Self {
+        self.normalizers.insert(name.into(), normalizer);
+        self
+    }
+
+    /**
+    Define a normalizer that lowercases `keyword` values, named [`LOWERCASE_NORMALIZER`][LOWERCASE_NORMALIZER].
+
+    This is the normalizer used by the `CaseInsensitiveKeywordMapping`.
+
+    [LOWERCASE_NORMALIZER]: constant.LOWERCASE_NORMALIZER.html
+    */
+    pub fn lowercase_normalizer(self) -> Self {
+        self.normalizer(
+            LOWERCASE_NORMALIZER,
+            CustomNormalizer::new().filter("lowercase"),
+        )
+    }
+
+    /** Define a tokenizer with the given name. */
+    pub fn tokenizer(mut self, name: impl Into<String>, tokenizer: impl Into<Tokenizer>) -> Self {
+        self.tokenizers.insert(name.into(), tokenizer.into());
+        self
+    }
+
+    /** Define a token filter with the given name. */
+    pub fn filter(mut self, name: impl Into<String>, filter: impl Into<TokenFilter>) -> Self {
+        self.filters.insert(name.into(), filter.into());
+        self
+    }
+
+    /** Define a character filter with the given name. */
+    pub fn char_filter(
+        mut self,
+        name: impl Into<String>,
+        char_filter: impl Into<CharFilter>,
+    ) -> Self {
+        self.char_filters.insert(name.into(), char_filter.into());
+        self
+    }
+
+    /**
+    Whether a field in an index with this analysis can use the analyzer with the given name.
+
+    The analyzer must either be [builtin][builtin] or defined in this set of analysis components.
+
+    [builtin]: builtin/index.html
+    */
+    pub fn has_analyzer(&self, name: &str) -> bool {
+        builtin::is_builtin(name) || self.analyzers.contains_key(name)
+    }
+
+    /** Whether or not any analysis components have been defined. */
+    pub fn is_empty(&self) -> bool {
+        self.analyzers.is_empty()
+            && self.normalizers.is_empty()
+            && self.tokenizers.is_empty()
+            && self.filters.is_empty()
+            && self.char_filters.is_empty()
+    }
+}
+
+impl Serialize for Analysis {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(None)?;
+
+        if !self.analyzers.is_empty() {
+            state.serialize_entry("analyzer", &self.analyzers)?;
+        }
+
+        if !self.normalizers.is_empty() {
+            state.serialize_entry("normalizer", &self.normalizers)?;
+        }
+
+        if !self.tokenizers.is_empty() {
+            state.serialize_entry("tokenizer", &self.tokenizers)?;
+        }
+
+        if !self.filters.is_empty() {
+            state.serialize_entry("filter", &self.filters)?;
+        }
+
+        if !self.char_filters.is_empty() {
+            state.serialize_entry("char_filter", &self.char_filters)?;
+        }
+
+        state.end()
+    }
+}
+
+/**
+A custom analyzer built from a tokenizer and zero or more token filters and character filters.
+
+Components are referred to by name, which can either be a builtin component like `lowercase`,
+or a component defined in the same [`Analysis`][Analysis].
+
+[Analysis]: struct.Analysis.html
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct CustomAnalyzer {
+    tokenizer: String,
+    filter: Vec<String>,
+    char_filter: Vec<String>,
+    position_increment_gap: Option<u32>,
+}
+
+impl CustomAnalyzer {
+    /** Create a new custom analyzer that uses the given tokenizer. */
+    pub fn new(tokenizer: impl Into<String>) -> Self {
+        CustomAnalyzer {
+            tokenizer: tokenizer.into(),
+            filter: Vec::new(),
+            char_filter: Vec::new(),
+            position_increment_gap: None,
+        }
+    }
+
+    /** Add a token filter to the end of the filter chain. */
+    pub fn filter(mut self, filter: impl Into<String>) -> Self {
+        self.filter.push(filter.into());
+        self
+    }
+
+    /** Add a character filter to the end of the character filter chain. */
+    pub fn char_filter(mut self, char_filter: impl Into<String>) -> Self {
+        self.char_filter.push(char_filter.into());
+        self
+    }
+
+    /** The number of fake term positions to insert between the values of an array. */
+    pub fn position_increment_gap(mut self, position_increment_gap: u32) -> Self {
+        self.position_increment_gap = Some(position_increment_gap);
+        self
+    }
+}
+
+impl Serialize for CustomAnalyzer {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("analyzer", 5)?;
+
+        state.serialize_field("type", "custom")?;
+        state.serialize_field("tokenizer", &self.tokenizer)?;
+
+        if !self.filter.is_empty() {
+            state.serialize_field("filter", &self.filter)?;
+        }
+
+        if !self.char_filter.is_empty() {
+            state.serialize_field("char_filter", &self.char_filter)?;
+        }
+
+        ser_field!(state, "position_increment_gap", self.position_increment_gap);
+
+        state.end()
+    }
+}
+
+/**
+A custom normalizer built from zero or more token filters and character filters.
+
+Normalizers are like analyzers for `keyword` fields, except they always produce a single token,
+so only filters that work on a character-by-character basis, like `lowercase` or `asciifolding`, can be used.
+*/
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CustomNormalizer {
+    filter: Vec<String>,
+    char_filter: Vec<String>,
+}
+
+impl CustomNormalizer {
+    /** Create a new custom normalizer without any filters. */
+    pub fn new() -> Self {
+        CustomNormalizer::default()
+    }
+
+    /** Add a token filter to the end of the filter chain. */
+    pub fn filter(mut self, filter: impl Into<String>) -> Self {
+        self.filter.push(filter.into());
+        self
+    }
+
+    /** Add a character filter to the end of the character filter chain. */
+    pub fn char_filter(mut self, char_filter: impl Into<String>) -> Self {
+        self.char_filter.push(char_filter.into());
+        self
+    }
+}
+
+impl Serialize for CustomNormalizer {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("normalizer", 3)?;
+
+        state.serialize_field("type", "custom")?;
+
+        if !self.filter.is_empty() {
+            state.serialize_field("filter", &self.filter)?;
+        }
+
+        if !self.char_filter.is_empty() {
+            state.serialize_field("char_filter", &self.char_filter)?;
+        }
+
+        state.end()
+    }
+}
+
+/** A tokenizer definition. */
+#[derive(Debug, Clone, PartialEq)]
+pub enum Tokenizer {
+    /** A `standard` tokenizer. */
+    Standard(StandardTokenizer),
+    /** An `edge_ngram` tokenizer. */
+    EdgeNGram(EdgeNGramTokenizer),
+    /** A `pattern` tokenizer. */
+    Pattern(PatternTokenizer),
+}
+
+impl Serialize for Tokenizer {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match *self {
+            Tokenizer::Standard(ref tokenizer) => tokenizer.serialize(serializer),
+            Tokenizer::EdgeNGram(ref tokenizer) => tokenizer.serialize(serializer),
+            Tokenizer::Pattern(ref tokenizer) => tokenizer.serialize(serializer),
+        }
+    }
+}
+
+/** A grammar-based tokenizer that splits text on word boundaries. */
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StandardTokenizer {
+    /** The maximum token length. Tokens longer than this are split. Defaults to `255`. */
+    pub max_token_length: Option<u32>,
+}
+
+impl From<StandardTokenizer> for Tokenizer {
+    fn from(tokenizer: StandardTokenizer) -> Self {
+        Tokenizer::Standard(tokenizer)
+    }
+}
+
+impl Serialize for StandardTokenizer {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("tokenizer", 2)?;
+
+        state.serialize_field("type", "standard")?;
+        ser_field!(state, "max_token_length", self.max_token_length);
+
+        state.end()
+    }
+}
+
+/** A tokenizer that emits n-grams anchored to the start of each word. */
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EdgeNGramTokenizer {
+    /** The minimum length of characters in a gram. Defaults to `1`. */
+    pub min_gram: Option<u32>,
+    /** The maximum length of characters in a gram. Defaults to `2`. */
+    pub max_gram: Option<u32>,
+    /** The character classes that should be included in a token. Defaults to all characters. */
+    pub token_chars: Option<Vec<TokenChars>>,
+}
+
+impl From<EdgeNGramTokenizer> for Tokenizer {
+    fn from(tokenizer: EdgeNGramTokenizer) -> Self {
+        Tokenizer::EdgeNGram(tokenizer)
+    }
+}
+
+impl Serialize for EdgeNGramTokenizer {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("tokenizer", 4)?;
+
+        state.serialize_field("type", "edge_ngram")?;
+        ser_field!(state, "min_gram", self.min_gram);
+        ser_field!(state, "max_gram", self.max_gram);
+        ser_field!(state, "token_chars", self.token_chars.as_ref());
+
+        state.end()
+    }
+}
+
+/** A character class for the `token_chars` of an n-gram tokenizer. */
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TokenChars {
+    /** Letters, like `a` or `京`. */
+    Letter,
+    /** Digits, like `3` or `7`. */
+    Digit,
+    /** Whitespace, like `" "` or `"\n"`. */
+    Whitespace,
+    /** Punctuation, like `!` or `"`. */
+    Punctuation,
+    /** Symbols, like `$` or `√`. */
+    Symbol,
+}
+
+impl Serialize for TokenChars {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(match *self {
+            TokenChars::Letter => "letter",
+            TokenChars::Digit => "digit",
+            TokenChars::Whitespace => "whitespace",
+            TokenChars::Punctuation => "punctuation",
+            TokenChars::Symbol => "symbol",
+        })
+    }
+}
+
+/** A tokenizer that splits text using a regular expression. */
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PatternTokenizer {
+    /** A Java regular expression. Defaults to `\W+`. */
+    pub pattern: Option<String>,
+    /** Java regular expression flags, separated by `|`, like `CASE_INSENSITIVE|COMMENTS`. */
+    pub flags: Option<String>,
+    /** The capture group to extract as tokens. Defaults to `-1`, which splits on the pattern. */
+    pub group: Option<i32>,
+}
+
+impl From<PatternTokenizer> for Tokenizer {
+    fn from(tokenizer: PatternTokenizer) -> Self {
+        Tokenizer::Pattern(tokenizer)
+    }
+}
+
+impl Serialize for PatternTokenizer {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("tokenizer", 4)?;
+
+        state.serialize_field("type", "pattern")?;
+        ser_field!(state, "pattern", self.pattern.as_ref());
+        ser_field!(state, "flags", self.flags.as_ref());
+        ser_field!(state, "group", self.group);
+
+        state.end()
+    }
+}
+
+/** A token filter definition. */
+#[derive(Debug, Clone, PartialEq)]
+pub enum TokenFilter {
+    /** A `lowercase` filter. */
+    Lowercase(LowercaseFilter),
+    /** A `stop` filter. */
+    Stop(StopFilter),
+    /** A `synonym` filter. */
+    Synonym(SynonymFilter),
+    /** A `shingle` filter. */
+    Shingle(ShingleFilter),
+    /** An `asciifolding` filter. */
+    AsciiFolding(AsciiFoldingFilter),
+    /** An `icu_folding` filter. */
+    #[cfg(feature = "icu")]
+    IcuFolding(IcuFoldingFilter),
+}
+
+impl Serialize for TokenFilter {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match *self {
+            TokenFilter::Lowercase(ref filter) => filter.serialize(serializer),
+            TokenFilter::Stop(ref filter) => filter.serialize(serializer),
+            TokenFilter::Synonym(ref filter) => filter.serialize(serializer),
+            TokenFilter::Shingle(ref filter) => filter.serialize(serializer),
+            TokenFilter::AsciiFolding(ref filter) => filter.serialize(serializer),
+            #[cfg(feature = "icu")]
+            TokenFilter::IcuFolding(ref filter) => filter.serialize(serializer),
+        }
+    }
+}
+
+/** A filter that normalises tokens to lowercase. */
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LowercaseFilter {
+    /** Use language-specific rules, like `greek`, `irish` or `turkish`. */
+    pub language: Option<String>,
+}
+
+impl From<LowercaseFilter> for TokenFilter {
+    fn from(filter: LowercaseFilter) -> Self {
+        TokenFilter::Lowercase(filter)
+    }
+}
+
+impl Serialize for LowercaseFilter {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("filter", 2)?;
+
+        state.serialize_field("type", "lowercase")?;
+        ser_field!(state, "language", self.language.as_ref());
+
+        state.end()
+    }
+}
+
+/** A filter that removes stop words from a token stream. */
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StopFilter {
+    /** The stop words to remove. Defaults to `_english_`. */
+    pub stopwords: Option<Stopwords>,
+    /** Whether stop words should be matched case insensitively. Defaults to `false`. */
+    pub ignore_case: Option<bool>,
+    /** Whether the last token should be removed if it's a stop word. Defaults to `true`. */
+    pub remove_trailing: Option<bool>,
+}
+
+impl From<StopFilter> for TokenFilter {
+    fn from(filter: StopFilter) -> Self {
+        TokenFilter::Stop(filter)
+    }
+}
+
+impl Serialize for StopFilter {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("filter", 4)?;
+
+        state.serialize_field("type", "stop")?;
+        ser_field!(state, "stopwords", self.stopwords.as_ref());
+        ser_field!(state, "ignore_case", self.ignore_case);
+        ser_field!(state, "remove_trailing", self.remove_trailing);
+
+        state.end()
+    }
+}
+
+/** A set of stop words. */
+#[derive(Debug, Clone, PartialEq)]
+pub enum Stopwords {
+    /** A predefined list of stop words, like `_english_` or `_none_`. */
+    Predefined(String),
+    /** An explicit list of stop words. */
+    List(Vec<String>),
+}
+
+impl Stopwords {
+    /** An explicit list of stop words. Duplicate words are ignored. */
+    pub fn list<I>(words: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        let mut list: Vec<String> = Vec::new();
+
+        for word in words {
+            let word = word.into();
+
+            if !list.contains(&word) {
+                list.push(word);
+            }
+        }
+
+        Stopwords::List(list)
+    }
+}
+
+impl Serialize for Stopwords {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match *self {
+            Stopwords::Predefined(ref name) => name.serialize(serializer),
+            Stopwords::List(ref words) => words.serialize(serializer),
+        }
+    }
+}
+
+/** A filter that adds synonyms to a token stream. */
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SynonymFilter {
+    /** Inline synonym rules in Solr format, like `i-pod, i pod => ipod`. */
+    pub synonyms: Option<Vec<String>>,
+    /** The path to a synonyms file, relative to the Elasticsearch config directory. */
+    pub synonyms_path: Option<String>,
+    /** Whether equivalent synonyms should be expanded to all other synonyms. Defaults to `true`. */
+    pub expand: Option<bool>,
+    /** Whether synonym rules that can't be parsed should be ignored. Defaults to `false`. */
+    pub lenient: Option<bool>,
+}
+
+impl SynonymFilter {
+    /** A synonym filter with the given inline rules. */
+    pub fn from_rules(rules: impl IntoIterator<Item = SynonymRule>) -> Self {
+        SynonymFilter {
+            synonyms: Some(rules.into_iter().map(|rule| rule.to_string()).collect()),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<SynonymFilter> for TokenFilter {
+    fn from(filter: SynonymFilter) -> Self {
+        TokenFilter::Synonym(filter)
+    }
+}
+
+impl Serialize for SynonymFilter {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("filter", 5)?;
+
+        state.serialize_field("type", "synonym")?;
+        ser_field!(state, "synonyms", self.synonyms.as_ref());
+        ser_field!(state, "synonyms_path", self.synonyms_path.as_ref());
+        ser_field!(state, "expand", self.expand);
+        ser_field!(state, "lenient", self.lenient);
+
+        state.end()
+    }
+}
+
+/**
+An inline synonym rule.
+
+Rules are formatted in the Solr synonym format.
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub enum SynonymRule {
+    /** Terms that are all equivalent to each other, like `couch, sofa, settee`. */
+    Equivalent(Vec<String>),
+    /** Terms that are replaced by other terms, like `i-pod, i pod => ipod`. */
+    Explicit {
+        /** The terms to replace. */
+        from: Vec<String>,
+        /** The terms to replace them with. */
+        to: Vec<String>,
+    },
+}
+
+impl SynonymRule {
+    /** A rule for terms that are all equivalent to each other. */
+    pub fn equivalent<I>(terms: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        SynonymRule::Equivalent(terms.into_iter().map(Into::into).collect())
+    }
+
+    /** A rule for terms that are replaced by other terms. */
+    pub fn explicit<I, J>(from: I, to: J) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+        J: IntoIterator,
+        J::Item: Into<String>,
+    {
+        SynonymRule::Explicit {
+            from: from.into_iter().map(Into::into).collect(),
+            to: to.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl fmt::Display for SynonymRule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            SynonymRule::Equivalent(ref terms) => write!(f, "{}", terms.join(", ")),
+            SynonymRule::Explicit { ref from, ref to } => {
+                write!(f, "{} => {}", from.join(", "), to.join(", "))
+            }
+        }
+    }
+}
+
+/** A filter that combines adjacent tokens into shingles. */
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ShingleFilter {
+    /** The minimum number of tokens in a shingle. Defaults to `2`. */
+    pub min_shingle_size: Option<u32>,
+    /** The maximum number of tokens in a shingle. Defaults to `2`. */
+    pub max_shingle_size: Option<u32>,
+    /** Whether the original tokens should also be emitted. Defaults to `true`. */
+    pub output_unigrams: Option<bool>,
+    /** The separator used to join adjacent tokens. Defaults to `" "`. */
+    pub token_separator: Option<String>,
+}
+
+impl From<ShingleFilter> for TokenFilter {
+    fn from(filter: ShingleFilter) -> Self {
+        TokenFilter::Shingle(filter)
+    }
+}
+
+impl Serialize for ShingleFilter {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("filter", 5)?;
+
+        state.serialize_field("type", "shingle")?;
+        ser_field!(state, "min_shingle_size", self.min_shingle_size);
+        ser_field!(state, "max_shingle_size", self.max_shingle_size);
+        ser_field!(state, "output_unigrams", self.output_unigrams);
+        ser_field!(state, "token_separator", self.token_separator.as_ref());
+
+        state.end()
+    }
+}
+
+/**
+A filter that converts alphabetic, numeric and symbolic characters that aren't in the Basic Latin Unicode block
+into their ASCII equivalents, like `é` into `e`.
+*/
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AsciiFoldingFilter {
+    /** Whether the original token should also be emitted. Defaults to `false`. */
+    pub preserve_original: Option<bool>,
+}
+
+impl From<AsciiFoldingFilter> for TokenFilter {
+    fn from(filter: AsciiFoldingFilter) -> Self {
+        TokenFilter::AsciiFolding(filter)
+    }
+}
+
+impl Serialize for AsciiFoldingFilter {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("filter", 2)?;
+
+        state.serialize_field("type", "asciifolding")?;
+        ser_field!(state, "preserve_original", self.preserve_original);
+
+        state.end()
+    }
+}
+
+/**
+A filter that applies Unicode case folding, normalisation and accent removal to tokens.
+
+This is a more thorough alternative to the `asciifolding` and `lowercase` filters that works for all scripts.
+It requires the [`analysis-icu`][plugin] plugin to be installed on the cluster, and the `icu` feature to be enabled.
+
+[plugin]: https://www.elastic.co/guide/en/elasticsearch/plugins/master/analysis-icu.html
+*/
+#[cfg(feature = "icu")]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct IcuFoldingFilter {
+    /**
+    A [`UnicodeSet`][unicode-set] of characters to fold, like `[^åäöÅÄÖ]` to leave Swedish characters untouched.
+    Defaults to all characters.
+
+    [unicode-set]: https://unicode-org.github.io/icu-docs/apidoc/released/icu4j/com/ibm/icu/text/UnicodeSet.html
+    */
+    pub unicode_set_filter: Option<String>,
+}
+
+#[cfg(feature = "icu")]
+impl From<IcuFoldingFilter> for TokenFilter {
+    fn from(filter: IcuFoldingFilter) -> Self {
+        TokenFilter::IcuFolding(filter)
+    }
+}
+
+#[cfg(feature = "icu")]
+impl Serialize for IcuFoldingFilter {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("filter", 2)?;
+
+        state.serialize_field("type", "icu_folding")?;
+        ser_field!(state, "unicode_set_filter", self.unicode_set_filter.as_ref());
+
+        state.end()
+    }
+}
+
+/** A character filter definition. */
+#[derive(Debug, Clone, PartialEq)]
+pub enum CharFilter {
+    /** An `html_strip` character filter. */
+    HtmlStrip(HtmlStripCharFilter),
+    /** A `mapping` character filter. */
+    Mapping(MappingCharFilter),
+    /** A `pattern_replace` character filter. */
+    PatternReplace(PatternReplaceCharFilter),
+}
+
+impl Serialize for CharFilter {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match *self {
+            CharFilter::HtmlStrip(ref filter) => filter.serialize(serializer),
+            CharFilter::Mapping(ref filter) => filter.serialize(serializer),
+            CharFilter::PatternReplace(ref filter) => filter.serialize(serializer),
+        }
+    }
+}
+
+/** A character filter that strips HTML elements and decodes HTML entities. */
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HtmlStripCharFilter {
+    /** HTML tags that shouldn't be stripped. */
+    pub escaped_tags: Option<Vec<String>>,
+}
+
+impl From<HtmlStripCharFilter> for CharFilter {
+    fn from(filter: HtmlStripCharFilter) -> Self {
+        CharFilter::HtmlStrip(filter)
+    }
+}
+
+impl Serialize for HtmlStripCharFilter {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("char_filter", 2)?;
+
+        state.serialize_field("type", "html_strip")?;
+        ser_field!(state, "escaped_tags", self.escaped_tags.as_ref());
+
+        state.end()
+    }
+}
+
+/** A character filter that replaces strings using a list of mappings. */
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MappingCharFilter {
+    /** Mappings in the form `key => value`, like `:) => _happy_`. */
+    pub mappings: Option<Vec<String>>,
+    /** The path to a mappings file, relative to the Elasticsearch config directory. */
+    pub mappings_path: Option<String>,
+}
+
+impl From<MappingCharFilter> for CharFilter {
+    fn from(filter: MappingCharFilter) -> Self {
+        CharFilter::Mapping(filter)
+    }
+}
+
+impl Serialize for MappingCharFilter {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("char_filter", 3)?;
+
+        state.serialize_field("type", "mapping")?;
+        ser_field!(state, "mappings", self.mappings.as_ref());
+        ser_field!(state, "mappings_path", self.mappings_path.as_ref());
+
+        state.end()
+    }
+}
+
+/** A character filter that replaces characters matching a regular expression. */
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PatternReplaceCharFilter {
+    /** A Java regular expression. */
+    pub pattern: Option<String>,
+    /** The replacement string, which can refer to capture groups like `$1`. */
+    pub replacement: Option<String>,
+    /** Java regular expression flags, separated by `|`, like `CASE_INSENSITIVE|COMMENTS`. */
+    pub flags: Option<String>,
+}
+
+impl From<PatternReplaceCharFilter> for CharFilter {
+    fn from(filter: PatternReplaceCharFilter) -> Self {
+        CharFilter::PatternReplace(filter)
+    }
+}
+
+impl Serialize for PatternReplaceCharFilter {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("char_filter", 4)?;
+
+        state.serialize_field("type", "pattern_replace")?;
+        ser_field!(state, "pattern", self.pattern.as_ref());
+        ser_field!(state, "replacement", self.replacement.as_ref());
+        ser_field!(state, "flags", self.flags.as_ref());
+
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+
+    use super::*;
+
+    #[test]
+    fn has_builtin_or_defined_analyzer() {
+        let analysis = Analysis::new().analyzer(
+            "content_text",
+            CustomAnalyzer::new(builtin::STANDARD).filter("lowercase"),
+        );
+
+        assert!(analysis.has_analyzer("content_text"));
+        assert!(analysis.has_analyzer(builtin::WHITESPACE));
+        assert!(analysis.has_analyzer(builtin::LanguageAnalyzer::German.name()));
+        assert!(!analysis.has_analyzer("content"));
+    }
+
+    #[test]
+    fn serialise_lowercase_normalizer() {
+        let ser = serde_json::to_value(&Analysis::new().lowercase_normalizer()).unwrap();
+
+        let expected = json!({
+            "normalizer": {
+                "lowercase_normalizer": {
+                    "type": "custom",
+                    "filter": ["lowercase"]
+                }
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_synonym_rules_and_stopwords() {
+        let synonyms = SynonymFilter::from_rules(vec![
+            SynonymRule::equivalent(vec!["couch", "sofa"]),
+            SynonymRule::explicit(vec!["i-pod", "i pod"], vec!["ipod"]),
+        ]);
+
+        let stop = StopFilter {
+            stopwords: Some(Stopwords::list(vec!["a", "the", "a"])),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            json!({
+                "type": "synonym",
+                "synonyms": ["couch, sofa", "i-pod, i pod => ipod"]
+            }),
+            serde_json::to_value(&synonyms).unwrap()
+        );
+        assert_eq!(
+            json!({
+                "type": "stop",
+                "stopwords": ["a", "the"]
+            }),
+            serde_json::to_value(&stop).unwrap()
+        );
+    }
+
+    #[test]
+    fn serialise_analysis() {
+        let analysis = Analysis::new()
+            .char_filter(
+                "strip_quotes",
+                PatternReplaceCharFilter {
+                    pattern: Some("\"".to_owned()),
+                    replacement: Some("".to_owned()),
+                    ..Default::default()
+                },
+            )
+            .tokenizer(
+                "autocomplete",
+                EdgeNGramTokenizer {
+                    min_gram: Some(2),
+                    max_gram: Some(10),
+                    token_chars: Some(vec![TokenChars::Letter, TokenChars::Digit]),
+                },
+            )
+            .filter(
+                "english_stop",
+                StopFilter {
+                    stopwords: Some(Stopwords::Predefined("_english_".to_owned())),
+                    ..Default::default()
+                },
+            )
+            .filter(
+                "product_synonyms",
+                SynonymFilter {
+                    synonyms: Some(vec!["i-pod, i pod => ipod".to_owned()]),
+                    ..Default::default()
+                },
+            )
+            .filter(
+                "shingles",
+                ShingleFilter {
+                    max_shingle_size: Some(3),
+                    output_unigrams: Some(false),
+                    ..Default::default()
+                },
+            )
+            .analyzer(
+                "autocomplete",
+                CustomAnalyzer::new("autocomplete")
+                    .char_filter("strip_quotes")
+                    .filter("lowercase")
+                    .filter("english_stop"),
+            );
+
+        let ser = serde_json::to_value(&analysis).unwrap();
+
+        let expected = json!({
+            "analyzer": {
+                "autocomplete": {
+                    "type": "custom",
+                    "tokenizer": "autocomplete",
+                    "filter": ["lowercase", "english_stop"],
+                    "char_filter": ["strip_quotes"]
+                }
+            },
+            "tokenizer": {
+                "autocomplete": {
+                    "type": "edge_ngram",
+                    "min_gram": 2,
+                    "max_gram": 10,
+                    "token_chars": ["letter", "digit"]
+                }
+            },
+            "filter": {
+                "english_stop": {
+                    "type": "stop",
+                    "stopwords": "_english_"
+                },
+                "product_synonyms": {
+                    "type": "synonym",
+                    "synonyms": ["i-pod, i pod => ipod"]
+                },
+                "shingles": {
+                    "type": "shingle",
+                    "max_shingle_size": 3,
+                    "output_unigrams": false
+                }
+            },
+            "char_filter": {
+                "strip_quotes": {
+                    "type": "pattern_replace",
+                    "pattern": "\"",
+                    "replacement": ""
+                }
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_empty_analysis() {
+        let ser = serde_json::to_value(&Analysis::new()).unwrap();
+
+        assert_eq!(json!({}), ser);
+    }
+
+    #[test]
+    fn serialise_tokenizers() {
+        let ser = serde_json::to_value(&Tokenizer::from(PatternTokenizer {
+            pattern: Some(",".to_owned()),
+            ..Default::default()
+        }))
+        .unwrap();
+
+        assert_eq!(json!({ "type": "pattern", "pattern": "," }), ser);
+
+        let ser = serde_json::to_value(&Tokenizer::from(StandardTokenizer {
+            max_token_length: Some(5),
+        }))
+        .unwrap();
+
+        assert_eq!(json!({ "type": "standard", "max_token_length": 5 }), ser);
+    }
+
+    #[test]
+    fn serialise_folding_filters() {
+        let ser = serde_json::to_value(&TokenFilter::from(AsciiFoldingFilter {
+            preserve_original: Some(true),
+        }))
+        .unwrap();
+
+        assert_eq!(
+            json!({ "type": "asciifolding", "preserve_original": true }),
+            ser
+        );
+    }
+
+    #[cfg(feature = "icu")]
+    #[test]
+    fn serialise_icu_folding_filter() {
+        let ser = serde_json::to_value(&TokenFilter::from(IcuFoldingFilter {
+            unicode_set_filter: Some("[^åäöÅÄÖ]".to_owned()),
+        }))
+        .unwrap();
+
+        assert_eq!(
+            json!({ "type": "icu_folding", "unicode_set_filter": "[^åäöÅÄÖ]" }),
+            ser
+        );
+    }
+
+    #[test]
+    fn serialise_stopwords_list() {
+        let ser = serde_json::to_value(&Stopwords::List(vec!["and".to_owned(), "the".to_owned()]))
+            .unwrap();
+
+        assert_eq!(json!(["and", "the"]), ser);
+    }
+}
@@ -0,0 +1,733 @@
+use serde::ser::{
+    Serialize,
+    SerializeMap,
+    Serializer,
+};
+use serde_json::Value;
+use std::{
+    collections::BTreeMap,
+    error::Error,
+    fmt,
+    time::Duration,
+};
+
+use super::{
+    analysis::Analysis,
+    similarity::Similarity,
+};
+
+/**
+A builder for the static and dynamic settings of an index.
+
+Settings that don't have a method on the builder can be given with [`setting`][setting].
+
+[setting]: #method.setting
+*/
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct IndexSettings {
+    number_of_shards: Option<u32>,
+    number_of_replicas: Option<u32>,
+    refresh_interval: Option<String>,
+    max_result_window: Option<u64>,
+    codec: Option<Codec>,
+    translog_durability: Option<TranslogDurability>,
+    translog_sync_interval: Option<Duration>,
+    translog_flush_threshold_size: Option<u64>,
+    merge_policy_segments_per_tier: Option<u32>,
+    merge_policy_max_merge_at_once: Option<u32>,
+    merge_policy_max_merged_segment: Option<u64>,
+    merge_scheduler_max_thread_count: Option<u32>,
+    similarity: BTreeMap<String, Similarity>,
+    extra: BTreeMap<String, Value>,
+    analysis: Option<Analysis>,
+}
+
+/**
+The compression used for stored fields.
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Codec {
+    /** LZ4 compression (default). */
+    Default,
+    /** DEFLATE compression, which has a higher compression ratio at the expense of slower stored field performance. */
+    BestCompression,
+}
+
+impl Serialize for Codec {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(match *self {
+            Codec::Default => "default",
+            Codec::BestCompression => "best_compression",
+        })
+    }
+}
+
+/**
+When the translog is fsynced and committed.
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TranslogDurability {
+    /** Fsync and commit after every request, so acknowledged writes are never lost (default). */
+    Request,
+    /** Fsync and commit in the background every sync interval, so recent writes may be lost on a crash. */
+    Async,
+}
+
+impl Serialize for TranslogDurability {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(match *self {
+            TranslogDurability::Request => "request",
+            TranslogDurability::Async => "async",
+        })
+    }
+}
+
+/**
+An index setting has a value that Elasticsearch won't accept.
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvalidSettingError {
+    key: &'static str,
+    reason: String,
+}
+
+impl InvalidSettingError {
+    fn new(key: &'static str, reason: impl Into<String>) -> Self {
+        InvalidSettingError {
+            key,
+            reason: reason.into(),
+        }
+    }
+
+    /** The key of the invalid setting, relative to the `index` settings. */
+    pub fn key(&self) -> &str {
+        self.key
+    }
+}
+
+impl fmt::Display for InvalidSettingError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "invalid value for index setting `{}`: {}",
+            self.key, self.reason
+        )
+    }
+}
+
+impl Error for InvalidSettingError {
+    fn description(&self) -> &str {
+        &self.reason
+    }
+}
+
+impl IndexSettings {
+    /** Create a new set of index settings where all settings use their defaults. */
+    pub fn new() -> Self {
+        IndexSettings::default()
+    }
+
+    /**
+    A starting point for append-only log or event indices.
+
+    Refreshes are infrequent, the translog is synced asynchronously and stored fields use `best_compression`.
+    Any setting can be overridden by calling other methods on the returned builder.
+    */
+    pub fn logs() -> Self {
+        IndexSettings::new()
+            .refresh_interval(Duration::from_secs(30))
+            .codec(Codec::BestCompression)
+            .translog_durability(TranslogDurability::Async)
+            .translog_sync_interval(Duration::from_secs(30))
+    }
+
+    /**
+    A starting point for indices that are searched much more often than they're written.
+
+    Refreshes are frequent, every write is durable and segments are merged aggressively to keep the number of segments searched small.
+    Any setting can be overridden by calling other methods on the returned builder.
+    */
+    pub fn search_heavy() -> Self {
+        IndexSettings::new()
+            .refresh_interval(Duration::from_secs(1))
+            .translog_durability(TranslogDurability::Request)
+            .merge_policy_segments_per_tier(5)
+    }
+
+    /**
+    A starting point for indices that take a high volume of writes, like bulk loads.
+
+    Refreshes are infrequent, the translog is synced asynchronously and flushed less often, and segments are merged less aggressively.
+    Any setting can be overridden by calling other methods on the returned builder.
+    */
+    pub fn write_heavy() -> Self {
+        IndexSettings::new()
+            .refresh_interval(Duration::from_secs(30))
+            .translog_durability(TranslogDurability::Async)
+            .translog_sync_interval(Duration::from_secs(30))
+            .translog_flush_threshold_size(1024 * 1024 * 1024)
+            .merge_policy_segments_per_tier(20)
+    }
+
+    /**
+    The number of primary shards for the index.
+
+    This setting can only be set when the index is created.
+    */
+    pub fn number_of_shards(mut self, number_of_shards: u32) -> Self {
+        self.number_of_shards = Some(number_of_shards);
+        self
+    }
+
+    /** The number of replicas each primary shard has. */
+    pub fn number_of_replicas(mut self, number_of_replicas: u32) -> Self {
+        self.number_of_replicas = Some(number_of_replicas);
+        self
+    }
+
+    /** How often to make recent changes to the index visible to search. */
+    pub fn refresh_interval(mut self, refresh_interval: Duration) -> Self {
+        self.refresh_interval = Some(time_value(refresh_interval));
+        self
+    }
+
+    /** Disable periodic refreshes of the index. */
+    pub fn disable_refresh_interval(mut self) -> Self {
+        self.refresh_interval = Some("-1".to_owned());
+        self
+    }
+
+    /** The maximum value of `from + size` for searches on the index. */
+    pub fn max_result_window(mut self, max_result_window: u64) -> Self {
+        self.max_result_window = Some(max_result_window);
+        self
+    }
+
+    /**
+    The compression used for stored fields.
+
+    This setting can only be set when the index is created or closed.
+    */
+    pub fn codec(mut self, codec: Codec) -> Self {
+        self.codec = Some(codec);
+        self
+    }
+
+    /** When the translog is fsynced and committed. */
+    pub fn translog_durability(mut self, durability: TranslogDurability) -> Self {
+        self.translog_durability = Some(durability);
+        self
+    }
+
+    /**
+    How often the translog is fsynced and committed when the durability is `async`.
+
+    The interval must be at least `100ms`.
+    */
+    pub fn translog_sync_interval(mut self, sync_interval: Duration) -> Self {
+        self.translog_sync_interval = Some(sync_interval);
+        self
+    }
+
+    /** The size in bytes the translog can grow to before a flush is triggered. */
+    pub fn translog_flush_threshold_size(mut self, bytes: u64) -> Self {
+        self.translog_flush_threshold_size = Some(bytes);
+        self
+    }
+
+    /**
+    The number of segments allowed per tier before they're merged.
+
+    Smaller values mean more merging and fewer segments to search.
+    The value must be at least `2`.
+    */
+    pub fn merge_policy_segments_per_tier(mut self, segments: u32) -> Self {
+        self.merge_policy_segments_per_tier = Some(segments);
+        self
+    }
+
+    /**
+    The maximum number of segments merged at once.
+
+    The value must be at least `2`.
+    */
+    pub fn merge_policy_max_merge_at_once(mut self, segments: u32) -> Self {
+        self.merge_policy_max_merge_at_once = Some(segments);
+        self
+    }
+
+    /** The maximum size in bytes of a segment produced by a merge. */
+    pub fn merge_policy_max_merged_segment(mut self, bytes: u64) -> Self {
+        self.merge_policy_max_merged_segment = Some(bytes);
+        self
+    }
+
+    /**
+    The maximum number of threads used to merge segments on each shard.
+
+    The value must be at least `1`.
+    */
+    pub fn merge_scheduler_max_thread_count(mut self, threads: u32) -> Self {
+        self.merge_scheduler_max_thread_count = Some(threads);
+        self
+    }
+
+    /**
+    Define a similarity with the given name.
+
+    Text and keyword fields can use the similarity by name.
+    See the [`similarity`][similarity] module for details.
+
+    [similarity]: similarity/index.html
+    */
+    pub fn similarity(
+        mut self,
+        name: impl Into<String>,
+        similarity: impl Into<Similarity>,
+    ) -> Self {
+        self.similarity.insert(name.into(), similarity.into());
+        self
+    }
+
+    /**
+    Custom analyzers, tokenizers and filters for the index.
+
+    See the [`analysis`][analysis] module for details.
+
+    [analysis]: analysis/index.html
+    */
+    pub fn analysis(mut self, analysis: Analysis) -> Self {
+        self.analysis = Some(analysis);
+        self
+    }
+
+    /**
+    Set an arbitrary index setting.
+
+    The `key` is relative to the `index` settings, like `mapping.total_fields.limit`.
+    A setting given here will replace a setting with the same key that's set by another method on the builder.
+    */
+    pub fn setting(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.extra.insert(key.into(), value.into());
+        self
+    }
+
+    /**
+    Check that the typed settings have values that Elasticsearch will accept.
+
+    Settings given with [`setting`][setting] aren't checked.
+
+    [setting]: #method.setting
+    */
+    pub fn validate(&self) -> Result<(), InvalidSettingError> {
+        if let Some(sync_interval) = self.translog_sync_interval {
+            if sync_interval < Duration::from_millis(100) {
+                return Err(InvalidSettingError::new(
+                    "translog.sync_interval",
+                    "the interval must be at least 100ms",
+                ));
+            }
+        }
+
+        let minimums = [
+            (
+                "merge.policy.segments_per_tier",
+                self.merge_policy_segments_per_tier,
+                2,
+            ),
+            (
+                "merge.policy.max_merge_at_once",
+                self.merge_policy_max_merge_at_once,
+                2,
+            ),
+            (
+                "merge.scheduler.max_thread_count",
+                self.merge_scheduler_max_thread_count,
+                1,
+            ),
+        ];
+
+        for &(key, value, min) in &minimums {
+            if let Some(value) = value {
+                if value < min {
+                    return Err(InvalidSettingError::new(
+                        key,
+                        format!("the value must be at least {}", min),
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /**
+    Check that fields in an index with these settings can use each of the given analyzers.
+
+    Each analyzer must either be [builtin][builtin] or defined in the [`analysis`][analysis] of these settings,
+    so the names of analyzers used by field mappings can be checked before the index is created.
+
+    [builtin]: analysis/builtin/index.html
+    [analysis]: #method.analysis
+    */
+    pub fn validate_analyzers<'a>(
+        &self,
+        analyzers: impl IntoIterator<Item = &'a str>,
+    ) -> Result<(), InvalidSettingError> {
+        let empty = Analysis::new();
+        let analysis = self.analysis.as_ref().unwrap_or(&empty);
+
+        for analyzer in analyzers {
+            if !analysis.has_analyzer(analyzer) {
+                return Err(InvalidSettingError::new(
+                    "analysis.analyzer",
+                    format!(
+                        "`{}` isn't a builtin analyzer or defined in the analysis settings",
+                        analyzer
+                    ),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/** Format a number of bytes as an Elasticsearch byte size value, like `512b`. */
+fn byte_size_value(bytes: u64) -> String {
+    format!("{}b", bytes)
+}
+
+/** Format a duration as an Elasticsearch time value, like `30s`. */
+pub(crate) fn time_value(duration: Duration) -> String {
+    let nanos = duration.subsec_nanos();
+
+    if nanos == 0 {
+        format!("{}s", duration.as_secs())
+    } else if nanos % 1_000_000 == 0 {
+        format!("{}ms", duration.as_millis())
+    } else if nanos % 1_000 == 0 {
+        format!("{}micros", duration.as_micros())
+    } else {
+        format!("{}nanos", duration.as_nanos())
+    }
+}
+
+struct IndexSettingsInner<'a>(&'a IndexSettings);
+
+impl<'a> Serialize for IndexSettingsInner<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let settings = self.0;
+        let mut state = serializer.serialize_map(None)?;
+
+        macro_rules! ser_setting {
+            ($key:expr, $value:expr) => {
+                if !settings.extra.contains_key($key) {
+                    if let Some(ref value) = $value {
+                        state.serialize_entry($key, value)?;
+                    }
+                }
+            };
+        }
+
+        ser_setting!("number_of_shards", settings.number_of_shards);
+        ser_setting!("number_of_replicas", settings.number_of_replicas);
+        ser_setting!("refresh_interval", settings.refresh_interval);
+        ser_setting!("max_result_window", settings.max_result_window);
+        ser_setting!("codec", settings.codec);
+        ser_setting!("translog.durability", settings.translog_durability);
+        ser_setting!(
+            "translog.sync_interval",
+            settings.translog_sync_interval.map(time_value)
+        );
+        ser_setting!(
+            "translog.flush_threshold_size",
+            settings.translog_flush_threshold_size.map(byte_size_value)
+        );
+        ser_setting!(
+            "merge.policy.segments_per_tier",
+            settings.merge_policy_segments_per_tier
+        );
+        ser_setting!(
+            "merge.policy.max_merge_at_once",
+            settings.merge_policy_max_merge_at_once
+        );
+        ser_setting!(
+            "merge.policy.max_merged_segment",
+            settings
+                .merge_policy_max_merged_segment
+                .map(byte_size_value)
+        );
+        ser_setting!(
+            "merge.scheduler.max_thread_count",
+            settings.merge_scheduler_max_thread_count
+        );
+
+        if !settings.similarity.is_empty() && !settings.extra.contains_key("similarity") {
+            state.serialize_entry("similarity", &settings.similarity)?;
+        }
+
+        for (key, value) in &settings.extra {
+            state.serialize_entry(key, value)?;
+        }
+
+        state.end()
+    }
+}
+
+impl Serialize for IndexSettings {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(None)?;
+
+        state.serialize_entry("index", &IndexSettingsInner(self))?;
+
+        if let Some(ref analysis) = self.analysis {
+            state.serialize_entry("analysis", analysis)?;
+        }
+
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+
+    use super::*;
+    use crate::types::settings::{
+        analysis::CustomAnalyzer,
+        similarity::{
+            Bm25Similarity,
+            BooleanSimilarity,
+        },
+    };
+
+    #[test]
+    fn serialise_index_settings() {
+        let settings = IndexSettings::new()
+            .number_of_shards(3)
+            .number_of_replicas(2)
+            .refresh_interval(Duration::from_millis(500))
+            .max_result_window(50_000)
+            .codec(Codec::BestCompression)
+            .setting("mapping.total_fields.limit", 2000);
+
+        let ser = serde_json::to_value(&settings).unwrap();
+
+        let expected = json!({
+            "index": {
+                "number_of_shards": 3,
+                "number_of_replicas": 2,
+                "refresh_interval": "500ms",
+                "max_result_window": 50000,
+                "codec": "best_compression",
+                "mapping.total_fields.limit": 2000
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_default_index_settings() {
+        let ser = serde_json::to_value(&IndexSettings::new()).unwrap();
+
+        assert_eq!(json!({ "index": {} }), ser);
+    }
+
+    #[test]
+    fn extra_settings_replace_builder_settings() {
+        let settings = IndexSettings::new()
+            .number_of_shards(3)
+            .setting("number_of_shards", 5);
+
+        let ser = serde_json::to_value(&settings).unwrap();
+
+        assert_eq!(json!({ "index": { "number_of_shards": 5 } }), ser);
+    }
+
+    #[test]
+    fn serialise_index_settings_with_analysis() {
+        let settings = IndexSettings::new().analysis(Analysis::new().analyzer(
+            "folded",
+            CustomAnalyzer::new("standard").filter("lowercase"),
+        ));
+
+        let ser = serde_json::to_value(&settings).unwrap();
+
+        let expected = json!({
+            "index": {},
+            "analysis": {
+                "analyzer": {
+                    "folded": {
+                        "type": "custom",
+                        "tokenizer": "standard",
+                        "filter": ["lowercase"]
+                    }
+                }
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_index_settings_with_similarity() {
+        let settings = IndexSettings::new()
+            .similarity(
+                "short_text",
+                Bm25Similarity {
+                    b: Some(0.0),
+                    ..Default::default()
+                },
+            )
+            .similarity("matches", BooleanSimilarity);
+
+        let ser = serde_json::to_value(&settings).unwrap();
+
+        let expected = json!({
+            "index": {
+                "similarity": {
+                    "matches": {
+                        "type": "boolean"
+                    },
+                    "short_text": {
+                        "type": "BM25",
+                        "b": 0.0
+                    }
+                }
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_preset_with_overrides() {
+        let settings = IndexSettings::logs()
+            .refresh_interval(Duration::from_secs(5))
+            .setting("translog.durability", "request");
+
+        let ser = serde_json::to_value(&settings).unwrap();
+
+        let expected = json!({
+            "index": {
+                "refresh_interval": "5s",
+                "codec": "best_compression",
+                "translog.durability": "request",
+                "translog.sync_interval": "30s"
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_presets() {
+        let search_heavy = serde_json::to_value(&IndexSettings::search_heavy()).unwrap();
+        let write_heavy = serde_json::to_value(&IndexSettings::write_heavy()).unwrap();
+
+        assert_eq!(json!("1s"), search_heavy["index"]["refresh_interval"]);
+        assert_eq!(
+            json!(5),
+            search_heavy["index"]["merge.policy.segments_per_tier"]
+        );
+
+        assert_eq!(json!("30s"), write_heavy["index"]["refresh_interval"]);
+        assert_eq!(json!("async"), write_heavy["index"]["translog.durability"]);
+    }
+
+    #[test]
+    fn serialise_translog_and_merge_settings() {
+        let settings = IndexSettings::new()
+            .translog_durability(TranslogDurability::Async)
+            .translog_sync_interval(Duration::from_secs(5))
+            .translog_flush_threshold_size(512)
+            .merge_policy_segments_per_tier(10)
+            .merge_policy_max_merge_at_once(10)
+            .merge_policy_max_merged_segment(1024)
+            .merge_scheduler_max_thread_count(1);
+
+        let ser = serde_json::to_value(&settings).unwrap();
+
+        let expected = json!({
+            "index": {
+                "translog.durability": "async",
+                "translog.sync_interval": "5s",
+                "translog.flush_threshold_size": "512b",
+                "merge.policy.segments_per_tier": 10,
+                "merge.policy.max_merge_at_once": 10,
+                "merge.policy.max_merged_segment": "1024b",
+                "merge.scheduler.max_thread_count": 1
+            }
+        });
+
+        assert!(settings.validate().is_ok());
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn validate_invalid_settings() {
+        let sync_interval = IndexSettings::new()
+            .translog_sync_interval(Duration::from_millis(50))
+            .validate()
+            .unwrap_err();
+
+        let segments_per_tier = IndexSettings::new()
+            .merge_policy_segments_per_tier(1)
+            .validate()
+            .unwrap_err();
+
+        let max_thread_count = IndexSettings::new()
+            .merge_scheduler_max_thread_count(0)
+            .validate()
+            .unwrap_err();
+
+        assert_eq!("translog.sync_interval", sync_interval.key());
+        assert_eq!("merge.policy.segments_per_tier", segments_per_tier.key());
+        assert_eq!("merge.scheduler.max_thread_count", max_thread_count.key());
+    }
+
+    #[test]
+    fn validate_analyzers() {
+        let settings = IndexSettings::new()
+            .analysis(Analysis::new().analyzer("content_text", CustomAnalyzer::new("standard")));
+
+        assert!(settings
+            .validate_analyzers(vec!["content_text", "english", "whitespace"])
+            .is_ok());
+
+        let err = settings
+            .validate_analyzers(vec!["content_text", "engilsh"])
+            .unwrap_err();
+
+        assert_eq!("analysis.analyzer", err.key());
+    }
+
+    #[test]
+    fn format_time_values() {
+        assert_eq!("30s", time_value(Duration::from_secs(30)));
+        assert_eq!("1500ms", time_value(Duration::from_millis(1500)));
+        assert_eq!("15micros", time_value(Duration::from_micros(15)));
+        assert_eq!("7nanos", time_value(Duration::from_nanos(7)));
+    }
+
+    #[test]
+    fn disable_refresh_interval() {
+        let ser = serde_json::to_value(&IndexSettings::new().disable_refresh_interval()).unwrap();
+
+        assert_eq!(json!({ "index": { "refresh_interval": "-1" } }), ser);
+    }
+}
@@ -0,0 +1,71 @@
+/*!
+Index settings.
+
+Settings are serialised into the `settings` section of a create index or index template request body.
+
+# Examples
+
+Create an index with `3` primary shards that refreshes every `30` seconds:
+
+```
+# #[macro_use] extern crate serde_json;
+# use std::time::Duration;
+# use elastic::types::prelude::*;
+# fn main() {
+let settings = IndexSettings::new()
+    .number_of_shards(3)
+    .refresh_interval(Duration::from_secs(30));
+
+let body = json!({
+    "settings": settings
+});
+# let expected = json!({
+#     "settings": {
+#         "index": {
+#             "number_of_shards": 3,
+#             "refresh_interval": "30s"
+#         }
+#     }
+# });
+# assert_eq!(expected, body);
+# }
+```
+
+Presets like [`IndexSettings::logs`][IndexSettings.logs] are starting points for common workloads that can be customised further:
+
+```
+# use std::time::Duration;
+# use elastic::types::prelude::*;
+# fn main() {
+let settings = IndexSettings::logs()
+    .number_of_shards(3)
+    .refresh_interval(Duration::from_secs(10));
+# }
+```
+
+# Links
+
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/index-modules.html#index-modules-settings)
+
+[IndexSettings.logs]: struct.IndexSettings.html#method.logs
+*/
+
+pub mod analysis;
+pub mod similarity;
+
+mod index;
+pub use self::index::*;
+
+pub mod prelude {
+    /*!
+    Includes all types for index settings.
+
+    This is a convenience module to make it easy to build settings without too many `use` statements.
+    */
+
+    pub use super::{
+        analysis::*,
+        index::*,
+        similarity::*,
+    };
+}
@@ -0,0 +1,205 @@
+/*!
+Similarity settings for custom scoring algorithms.
+
+Similarities are defined with a name in the index settings,
+and `text` and `keyword` field mappings can then refer to a similarity by that name.
+
+# Examples
+
+Define a `short_text` similarity that doesn't normalise scores by field length:
+
+```
+# #[macro_use] extern crate serde_json;
+# use elastic::types::prelude::*;
+# fn main() {
+let settings = IndexSettings::new().similarity(
+    "short_text",
+    Bm25Similarity {
+        k1: Some(1.5),
+        b: Some(0.0),
+        ..Default::default()
+    },
+);
+# let ser = serde_json::to_value(&settings).unwrap();
+# let expected = json!({
+#     "index": {
+#         "similarity": {
+#             "short_text": {
+#                 "type": "BM25",
+#                 "k1": 1.5,
+#                 "b": 0.0
+#             }
+#         }
+#     }
+# });
+# assert_eq!(expected, ser);
+# }
+```
+
+A field mapping can then use the similarity:
+
+```
+# use elastic::types::prelude::*;
+#[derive(Default)]
+struct TitleMapping;
+impl TextMapping for TitleMapping {
+    fn similarity() -> Option<&'static str> {
+        Some("short_text")
+    }
+}
+```
+
+# Links
+
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/index-modules-similarity.html)
+*/
+
+use serde::ser::{
+    Serialize,
+    SerializeStruct,
+    Serializer,
+};
+
+/** A similarity definition. */
+#[derive(Debug, Clone, PartialEq)]
+pub enum Similarity {
+    /** A `BM25` similarity. */
+    Bm25(Bm25Similarity),
+    /** A `classic` TF/IDF similarity. */
+    Classic(ClassicSimilarity),
+    /** A `boolean` similarity. */
+    Boolean(BooleanSimilarity),
+}
+
+impl Serialize for Similarity {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match *self {
+            Similarity::Bm25(ref similarity) => similarity.serialize(serializer),
+            Similarity::Classic(ref similarity) => similarity.serialize(serializer),
+            Similarity::Boolean(ref similarity) => similarity.serialize(serializer),
+        }
+    }
+}
+
+/** The Okapi BM25 similarity, which is the default similarity in Elasticsearch. */
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Bm25Similarity {
+    /** Controls non-linear term frequency normalisation. Defaults to `1.2`. */
+    pub k1: Option<f32>,
+    /** Controls how much document length normalises term frequency values. Defaults to `0.75`. */
+    pub b: Option<f32>,
+    /** Whether or not overlapping tokens are ignored when computing the norm. Defaults to `true`. */
+    pub discount_overlaps: Option<bool>,
+}
+
+impl From<Bm25Similarity> for Similarity {
+    fn from(similarity: Bm25Similarity) -> Self {
+        Similarity::Bm25(similarity)
+    }
+}
+
+impl Serialize for Bm25Similarity {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("similarity", 4)?;
+
+        state.serialize_field("type", "BM25")?;
+        ser_field!(state, "k1", self.k1);
+        ser_field!(state, "b", self.b);
+        ser_field!(state, "discount_overlaps", self.discount_overlaps);
+
+        state.end()
+    }
+}
+
+/** The TF/IDF similarity that was the default before Elasticsearch `5.0`. */
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ClassicSimilarity {
+    /** Whether or not overlapping tokens are ignored when computing the norm. Defaults to `true`. */
+    pub discount_overlaps: Option<bool>,
+}
+
+impl From<ClassicSimilarity> for Similarity {
+    fn from(similarity: ClassicSimilarity) -> Self {
+        Similarity::Classic(similarity)
+    }
+}
+
+impl Serialize for ClassicSimilarity {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("similarity", 2)?;
+
+        state.serialize_field("type", "classic")?;
+        ser_field!(state, "discount_overlaps", self.discount_overlaps);
+
+        state.end()
+    }
+}
+
+/**
+A similarity where the score of a matching term is only its query boost.
+
+This is useful when the relevance of a field only depends on whether or not the query terms match.
+*/
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct BooleanSimilarity;
+
+impl From<BooleanSimilarity> for Similarity {
+    fn from(similarity: BooleanSimilarity) -> Self {
+        Similarity::Boolean(similarity)
+    }
+}
+
+impl Serialize for BooleanSimilarity {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("similarity", 1)?;
+
+        state.serialize_field("type", "boolean")?;
+
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+
+    use super::*;
+
+    #[test]
+    fn serialise_similarities() {
+        let bm25 = serde_json::to_value(&Similarity::from(Bm25Similarity {
+            k1: Some(1.5),
+            b: Some(0.5),
+            discount_overlaps: Some(false),
+        }))
+        .unwrap();
+
+        let classic =
+            serde_json::to_value(&Similarity::from(ClassicSimilarity::default())).unwrap();
+        let boolean = serde_json::to_value(&Similarity::from(BooleanSimilarity)).unwrap();
+
+        assert_eq!(
+            json!({
+                "type": "BM25",
+                "k1": 1.5,
+                "b": 0.5,
+                "discount_overlaps": false
+            }),
+            bm25
+        );
+        assert_eq!(json!({ "type": "classic" }), classic);
+        assert_eq!(json!({ "type": "boolean" }), boolean);
+    }
+}
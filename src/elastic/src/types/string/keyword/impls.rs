@@ -1,4 +1,6 @@
 use super::mapping::{
+    CaseInsensitiveKeywordMapping,
+    DefaultKeywordMapping,
     KeywordFieldType,
     KeywordMapping,
 };
@@ -21,6 +23,7 @@ use std::{
 An Elasticsearch `keyword` with a mapping.
 
 Where the mapping isn't custom, you can use the standard library `String` instead.
+The value is a `String` by default, but can be any type that implements `AsRef<str>`, like a borrowed `&str` or `Cow<str>`.
 
 # Examples
 
@@ -34,17 +37,18 @@ let string = Keyword::<DefaultKeywordMapping>::new("my string value");
 ```
 */
 #[derive(Debug, Clone, Default, PartialEq)]
-pub struct Keyword<TMapping>
+pub struct Keyword<TMapping, TValue = String>
 where
     TMapping: KeywordMapping,
 {
-    value: String,
+    value: TValue,
     _m: PhantomData<TMapping>,
 }
 
-impl<TMapping> Keyword<TMapping>
+impl<TMapping, TValue> Keyword<TMapping, TValue>
 where
     TMapping: KeywordMapping,
+    TValue: AsRef<str>,
 {
     /**
     Creates a new `Keyword` with the given mapping.
@@ -60,9 +64,9 @@ where
     let string = Keyword::<DefaultKeywordMapping>::new("my string");
     ```
     */
-    pub fn new<I>(string: I) -> Keyword<TMapping>
+    pub fn new<I>(string: I) -> Keyword<TMapping, TValue>
     where
-        I: Into<String>,
+        I: Into<TValue>,
     {
         Keyword {
             value: string.into(),
@@ -71,12 +75,29 @@ where
     }
 
     /** Change the mapping of this string. */
-    pub fn remap<TNewMapping>(keyword: Keyword<TMapping>) -> Keyword<TNewMapping>
+    pub fn remap<TNewMapping>(keyword: Keyword<TMapping, TValue>) -> Keyword<TNewMapping, TValue>
     where
         TNewMapping: KeywordMapping,
     {
         Keyword::new(keyword.value)
     }
+
+    /** Copy a borrowed string into an owned one. */
+    pub fn into_owned(self) -> Keyword<TMapping> {
+        Keyword::new(self.value.as_ref())
+    }
 }
 
 impl_string_type!(Keyword, KeywordMapping, KeywordFieldType);
+
+#[cfg(feature = "uuid")]
+impl KeywordFieldType<DefaultKeywordMapping> for uuid::Uuid {}
+
+/**
+A `keyword` that's matched case-insensitively.
+
+See [`CaseInsensitiveKeywordMapping`][CaseInsensitiveKeywordMapping] for the index settings the mapping needs.
+
+[CaseInsensitiveKeywordMapping]: mapping/struct.CaseInsensitiveKeywordMapping.html
+*/
+pub type CaseInsensitiveKeyword = Keyword<CaseInsensitiveKeywordMapping>;
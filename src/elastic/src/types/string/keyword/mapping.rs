@@ -2,6 +2,7 @@
 
 use crate::types::{
     private::field::FieldMapping,
+    settings::analysis::LOWERCASE_NORMALIZER,
     string::mapping::{
         IndexOptions,
         StringField,
@@ -15,7 +16,17 @@ use serde::{
 use std::collections::BTreeMap;
 
 /** A field that will be mapped as a `keyword`. */
-pub trait KeywordFieldType<TMapping> {}
+pub trait KeywordFieldType<TMapping> {
+    /**
+    The values the field can take, if it only takes a fixed set of values.
+
+    Enums that derive `ElasticType` return the serialised names of their variants.
+    The values are listed in the `_meta` of the index mapping for documents that contain the field.
+    */
+    fn values() -> Option<&'static [&'static str]> {
+        None
+    }
+}
 
 /**
 The base requirements for mapping a `string` type.
@@ -166,11 +177,29 @@ pub trait KeywordMapping {
         None
     }
 
+    /**
+    Metadata about the field, like its unit or the values it can take.
+    Elasticsearch doesn't use the metadata itself, but returns it with the mapping.
+    Field metadata requires Elasticsearch `7.6` or later, and each value can be at most `50` characters long.
+    */
+    fn meta() -> Option<BTreeMap<&'static str, &'static str>> {
+        None
+    }
+
     /** Whether field-length should be taken into account when scoring queries. Accepts `true` (default) or `false`. */
     fn norms() -> Option<bool> {
         None
     }
 
+    /**
+    The normalizer that should be applied to the value before it's indexed and to query terms.
+    Normalizers are defined in the `analysis` module of the index settings.
+    Defaults to `null`, which means the value is indexed as-is.
+    */
+    fn normalizer() -> Option<&'static str> {
+        None
+    }
+
     /**
     Accepts a `string` value which is substituted for any explicit null values.
     Defaults to `null`, which means the field is treated as missing.
@@ -197,7 +226,8 @@ pub trait KeywordMapping {
 
     /**
     Which scoring algorithm or similarity should be used.
-    Defaults to `"classic"`, which uses TF/IDF.
+    Defaults to `"BM25"`.
+    Custom similarities can be defined in the `similarity` module of the index settings.
     */
     fn similarity() -> Option<&'static str> {
         None
@@ -209,6 +239,39 @@ pub trait KeywordMapping {
 pub struct DefaultKeywordMapping;
 impl KeywordMapping for DefaultKeywordMapping {}
 
+/**
+A mapping for `keyword` fields that are matched case-insensitively.
+
+The mapping uses the [`LOWERCASE_NORMALIZER`][LOWERCASE_NORMALIZER] normalizer, which needs to be defined in the index settings
+with [`Analysis::lowercase_normalizer`][Analysis.lowercase_normalizer].
+
+# Examples
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# use elastic::types::prelude::*;
+#[derive(Serialize, ElasticType)]
+struct User {
+    pub email: CaseInsensitiveKeyword,
+}
+
+# fn main() {
+let settings = IndexSettings::new().analysis(Analysis::new().lowercase_normalizer());
+# }
+```
+
+[LOWERCASE_NORMALIZER]: ../../../settings/analysis/constant.LOWERCASE_NORMALIZER.html
+[Analysis.lowercase_normalizer]: ../../../settings/analysis/struct.Analysis.html#method.lowercase_normalizer
+*/
+#[derive(PartialEq, Debug, Default, Clone, Copy)]
+pub struct CaseInsensitiveKeywordMapping;
+impl KeywordMapping for CaseInsensitiveKeywordMapping {
+    fn normalizer() -> Option<&'static str> {
+        Some(LOWERCASE_NORMALIZER)
+    }
+}
+
 /** A multi-field string mapping. */
 #[derive(Debug, Default, Clone, Copy)]
 pub struct KeywordFieldMapping {
@@ -249,6 +312,16 @@ pub struct KeywordFieldMapping {
     /** Whether field-length should be taken into account when scoring queries. Accepts `true` (default) or `false`. */
     pub norms: Option<bool>,
     /**
+    The normalizer that should be applied to the value before it's indexed and to query terms.
+    Defaults to `null`, which means the value is indexed as-is.
+    */
+    pub normalizer: Option<&'static str>,
+    /**
+    Accepts a `string` value which is substituted for any explicit null values.
+    Defaults to `null`, which means the field is treated as missing.
+    */
+    pub null_value: Option<&'static str>,
+    /**
     Whether the field value should be stored and retrievable separately from the `_source` field.
     Accepts `true` or `false` (default).
     */
@@ -270,7 +343,7 @@ impl Serialize for KeywordFieldMapping {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct("mapping", 12)?;
+        let mut state = serializer.serialize_struct("mapping", 14)?;
 
         state.serialize_field("type", DefaultKeywordMapping::data_type())?;
 
@@ -282,6 +355,8 @@ impl Serialize for KeywordFieldMapping {
         ser_field!(state, "index", self.index);
         ser_field!(state, "index_options", self.index_options);
         ser_field!(state, "norms", self.norms);
+        ser_field!(state, "normalizer", self.normalizer);
+        ser_field!(state, "null_value", self.null_value);
         ser_field!(state, "store", self.store);
         ser_field!(state, "search_analyzer", self.search_analyzer);
         ser_field!(state, "similarity", self.similarity);
@@ -315,6 +390,9 @@ mod private {
         TField: KeywordFieldType<TMapping> + Serialize,
         TMapping: KeywordMapping,
     {
+        fn field_values() -> Option<&'static [&'static str]> {
+            TField::values()
+        }
     }
 
     impl<TMapping> FieldMapping<KeywordPivot> for TMapping
@@ -336,7 +414,7 @@ mod private {
         where
             S: Serializer,
         {
-            let mut state = serializer.serialize_struct("mapping", 15)?;
+            let mut state = serializer.serialize_struct("mapping", 17)?;
 
             state.serialize_field("type", TMapping::data_type())?;
 
@@ -353,7 +431,9 @@ mod private {
             ser_field!(state, "ignore_above", TMapping::ignore_above());
             ser_field!(state, "index", TMapping::index());
             ser_field!(state, "index_options", TMapping::index_options());
+            ser_field!(state, "meta", TMapping::meta());
             ser_field!(state, "norms", TMapping::norms());
+            ser_field!(state, "normalizer", TMapping::normalizer());
             ser_field!(state, "null_value", TMapping::null_value());
             ser_field!(state, "store", TMapping::store());
             ser_field!(state, "search_analyzer", TMapping::search_analyzer());
@@ -1,109 +1,144 @@
 macro_rules! impl_string_type {
     ($wrapper_ty:ident, $mapping_ty:ident, $field_type:ident) => {
-        impl<TMapping> $field_type<TMapping> for $wrapper_ty<TMapping> where TMapping: $mapping_ty {}
+        impl<TMapping, TValue> $field_type<TMapping> for $wrapper_ty<TMapping, TValue>
+        where
+            TMapping: $mapping_ty,
+            TValue: AsRef<str>,
+        {
+        }
 
         impl_mapping_type!(String, $wrapper_ty, $mapping_ty);
 
-        impl<'a, TMapping> From<$wrapper_ty<TMapping>> for String
+        impl<TMapping, TValue> From<$wrapper_ty<TMapping, TValue>> for String
         where
             TMapping: $mapping_ty,
+            TValue: Into<String>,
         {
-            fn from(wrapper: $wrapper_ty<TMapping>) -> Self {
-                wrapper.value
+            fn from(wrapper: $wrapper_ty<TMapping, TValue>) -> Self {
+                wrapper.value.into()
             }
         }
 
-        impl<'a, TMapping> From<&'a $wrapper_ty<TMapping>> for std::borrow::Cow<'a, str>
+        impl<'a, TMapping, TValue> From<&'a $wrapper_ty<TMapping, TValue>>
+            for std::borrow::Cow<'a, str>
         where
             TMapping: $mapping_ty,
+            TValue: AsRef<str>,
         {
-            fn from(wrapper: &'a $wrapper_ty<TMapping>) -> Self {
+            fn from(wrapper: &'a $wrapper_ty<TMapping, TValue>) -> Self {
                 wrapper.as_ref().into()
             }
         }
 
-        impl<'a, TMapping> From<&'a $wrapper_ty<TMapping>> for &'a str
+        impl<'a, TMapping, TValue> From<&'a $wrapper_ty<TMapping, TValue>> for &'a str
         where
             TMapping: $mapping_ty,
+            TValue: AsRef<str>,
         {
-            fn from(wrapper: &'a $wrapper_ty<TMapping>) -> Self {
+            fn from(wrapper: &'a $wrapper_ty<TMapping, TValue>) -> Self {
                 wrapper.as_ref()
             }
         }
 
-        impl<'a, TMapping> From<$wrapper_ty<TMapping>> for std::borrow::Cow<'a, str>
+        impl<'a, TMapping, TValue> From<$wrapper_ty<TMapping, TValue>> for std::borrow::Cow<'a, str>
+        where
+            TMapping: $mapping_ty,
+            TValue: Into<std::borrow::Cow<'a, str>>,
+        {
+            fn from(wrapper: $wrapper_ty<TMapping, TValue>) -> Self {
+                wrapper.value.into()
+            }
+        }
+
+        impl<'a, TMapping> From<&'a str> for $wrapper_ty<TMapping, &'a str>
+        where
+            TMapping: $mapping_ty,
+        {
+            fn from(value: &'a str) -> Self {
+                $wrapper_ty::new(value)
+            }
+        }
+
+        impl<'a, TMapping> From<std::borrow::Cow<'a, str>>
+            for $wrapper_ty<TMapping, std::borrow::Cow<'a, str>>
         where
             TMapping: $mapping_ty,
         {
-            fn from(wrapper: $wrapper_ty<TMapping>) -> Self {
-                String::from(wrapper).into()
+            fn from(value: std::borrow::Cow<'a, str>) -> Self {
+                $wrapper_ty::new(value)
             }
         }
 
-        impl<TMapping> AsRef<str> for $wrapper_ty<TMapping>
+        impl<TMapping, TValue> AsRef<str> for $wrapper_ty<TMapping, TValue>
         where
             TMapping: $mapping_ty,
+            TValue: AsRef<str>,
         {
             fn as_ref(&self) -> &str {
-                &self.value
+                self.value.as_ref()
             }
         }
 
-        impl<'a, TMapping> PartialEq<&'a str> for $wrapper_ty<TMapping>
+        impl<'a, TMapping, TValue> PartialEq<&'a str> for $wrapper_ty<TMapping, TValue>
         where
             TMapping: $mapping_ty,
+            TValue: AsRef<str>,
         {
             fn eq(&self, other: &&'a str) -> bool {
-                PartialEq::eq(&self.value, *other)
+                PartialEq::eq(self.value.as_ref(), *other)
             }
 
             fn ne(&self, other: &&'a str) -> bool {
-                PartialEq::ne(&self.value, *other)
+                PartialEq::ne(self.value.as_ref(), *other)
             }
         }
 
-        impl<'a, TMapping> PartialEq<$wrapper_ty<TMapping>> for &'a str
+        impl<'a, TMapping, TValue> PartialEq<$wrapper_ty<TMapping, TValue>> for &'a str
         where
             TMapping: $mapping_ty,
+            TValue: AsRef<str>,
         {
-            fn eq(&self, other: &$wrapper_ty<TMapping>) -> bool {
-                PartialEq::eq(*self, &other.value)
+            fn eq(&self, other: &$wrapper_ty<TMapping, TValue>) -> bool {
+                PartialEq::eq(*self, other.value.as_ref())
             }
 
-            fn ne(&self, other: &$wrapper_ty<TMapping>) -> bool {
-                PartialEq::ne(*self, &other.value)
+            fn ne(&self, other: &$wrapper_ty<TMapping, TValue>) -> bool {
+                PartialEq::ne(*self, other.value.as_ref())
             }
         }
 
-        impl<TMapping> Serialize for $wrapper_ty<TMapping>
+        impl<TMapping, TValue> Serialize for $wrapper_ty<TMapping, TValue>
         where
             TMapping: $mapping_ty,
+            TValue: AsRef<str>,
         {
             fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
             where
                 S: Serializer,
             {
-                serializer.serialize_str(&self.value)
+                serializer.serialize_str(self.value.as_ref())
             }
         }
 
-        impl<'de, TMapping> Deserialize<'de> for $wrapper_ty<TMapping>
+        impl<'de, TMapping, TValue> Deserialize<'de> for $wrapper_ty<TMapping, TValue>
         where
             TMapping: $mapping_ty,
+            TValue: AsRef<str> + From<&'de str> + From<String>,
         {
-            fn deserialize<D>(deserializer: D) -> Result<$wrapper_ty<TMapping>, D::Error>
+            fn deserialize<D>(deserializer: D) -> Result<$wrapper_ty<TMapping, TValue>, D::Error>
             where
                 D: Deserializer<'de>,
             {
-                struct StringVisitor<TMapping> {
-                    _m: PhantomData<TMapping>,
+                struct StringVisitor<TMapping, TValue> {
+                    _m: PhantomData<(TMapping, TValue)>,
                 }
 
-                impl<'de, TMapping> Visitor<'de> for StringVisitor<TMapping>
+                impl<'de, TMapping, TValue> Visitor<'de> for StringVisitor<TMapping, TValue>
                 where
                     TMapping: $mapping_ty,
+                    TValue: AsRef<str> + From<&'de str> + From<String>,
                 {
-                    type Value = $wrapper_ty<TMapping>;
+                    type Value = $wrapper_ty<TMapping, TValue>;
 
                     fn expecting(
                         &self,
@@ -112,11 +147,28 @@ macro_rules! impl_string_type {
                         write!(formatter, "a json string")
                     }
 
-                    fn visit_str<E>(self, v: &str) -> Result<$wrapper_ty<TMapping>, E>
+                    fn visit_borrowed_str<E>(
+                        self,
+                        v: &'de str,
+                    ) -> Result<$wrapper_ty<TMapping, TValue>, E>
+                    where
+                        E: Error,
+                    {
+                        Ok($wrapper_ty::new(TValue::from(v)))
+                    }
+
+                    fn visit_str<E>(self, v: &str) -> Result<$wrapper_ty<TMapping, TValue>, E>
+                    where
+                        E: Error,
+                    {
+                        Ok($wrapper_ty::new(TValue::from(v.to_owned())))
+                    }
+
+                    fn visit_string<E>(self, v: String) -> Result<$wrapper_ty<TMapping, TValue>, E>
                     where
                         E: Error,
                     {
-                        Ok($wrapper_ty::new(v))
+                        Ok($wrapper_ty::new(TValue::from(v)))
                     }
                 }
 
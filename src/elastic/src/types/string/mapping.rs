@@ -12,6 +12,10 @@ use serde::{
     Serialize,
     Serializer,
 };
+use serde_json::{
+    Map,
+    Value,
+};
 use std::collections::BTreeMap;
 
 /** Default mapping for `String`. */
@@ -19,19 +23,107 @@ use std::collections::BTreeMap;
 pub struct DefaultStringMapping;
 impl TextMapping for DefaultStringMapping {
     fn fields() -> Option<BTreeMap<&'static str, StringField>> {
-        let mut fields = BTreeMap::new();
+        Some(keyword_sub_fields("keyword"))
+    }
+}
 
-        let keyword = KeywordFieldMapping {
-            ignore_above: Some(256),
-            ..Default::default()
-        };
+/**
+The sub-fields of the [`DefaultStringMapping`][DefaultStringMapping], with the `keyword` sub-field given the name `name`.
 
-        fields.insert("keyword", StringField::Keyword(keyword));
+Elasticsearch `2.x` conventions named the exact-match sub-field `raw` instead of `keyword`.
+Use this function in a custom mapping to keep that name while migrating:
 
-        Some(fields)
+```
+# use std::collections::BTreeMap;
+# use elastic::types::prelude::*;
+#[derive(Default)]
+struct RawStringMapping;
+impl TextMapping for RawStringMapping {
+    fn fields() -> Option<BTreeMap<&'static str, StringField>> {
+        Some(keyword_sub_fields("raw"))
     }
 }
 
+assert_eq!(Some("raw"), RawStringMapping::keyword_field());
+```
+
+[DefaultStringMapping]: struct.DefaultStringMapping.html
+*/
+pub fn keyword_sub_fields(name: &'static str) -> BTreeMap<&'static str, StringField> {
+    keyword_sub_fields_with_ignore_above(name, Some(DEFAULT_IGNORE_ABOVE))
+}
+
+/** The `ignore_above` of the `keyword` sub-field in the [`DefaultStringMapping`][DefaultStringMapping]. */
+pub const DEFAULT_IGNORE_ABOVE: u32 = 256;
+
+/**
+A `keyword` sub-field given the name `name`, that ignores values longer than `ignore_above` characters.
+
+Values longer than `ignore_above` aren't indexed in the sub-field, so they can't be matched exactly or aggregated on.
+Use this function in a custom mapping to change the limit of the [`DefaultStringMapping`][DefaultStringMapping],
+or pass `None` to index values of any length:
+
+```
+# use std::collections::BTreeMap;
+# use elastic::types::prelude::*;
+#[derive(Default)]
+struct LongStringMapping;
+impl TextMapping for LongStringMapping {
+    fn fields() -> Option<BTreeMap<&'static str, StringField>> {
+        Some(keyword_sub_fields_with_ignore_above("keyword", Some(1024)))
+    }
+}
+```
+
+[DefaultStringMapping]: struct.DefaultStringMapping.html
+*/
+pub fn keyword_sub_fields_with_ignore_above(
+    name: &'static str,
+    ignore_above: Option<u32>,
+) -> BTreeMap<&'static str, StringField> {
+    let mut fields = BTreeMap::new();
+
+    let keyword = KeywordFieldMapping {
+        ignore_above,
+        ..Default::default()
+    };
+
+    fields.insert(name, StringField::Keyword(keyword));
+
+    fields
+}
+
+/**
+A `term` query against the `keyword` sub-field of a `text` field.
+
+The name of the sub-field is read from the [`keyword_field`][TextMapping.keyword_field] of the mapping,
+so queries don't need to change when the sub-field is renamed.
+Returns `None` if the mapping doesn't have a `keyword` sub-field.
+
+```
+# #[macro_use] extern crate serde_json;
+# use elastic::types::prelude::*;
+# fn main() {
+let query = term_keyword::<DefaultStringMapping>("title", "Ferris").unwrap();
+
+assert_eq!(json!({ "term": { "title.keyword": "Ferris" } }), query);
+# }
+```
+
+[TextMapping.keyword_field]: ../text/mapping/trait.TextMapping.html#method.keyword_field
+*/
+pub fn term_keyword<TMapping>(field: &str, value: impl Into<Value>) -> Option<Value>
+where
+    TMapping: TextMapping,
+{
+    let keyword = TMapping::keyword_field()?;
+
+    let mut term = Map::new();
+    term.insert(format!("{}.{}", field, keyword), value.into());
+
+    Some(json!({ "term": term }))
+}
+
 /** The `index_options` parameter controls what information is added to the inverted index, for search and highlighting purposes. */
 #[derive(Debug, Clone, Copy)]
 pub enum IndexOptions {
@@ -84,6 +176,9 @@ pub enum StringField {
     Keyword(KeywordFieldMapping),
     /** A `text` sub field. */
     Text(TextFieldMapping),
+    /** An `icu_collation_keyword` sub field. */
+    #[cfg(feature = "icu")]
+    IcuCollationKeyword(IcuCollationKeywordFieldMapping),
 }
 
 impl Serialize for StringField {
@@ -96,6 +191,8 @@ impl Serialize for StringField {
             StringField::Completion(m) => m.serialize(serializer),
             StringField::Keyword(m) => m.serialize(serializer),
             StringField::Text(m) => m.serialize(serializer),
+            #[cfg(feature = "icu")]
+            StringField::IcuCollationKeyword(m) => m.serialize(serializer),
         }
     }
 }
@@ -159,6 +256,119 @@ impl Serialize for ElasticTokenCountFieldMapping {
     }
 }
 
+/**
+A multi-field string mapping for an [ICU collation keyword](https://www.elastic.co/guide/en/elasticsearch/plugins/master/analysis-icu-collation-keyword-field.html).
+
+Collation keywords sort values using the rules of a particular language,
+so they're useful as a sort field for `text` in languages with accented characters.
+They require the `analysis-icu` plugin to be installed on the cluster, and the `icu` feature to be enabled.
+
+# Examples
+
+Sort a `text` field using German collation rules:
+
+```
+# use std::collections::BTreeMap;
+# use elastic::types::prelude::*;
+#[derive(Default)]
+struct NameMapping;
+impl TextMapping for NameMapping {
+    fn fields() -> Option<BTreeMap<&'static str, StringField>> {
+        let mut fields = BTreeMap::new();
+
+        fields.insert(
+            "sort",
+            StringField::IcuCollationKeyword(IcuCollationKeywordFieldMapping {
+                language: Some("de"),
+                strength: Some(IcuCollationStrength::Primary),
+                ..Default::default()
+            }),
+        );
+
+        Some(fields)
+    }
+}
+```
+*/
+#[cfg(feature = "icu")]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct IcuCollationKeywordFieldMapping {
+    /** The ISO 639 language code of the collation rules, like `de`. */
+    pub language: Option<&'static str>,
+    /** The ISO 3166 country code of the collation rules, like `DE`. */
+    pub country: Option<&'static str>,
+    /** The variant of the collation rules, like `@collation=phonebook`. */
+    pub variant: Option<&'static str>,
+    /** The differences between characters that are significant when comparing. Defaults to `tertiary`. */
+    pub strength: Option<IcuCollationStrength>,
+    /** Whether differences in case are significant at the `primary` and `secondary` strengths. */
+    pub case_level: Option<bool>,
+    /** Whether sequences of digits are sorted by their numeric value. Defaults to `false`. */
+    pub numeric: Option<bool>,
+    /** Whether the field should be searchable. Defaults to `true`. */
+    pub index: Option<bool>,
+    /** Whether the field should be stored on disk so it can be used for sorting. Defaults to `true`. */
+    pub doc_values: Option<bool>,
+    /** Values longer than this won't be indexed. */
+    pub ignore_above: Option<u32>,
+}
+
+#[cfg(feature = "icu")]
+impl Serialize for IcuCollationKeywordFieldMapping {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("mapping", 10)?;
+
+        state.serialize_field("type", "icu_collation_keyword")?;
+
+        ser_field!(state, "language", self.language);
+        ser_field!(state, "country", self.country);
+        ser_field!(state, "variant", self.variant);
+        ser_field!(state, "strength", self.strength);
+        ser_field!(state, "case_level", self.case_level);
+        ser_field!(state, "numeric", self.numeric);
+        ser_field!(state, "index", self.index);
+        ser_field!(state, "doc_values", self.doc_values);
+        ser_field!(state, "ignore_above", self.ignore_above);
+
+        state.end()
+    }
+}
+
+/** The differences between characters that are significant for ICU collation. */
+#[cfg(feature = "icu")]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IcuCollationStrength {
+    /** Only differences in base letters are significant, so `a` and `á` are equal. */
+    Primary,
+    /** Differences in accents are also significant. */
+    Secondary,
+    /** Differences in case are also significant. */
+    Tertiary,
+    /** Differences in punctuation are also significant. */
+    Quaternary,
+    /** All differences are significant. */
+    Identical,
+}
+
+#[cfg(feature = "icu")]
+impl Serialize for IcuCollationStrength {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(match *self {
+            IcuCollationStrength::Primary => "primary",
+            IcuCollationStrength::Secondary => "secondary",
+            IcuCollationStrength::Tertiary => "tertiary",
+            IcuCollationStrength::Quaternary => "quaternary",
+            IcuCollationStrength::Identical => "identical",
+        })
+    }
+}
+
 /** A multi-field string mapping for a [completion suggester](https://www.elastic.co/guide/en/elasticsearch/reference/master/search-suggesters-completion.html#search-suggesters-completion). */
 #[derive(Debug, Default, Clone, Copy)]
 pub struct ElasticCompletionFieldMapping {
@@ -357,6 +567,30 @@ mod tests {
         }
     }
 
+    #[derive(Default, Clone)]
+    pub struct SearchAnalyzerTextMapping;
+    impl TextMapping for SearchAnalyzerTextMapping {
+        fn search_analyzer() -> Option<&'static str> {
+            Some(builtin::WHITESPACE)
+        }
+    }
+
+    #[derive(Default, Clone)]
+    pub struct AutocompleteTextMapping;
+    impl TextMapping for AutocompleteTextMapping {
+        fn analyzer() -> Option<&'static str> {
+            Some("autocomplete")
+        }
+
+        fn search_analyzer() -> Option<&'static str> {
+            Some(builtin::STANDARD)
+        }
+
+        fn search_quote_analyzer() -> Option<&'static str> {
+            Some(builtin::LanguageAnalyzer::English.name())
+        }
+    }
+
     #[derive(Default, Clone)]
     pub struct MyKeywordMapping;
     impl KeywordMapping for MyKeywordMapping {
@@ -450,6 +684,26 @@ mod tests {
         assert_eq!(expected, ser);
     }
 
+    #[test]
+    fn keyword_field_names() {
+        assert_eq!(Some("keyword"), DefaultStringMapping::keyword_field());
+        assert_eq!(Some("raw"), MyTextMapping::keyword_field());
+        assert_eq!(None, DefaultTextMapping::keyword_field());
+    }
+
+    #[test]
+    fn term_keyword_uses_keyword_field() {
+        assert_eq!(
+            Some(json!({ "term": { "title.keyword": "Ferris" } })),
+            term_keyword::<DefaultStringMapping>("title", "Ferris")
+        );
+        assert_eq!(
+            Some(json!({ "term": { "title.raw": "Ferris" } })),
+            term_keyword::<MyTextMapping>("title", "Ferris")
+        );
+        assert_eq!(None, term_keyword::<DefaultTextMapping>("title", "Ferris"));
+    }
+
     #[test]
     fn serialise_text_mapping_default() {
         let ser = serde_json::to_value(&field::serialize(DefaultTextMapping)).unwrap();
@@ -501,6 +755,32 @@ mod tests {
         assert_eq!(expected, ser);
     }
 
+    #[test]
+    fn serialise_text_mapping_search_analyzer_without_analyzer() {
+        let ser = serde_json::to_value(&field::serialize(SearchAnalyzerTextMapping)).unwrap();
+
+        let expected = json!({
+            "type": "text",
+            "search_analyzer": "whitespace"
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn serialise_text_mapping_search_analyzers_override_analyzer() {
+        let ser = serde_json::to_value(&field::serialize(AutocompleteTextMapping)).unwrap();
+
+        let expected = json!({
+            "type": "text",
+            "analyzer": "autocomplete",
+            "search_analyzer": "standard",
+            "search_quote_analyzer": "english"
+        });
+
+        assert_eq!(expected, ser);
+    }
+
     #[test]
     fn serialise_keyword_mapping_default() {
         let ser = serde_json::to_value(&field::serialize(DefaultKeywordMapping)).unwrap();
@@ -547,6 +827,18 @@ mod tests {
         assert_eq!(expected, ser);
     }
 
+    #[test]
+    fn serialise_keyword_mapping_case_insensitive() {
+        let ser = serde_json::to_value(&field::serialize(CaseInsensitiveKeywordMapping)).unwrap();
+
+        let expected = json!({
+            "type": "keyword",
+            "normalizer": "lowercase_normalizer"
+        });
+
+        assert_eq!(expected, ser);
+    }
+
     #[test]
     fn serialise_mapping_field_filter() {
         let filter = FieldDataFrequencyFilter {
@@ -632,6 +924,8 @@ mod tests {
             index: Some(false),
             index_options: Some(IndexOptions::Docs),
             norms: Some(true),
+            normalizer: Some("my_normalizer"),
+            null_value: Some("my string"),
             store: Some(true),
             search_analyzer: Some("my_analyzer"),
             similarity: Some("my_analyzer"),
@@ -648,6 +942,8 @@ mod tests {
             "index":false,
             "index_options":"docs",
             "norms":true,
+            "normalizer":"my_normalizer",
+            "null_value":"my string",
             "store":true,
             "search_analyzer":"my_analyzer",
             "similarity":"my_analyzer"
@@ -754,4 +1050,29 @@ mod tests {
 
         assert_eq!(expected, ser);
     }
+
+    #[cfg(feature = "icu")]
+    #[test]
+    fn serialise_mapping_icu_collation_keyword_field() {
+        let mapping = StringField::IcuCollationKeyword(IcuCollationKeywordFieldMapping {
+            language: Some("de"),
+            country: Some("DE"),
+            variant: Some("@collation=phonebook"),
+            strength: Some(IcuCollationStrength::Primary),
+            numeric: Some(true),
+            ..Default::default()
+        });
+        let ser = serde_json::to_value(&mapping).unwrap();
+
+        let expected = json!({
+            "type": "icu_collation_keyword",
+            "language": "de",
+            "country": "DE",
+            "variant": "@collation=phonebook",
+            "strength": "primary",
+            "numeric": true
+        });
+
+        assert_eq!(expected, ser);
+    }
 }
@@ -68,6 +68,52 @@ impl KeywordFieldType<DefaultKeywordMapping> for MyKeywordField {}
 # }
 ```
 
+Map a `keyword` that borrows its value, so a document can be serialised without copying its strings:
+
+```
+#[macro_use] extern crate serde_derive;
+# fn main() {
+# use elastic::types::prelude::*;
+#[derive(Serialize)]
+struct MyType<'a> {
+    pub field: Keyword<DefaultKeywordMapping, &'a str>
+}
+
+let line = String::from("some borrowed value");
+let doc = MyType { field: Keyword::new(line.as_str()) };
+# }
+```
+
+With the `uuid` feature, a `uuid::Uuid` is mapped as a `keyword` and serialised as a hyphenated string.
+Map it as `text` instead with `#[elastic(mapping = "DefaultTextMapping")]`:
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# #[cfg(feature = "uuid")]
+# fn main() {
+# extern crate uuid;
+# use elastic::types::prelude::*;
+use uuid::Uuid;
+
+#[derive(Serialize, ElasticType)]
+pub struct MyType {
+    pub id: Uuid,
+    #[elastic(mapping = "DefaultTextMapping")]
+    pub correlation_id: Uuid,
+}
+# }
+# #[cfg(not(feature = "uuid"))]
+# fn main() {}
+```
+
+# Borrowed strings
+
+`Keyword` and `Text` own a `String` by default, but can wrap any type that implements `AsRef<str>`,
+like `&'a str`, `Cow<'a, str>` or `Arc<str>`.
+A `Cow<'a, str>` can also be deserialised, and will borrow from the input where it can.
+Use [`into_owned`](keyword/struct.Keyword.html#method.into_owned) to convert a borrowed string into an owned one.
+
 # Links
 
 - [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/string.html)
@@ -103,6 +149,7 @@ pub mod prelude {
 #[cfg(test)]
 mod tests {
     use serde_json;
+    use std::borrow::Cow;
 
     use crate::types::prelude::*;
 
@@ -125,6 +172,35 @@ mod tests {
         assert!(takes_custom_mapping(Keyword::remap(string)));
     }
 
+    #[cfg(feature = "uuid")]
+    #[test]
+    fn serialise_uuid_as_keyword() {
+        use uuid::Uuid;
+
+        #[derive(Serialize, ElasticType)]
+        #[elastic(crate_root = "crate::types")]
+        struct UuidType {
+            id: Uuid,
+            #[elastic(mapping = "DefaultTextMapping")]
+            text_id: Uuid,
+        }
+
+        let id = Uuid::parse_str("936da01f-9abd-4d9d-80c7-02af85c822a8").unwrap();
+
+        let ser = serde_json::to_value(&UuidType { id, text_id: id }).unwrap();
+        let mapping = serde_json::to_value(&UuidType::index_mapping()).unwrap();
+
+        assert_eq!(
+            json!({
+                "id": "936da01f-9abd-4d9d-80c7-02af85c822a8",
+                "text_id": "936da01f-9abd-4d9d-80c7-02af85c822a8"
+            }),
+            ser
+        );
+        assert_eq!(json!("keyword"), mapping["properties"]["id"]["type"]);
+        assert_eq!(json!("text"), mapping["properties"]["text_id"]["type"]);
+    }
+
     #[test]
     fn serialise_elastic_keyword() {
         let string: Keyword<DefaultKeywordMapping> = Keyword::new("my string");
@@ -142,6 +218,30 @@ mod tests {
         assert_eq!("my string", string);
     }
 
+    #[test]
+    fn serialise_borrowed_keyword() {
+        let value = String::from("my string");
+        let string: Keyword<DefaultKeywordMapping, &str> = Keyword::new(value.as_str());
+
+        let ser = serde_json::to_string(&string).unwrap();
+
+        assert_eq!(r#""my string""#, ser);
+        assert_eq!("my string", string.into_owned());
+    }
+
+    #[test]
+    fn deserialise_cow_keyword_borrows() {
+        let json = String::from(r#""my string""#);
+        let string: Keyword<DefaultKeywordMapping, Cow<str>> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!("my string", string);
+
+        match Cow::from(string) {
+            Cow::Borrowed(_) => (),
+            Cow::Owned(_) => panic!("expected a borrowed string"),
+        }
+    }
+
     #[test]
     fn can_change_text_mapping() {
         fn takes_custom_mapping(_: Text<MyTextMapping>) -> bool {
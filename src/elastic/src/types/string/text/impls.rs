@@ -14,17 +14,27 @@ use serde::{
     Serializer,
 };
 use std::{
-    borrow::Borrow,
+    borrow::{
+        Borrow,
+        Cow,
+    },
     marker::PhantomData,
+    rc::Rc,
+    sync::Arc,
 };
 
 impl TextFieldType<DefaultStringMapping> for String {}
 impl<'a> TextFieldType<DefaultStringMapping> for &'a str {}
+impl<'a> TextFieldType<DefaultStringMapping> for Cow<'a, str> {}
+impl TextFieldType<DefaultStringMapping> for Box<str> {}
+impl TextFieldType<DefaultStringMapping> for Rc<str> {}
+impl TextFieldType<DefaultStringMapping> for Arc<str> {}
 
 /**
 An Elasticsearch `text` field with a mapping.
 
 Where the mapping isn't custom, you can use the standard library `String` instead.
+The value is a `String` by default, but can be any type that implements `AsRef<str>`, like a borrowed `&str` or `Cow<str>`.
 
 # Examples
 
@@ -38,17 +48,18 @@ let string = Text::<DefaultTextMapping>::new("my string value");
 ```
 */
 #[derive(Debug, Clone, Default, PartialEq)]
-pub struct Text<TMapping>
+pub struct Text<TMapping, TValue = String>
 where
     TMapping: TextMapping,
 {
-    value: String,
+    value: TValue,
     _m: PhantomData<TMapping>,
 }
 
-impl<TMapping> Text<TMapping>
+impl<TMapping, TValue> Text<TMapping, TValue>
 where
     TMapping: TextMapping,
+    TValue: AsRef<str>,
 {
     /**
     Creates a new `Text` with the given mapping.
@@ -64,9 +75,9 @@ where
     let string = Text::<DefaultTextMapping>::new("my string");
     ```
     */
-    pub fn new<I>(string: I) -> Text<TMapping>
+    pub fn new<I>(string: I) -> Text<TMapping, TValue>
     where
-        I: Into<String>,
+        I: Into<TValue>,
     {
         Text {
             value: string.into(),
@@ -75,12 +86,17 @@ where
     }
 
     /** Change the mapping of this string. */
-    pub fn remap<TNewMapping>(text: Text<TMapping>) -> Text<TNewMapping>
+    pub fn remap<TNewMapping>(text: Text<TMapping, TValue>) -> Text<TNewMapping, TValue>
     where
         TNewMapping: TextMapping,
     {
         Text::new(text.value)
     }
+
+    /** Copy a borrowed string into an owned one. */
+    pub fn into_owned(self) -> Text<TMapping> {
+        Text::new(self.value.as_ref())
+    }
 }
 
 impl_string_type!(Text, TextMapping, TextFieldType);
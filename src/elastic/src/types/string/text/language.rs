@@ -0,0 +1,312 @@
+/*!
+Multi-language `text` fields.
+
+A multi-language field is a `text` field with a sub-field for each language it could be written in,
+like `title.en` and `title.de`, where each sub-field uses the builtin analyzer for its language.
+Queries can then be expanded across all of the sub-fields so the query terms are analysed for each language.
+
+# Examples
+
+Map a `title` with English and German sub-fields:
+
+```
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# #[macro_use] extern crate serde_json;
+# use std::collections::BTreeMap;
+# use elastic::types::prelude::*;
+# use elastic::types::string::text::language::*;
+fn title_languages() -> MultiLanguageText {
+    MultiLanguageText::new(vec![Language::English, Language::German])
+}
+
+#[derive(Default)]
+struct TitleMapping;
+impl TextMapping for TitleMapping {
+    fn fields() -> Option<BTreeMap<&'static str, StringField>> {
+        Some(title_languages().fields())
+    }
+}
+
+#[derive(Serialize, ElasticType)]
+struct Article {
+    pub title: Text<TitleMapping>,
+}
+
+# fn main() {
+let query = json!({
+    "query": title_languages().query("title", "running shoes")
+});
+# let mapping = serde_json::to_value(&Article::index_mapping()).unwrap();
+# let expected = json!({
+#     "properties": {
+#         "title": {
+#             "type": "text",
+#             "fields": {
+#                 "de": { "type": "text", "analyzer": "german" },
+#                 "en": { "type": "text", "analyzer": "english" }
+#             }
+#         }
+#     }
+# });
+# assert_eq!(expected, mapping);
+# }
+```
+
+# Links
+
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/analysis-lang-analyzer.html)
+*/
+
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+use super::mapping::TextFieldMapping;
+use crate::types::string::mapping::StringField;
+
+/**
+A language with a builtin Elasticsearch analyzer.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Language {
+    /** Arabic, analysed with the `arabic` analyzer. */
+    Arabic,
+    /** Chinese, analysed with the `cjk` analyzer. */
+    Chinese,
+    /** Czech, analysed with the `czech` analyzer. */
+    Czech,
+    /** Danish, analysed with the `danish` analyzer. */
+    Danish,
+    /** Dutch, analysed with the `dutch` analyzer. */
+    Dutch,
+    /** English, analysed with the `english` analyzer. */
+    English,
+    /** Finnish, analysed with the `finnish` analyzer. */
+    Finnish,
+    /** French, analysed with the `french` analyzer. */
+    French,
+    /** German, analysed with the `german` analyzer. */
+    German,
+    /** Greek, analysed with the `greek` analyzer. */
+    Greek,
+    /** Hindi, analysed with the `hindi` analyzer. */
+    Hindi,
+    /** Hungarian, analysed with the `hungarian` analyzer. */
+    Hungarian,
+    /** Indonesian, analysed with the `indonesian` analyzer. */
+    Indonesian,
+    /** Italian, analysed with the `italian` analyzer. */
+    Italian,
+    /** Japanese, analysed with the `cjk` analyzer. */
+    Japanese,
+    /** Korean, analysed with the `cjk` analyzer. */
+    Korean,
+    /** Norwegian, analysed with the `norwegian` analyzer. */
+    Norwegian,
+    /** Persian, analysed with the `persian` analyzer. */
+    Persian,
+    /** Portuguese, analysed with the `portuguese` analyzer. */
+    Portuguese,
+    /** Romanian, analysed with the `romanian` analyzer. */
+    Romanian,
+    /** Russian, analysed with the `russian` analyzer. */
+    Russian,
+    /** Spanish, analysed with the `spanish` analyzer. */
+    Spanish,
+    /** Swedish, analysed with the `swedish` analyzer. */
+    Swedish,
+    /** Thai, analysed with the `thai` analyzer. */
+    Thai,
+    /** Turkish, analysed with the `turkish` analyzer. */
+    Turkish,
+}
+
+impl Language {
+    /** The ISO 639-1 code for the language, which is used as the name of its sub-field. */
+    pub fn code(&self) -> &'static str {
+        match *self {
+            Language::Arabic => "ar",
+            Language::Chinese => "zh",
+            Language::Czech => "cs",
+            Language::Danish => "da",
+            Language::Dutch => "nl",
+            Language::English => "en",
+            Language::Finnish => "fi",
+            Language::French => "fr",
+            Language::German => "de",
+            Language::Greek => "el",
+            Language::Hindi => "hi",
+            Language::Hungarian => "hu",
+            Language::Indonesian => "id",
+            Language::Italian => "it",
+            Language::Japanese => "ja",
+            Language::Korean => "ko",
+            Language::Norwegian => "no",
+            Language::Persian => "fa",
+            Language::Portuguese => "pt",
+            Language::Romanian => "ro",
+            Language::Russian => "ru",
+            Language::Spanish => "es",
+            Language::Swedish => "sv",
+            Language::Thai => "th",
+            Language::Turkish => "tr",
+        }
+    }
+
+    /** The name of the builtin analyzer for the language. */
+    pub fn analyzer(&self) -> &'static str {
+        match *self {
+            Language::Arabic => "arabic",
+            Language::Chinese | Language::Japanese | Language::Korean => "cjk",
+            Language::Czech => "czech",
+            Language::Danish => "danish",
+            Language::Dutch => "dutch",
+            Language::English => "english",
+            Language::Finnish => "finnish",
+            Language::French => "french",
+            Language::German => "german",
+            Language::Greek => "greek",
+            Language::Hindi => "hindi",
+            Language::Hungarian => "hungarian",
+            Language::Indonesian => "indonesian",
+            Language::Italian => "italian",
+            Language::Norwegian => "norwegian",
+            Language::Persian => "persian",
+            Language::Portuguese => "portuguese",
+            Language::Romanian => "romanian",
+            Language::Russian => "russian",
+            Language::Spanish => "spanish",
+            Language::Swedish => "swedish",
+            Language::Thai => "thai",
+            Language::Turkish => "turkish",
+        }
+    }
+}
+
+/**
+A set of languages for a multi-language `text` field.
+
+The sub-fields can be used as the [`fields`][fields] of a `TextMapping`,
+and [`query`][query] expands a query across the field and all of its sub-fields.
+
+[fields]: #method.fields
+[query]: #method.query
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultiLanguageText {
+    languages: Vec<Language>,
+}
+
+impl MultiLanguageText {
+    /** Create a multi-language field for the given languages. Duplicate languages are ignored. */
+    pub fn new(languages: impl IntoIterator<Item = Language>) -> Self {
+        let mut deduped = Vec::new();
+
+        for language in languages {
+            if !deduped.contains(&language) {
+                deduped.push(language);
+            }
+        }
+
+        MultiLanguageText { languages: deduped }
+    }
+
+    /** The languages of the field. */
+    pub fn languages(&self) -> &[Language] {
+        &self.languages
+    }
+
+    /**
+    The `text` sub-fields for each language, keyed by the language code.
+
+    Each sub-field uses the builtin analyzer for its language.
+    */
+    pub fn fields(&self) -> BTreeMap<&'static str, StringField> {
+        self.languages
+            .iter()
+            .map(|language| {
+                let field = StringField::Text(TextFieldMapping {
+                    analyzer: Some(language.analyzer()),
+                    ..Default::default()
+                });
+
+                (language.code(), field)
+            })
+            .collect()
+    }
+
+    /**
+    The full paths of the sub-fields for a field with the given name, like `title.en`.
+    */
+    pub fn field_names(&self, field: &str) -> Vec<String> {
+        self.languages
+            .iter()
+            .map(|language| format!("{}.{}", field, language.code()))
+            .collect()
+    }
+
+    /**
+    A `multi_match` query that searches the field with the given name and all of its language sub-fields.
+
+    The query terms are analysed by the analyzer for each sub-field,
+    and the scores from each sub-field that matches are combined.
+    */
+    pub fn query(&self, field: &str, query: &str) -> Value {
+        let mut fields = vec![field.to_owned()];
+        fields.extend(self.field_names(field));
+
+        json!({
+            "multi_match": {
+                "query": query,
+                "type": "most_fields",
+                "fields": fields
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+
+    use super::*;
+
+    #[test]
+    fn serialise_language_fields() {
+        let languages = MultiLanguageText::new(vec![
+            Language::English,
+            Language::Japanese,
+            Language::English,
+        ]);
+
+        let ser = serde_json::to_value(&languages.fields()).unwrap();
+
+        let expected = json!({
+            "en": {
+                "type": "text",
+                "analyzer": "english"
+            },
+            "ja": {
+                "type": "text",
+                "analyzer": "cjk"
+            }
+        });
+
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn expand_query_across_languages() {
+        let languages = MultiLanguageText::new(vec![Language::English, Language::German]);
+
+        let expected = json!({
+            "multi_match": {
+                "query": "running shoes",
+                "type": "most_fields",
+                "fields": ["title", "title.en", "title.de"]
+            }
+        });
+
+        assert_eq!(expected, languages.query("title", "running shoes"));
+    }
+}
@@ -143,6 +143,22 @@ pub trait TextMapping {
         None
     }
 
+    /**
+    The name of the `keyword` sub-field that's used for exact matches on this field.
+
+    This is the first `keyword` sub-field in [`fields`](#method.fields) by default, like `keyword` for the `DefaultStringMapping`.
+    It's used by [`term_keyword`](../../mapping/fn.term_keyword.html) to build queries against the sub-field.
+    */
+    fn keyword_field() -> Option<&'static str> {
+        Self::fields()?
+            .into_iter()
+            .find(|&(_, ref field)| match *field {
+                StringField::Keyword(_) => true,
+                _ => false,
+            })
+            .map(|(name, _)| name)
+    }
+
     /**
     Whether or not the field value should be included in the `_all` field?
     Accepts true or false.
@@ -212,7 +228,8 @@ pub trait TextMapping {
 
     /**
     Which scoring algorithm or similarity should be used.
-    Defaults to `"classic"`, which uses TF/IDF.
+    Defaults to `"BM25"`.
+    Custom similarities can be defined in the `similarity` module of the index settings.
     */
     fn similarity() -> Option<&'static str> {
         None
@@ -5,6 +5,7 @@ Text fields are stored as a sequence of tokens, constructed based on the given `
 They're useful for blobs of content that can be sliced in various ways, like prose.
 */
 
+pub mod language;
 pub mod mapping;
 
 mod impls;
@@ -0,0 +1,247 @@
+/*!
+Index templates.
+
+An index template contains settings and mappings that are applied automatically to new indices with names
+that match the template's index patterns.
+
+# Examples
+
+Create a template that applies to indices whose names start with `logs-`:
+
+```no_run
+# #[macro_use] extern crate serde_derive;
+# #[macro_use] extern crate elastic_derive;
+# extern crate serde_json;
+# use elastic::prelude::*;
+# use elastic::types::template::IndexTemplate;
+# fn main() { run().unwrap() }
+# fn run() -> Result<(), Box<dyn ::std::error::Error>> {
+# let client = SyncClientBuilder::new().build()?;
+#[derive(Serialize, ElasticType)]
+struct LogEntry {
+    pub message: String,
+}
+
+let template = IndexTemplate::new("logs", "logs-*")
+    .order(1)
+    .settings(IndexSettings::new().number_of_shards(3))
+    .mapping::<LogEntry>();
+
+let body = serde_json::to_string(&template)?;
+
+client
+    .request(IndicesPutTemplateRequest::for_name(template.name().to_owned(), body))
+    .send()?;
+# Ok(())
+# }
+```
+
+# Links
+
+- [Elasticsearch Doc](https://www.elastic.co/guide/en/elasticsearch/reference/master/indices-templates.html)
+*/
+
+use serde::ser::{
+    Error as SerError,
+    Serialize,
+    SerializeMap,
+    SerializeStruct,
+    Serializer,
+};
+use serde_json::{
+    self,
+    Value,
+};
+
+use crate::types::{
+    document::{
+        DocumentType,
+        DEFAULT_DOC_TYPE,
+    },
+    settings::IndexSettings,
+};
+
+/**
+An index template that combines index patterns, settings and document mappings.
+
+Serialising an `IndexTemplate` produces the body for a put index template request.
+*/
+#[derive(Clone)]
+pub struct IndexTemplate {
+    name: String,
+    index_patterns: Vec<String>,
+    order: Option<i32>,
+    settings: Option<IndexSettings>,
+    mappings: Vec<(String, fn() -> Result<Value, serde_json::Error>)>,
+}
+
+impl IndexTemplate {
+    /** Create a new index template with the given name that applies to indices matching the given pattern. */
+    pub fn new(name: impl Into<String>, index_pattern: impl Into<String>) -> Self {
+        IndexTemplate {
+            name: name.into(),
+            index_patterns: vec![index_pattern.into()],
+            order: None,
+            settings: None,
+            mappings: Vec::new(),
+        }
+    }
+
+    /** Get the name of the template. */
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /** Apply the template to indices matching an additional pattern, like `metrics-*`. */
+    pub fn index_pattern(mut self, index_pattern: impl Into<String>) -> Self {
+        self.index_patterns.push(index_pattern.into());
+        self
+    }
+
+    /**
+    The order of the template.
+
+    When multiple templates match an index, templates with a higher order are merged over those with a lower order.
+    */
+    pub fn order(mut self, order: i32) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /** The settings for indices created from the template. */
+    pub fn settings(mut self, settings: IndexSettings) -> Self {
+        self.settings = Some(settings);
+        self
+    }
+
+    /**
+    Add the mapping for a document type to the template.
+
+    The mapping is added under the document's static type name, or `_doc` if the type name isn't static.
+    Adding a mapping for a type name that's already in the template will replace it.
+    */
+    pub fn mapping<TDocument>(mut self) -> Self
+    where
+        TDocument: DocumentType,
+    {
+        fn serialize_mapping<TDocument>() -> Result<Value, serde_json::Error>
+        where
+            TDocument: DocumentType,
+        {
+            serde_json::to_value(&TDocument::index_mapping())
+        }
+
+        let ty = TDocument::partial_static_ty()
+            .map(|ty| ty.to_string())
+            .unwrap_or_else(|| DEFAULT_DOC_TYPE.to_owned());
+
+        self.mappings.retain(|&(ref existing, _)| *existing != ty);
+        self.mappings.push((ty, serialize_mapping::<TDocument>));
+        self
+    }
+}
+
+struct Mappings<'a>(&'a [(String, fn() -> Result<Value, serde_json::Error>)]);
+
+impl<'a> Serialize for Mappings<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(Some(self.0.len()))?;
+
+        for &(ref ty, mapping) in self.0 {
+            state.serialize_entry(ty, &mapping().map_err(S::Error::custom)?)?;
+        }
+
+        state.end()
+    }
+}
+
+impl Serialize for IndexTemplate {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("template", 4)?;
+
+        state.serialize_field("index_patterns", &self.index_patterns)?;
+        ser_field!(state, "order", self.order);
+        ser_field!(state, "settings", self.settings.as_ref());
+        state.serialize_field("mappings", &Mappings(&self.mappings))?;
+
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+
+    use super::*;
+    use crate::types::prelude::*;
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types", ty = "log")]
+    pub struct LogEntry {
+        pub level: i32,
+    }
+
+    #[derive(Serialize, ElasticType)]
+    #[elastic(crate_root = "crate::types")]
+    pub struct Metric {
+        pub value: f64,
+    }
+
+    #[test]
+    fn serialise_template() {
+        let template = IndexTemplate::new("logs", "logs-*")
+            .index_pattern("metrics-*")
+            .order(1)
+            .settings(IndexSettings::new().number_of_shards(3))
+            .mapping::<LogEntry>()
+            .mapping::<Metric>();
+
+        let ser = serde_json::to_value(&template).unwrap();
+
+        let expected = json!({
+            "index_patterns": ["logs-*", "metrics-*"],
+            "order": 1,
+            "settings": {
+                "index": {
+                    "number_of_shards": 3
+                }
+            },
+            "mappings": {
+                "log": {
+                    "properties": {
+                        "level": {
+                            "type": "integer"
+                        }
+                    }
+                },
+                "_doc": {
+                    "properties": {
+                        "value": {
+                            "type": "double"
+                        }
+                    }
+                }
+            }
+        });
+
+        assert_eq!("logs", template.name());
+        assert_eq!(expected, ser);
+    }
+
+    #[test]
+    fn replace_mapping_for_same_type() {
+        let template = IndexTemplate::new("metrics", "metrics-*")
+            .mapping::<Metric>()
+            .mapping::<Metric>();
+
+        let ser = serde_json::to_value(&template).unwrap();
+
+        assert_eq!(1, ser["mappings"].as_object().unwrap().len());
+    }
+}
@@ -127,6 +127,15 @@ impl<'a> parse::DateFormatToken<'a> {
             DayOfYear => {
                 quote!(#crate_root::__derive::Item::Numeric(#crate_root::__derive::Numeric::Ordinal, #crate_root::__derive::Pad::Zero))
             }
+            WeekYear => {
+                quote!(#crate_root::__derive::Item::Numeric(#crate_root::__derive::Numeric::IsoYear, #crate_root::__derive::Pad::Zero))
+            }
+            WeekOfWeekYear => {
+                quote!(#crate_root::__derive::Item::Numeric(#crate_root::__derive::Numeric::IsoWeek, #crate_root::__derive::Pad::Zero))
+            }
+            DayOfWeek => {
+                quote!(#crate_root::__derive::Item::Numeric(#crate_root::__derive::Numeric::WeekdayFromMon, #crate_root::__derive::Pad::Zero))
+            }
             Hour => {
                 quote!(#crate_root::__derive::Item::Numeric(#crate_root::__derive::Numeric::Hour, #crate_root::__derive::Pad::Zero))
             }
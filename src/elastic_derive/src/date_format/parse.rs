@@ -9,6 +9,9 @@ pub enum DateFormatToken<'a> {
     Month,
     DayOfMonth,
     DayOfYear,
+    WeekYear,
+    WeekOfWeekYear,
+    DayOfWeek,
     Hour,
     Minute,
     Second,
@@ -33,6 +36,9 @@ named!(tokens(&[u8]) -> Vec<DateFormatToken>,
             month |
             day_of_month |
             day_of_year |
+            week_year |
+            week_of_week_year |
+            day_of_week |
             hour |
             minute |
             second |
@@ -76,6 +82,30 @@ named!(day_of_year(&[u8]) -> DateFormatToken,
     )
 );
 
+/* Parse `xxxx` as a 4 digit ISO week-based year. */
+named!(week_year(&[u8]) -> DateFormatToken,
+    do_parse!(
+        count!(char!('x'), 4) >>
+        (DateFormatToken::WeekYear)
+    )
+);
+
+/* Parse `ww` as a 2 digit ISO week of the week-based year. */
+named!(week_of_week_year(&[u8]) -> DateFormatToken,
+    do_parse!(
+        count!(char!('w'), 2) >>
+        (DateFormatToken::WeekOfWeekYear)
+    )
+);
+
+/* Parse `e` as a 1 digit day of week, starting from Monday. */
+named!(day_of_week(&[u8]) -> DateFormatToken,
+    do_parse!(
+        char!('e') >>
+        (DateFormatToken::DayOfWeek)
+    )
+);
+
 /* Parse `HH` as a 2 digit hour of day (24hr). */
 named!(hour(&[u8]) -> DateFormatToken,
     do_parse!(
@@ -193,6 +223,21 @@ mod tests {
         assert_parse(b"yyyyDDD", vec![Year, DayOfYear]);
     }
 
+    #[test]
+    fn parse_week_date() {
+        assert_parse(
+            b"xxxx-'W'ww-e",
+            vec![
+                WeekYear,
+                Delim("-"),
+                Escaped("W"),
+                WeekOfWeekYear,
+                Delim("-"),
+                DayOfWeek,
+            ],
+        );
+    }
+
     #[test]
     fn parse_date_hour_minute_second_millis() {
         assert_parse(
@@ -4,11 +4,13 @@ use super::{
     expect_name_value,
     get_elastic_meta_items,
     get_ident_from_lit,
+    get_str_from_lit,
     get_tokens_from_lit,
 };
 use quote::Tokens;
 use serde_derive_internals::{
     self,
+    ast as serde_ast,
     attr as serde_attr,
 };
 use syn;
@@ -19,13 +21,24 @@ struct ElasticDocumentMapping {
     impl_block: Tokens,
 }
 
+struct ElasticFieldNames {
+    definition: Tokens,
+    impl_block: Tokens,
+}
+
+struct ElasticFieldMapping {
+    ty: Tokens,
+    definition: Tokens,
+}
+
 /**
 Derive `DocumentType` for the given input.
 
 The input must satisfy the following rules:
 
 - It must be a struct.
-- The structs field types must implement `FieldType` (or be ignored).
+- The structs field types must implement `FieldType` (or be ignored),
+unless they supply a mapping with `#[elastic(mapping="<ident>")]`.
 - A mapping type supplied by `#[elastic(mapping="<ident>")]` must implement `DocumentMapping`,
 but not `PropertiesMapping`.
 */
@@ -33,6 +46,11 @@ pub fn expand_derive(
     crate_root: Tokens,
     input: &syn::MacroInput,
 ) -> Result<Vec<Tokens>, DeriveElasticTypeError> {
+    // C-like enums are mapped as keywords
+    if let syn::Body::Enum(_) = input.body {
+        return expand_enum_derive(crate_root, input);
+    }
+
     // Annotatable item for a struct with struct fields
     let fields = match input.body {
         syn::Body::Struct(ref data) => match *data {
@@ -44,29 +62,45 @@ pub fn expand_derive(
 
     let fields = fields.ok_or(DeriveElasticTypeError::InvalidInput)?;
 
-    // Get the serializable fields
+    // Get the serializable fields, renamed by any container-level `serde` rule
+    let container = get_ser_container(input);
+
     let fields: Vec<(syn::Ident, &syn::Field)> = fields
         .iter()
-        .map(|f| get_ser_field(f))
+        .map(|f| get_ser_field(f, container.as_ref()))
         .filter(|f| f.is_some())
         .map(|f| f.unwrap())
         .collect();
 
-    let mapping = get_mapping(&crate_root, input);
+    let mapping = get_mapping(&crate_root, input, &fields, container.as_ref());
 
     let doc_ty_impl_block = get_doc_ty_impl_block(&crate_root, input, &fields, &mapping.ident);
 
-    let props_impl_block = get_props_impl_block(&crate_root, &input.ident, &fields);
+    let props_impl_block = get_props_impl_block(&crate_root, input, &fields);
+
+    let defaults_impl_block = get_defaults_impl_block(&input.ident, &fields);
+
+    let field_mappings_impl_block =
+        get_field_mappings_impl_block(&crate_root, &input.ident, &fields);
+
+    let optional_fields_impl_block =
+        get_optional_fields_impl_block(&crate_root, &input.ident, &fields);
+
+    let field_names = get_field_names(&crate_root, input, &fields);
 
     let dummy_wrapper = syn::Ident::new(format!("_IMPL_EASTIC_TYPE_FOR_{}", input.ident));
 
     let mapping_definition = &mapping.definition;
     let mapping_impl_block = &mapping.impl_block;
+    let field_names_definition = field_names.as_ref().map(|names| &names.definition);
+    let field_names_impl_block = field_names.as_ref().map(|names| &names.impl_block);
 
     Ok(vec![quote!(
         #[allow(missing_docs)]
         #mapping_definition
 
+        #field_names_definition
+
         #[allow(non_upper_case_globals, dead_code, unused_variables)]
         const #dummy_wrapper: () = {
             #mapping_impl_block
@@ -74,11 +108,132 @@ pub fn expand_derive(
             #doc_ty_impl_block
 
             #props_impl_block
+
+            #field_mappings_impl_block
+
+            #defaults_impl_block
+
+            #optional_fields_impl_block
+
+            #field_names_impl_block
         };
     )])
 }
 
-fn get_mapping(crate_root: &Tokens, input: &syn::MacroInput) -> ElasticDocumentMapping {
+/**
+Derive `KeywordFieldType` for the given enum input.
+
+The input must satisfy the following rules:
+
+- All of its variants must be units.
+- A mapping type supplied by `#[elastic(mapping="<ident>")]` must implement `KeywordMapping`.
+
+The serialised names of the variants are returned as the `values` of the field,
+so they're listed in the `_meta` of documents that contain it.
+*/
+fn expand_enum_derive(
+    crate_root: Tokens,
+    input: &syn::MacroInput,
+) -> Result<Vec<Tokens>, DeriveElasticTypeError> {
+    let variants = get_ser_variants(input).ok_or(DeriveElasticTypeError::InvalidInput)?;
+    let variants: Vec<syn::Lit> = variants
+        .into_iter()
+        .map(|variant| syn::Lit::Str(variant, syn::StrStyle::Cooked))
+        .collect();
+
+    let enum_ty = &input.ident;
+    let mapping_ident = get_mapping_ident_from_attr(input);
+
+    let (mapping, mapping_definition, mapping_impl_block) = match mapping_ident {
+        Some(mapping) => (mapping, Tokens::new(), Tokens::new()),
+        None => {
+            let mapping = syn::Ident::from(format!("{}Mapping", input.ident));
+            let vis = &input.vis;
+
+            let definition = quote!(
+                #[derive(Default, Clone, Copy, Debug)]
+                #vis struct #mapping;
+            );
+
+            let impl_block = quote!(
+                impl #crate_root::__derive::KeywordMapping for #mapping { }
+            );
+
+            (mapping, definition, impl_block)
+        }
+    };
+
+    let dummy_wrapper = syn::Ident::new(format!("_IMPL_EASTIC_TYPE_FOR_{}", input.ident));
+
+    Ok(vec![quote!(
+        #[allow(missing_docs)]
+        #mapping_definition
+
+        #[allow(non_upper_case_globals, dead_code, unused_variables)]
+        const #dummy_wrapper: () = {
+            #mapping_impl_block
+
+            impl #crate_root::__derive::KeywordFieldType<#mapping> for #enum_ty {
+                fn values() -> ::std::option::Option<&'static [&'static str]> {
+                    Some(&[#(#variants),*])
+                }
+            }
+        };
+    )])
+}
+
+// Get the mapping ident supplied by an #[elastic(mapping = "<ident>")] attribute
+fn get_mapping_ident_from_attr(item: &syn::MacroInput) -> Option<syn::Ident> {
+    let val = get_elastic_meta_items(&item.attrs);
+
+    let val = val
+        .iter()
+        .filter_map(|meta| expect_name_value("mapping", &meta))
+        .next();
+
+    val.and_then(|v| get_ident_from_lit(v).ok())
+}
+
+// Get the serialised names of the variants of a C-like enum, respecting `serde` renames
+fn get_ser_variants(input: &syn::MacroInput) -> Option<Vec<String>> {
+    let ctxt = serde_derive_internals::Ctxt::new();
+    let container = serde_ast::Container::from_ast(&ctxt, input);
+
+    // If the `serde` parse fails, return `None` and let `serde` panic later
+    if ctxt.check().is_err() {
+        return None;
+    }
+
+    let variants = match container.body {
+        serde_ast::Body::Enum(variants) => variants,
+        _ => return None,
+    };
+
+    // Only enums where every variant is a unit can be mapped as a keyword
+    let is_c_like = variants.iter().all(|variant| match variant.style {
+        serde_ast::Style::Unit => true,
+        _ => false,
+    });
+
+    if !is_c_like {
+        return None;
+    }
+
+    Some(
+        variants
+            .iter()
+            .filter(|variant| !variant.attrs.skip_serializing())
+            .map(|variant| variant.attrs.name().serialize_name())
+            .collect(),
+    )
+}
+
+fn get_mapping(
+    crate_root: &Tokens,
+    input: &syn::MacroInput,
+    fields: &[(syn::Ident, &syn::Field)],
+    container: Option<&serde_attr::Container>,
+) -> ElasticDocumentMapping {
     // Define a struct for the mapping with a few defaults
     fn define_mapping(vis: &syn::Visibility, name: &syn::Ident) -> Tokens {
         quote!(
@@ -92,16 +247,63 @@ fn get_mapping(crate_root: &Tokens, input: &syn::MacroInput) -> ElasticDocumentM
         syn::Ident::from(format!("{}Mapping", item.ident))
     }
 
-    // Get the mapping ident supplied by an #[elastic()] attribute or create a default one
-    fn get_mapping_from_attr(item: &syn::MacroInput) -> Option<syn::Ident> {
+    // Get the datatype supplied by an #[elastic(data_type = "object")] attribute
+    fn get_data_type_from_attr(item: &syn::MacroInput) -> Option<Tokens> {
         let val = get_elastic_meta_items(&item.attrs);
 
         let val = val
             .iter()
-            .filter_map(|meta| expect_name_value("mapping", &meta))
+            .filter_map(|meta| expect_name_value("data_type", &meta))
             .next();
 
-        val.and_then(|v| get_ident_from_lit(v).ok())
+        val.map(|v| match get_str_from_lit(v) {
+            Ok("object") => quote!(OBJECT_DATATYPE),
+            Ok("nested") => quote!(NESTED_DATATYPE),
+            _ => panic!("data_type attributes must be of the form #[elastic(data_type = \"object\")] or #[elastic(data_type = \"nested\")]"),
+        })
+    }
+
+    // Get the `_source` excludes for fields with an #[elastic(source_exclude)] attribute
+    fn get_source_excludes(
+        fields: &[(syn::Ident, &syn::Field)],
+        container: Option<&serde_attr::Container>,
+    ) -> Vec<syn::Lit> {
+        fields
+            .iter()
+            .filter(|&&(_, field)| is_source_excluded(field))
+            .map(|&(ref name, field)| {
+                // The field is missing from the `_source` of hits, so it needs a value to deserialise without it
+                if !is_option(&field.ty) && !has_serde_default(field, container) {
+                    panic!(
+                        "source_exclude attributes are only valid on `Option` fields or fields with a #[serde(default)] attribute"
+                    );
+                }
+
+                syn::Lit::Str(name.as_ref().to_string(), syn::StrStyle::Cooked)
+            })
+            .collect()
+    }
+
+    // Get dynamic templates for the values of map fields with an #[elastic(map_values)] attribute
+    fn get_map_value_templates(
+        crate_root: &Tokens,
+        fields: &[(syn::Ident, &syn::Field)],
+    ) -> Vec<Tokens> {
+        fields
+            .iter()
+            .filter(|&&(_, field)| has_map_values(field))
+            .map(|&(ref name, field)| {
+                let ty = &field.ty;
+                let name = name.as_ref().to_string();
+                let path_match = format!("{}.*", name);
+
+                quote!(
+                    #crate_root::__derive::DynamicTemplate::new(#name)
+                        .path_match(#path_match)
+                        .map_values::<#ty, _, _>()
+                )
+            })
+            .collect()
     }
 
     // Implement DocumentMapping for the mapping
@@ -109,15 +311,66 @@ fn get_mapping(crate_root: &Tokens, input: &syn::MacroInput) -> ElasticDocumentM
         crate_root: &Tokens,
         mapping: &syn::Ident,
         properties: &syn::Ident,
+        data_type: Option<Tokens>,
+        source_excludes: Vec<syn::Lit>,
+        map_value_templates: Vec<Tokens>,
     ) -> Tokens {
+        let data_type = data_type.map(|data_type| {
+            quote!(
+                fn data_type() -> &'static str {
+                    #crate_root::__derive::#data_type
+                }
+            )
+        });
+
+        let source = if source_excludes.is_empty() {
+            None
+        } else {
+            Some(quote!(
+                fn source() -> Option<#crate_root::__derive::SourceMapping> {
+                    Some(#crate_root::__derive::SourceMapping {
+                        excludes: Some(&[#(#source_excludes),*]),
+                        ..::std::default::Default::default()
+                    })
+                }
+            ))
+        };
+
+        let dynamic_templates = if map_value_templates.is_empty() {
+            None
+        } else {
+            Some(quote!(
+                fn dynamic_templates() -> Option<Vec<#crate_root::__derive::DynamicTemplate>> {
+                    Some(vec![#(#map_value_templates),*])
+                }
+            ))
+        };
+
         quote!(
             impl #crate_root::__derive::ObjectMapping for #mapping {
                 type Properties = #properties;
+
+                #data_type
+
+                #source
+
+                #dynamic_templates
             }
         )
     }
 
-    if let Some(ident) = get_mapping_from_attr(input) {
+    let source_excludes = get_source_excludes(fields, container);
+    let map_value_templates = get_map_value_templates(&crate_root, fields);
+
+    if let Some(ident) = get_mapping_ident_from_attr(input) {
+        if !source_excludes.is_empty() {
+            panic!("source_exclude attributes can't be used with a mapping supplied by #[elastic(mapping = \"<ident>\")], return the excludes from its `source` method instead");
+        }
+
+        if !map_value_templates.is_empty() {
+            panic!("map_values attributes can't be used with a mapping supplied by #[elastic(mapping = \"<ident>\")], return the templates from its `dynamic_templates` method instead");
+        }
+
         ElasticDocumentMapping {
             ident,
             definition: Tokens::new(),
@@ -126,7 +379,15 @@ fn get_mapping(crate_root: &Tokens, input: &syn::MacroInput) -> ElasticDocumentM
     } else {
         let ident = get_default_mapping(input);
         let definition = define_mapping(&input.vis, &ident);
-        let impl_block = impl_document_mapping(&crate_root, &ident, &input.ident);
+        let data_type = get_data_type_from_attr(input);
+        let impl_block = impl_document_mapping(
+            &crate_root,
+            &ident,
+            &input.ident,
+            data_type,
+            source_excludes,
+            map_value_templates,
+        );
 
         ElasticDocumentMapping {
             ident,
@@ -198,7 +459,10 @@ fn get_doc_ty_impl_block(
             let id = get_method_from_struct(item, "id")
                 .map(|id_expr| match id_expr {
                     MethodFromStruct::Expr(expr) => expr,
-                    _ => panic!("id attributes on a struct definition must be of the form #[id(expr = \"expression\")]"),
+                    MethodFromStruct::Literal(_) => match get_field_from_struct(item, "id") {
+                        Some(field) => quote!(&self . #field),
+                        None => panic!("id attributes on a struct definition must be of the form #[id = \"field\"] or #[id(expr = \"expression\")]"),
+                    },
                 })
                 .or_else(|| {
                     get_method_from_fields(fields, "id").map(|field| match field {
@@ -232,6 +496,44 @@ fn get_doc_ty_impl_block(
 
         let doc_ty = &item.ident;
 
+        let before_index = if get_elastic_meta_items(&item.attrs)
+            .iter()
+            .any(|meta| expect_ident("before_index", meta))
+        {
+            Some(quote!(
+                fn before_index_hook(&mut self) {
+                    #crate_root::__derive::BeforeIndex::before_index(self)
+                }
+            ))
+        } else {
+            None
+        };
+
+        let fill_null_values: Vec<Tokens> = fields
+            .iter()
+            .filter(|&&(_, field)| get_default_from_field(field).is_some())
+            .map(|&(ref name, field)| {
+                let lit = syn::Lit::Str(name.as_ref().to_string(), syn::StrStyle::Cooked);
+                let default_fn = get_default_fn_ident(field);
+
+                quote!(#crate_root::__derive::fill_null_value(source, #lit, &Self::#default_fn())?;)
+            })
+            .collect();
+
+        let fill_null_values = if fill_null_values.is_empty() {
+            None
+        } else {
+            Some(quote!(
+                fn fill_null_values(
+                    source: &mut #crate_root::__derive::Map<String, #crate_root::__derive::Value>,
+                ) -> ::std::result::Result<(), #crate_root::__derive::SerdeJsonError> {
+                    #(#fill_null_values)*
+
+                    Ok(())
+                }
+            ))
+        };
+
         let (partial_static_index, static_index_block) = if index_is_static {
             let method = quote!(
                 fn partial_static_index() -> ::std::option::Option<#crate_root::__derive::Index<'static>> {
@@ -292,6 +594,10 @@ fn get_doc_ty_impl_block(
             #partial_static_index
 
             #partial_static_ty
+
+            #before_index
+
+            #fill_null_values
         );
 
         MetadataBlock {
@@ -327,12 +633,29 @@ fn get_doc_ty_impl_block(
 // Implement PropertiesMapping for the mapping
 fn get_props_impl_block(
     crate_root: &Tokens,
-    props_ty: &syn::Ident,
+    input: &syn::MacroInput,
     fields: &[(syn::Ident, &syn::Field)],
 ) -> Tokens {
+    // Get the maximum depth supplied by an #[elastic(recursion_limit = 3)] attribute
+    fn get_recursion_limit_from_attr(item: &syn::MacroInput) -> Option<usize> {
+        let val = get_elastic_meta_items(&item.attrs);
+
+        let val = val
+            .iter()
+            .filter_map(|meta| expect_name_value("recursion_limit", &meta))
+            .next();
+
+        val.map(|v| match *v {
+            syn::Lit::Int(limit, _) if limit > 0 => limit as usize,
+            _ => panic!("recursion_limit attributes must be of the form #[elastic(recursion_limit = 3)] with a limit greater than 0"),
+        })
+    }
+
     // Get the serde serialisation statements for each of the fields on the type being derived
+    // The statements are kept in declaration order so the properties are always serialised in the same order
     fn get_field_ser_stmts(
         crate_root: &Tokens,
+        props_ty: &syn::Ident,
         fields: &[(syn::Ident, &syn::Field)],
     ) -> Vec<Tokens> {
         let fields: Vec<Tokens> = fields
@@ -342,55 +665,604 @@ fn get_props_impl_block(
                 let lit = syn::Lit::Str(name.as_ref().to_string(), syn::StrStyle::Cooked);
                 let ty = &field.ty;
 
-                quote!(#crate_root::__derive::field_ser::<#ty, _, _, _>(state, #lit)?;)
+                let mapping = get_mapping_from_field(field)
+                    .map(|mapping| quote!(#mapping))
+                    .or_else(|| {
+                        get_mapping_from_field_attrs(crate_root, props_ty, field)
+                            .map(|mapping| mapping.ty)
+                    });
+
+                match (mapping, get_default_from_field(field).is_some()) {
+                    (Some(mapping), true) => {
+                        let default_fn = get_default_fn_ident(field);
+
+                        quote!(#crate_root::__derive::mapping_ser_with_null_value::<#mapping, _, _, _>(state, #lit, &Self::#default_fn())?;)
+                    }
+                    (Some(mapping), false) => {
+                        quote!(#crate_root::__derive::mapping_ser::<#mapping, _, _>(state, #lit)?;)
+                    }
+                    (None, true) => {
+                        let default_fn = get_default_fn_ident(field);
+
+                        quote!(#crate_root::__derive::field_ser_with_null_value::<#ty, _, _, _, _>(state, #lit, &Self::#default_fn())?;)
+                    }
+                    (None, false) => {
+                        quote!(#crate_root::__derive::field_ser::<#ty, _, _, _>(state, #lit)?;)
+                    }
+                }
             })
             .collect();
 
         fields
     }
 
-    let stmts = get_field_ser_stmts(crate_root, fields);
+    // Get the statements that collect the values of fields that only take a fixed set of values
+    // Fields with a mapping supplied by an attribute aren't checked because their type might not implement `FieldType`
+    fn get_field_values_stmts(
+        crate_root: &Tokens,
+        props_ty: &syn::Ident,
+        fields: &[(syn::Ident, &syn::Field)],
+    ) -> Vec<Tokens> {
+        fields
+            .iter()
+            .filter(|&&(_, field)| {
+                get_mapping_from_field(field).is_none()
+                    && get_mapping_from_field_attrs(crate_root, props_ty, field).is_none()
+            })
+            .map(|&(ref name, field)| {
+                let lit = syn::Lit::Str(name.as_ref().to_string(), syn::StrStyle::Cooked);
+                let ty = &field.ty;
+
+                quote!(#crate_root::__derive::field_values::<#ty, _, _>(&mut values, #lit);)
+            })
+            .collect()
+    }
+
+    let props_ty = &input.ident;
+    let stmts = get_field_ser_stmts(crate_root, props_ty, fields);
     let stmts_len = stmts.len();
 
+    let values_stmts = get_field_values_stmts(crate_root, props_ty, fields);
+
+    let props_values = if values_stmts.is_empty() {
+        None
+    } else {
+        Some(quote!(
+            fn props_values() -> ::std::vec::Vec<(&'static str, &'static [&'static str])> {
+                let mut values = ::std::vec::Vec::new();
+
+                #(#values_stmts)*
+
+                values
+            }
+        ))
+    };
+
+    let recursion_limit = get_recursion_limit_from_attr(input);
+
+    // A type that contains itself would serialise its properties forever without a limit
+    if recursion_limit.is_none() {
+        let recursive_field = fields.iter().find(|&&(_, field)| {
+            get_mapping_from_field(field).is_none() && ty_contains_ident(&field.ty, props_ty)
+        });
+
+        if let Some(&(ref name, _)) = recursive_field {
+            panic!(
+                "the field `{}` contains the type `{}` itself, so it needs a #[elastic(recursion_limit = <depth>)] attribute on the type",
+                name, props_ty
+            );
+        }
+    }
+
+    let serialize_props = match recursion_limit {
+        Some(limit) => quote!(
+            thread_local!(static DEPTH: ::std::cell::Cell<usize> = ::std::cell::Cell::new(0));
+
+            #crate_root::__derive::props_ser_with_recursion_limit(&DEPTH, #limit, state, |state| {
+                #(#stmts)*
+                Ok(())
+            })
+        ),
+        None => quote!(
+            #(#stmts)*
+            Ok(())
+        ),
+    };
+
     quote!(
         impl #crate_root::__derive::PropertiesMapping for #props_ty {
             fn props_len() -> usize { #stmts_len }
 
             fn serialize_props<S>(state: &mut S) -> ::std::result::Result<(), S::Error>
                 where S: #crate_root::__derive::SerializeStruct {
-                #(#stmts)*
-                Ok(())
+                #serialize_props
             }
+
+            #props_values
+        }
+    )
+}
+
+// Define the mappings for fields with attributes like #[elastic(alias_of = "path")] or #[elastic(ignore_above = 512)]
+fn get_field_mappings_impl_block(
+    crate_root: &Tokens,
+    props_ty: &syn::Ident,
+    fields: &[(syn::Ident, &syn::Field)],
+) -> Tokens {
+    let definitions: Vec<Tokens> = fields
+        .iter()
+        .filter_map(|&(_, field)| get_mapping_from_field_attrs(crate_root, props_ty, field))
+        .map(|mapping| mapping.definition)
+        .collect();
+
+    quote!(
+        #(#definitions)*
+    )
+}
+
+// Implement default value functions for fields with an #[elastic(default = "expr")] attribute
+fn get_defaults_impl_block(props_ty: &syn::Ident, fields: &[(syn::Ident, &syn::Field)]) -> Tokens {
+    let default_fns: Vec<Tokens> = fields
+        .iter()
+        .filter_map(|&(_, field)| {
+            get_default_from_field(field).map(|expr| {
+                let ty = &field.ty;
+                let default_fn = get_default_fn_ident(field);
+
+                quote!(
+                    pub fn #default_fn() -> #ty {
+                        #expr
+                    }
+                )
+            })
+        })
+        .collect();
+
+    if default_fns.is_empty() {
+        return Tokens::new();
+    }
+
+    quote!(
+        impl #props_ty {
+            #(#default_fns)*
+        }
+    )
+}
+
+// Implement a function that returns a marker for each `Option` field, that can build `exists` queries
+fn get_optional_fields_impl_block(
+    crate_root: &Tokens,
+    props_ty: &syn::Ident,
+    fields: &[(syn::Ident, &syn::Field)],
+) -> Tokens {
+    let optional_fns: Vec<Tokens> = fields
+        .iter()
+        .filter(|&&(_, field)| is_option(&field.ty))
+        .filter_map(|&(ref name, field)| {
+            field.ident.as_ref().map(|ident| {
+                let lit = syn::Lit::Str(name.as_ref().to_string(), syn::StrStyle::Cooked);
+                let optional_fn = syn::Ident::from(format!("optional_{}", ident));
+
+                quote!(
+                    pub fn #optional_fn() -> #crate_root::__derive::OptionalField<#props_ty> {
+                        #crate_root::__derive::OptionalField::new(#lit)
+                    }
+                )
+            })
+        })
+        .collect();
+
+    if optional_fns.is_empty() {
+        return Tokens::new();
+    }
+
+    quote!(
+        impl #props_ty {
+            #(#optional_fns)*
         }
     )
 }
 
-fn get_ser_field(field: &syn::Field) -> Option<(syn::Ident, &syn::Field)> {
+// Define a struct with the name of each field in the mapping if there's an #[elastic(field_names)] attribute
+fn get_field_names(
+    crate_root: &Tokens,
+    input: &syn::MacroInput,
+    fields: &[(syn::Ident, &syn::Field)],
+) -> Option<ElasticFieldNames> {
+    if !has_field_names(&input.attrs) {
+        return None;
+    }
+
+    let doc_ty = &input.ident;
+    let vis = &input.vis;
+    let ident = syn::Ident::from(format!("{}Fields", input.ident));
+
+    let names: Vec<Tokens> = fields
+        .iter()
+        .filter_map(|&(ref name, field)| {
+            field.ident.as_ref().map(|field_ident| {
+                let lit = syn::Lit::Str(name.as_ref().to_string(), syn::StrStyle::Cooked);
+                let const_ident =
+                    syn::Ident::from(field_ident.as_ref().trim_start_matches("r#").to_uppercase());
+
+                // Fields with their own field names return them at the path of the field
+                let path_fn = if has_field_names(&field.attrs) {
+                    let field_ty = &field.ty;
+
+                    quote!(
+                        pub fn #field_ident(&self) -> <#field_ty as #crate_root::__derive::FieldNames>::Fields {
+                            let path = #crate_root::__derive::field_path(&self.path, #ident::#const_ident);
+
+                            <#field_ty as #crate_root::__derive::FieldNames>::fields_at(&path)
+                        }
+                    )
+                } else {
+                    quote!(
+                        pub fn #field_ident(&self) -> String {
+                            #crate_root::__derive::field_path(&self.path, #ident::#const_ident)
+                        }
+                    )
+                };
+
+                quote!(
+                    pub const #const_ident: &'static str = #lit;
+
+                    #path_fn
+                )
+            })
+        })
+        .collect();
+
+    Some(ElasticFieldNames {
+        definition: quote!(
+            #[allow(missing_docs, dead_code)]
+            #[derive(Clone, Debug, Default, PartialEq)]
+            #vis struct #ident {
+                path: String,
+            }
+        ),
+        impl_block: quote!(
+            #[allow(missing_docs)]
+            impl #ident {
+                #(#names)*
+            }
+
+            impl #crate_root::__derive::FieldNames for #doc_ty {
+                type Fields = #ident;
+
+                fn fields_at(path: &str) -> Self::Fields {
+                    #ident {
+                        path: path.to_owned(),
+                    }
+                }
+            }
+        ),
+    })
+}
+
+// Whether a field type mentions the given type, like `Vec<Comment>` or `Option<Box<Comment>>`
+fn ty_contains_ident(ty: &syn::Ty, ident: &syn::Ident) -> bool {
+    match *ty {
+        syn::Ty::Path(_, ref path) => path.segments.iter().any(|segment| {
+            if segment.ident == *ident {
+                return true;
+            }
+
+            match segment.parameters {
+                syn::PathParameters::AngleBracketed(ref data) => {
+                    data.types.iter().any(|ty| ty_contains_ident(ty, ident))
+                }
+                _ => false,
+            }
+        }),
+        syn::Ty::Slice(ref ty) | syn::Ty::Array(ref ty, _) | syn::Ty::Paren(ref ty) => {
+            ty_contains_ident(ty, ident)
+        }
+        syn::Ty::Ptr(ref ty) | syn::Ty::Rptr(_, ref ty) => ty_contains_ident(&ty.ty, ident),
+        syn::Ty::Tup(ref tys) => tys.iter().any(|ty| ty_contains_ident(ty, ident)),
+        _ => false,
+    }
+}
+
+// Whether a field type is an `Option<T>`
+fn is_option(ty: &syn::Ty) -> bool {
+    match *ty {
+        syn::Ty::Path(None, ref path) => path
+            .segments
+            .last()
+            .map(|segment| segment.ident.as_ref() == "Option")
+            .unwrap_or(false),
+        _ => false,
+    }
+}
+
+// Whether a container or field has an #[elastic(field_names)] attribute
+fn has_field_names(attrs: &[syn::Attribute]) -> bool {
+    get_elastic_meta_items(attrs)
+        .iter()
+        .any(|meta| expect_ident("field_names", meta))
+}
+
+// Whether a field has an #[elastic(source_exclude)] attribute
+fn is_source_excluded(field: &syn::Field) -> bool {
+    get_elastic_meta_items(&field.attrs)
+        .iter()
+        .any(|meta| expect_ident("source_exclude", meta))
+}
+
+// Whether a field has an #[elastic(map_values)] attribute
+fn has_map_values(field: &syn::Field) -> bool {
+    get_elastic_meta_items(&field.attrs)
+        .iter()
+        .any(|meta| expect_ident("map_values", meta))
+}
+
+// Whether a field can be deserialised without a value because of a #[serde(default)] attribute on it or its container
+fn has_serde_default(field: &syn::Field, container: Option<&serde_attr::Container>) -> bool {
     let ctxt = serde_derive_internals::Ctxt::new();
     let serde_field = serde_attr::Field::from_ast(&ctxt, 0, field);
 
+    // If the `serde` parse fails, let `serde` panic later
+    if ctxt.check().is_err() {
+        return true;
+    }
+
+    let field_default = match *serde_field.default() {
+        serde_attr::Default::None => false,
+        _ => true,
+    };
+
+    let container_default = match container.map(|container| container.default()) {
+        Some(&serde_attr::Default::None) | None => false,
+        _ => true,
+    };
+
+    field_default || container_default
+}
+
+// Get the default expression supplied by an #[elastic(default = "expr")] attribute
+fn get_default_from_field(field: &syn::Field) -> Option<Tokens> {
+    let val = get_elastic_meta_items(&field.attrs);
+
+    val.iter()
+        .filter_map(|meta| expect_name_value("default", meta))
+        .next()
+        .map(|expr| match get_tokens_from_lit(expr) {
+            Ok(expr) => expr,
+            Err(_) => panic!(
+                "default attributes must be of the form #[elastic(default = \"expression\")]"
+            ),
+        })
+}
+
+// Get the mapping supplied by an #[elastic(mapping = "<ident>")] attribute on a field
+fn get_mapping_from_field(field: &syn::Field) -> Option<syn::Ident> {
+    let val = get_elastic_meta_items(&field.attrs);
+
+    val.iter()
+        .filter_map(|meta| expect_name_value("mapping", meta))
+        .next()
+        .map(|mapping| match get_ident_from_lit(mapping) {
+            Ok(mapping) => mapping,
+            Err(_) => panic!(
+                "mapping attributes on a field must be of the form #[elastic(mapping = \"<ident>\")]"
+            ),
+        })
+}
+
+// Get the mapping for a field that's defined by its attributes instead of a mapping type
+fn get_mapping_from_field_attrs(
+    crate_root: &Tokens,
+    props_ty: &syn::Ident,
+    field: &syn::Field,
+) -> Option<ElasticFieldMapping> {
+    match (
+        get_alias_mapping_from_field(crate_root, props_ty, field),
+        get_string_mapping_from_field(crate_root, props_ty, field),
+    ) {
+        (Some(_), Some(_)) => panic!(
+            "alias_of attributes can't be used with keyword, no_keyword or ignore_above attributes"
+        ),
+        (alias, string) => alias.or(string),
+    }
+}
+
+// Get the mapping for a field with an #[elastic(alias_of = "path")] attribute
+fn get_alias_mapping_from_field(
+    crate_root: &Tokens,
+    props_ty: &syn::Ident,
+    field: &syn::Field,
+) -> Option<ElasticFieldMapping> {
+    let path = get_alias_path_from_field(field)?;
+
+    if get_mapping_from_field(field).is_some() {
+        panic!("alias_of attributes can't be used with a mapping supplied by #[elastic(mapping = \"<ident>\")]");
+    }
+
+    let field_ident = field
+        .ident
+        .as_ref()
+        .expect("alias_of attributes are only valid on named fields");
+    let ident = syn::Ident::from(format!("{}_{}_Mapping", props_ty, field_ident));
+
+    Some(ElasticFieldMapping {
+        ty: quote!(#ident),
+        definition: quote!(
+            #[allow(non_camel_case_types)]
+            #[derive(Default, Clone, Copy, Debug)]
+            struct #ident;
+            impl #crate_root::__derive::AliasMapping for #ident {
+                fn path() -> &'static str {
+                    #path
+                }
+            }
+        ),
+    })
+}
+
+// Get the path of the field an #[elastic(alias_of = "path")] attribute points to
+fn get_alias_path_from_field(field: &syn::Field) -> Option<syn::Lit> {
+    let val = get_elastic_meta_items(&field.attrs);
+
+    val.iter()
+        .filter_map(|meta| expect_name_value("alias_of", meta))
+        .next()
+        .map(|path| match get_str_from_lit(path) {
+            Ok(path) if !path.is_empty() => syn::Lit::Str(path.to_owned(), syn::StrStyle::Cooked),
+            _ => panic!("alias_of attributes must be of the form #[elastic(alias_of = \"path\")]"),
+        })
+}
+
+// Get the mapping for a string field from #[elastic(keyword)], #[elastic(no_keyword)] or #[elastic(ignore_above = 512)] attributes
+fn get_string_mapping_from_field(
+    crate_root: &Tokens,
+    props_ty: &syn::Ident,
+    field: &syn::Field,
+) -> Option<ElasticFieldMapping> {
+    let val = get_elastic_meta_items(&field.attrs);
+
+    let keyword = val.iter().any(|meta| expect_ident("keyword", meta));
+    let no_keyword = val.iter().any(|meta| expect_ident("no_keyword", meta));
+    let ignore_above = val
+        .iter()
+        .filter_map(|meta| expect_name_value("ignore_above", meta))
+        .next()
+        .map(|ignore_above| match *ignore_above {
+            syn::Lit::Int(len, _) if len <= u64::from(u32::max_value()) => len as u32,
+            _ => {
+                panic!("ignore_above attributes must be of the form #[elastic(ignore_above = 512)]")
+            }
+        });
+
+    let field_ident = field
+        .ident
+        .as_ref()
+        .expect("keyword, no_keyword and ignore_above attributes are only valid on named fields");
+    let ident = syn::Ident::from(format!("{}_{}_Mapping", props_ty, field_ident));
+
+    let (ty, definition) = match (keyword, no_keyword, ignore_above) {
+        (false, false, None) => return None,
+        _ if get_mapping_from_field(field).is_some() => panic!("keyword, no_keyword and ignore_above attributes can't be used with a mapping supplied by #[elastic(mapping = \"<ident>\")]"),
+        (true, true, _) => panic!("keyword and no_keyword attributes can't be used on the same field"),
+        (false, true, Some(_)) => panic!("ignore_above attributes can't be used with a no_keyword attribute, because there's no `keyword` sub-field"),
+        (true, false, None) => (
+            quote!(#crate_root::__derive::DefaultKeywordMapping),
+            Tokens::new(),
+        ),
+        (false, true, None) => (
+            quote!(#crate_root::__derive::DefaultTextMapping),
+            Tokens::new(),
+        ),
+        (true, false, Some(ignore_above)) => (
+            quote!(#ident),
+            quote!(
+                #[allow(non_camel_case_types)]
+                #[derive(Default, Clone, Copy, Debug)]
+                struct #ident;
+                impl #crate_root::__derive::KeywordMapping for #ident {
+                    fn ignore_above() -> Option<u32> {
+                        Some(#ignore_above)
+                    }
+                }
+            ),
+        ),
+        (false, false, Some(ignore_above)) => (
+            quote!(#ident),
+            quote!(
+                #[allow(non_camel_case_types)]
+                #[derive(Default, Clone, Copy, Debug)]
+                struct #ident;
+                impl #crate_root::__derive::TextMapping for #ident {
+                    fn fields() -> Option<::std::collections::BTreeMap<&'static str, #crate_root::__derive::StringField>> {
+                        Some(#crate_root::__derive::keyword_sub_fields_with_ignore_above("keyword", Some(#ignore_above)))
+                    }
+                }
+            ),
+        ),
+    };
+
+    Some(ElasticFieldMapping { ty, definition })
+}
+
+// Get the name of the function that returns the default value for a field
+fn get_default_fn_ident(field: &syn::Field) -> syn::Ident {
+    let field = field
+        .ident
+        .as_ref()
+        .expect("default attributes are only valid on named fields");
+
+    syn::Ident::from(format!("default_{}", field))
+}
+
+// Get the `serde` attributes on the container, like #[serde(rename_all = "rule")]
+fn get_ser_container(input: &syn::MacroInput) -> Option<serde_attr::Container> {
+    let ctxt = serde_derive_internals::Ctxt::new();
+    let container = serde_attr::Container::from_ast(&ctxt, input);
+
+    // If the `serde` parse fails, return `None` and let `serde` panic later
+    match ctxt.check() {
+        Err(_) => None,
+        _ => Some(container),
+    }
+}
+
+// Get the name of a field in the mapping, or `None` if the field isn't mapped
+// Parses #[elastic(skip)] and #[elastic(rename = "name")], then falls back to `serde`s attributes
+fn get_ser_field<'a>(
+    field: &'a syn::Field,
+    container: Option<&serde_attr::Container>,
+) -> Option<(syn::Ident, &'a syn::Field)> {
+    let val = get_elastic_meta_items(&field.attrs);
+
+    if val.iter().any(|meta| expect_ident("skip", meta)) {
+        return None;
+    }
+
+    let rename = val
+        .iter()
+        .filter_map(|meta| expect_name_value("rename", meta))
+        .next()
+        .map(|name| match get_ident_from_lit(name) {
+            Ok(name) => name,
+            Err(_) => panic!("rename attributes must be of the form #[elastic(rename = \"name\")]"),
+        });
+
+    let ctxt = serde_derive_internals::Ctxt::new();
+    let mut serde_field = serde_attr::Field::from_ast(&ctxt, 0, field);
+
     // If the `serde` parse fails, return `None` and let `serde` panic later
     match ctxt.check() {
         Err(_) => return None,
         _ => (),
     };
 
+    // Apply a `#[serde(rename_all)]` rule, unless the field is explicitly renamed
+    if let Some(container) = container {
+        serde_field.rename_by_rule(container.rename_all());
+    }
+
+    // Aliases can't be indexed, so they're mapped even though they're not serialised
+    let is_alias = get_alias_path_from_field(field).is_some();
+    if is_alias && !serde_field.skip_serializing() {
+        panic!("alias_of attributes are only valid on fields with a #[serde(skip)] or #[serde(skip_serializing)] attribute, because an alias can't be indexed");
+    }
+
     // Get all fields on struct where there isn't `skip_serializing`
-    if serde_field.skip_serializing() {
+    if serde_field.skip_serializing() && !is_alias {
         return None;
     }
 
-    Some((
-        syn::Ident::from(serde_field.name().serialize_name().as_ref()),
-        field,
-    ))
+    let name =
+        rename.unwrap_or_else(|| syn::Ident::from(serde_field.name().serialize_name().as_ref()));
+
+    Some((name, field))
 }
 
 quick_error! {
     #[derive(Debug)]
     pub enum DeriveElasticTypeError {
         InvalidInput {
-            display("deriving a document type is only valid for structs")
+            display("deriving a document type is only valid for structs and enums where all variants are units")
         }
     }
 }
@@ -435,6 +1307,16 @@ fn get_method_from_struct(item: &syn::MacroInput, method: &str) -> Option<Method
     None
 }
 
+// Get the name of a field supplied by an #[elastic(method = "field")] attribute
+fn get_field_from_struct(item: &syn::MacroInput, method: &str) -> Option<syn::Ident> {
+    let val = get_elastic_meta_items(&item.attrs);
+
+    val.iter()
+        .filter_map(|meta| expect_name_value(method, meta))
+        .next()
+        .and_then(|field| get_ident_from_lit(field).ok())
+}
+
 fn get_method_from_fields(
     fields: &[(syn::Ident, &syn::Field)],
     method: &str,
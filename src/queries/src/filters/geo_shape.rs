@@ -0,0 +1,151 @@
+use serde::{
+    self,
+    de::Visitor,
+    ser::{
+        Serialize,
+        SerializeMap,
+        Serializer,
+    },
+};
+use std::fmt;
+
+/// A `geo_shape` filter that matches documents against a shape indexed in another document.
+///
+/// Large shapes, like the borders of a country, can be indexed once and referenced by id
+/// instead of being sent in every query.
+#[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
+pub struct GeoShapeFilter {
+    pub geo_shape: GeoShapeField,
+}
+
+impl GeoShapeFilter {
+    pub fn indexed_shape(field: &str, shape: IndexedShape) -> GeoShapeFilter {
+        GeoShapeFilter {
+            geo_shape: GeoShapeField {
+                field: field.to_string(),
+                params: GeoShapeParams {
+                    indexed_shape: shape,
+                    relation: None,
+                },
+            },
+        }
+    }
+
+    pub fn relation(mut self, relation: GeoShapeRelation) -> GeoShapeFilter {
+        self.geo_shape.params.relation = Some(relation);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
+pub struct GeoShapeParams {
+    pub indexed_shape: IndexedShape,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relation: Option<GeoShapeRelation>,
+}
+
+/// A reference to a shape in another document.
+#[derive(Builder, Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
+pub struct IndexedShape {
+    index: String,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    #[builder(default = "None")]
+    ty: Option<String>,
+    id: String,
+    // The field in the document containing the shape. Defaults to `shape`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default = "None")]
+    path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[builder(default = "None")]
+    routing: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum GeoShapeRelation {
+    Intersects,
+    Disjoint,
+    Within,
+    Contains,
+}
+
+#[derive(Clone, Debug, Ord, PartialOrd, Eq, PartialEq)]
+pub struct GeoShapeField {
+    pub field: String,
+    pub params: GeoShapeParams,
+}
+
+impl Serialize for GeoShapeField {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry(&self.field, &self.params)?;
+        map.end()
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for GeoShapeField {
+    fn deserialize<D>(deserializer: D) -> Result<GeoShapeField, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_map(GeoShapeFilterVisitor)
+    }
+}
+
+pub(crate) struct GeoShapeFilterVisitor;
+
+impl<'de> Visitor<'de> for GeoShapeFilterVisitor {
+    type Value = GeoShapeField;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a geo_shape filter structure")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        use serde::de::Error;
+        let field: String = map.next_key()?.ok_or(A::Error::custom("expected field"))?;
+        let value: GeoShapeParams = map.next_value()?;
+
+        Ok(GeoShapeField {
+            field: field,
+            params: value,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json;
+
+    #[test]
+    fn geo_shape_filter_new() {
+        let shape = IndexedShapeBuilder::default()
+            .index("shapes".to_string())
+            .id("deu".to_string())
+            .path(Some("location".to_string()))
+            .build()
+            .unwrap();
+
+        let f = GeoShapeFilter::indexed_shape("location", shape).relation(GeoShapeRelation::Within);
+
+        let o = r#"{"geo_shape":{"location":{"indexed_shape":{"index":"shapes","id":"deu","path":"location"},"relation":"within"}}}"#;
+        let j = serde_json::to_string(&f).unwrap();
+        assert_eq!(o, j);
+    }
+
+    #[test]
+    fn geo_shape_filter() {
+        let o = r#"{"geo_shape":{"location":{"indexed_shape":{"index":"shapes","type":"_doc","id":"deu"}}}}"#;
+        let s: GeoShapeFilter = serde_json::from_str(o).unwrap();
+        let j = serde_json::to_string(&s).unwrap();
+        assert_eq!(o, j);
+    }
+}
@@ -1,5 +1,6 @@
 pub(crate) mod common;
 pub(crate) mod exists;
+pub(crate) mod geo_shape;
 pub(crate) mod matchfilter;
 pub(crate) mod range;
 pub(crate) mod term;
@@ -7,6 +8,7 @@ pub(crate) mod wildcard;
 
 pub(crate) use self::{
     exists::ExistsFilter,
+    geo_shape::GeoShapeFilter,
     matchfilter::MatchFilter,
     range::RangeFilter,
     term::TermFilter,
@@ -23,6 +25,7 @@ pub enum Filters {
     #[serde(rename = "match")]
     match_(MatchFilter),
     wildcard(WildcardFilter),
+    geo_shape(GeoShapeFilter),
 }
 
 impl From<RangeFilter> for Filters {
@@ -54,3 +57,9 @@ impl From<WildcardFilter> for Filters {
         Filters::wildcard(w)
     }
 }
+
+impl From<GeoShapeFilter> for Filters {
+    fn from(g: GeoShapeFilter) -> Self {
+        Filters::geo_shape(g)
+    }
+}
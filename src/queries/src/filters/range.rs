@@ -34,25 +34,25 @@ impl RangeFilter {
 pub struct RangeParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default = "None")]
-    gte: Option<Values>,
+    pub(crate) gte: Option<Values>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default = "None")]
-    gt: Option<Values>,
+    pub(crate) gt: Option<Values>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default = "None")]
-    lte: Option<Values>,
+    pub(crate) lte: Option<Values>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default = "None")]
-    lt: Option<Values>,
+    pub(crate) lt: Option<Values>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default = "None")]
-    format: Option<EsDateFormat>,
+    pub(crate) format: Option<EsDateFormat>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default = "None")]
-    time_zone: Option<Values>,
+    pub(crate) time_zone: Option<Values>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[builder(default = "None")]
-    boost: Option<String>,
+    pub(crate) boost: Option<String>,
 }
 
 #[derive(Clone, Debug, Ord, PartialOrd, Eq, PartialEq)]
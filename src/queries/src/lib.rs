@@ -21,7 +21,10 @@ use aggregations::{
     EsAggregation,
 };
 use filters::Filters;
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    str::FromStr,
+};
 
 #[derive(Clone, Debug, Serialize, Deserialize, Ord, PartialOrd, Eq, PartialEq)]
 #[serde(untagged)]
@@ -61,90 +64,53 @@ pub struct Bool {
 }
 
 impl Bool {
-    fn add_filter(&mut self, section: BoolQuerySections, f: Filters) {
+    /// The filters in a section of the bool query.
+    pub fn filters(&self, section: BoolQuerySections) -> &[Filters] {
+        use BoolQuerySections::*;
+
+        let filters = match section {
+            Must => &self.must,
+            Should => &self.should,
+            Filter => &self.filter,
+            MustNot => &self.must_not,
+        };
+
+        filters.as_ref().map(|ve| ve.as_slice()).unwrap_or(&[])
+    }
+
+    fn section_mut(&mut self, section: BoolQuerySections) -> &mut Option<Vec<Filters>> {
         use BoolQuerySections::*;
 
         match section {
-            Must => {
-                if let Some(ref mut ve) = self.must {
-                    if let Err(i) = ve.binary_search(&f) {
-                        ve.insert(i, f);
-                    }
-                } else {
-                    self.must = Some(vec![f]);
-                }
-            }
-            Should => {
-                if let Some(ref mut ve) = self.should {
-                    if let Err(i) = ve.binary_search(&f) {
-                        ve.insert(i, f);
-                    }
-                } else {
-                    self.should = Some(vec![f]);
-                }
-            }
-            Filter => {
-                if let Some(ref mut ve) = self.filter {
-                    if let Err(i) = ve.binary_search(&f) {
-                        ve.insert(i, f);
-                    }
-                } else {
-                    self.filter = Some(vec![f]);
-                }
-            }
-            MustNot => {
-                if let Some(ref mut ve) = self.must_not {
-                    if let Err(i) = ve.binary_search(&f) {
-                        ve.insert(i, f);
-                    }
-                } else {
-                    self.must_not = Some(vec![f]);
-                }
-            }
+            Must => &mut self.must,
+            Should => &mut self.should,
+            Filter => &mut self.filter,
+            MustNot => &mut self.must_not,
+        }
+    }
+
+    fn add_filter(&mut self, section: BoolQuerySections, f: Filters) {
+        let ve = self.section_mut(section).get_or_insert_with(Vec::new);
+
+        // Filters parsed from a stored query may not be sorted
+        if !ve.contains(&f) {
+            let i = ve.binary_search(&f).unwrap_or_else(|i| i);
+            ve.insert(i, f);
         }
     }
 
     fn remove_filter(&mut self, section: BoolQuerySections, f: Filters) {
-        use BoolQuerySections::*;
+        let section = self.section_mut(section);
 
-        match section {
-            Must => {
-                if let Some(ref mut ve) = self.must {
-                    if let Ok(i) = ve.binary_search(&f) {
-                        ve.remove(i);
-                    }
-                } else {
-                    self.must = Some(vec![f]);
-                }
-            }
-            Should => {
-                if let Some(ref mut ve) = self.should {
-                    if let Ok(i) = ve.binary_search(&f) {
-                        ve.remove(i);
-                    }
-                } else {
-                    self.should = Some(vec![f]);
-                }
-            }
-            Filter => {
-                if let Some(ref mut ve) = self.filter {
-                    if let Ok(i) = ve.binary_search(&f) {
-                        ve.remove(i);
-                    }
-                } else {
-                    self.filter = Some(vec![f]);
-                }
-            }
-            MustNot => {
-                if let Some(ref mut ve) = self.must_not {
-                    if let Ok(i) = ve.binary_search(&f) {
-                        ve.remove(i);
-                    }
-                } else {
-                    self.must_not = Some(vec![f]);
-                }
+        if let Some(ref mut ve) = *section {
+            if let Some(i) = ve.iter().position(|existing| *existing == f) {
+                ve.remove(i);
             }
         }
+
+        if section.as_ref().map(|ve| ve.is_empty()).unwrap_or(false) {
+            *section = None;
+        }
     }
 }
 
@@ -173,18 +139,35 @@ pub struct Query {
     pub aggs: Option<HashMap<String, Aggregation>>,
 }
 
+/// Parse a query from its JSON representation, such as a query stored in a percolator field or config file.
+impl FromStr for Query {
+    type Err = serde_json::Error;
+
+    fn from_str(query: &str) -> Result<Query, serde_json::Error> {
+        serde_json::from_str(query)
+    }
+}
+
 impl Query {
     pub fn to_string(&self) -> Result<String, serde_json::Error> {
         serde_json::to_string(&self)
     }
 
+    /// The filters in a section of the bool query.
+    pub fn filters(&self, section: BoolQuerySections) -> &[Filters] {
+        match self.query {
+            Some(ref query) => query.bool.filters(section),
+            None => &[],
+        }
+    }
+
     pub fn add_filter(&mut self, section: BoolQuerySections, f: Filters) {
         if let Some(ref mut query) = self.query {
             query.bool.add_filter(section, f);
         }
     }
 
-    fn remove_filter(&mut self, section: BoolQuerySections, f: Filters) {
+    pub(crate) fn remove_filter(&mut self, section: BoolQuerySections, f: Filters) {
         if let Some(ref mut query) = self.query {
             query.bool.remove_filter(section, f);
         }
@@ -498,4 +481,41 @@ mod tests {
             "#;
         let _s: Query = super::serde_json::from_str(simple).unwrap();
     }
+
+    #[test]
+    fn stored_query_round_trip() {
+        let j = r#"{"query":{"bool":{"must":[{"term":{"status":"published"}},{"match":{"title":"Search"}}],"filter":[{"range":{"publish_date":{"gte":"2015-01-01"}}}]}}}"#;
+
+        let mut s: Query = j.parse().unwrap();
+
+        assert_eq!(2, s.filters(BoolQuerySections::Must).len());
+        match s.filters(BoolQuerySections::Filter)[0] {
+            Filters::range(ref r) => {
+                assert_eq!("publish_date", r.range.field);
+                assert_eq!(
+                    Some(Values::String("2015-01-01".to_owned())),
+                    r.range.params.gte
+                );
+            }
+            ref f => panic!("expected a range filter, got {:?}", f),
+        }
+
+        s.remove_filter(
+            BoolQuerySections::Must,
+            Filters::term(TermFilter::new(
+                String::from("status"),
+                Values::String(String::from("published")),
+            )),
+        );
+        s.add_filter(
+            BoolQuerySections::MustNot,
+            Filters::term(TermFilter::new(
+                String::from("status"),
+                Values::String(String::from("draft")),
+            )),
+        );
+
+        let expected = r#"{"query":{"bool":{"must":[{"match":{"title":"Search"}}],"filter":[{"range":{"publish_date":{"gte":"2015-01-01"}}}],"must_not":[{"term":{"status":"draft"}}]}}}"#;
+        assert_eq!(expected, s.to_string().unwrap());
+    }
 }
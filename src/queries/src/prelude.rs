@@ -1,10 +1,39 @@
+pub use aggregations::{
+    Aggregation,
+    BucketAggregation,
+};
 pub use filters::{
-    common::EsDateFormat,
+    common::{
+        EsDateFormat,
+        FieldAndValue,
+    },
+    exists::{
+        ExistsField,
+        ExistsFilter,
+    },
+    geo_shape::{
+        GeoShapeFilter,
+        GeoShapeRelation,
+        IndexedShape,
+        IndexedShapeBuilder,
+    },
+    matchfilter::MatchFilter,
     range::{
+        RangeField,
         RangeFilter,
+        RangeParams,
         RangeParamsBuilder,
     },
+    term::TermFilter,
+    wildcard::{
+        WildcardFilter,
+        WildcardVariants,
+    },
+    Filters,
 };
+pub use Bool;
 pub use BoolQuerySections;
 pub use Query;
 pub use QueryBuilder;
+pub use QueryField;
+pub use Values;